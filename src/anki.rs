@@ -0,0 +1,116 @@
+use anyhow::{Context, Result, bail};
+use std::path::Path;
+
+use crate::api::client::LeetCodeClient;
+use crate::api::site::site;
+use crate::api::types::{Category, QuestionDetail};
+use crate::app::load_cached_problems;
+use crate::config::Config;
+use crate::notes;
+
+/// Header lines telling Anki how to read the file.
+const HEADER: &str = "#separator:tab\n#html:true\n#tags column:3\n";
+
+pub struct AnkiReport {
+    pub cards: usize,
+    pub failed: Vec<String>,
+}
+
+/// Write one card per problem of favorite list `list` (or every solved
+/// problem) to `out`, as a tab-separated file Anki can import. The front
+/// holds the statement up to its examples, the back the problem's notes.
+pub async fn export(
+    client: &LeetCodeClient,
+    config: &Config,
+    list: Option<&str>,
+    out: &Path,
+) -> Result<AnkiReport> {
+    if !config.is_authenticated() {
+        bail!("Not logged in. Start leetui and log in first (press S for settings).");
+    }
+    let slugs = match list {
+        Some(name) => {
+            let lists = client.fetch_favorites().await?;
+            let list = lists
+                .into_iter()
+                .find(|l| l.name.eq_ignore_ascii_case(name))
+                .with_context(|| format!("No list named \"{name}\""))?;
+            list.questions.into_iter().map(|q| q.title_slug).collect()
+        }
+        None => solved_slugs(client).await?,
+    };
+
+    let workspace = config.expanded_workspace();
+    let mut file = String::from(HEADER);
+    let mut cards = 0;
+    let mut failed = Vec::new();
+    for slug in slugs {
+        eprintln!("{slug}");
+        match client.fetch_problem_detail(&slug).await {
+            Ok(mut detail) => {
+                detail.localize(config.locale.as_deref());
+                let back = notes::load(&workspace, &slug);
+                file.push_str(&card(&detail, back.as_deref()));
+                cards += 1;
+            }
+            Err(e) => failed.push(format!("{slug}: {e}")),
+        }
+    }
+    std::fs::write(out, file).with_context(|| format!("Failed to write {}", out.display()))?;
+    Ok(AnkiReport { cards, failed })
+}
+
+/// Slugs of every accepted problem, from the cached list when there is one.
+async fn solved_slugs(client: &LeetCodeClient) -> Result<Vec<String>> {
+    let problems = match load_cached_problems(Category::All) {
+        Some(problems) => problems,
+        None => client.fetch_problems(Category::All, 5000, 0, None, None).await?.0,
+    };
+    Ok(problems
+        .into_iter()
+        .filter(|p| p.status.as_deref() == Some("ac"))
+        .map(|p| p.title_slug)
+        .collect())
+}
+
+/// One line of the import file: front, back, tags.
+fn card(detail: &QuestionDetail, notes: Option<&str>) -> String {
+    let front = format!(
+        "<b>{}. {}</b> ({})<br>{}",
+        detail.frontend_question_id,
+        detail.title,
+        detail.difficulty,
+        statement_summary(detail.content.as_deref().unwrap_or_default())
+    );
+    let mut back = match notes {
+        Some(md) => {
+            let mut html = String::new();
+            pulldown_cmark::html::push_html(&mut html, pulldown_cmark::Parser::new(md));
+            html
+        }
+        None => "<i>No notes yet.</i>".to_string(),
+    };
+    let url = site().problem_url(&detail.title_slug);
+    back.push_str(&format!("<br><a href=\"{url}\">{url}</a>"));
+
+    let mut tags = vec![format!("leetcode::{}", detail.difficulty.to_lowercase())];
+    tags.extend(detail.topic_tags.iter().map(|t| format!("leetcode::{}", t.slug)));
+    format!("{}\t{}\t{}\n", field(&front), field(&back), tags.join(" "))
+}
+
+/// The statement before its first example.
+fn statement_summary(html: &str) -> &str {
+    let end = ["<strong class=\"example\">", "Example 1"]
+        .iter()
+        .filter_map(|marker| html.find(marker))
+        .min()
+        .unwrap_or(html.len());
+    let summary = html[..end].trim_end();
+    // Drop the paragraph the example heading opened
+    summary.strip_suffix("<p>").unwrap_or(summary).trim_end()
+}
+
+/// Anki fields can't hold raw tabs or newlines.
+fn field(html: &str) -> String {
+    html.replace('\t', " ").replace("\r\n", "\n").replace('\n', " ")
+}
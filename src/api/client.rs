@@ -1,28 +1,97 @@
 use anyhow::{Context, Result, bail};
-use reqwest::{Client, RequestBuilder, cookie::Jar};
+use reqwest::{Client, ClientBuilder, Proxy, RequestBuilder, Response, StatusCode, cookie::Jar};
 use serde_json::json;
 use std::sync::Arc;
 
-use super::queries::{FAVORITES_LIST_QUERY, GLOBAL_DATA_QUERY, PROBLEM_LIST_QUERY, QUESTION_DETAIL_QUERY, USER_PROFILE_QUERY};
+use super::queries::{
+    COMMUNITY_SOLUTION_QUERY, COMMUNITY_SOLUTIONS_QUERY, COMPANY_PROBLEMS_QUERY, COMPANY_TAGS_QUERY, CONTEST_HISTORY_QUERY, OFFICIAL_SOLUTION_QUERY, CONTEST_LIST_QUERY, DAILY_CHALLENGE_QUERY, STUDY_PLAN_DETAIL_QUERY,
+    STUDY_PLAN_PROGRESS_QUERY, DAILY_CHALLENGE_QUERY_CN, FAVORITES_LIST_QUERY, GLOBAL_DATA_QUERY,
+    GLOBAL_DATA_QUERY_CN, PROBLEM_LIST_QUERY, PROBLEM_LIST_QUERY_CN, QUESTION_DETAIL_QUERY,
+    SKILL_STATS_QUERY, SUBMISSION_DETAILS_QUERY, SUBMISSION_LIST_QUERY, USER_PROFILE_QUERY, USER_PROGRESS_QUERY_CN,
+};
+use super::site::{Site, site};
+use super::throttle::{SendExt, Throttle};
 use super::types::*;
 
-const LEETCODE_GRAPHQL: &str = "https://leetcode.com/graphql";
-const LEETCODE_RUN: &str = "https://leetcode.com/problems/{slug}/interpret_solution/";
-const LEETCODE_SUBMIT: &str = "https://leetcode.com/problems/{slug}/submit/";
-const LEETCODE_CHECK: &str = "https://leetcode.com/submissions/detail/{id}/check/";
-const LEETCODE_LIST_API: &str = "https://leetcode.com/list/api/";
-const LEETCODE_LIST_QUESTIONS_API: &str = "https://leetcode.com/list/api/questions";
+const LEETCODE_RUN: &str = "/problems/{slug}/interpret_solution/";
+const LEETCODE_SUBMIT: &str = "/problems/{slug}/submit/";
+const LEETCODE_CHECK: &str = "/submissions/detail/{id}/check/";
+const LEETCODE_LIST_API: &str = "/list/api/";
+const LEETCODE_LIST_QUESTIONS_API: &str = "/list/api/questions";
+const LEETCODE_CONTEST_INFO: &str = "/contest/api/info/{slug}/";
+
+/// How many past contests the contest browser shows.
+const PAST_CONTESTS: usize = 50;
+
+/// Upper bound on a company list; "all time" for the largest companies is
+/// under this.
+const COMPANY_PROBLEMS_LIMIT: usize = 3000;
+
+/// The stored session cookie was rejected: LeetCode answered 401/403 or
+/// bounced the request to the login page. Returned as the root cause of an
+/// `anyhow::Error`, so callers can check `err.is::<AuthExpired>()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuthExpired;
+
+impl std::fmt::Display for AuthExpired {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LeetCode session expired, please log in again")
+    }
+}
+
+impl std::error::Error for AuthExpired {}
+
+pub const DEFAULT_RETRIES: u32 = 3;
+pub const DEFAULT_RATE_LIMIT: f64 = 5.0;
+
+/// Connection settings that don't depend on the signed-in user.
+#[derive(Debug, Clone)]
+pub struct ClientOptions {
+    /// `http://`, `https://`, `socks5://` or `socks5h://` proxy for all
+    /// requests. When unset, `HTTPS_PROXY` / `ALL_PROXY` are honored.
+    pub proxy: Option<String>,
+    /// How many times a failed request is retried.
+    pub retries: u32,
+    /// Requests per second; 0 turns rate limiting off.
+    pub rate_limit: f64,
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            proxy: None,
+            retries: DEFAULT_RETRIES,
+            rate_limit: DEFAULT_RATE_LIMIT,
+        }
+    }
+}
+
+/// An HTTP client going through `options.proxy`, if set. Requests to other
+/// hosts (GitHub gists) use one too, so they take the same route.
+pub fn client_builder(options: &ClientOptions) -> Result<ClientBuilder> {
+    let mut builder = Client::builder();
+    if let Some(proxy) = options.proxy.as_deref().filter(|p| !p.trim().is_empty()) {
+        let proxy =
+            Proxy::all(proxy.trim()).with_context(|| format!("Invalid proxy URL \"{proxy}\""))?;
+        builder = builder.proxy(proxy);
+    }
+    Ok(builder)
+}
 
 #[derive(Clone)]
 pub struct LeetCodeClient {
     client: Client,
     csrf_token: Option<String>,
+    has_session: bool,
+    site: Site,
+    throttle: Throttle,
 }
 
 impl LeetCodeClient {
-    pub fn new(session: Option<&str>, csrf: Option<&str>) -> Result<Self> {
+    pub fn new(session: Option<&str>, csrf: Option<&str>, options: &ClientOptions) -> Result<Self> {
+        let site = site();
         let jar = Arc::new(Jar::default());
-        let url = "https://leetcode.com".parse().unwrap();
+        let url = site.base().parse().context("Invalid LeetCode base_url")?;
 
         if let Some(session) = session {
             if !session.is_empty() {
@@ -35,7 +104,7 @@ impl LeetCodeClient {
             }
         }
 
-        let client = Client::builder()
+        let client = client_builder(options)?
             .cookie_provider(jar)
             .build()
             .context("Failed to create HTTP client")?;
@@ -43,14 +112,29 @@ impl LeetCodeClient {
         Ok(Self {
             client,
             csrf_token: csrf.map(String::from),
+            has_session: session.is_some_and(|s| !s.is_empty()),
+            site,
+            throttle: Throttle::new(options.rate_limit, options.retries),
         })
     }
 
+    /// Map a rejected session to [`AuthExpired`]. Only applies when we sent a
+    /// session cookie; Cloudflare challenges (also 403) are passed through.
+    fn check_session(&self, resp: Response) -> Result<Response> {
+        let rejected = matches!(resp.status(), StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+            && !resp.headers().contains_key("cf-mitigated");
+        let to_login = resp.url().path().starts_with("/accounts/login");
+        if self.has_session && (rejected || to_login) {
+            return Err(AuthExpired.into());
+        }
+        Ok(resp)
+    }
+
     fn auth_request(&self, builder: RequestBuilder) -> RequestBuilder {
         let builder = builder
             .header("Content-Type", "application/json")
-            .header("Origin", "https://leetcode.com")
-            .header("Referer", "https://leetcode.com");
+            .header("Origin", self.site.base())
+            .header("Referer", self.site.base());
         if let Some(ref token) = self.csrf_token {
             builder.header("x-csrftoken", token)
         } else {
@@ -60,6 +144,7 @@ impl LeetCodeClient {
 
     pub async fn fetch_problems(
         &self,
+        category: Category,
         limit: i32,
         skip: i32,
         difficulty: Option<&str>,
@@ -73,10 +158,11 @@ impl LeetCodeClient {
             filters["searchKeywords"] = json!(kw);
         }
 
+        let query = if self.site.is_cn() { PROBLEM_LIST_QUERY_CN } else { PROBLEM_LIST_QUERY };
         let body = json!({
-            "query": PROBLEM_LIST_QUERY,
+            "query": query,
             "variables": {
-                "categorySlug": "all-code-essentials",
+                "categorySlug": category.slug(),
                 "limit": limit,
                 "skip": skip,
                 "filters": filters,
@@ -84,9 +170,9 @@ impl LeetCodeClient {
         });
 
         let resp = self
-            .auth_request(self.client.post(LEETCODE_GRAPHQL))
+            .auth_request(self.client.post(self.site.graphql_url()))
             .json(&body)
-            .send()
+            .send_retrying(&self.throttle)
             .await
             .context("Failed to send problem list request")?;
 
@@ -95,12 +181,18 @@ impl LeetCodeClient {
             .await
             .context("Failed to parse problem list response")?;
 
-        let list = data
-            .data
-            .and_then(|d| d.problemset_question_list)
-            .context("No problem list data in response")?;
+        let list = data.field(
+            |d| d.problemset_question_list,
+            "No problem list data in response",
+        )?;
 
-        Ok((list.questions, list.total))
+        let mut questions = list.questions;
+        if self.site.is_cn() {
+            for q in &mut questions {
+                normalize_problem(&mut q.difficulty, &mut q.status);
+            }
+        }
+        Ok((questions, list.total))
     }
 
     pub async fn fetch_problem_detail(&self, slug: &str) -> Result<QuestionDetail> {
@@ -112,10 +204,10 @@ impl LeetCodeClient {
         });
 
         let resp = self
-            .auth_request(self.client.post(LEETCODE_GRAPHQL))
-            .header("Referer", format!("https://leetcode.com/problems/{slug}/"))
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .header("Referer", self.site.problem_url(slug))
             .json(&body)
-            .send()
+            .send_retrying(&self.throttle)
             .await
             .context("Failed to send problem detail request")?;
 
@@ -124,9 +216,267 @@ impl LeetCodeClient {
             .await
             .context("Failed to parse problem detail response")?;
 
-        data.data
-            .and_then(|d| d.question)
-            .context("No question data in response")
+        let mut detail = data.field(|d| d.question, format!("Problem \"{slug}\" not found"))?;
+        if self.site.is_cn() {
+            normalize_problem(&mut detail.difficulty, &mut detail.status);
+        }
+        Ok(detail)
+    }
+
+    /// An image from a problem statement, usually on LeetCode's asset host.
+    pub async fn fetch_image(&self, url: &str) -> Result<Vec<u8>> {
+        let resp = self
+            .client
+            .get(url)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to fetch image")?
+            .error_for_status()?;
+        Ok(resp.bytes().await?.to_vec())
+    }
+
+    /// The problem's editorial, or None if it has none.
+    pub async fn fetch_editorial(&self, slug: &str) -> Result<Option<OfficialSolution>> {
+        let body = json!({
+            "query": OFFICIAL_SOLUTION_QUERY,
+            "variables": {
+                "titleSlug": slug,
+            }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .header("Referer", self.site.problem_url(slug))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send editorial request")?;
+
+        let data: GraphQLResponse<OfficialSolutionData> = resp
+            .json()
+            .await
+            .context("Failed to parse editorial response")?;
+
+        let question = data.field(|d| d.question, format!("Problem \"{slug}\" not found"))?;
+        Ok(question.solution)
+    }
+
+    /// A page of community solution posts, most upvoted first, and how many
+    /// there are in all.
+    pub async fn fetch_community_solutions(
+        &self,
+        slug: &str,
+        skip: usize,
+        first: usize,
+    ) -> Result<(Vec<CommunitySolution>, i32)> {
+        if self.site.is_cn() {
+            bail!("Community solutions aren't available for leetcode.cn yet");
+        }
+        let body = json!({
+            "query": COMMUNITY_SOLUTIONS_QUERY,
+            "variables": {
+                "questionSlug": slug,
+                "skip": skip,
+                "first": first,
+                "orderBy": "most_votes",
+            }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .header("Referer", self.site.problem_url(slug))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send community solutions request")?;
+
+        let data: GraphQLResponse<CommunitySolutionsData> = resp
+            .json()
+            .await
+            .context("Failed to parse community solutions response")?;
+
+        let list = data.field(
+            |d| d.question_solutions,
+            "No community solutions in response",
+        )?;
+        Ok((list.solutions, list.total_num))
+    }
+
+    /// The Markdown body of a community solution post.
+    pub async fn fetch_community_solution(&self, topic_id: i64) -> Result<String> {
+        let body = json!({
+            "query": COMMUNITY_SOLUTION_QUERY,
+            "variables": { "topicId": topic_id }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send solution post request")?;
+
+        let data: GraphQLResponse<CommunityPostData> = resp
+            .json()
+            .await
+            .context("Failed to parse solution post response")?;
+
+        let topic = data.field(|d| d.topic, "Solution post not found")?;
+        Ok(topic.post.content)
+    }
+
+    pub async fn fetch_daily_challenge(&self) -> Result<DailyChallenge> {
+        if self.site.is_cn() {
+            return self.fetch_daily_challenge_cn().await;
+        }
+        let body = json!({
+            "query": DAILY_CHALLENGE_QUERY,
+            "variables": {}
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send daily challenge request")?;
+
+        let data: GraphQLResponse<DailyChallengeData> = resp
+            .json()
+            .await
+            .context("Failed to parse daily challenge response")?;
+
+        data.field(
+            |d| d.active_daily_coding_challenge_question,
+            "No daily challenge in response",
+        )
+    }
+
+    async fn fetch_daily_challenge_cn(&self) -> Result<DailyChallenge> {
+        let body = json!({
+            "query": DAILY_CHALLENGE_QUERY_CN,
+            "variables": {}
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send daily challenge request")?;
+
+        let data: GraphQLResponse<DailyChallengeDataCn> = resp
+            .json()
+            .await
+            .context("Failed to parse daily challenge response")?;
+
+        data.field(
+            |d| d.today_record.and_then(|r| r.into_iter().next()),
+            "No daily challenge in response",
+        )
+    }
+
+    /// One page of the signed-in user's submissions for a problem, newest first.
+    pub async fn fetch_submissions(
+        &self,
+        slug: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<SubmissionPage> {
+        let body = json!({
+            "query": SUBMISSION_LIST_QUERY,
+            "variables": {
+                "questionSlug": slug,
+                "offset": offset,
+                "limit": limit,
+                "lastKey": null,
+            }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .header("Referer", self.site.problem_url(slug))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send submission list request")?;
+        let resp = self.check_session(resp)?;
+
+        let data: GraphQLResponse<SubmissionListData> = resp
+            .json()
+            .await
+            .context("Failed to parse submission list response")?;
+
+        let errors = data.errors_message();
+        match data.data.and_then(|d| d.question_submission_list) {
+            Some(page) => Ok(page),
+            // Signed-out requests get a null list rather than an HTTP error
+            None if self.has_session && errors.as_deref().is_none_or(is_auth_error) => {
+                Err(AuthExpired.into())
+            }
+            None => match errors {
+                Some(errors) => bail!("LeetCode: {errors}"),
+                None => bail!("No submission data in response (are you logged in?)"),
+            },
+        }
+    }
+
+    /// A past submission of the signed-in user, with its code.
+    pub async fn fetch_submission_detail(&self, id: &str) -> Result<SubmissionDetail> {
+        let submission_id: i64 = id
+            .parse()
+            .with_context(|| format!("Invalid submission id: {id}"))?;
+        let body = json!({
+            "query": SUBMISSION_DETAILS_QUERY,
+            "variables": { "submissionId": submission_id }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .header("Referer", self.site.url(&format!("/submissions/detail/{id}/")))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send submission details request")?;
+        let resp = self.check_session(resp)?;
+
+        let data: GraphQLResponse<SubmissionDetailsData> = resp
+            .json()
+            .await
+            .context("Failed to parse submission details response")?;
+
+        let errors = data.errors_message();
+        match data.data.and_then(|d| d.submission_details) {
+            Some(detail) => Ok(detail),
+            None if self.has_session && errors.as_deref().is_none_or(is_auth_error) => {
+                Err(AuthExpired.into())
+            }
+            None => match errors {
+                Some(errors) => bail!("LeetCode: {errors}"),
+                None => bail!("No submission details in response (are you logged in?)"),
+            },
+        }
+    }
+
+    /// The code of the signed-in user's latest accepted submission for
+    /// `slug`, None if nothing was accepted yet.
+    pub async fn fetch_last_accepted(&self, slug: &str) -> Result<Option<SubmissionDetail>> {
+        const PAGE: usize = 20;
+        let mut offset = 0;
+        loop {
+            let page = self.fetch_submissions(slug, offset, PAGE).await?;
+            if let Some(accepted) = page
+                .submissions
+                .iter()
+                .find(|s| s.status_display == "Accepted")
+            {
+                return self.fetch_submission_detail(&accepted.id).await.map(Some);
+            }
+            if !page.has_next || page.submissions.is_empty() {
+                return Ok(None);
+            }
+            offset += PAGE;
+        }
     }
 
     pub async fn run_code(
@@ -137,7 +487,7 @@ impl LeetCodeClient {
         typed_code: &str,
         data_input: &str,
     ) -> Result<String> {
-        let url = LEETCODE_RUN.replace("{slug}", slug);
+        let url = self.site.url(&LEETCODE_RUN.replace("{slug}", slug));
         let body = json!({
             "lang": lang,
             "question_id": question_id,
@@ -147,11 +497,12 @@ impl LeetCodeClient {
 
         let resp = self
             .auth_request(self.client.post(&url))
-            .header("Referer", format!("https://leetcode.com/problems/{slug}/"))
+            .header("Referer", self.site.problem_url(slug))
             .json(&body)
-            .send()
+            .send_throttled(&self.throttle)
             .await
             .context("Failed to send run request")?;
+        let resp = self.check_session(resp)?;
 
         let status = resp.status();
         if !status.is_success() {
@@ -178,7 +529,7 @@ impl LeetCodeClient {
         lang: &str,
         typed_code: &str,
     ) -> Result<String> {
-        let url = LEETCODE_SUBMIT.replace("{slug}", slug);
+        let url = self.site.url(&LEETCODE_SUBMIT.replace("{slug}", slug));
         let body = json!({
             "lang": lang,
             "question_id": question_id,
@@ -187,11 +538,12 @@ impl LeetCodeClient {
 
         let resp = self
             .auth_request(self.client.post(&url))
-            .header("Referer", format!("https://leetcode.com/problems/{slug}/"))
+            .header("Referer", self.site.problem_url(slug))
             .json(&body)
-            .send()
+            .send_throttled(&self.throttle)
             .await
             .context("Failed to send submit request")?;
+        let resp = self.check_session(resp)?;
 
         let status = resp.status();
         if !status.is_success() {
@@ -199,29 +551,32 @@ impl LeetCodeClient {
             bail!("LeetCode returned HTTP {status}: {body}");
         }
 
-        let data: SubmitResponse = resp
+        let body: serde_json::Value = resp
             .json()
             .await
             .context("Failed to parse submit response")?;
+        let data: SubmitResponse =
+            serde_json::from_value(body.clone()).context("Failed to parse submit response")?;
 
         if let Some(err) = data.error {
             bail!("LeetCode: {err}");
         }
 
         data.submission_id
-            .map(|id| id.to_string())
+            .or_else(|| find_submission_id(&body))
             .context("No submission_id in response")
     }
 
     pub async fn check_result(&self, id: &str) -> Result<CheckResponse> {
-        let url = LEETCODE_CHECK.replace("{id}", id);
+        let url = self.site.url(&LEETCODE_CHECK.replace("{id}", id));
 
         let resp = self
             .auth_request(self.client.get(&url))
-            .header("Referer", "https://leetcode.com")
-            .send()
+            .header("Referer", self.site.base())
+            .send_retrying(&self.throttle)
             .await
             .context("Failed to send check request")?;
+        let resp = self.check_session(resp)?;
 
         let data: CheckResponse = resp
             .json()
@@ -250,37 +605,52 @@ impl LeetCodeClient {
     }
 
     pub async fn fetch_username(&self) -> Option<String> {
+        let status = self.fetch_user_status().await?;
+        if status.is_signed_in {
+            status.username
+        } else {
+            None
+        }
+    }
+
+    /// Whether the signed-in account has LeetCode Premium.
+    pub async fn fetch_is_premium(&self) -> bool {
+        self.fetch_user_status()
+            .await
+            .is_some_and(|s| s.is_signed_in && s.is_premium == Some(true))
+    }
+
+    async fn fetch_user_status(&self) -> Option<UserStatus> {
+        let query = if self.site.is_cn() { GLOBAL_DATA_QUERY_CN } else { GLOBAL_DATA_QUERY };
         let body = json!({
-            "query": GLOBAL_DATA_QUERY,
+            "query": query,
             "variables": {}
         });
 
         let resp = self
-            .auth_request(self.client.post(LEETCODE_GRAPHQL))
+            .auth_request(self.client.post(self.site.graphql_url()))
             .json(&body)
-            .send()
+            .send_retrying(&self.throttle)
             .await
             .ok()?;
 
         let data: GraphQLResponse<UserStatusData> = resp.json().await.ok()?;
-        let status = data.data?.user_status?;
-        if status.is_signed_in {
-            status.username
-        } else {
-            None
-        }
+        data.data?.user_status
     }
 
     pub async fn fetch_user_stats(&self, username: &str) -> Result<UserStats> {
+        if self.site.is_cn() {
+            return self.fetch_user_stats_cn(username).await;
+        }
         let body = json!({
             "query": USER_PROFILE_QUERY,
             "variables": { "username": username }
         });
 
         let resp = self
-            .auth_request(self.client.post(LEETCODE_GRAPHQL))
+            .auth_request(self.client.post(self.site.graphql_url()))
             .json(&body)
-            .send()
+            .send_retrying(&self.throttle)
             .await
             .context("Failed to send user profile request")?;
 
@@ -289,29 +659,282 @@ impl LeetCodeClient {
             .await
             .context("Failed to parse user profile response")?;
 
-        let profile = data.data.context("No profile data")?;
-
-        let solved = profile
-            .matched_user
-            .and_then(|u| u.submit_stats)
+        let (user, totals, contest) = data.field(
+            |p| {
+                p.matched_user
+                    .map(|u| (u, p.all_questions_count, p.user_contest_ranking))
+            },
+            format!("User \"{username}\" not found"),
+        )?;
+        let solved = user
+            .submit_stats
             .map(|s| s.ac_submission_num)
+            .with_context(|| format!("\"{username}\" has a private profile"))?;
+
+        let totals = totals.unwrap_or_default();
+        let mut stats = user_stats(username, &solved, &totals);
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() as i64);
+        let calendar = user.user_calendar.and_then(|c| c.submission_calendar);
+        stats.streak = calendar
+            .as_deref()
+            .and_then(|raw| Streak::from_calendar(raw, now));
+        stats.calendar = calendar
+            .as_deref()
+            .and_then(calendar_days)
             .unwrap_or_default();
+        stats.contest = contest.filter(|c| c.attended_contests_count > 0);
+        Ok(stats)
+    }
+
+    /// Solved counts per topic for `username`.
+    pub async fn fetch_skill_stats(&self, username: &str) -> Result<TagProblemCounts> {
+        if self.site.is_cn() {
+            bail!("Topic stats aren't available for leetcode.cn yet");
+        }
+        let body = json!({
+            "query": SKILL_STATS_QUERY,
+            "variables": { "username": username }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send topic stats request")?;
+
+        let data: GraphQLResponse<SkillStatsData> = resp
+            .json()
+            .await
+            .context("Failed to parse topic stats response")?;
+
+        let user = data.field(|d| d.matched_user, format!("User \"{username}\" not found"))?;
+        user.tag_problem_counts
+            .with_context(|| format!("\"{username}\" has a private profile"))
+    }
+
+    async fn fetch_user_stats_cn(&self, username: &str) -> Result<UserStats> {
+        let body = json!({
+            "query": USER_PROGRESS_QUERY_CN,
+            "variables": { "userSlug": username }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send user profile request")?;
+
+        let data: GraphQLResponse<UserProgressDataCn> = resp
+            .json()
+            .await
+            .context("Failed to parse user profile response")?;
+
+        let (solved, totals) = data.field(
+            |p| {
+                p.user_profile_user_question_progress
+                    .map(|u| (u.num_accepted_questions, p.all_questions_count))
+            },
+            format!("User \"{username}\" not found"),
+        )?;
+        let totals = totals.unwrap_or_default();
+        Ok(user_stats(username, &solved, &totals))
+    }
+
+    /// Upcoming contests, soonest first, and the most recent past ones.
+    pub async fn fetch_contests(&self) -> Result<(Vec<Contest>, Vec<Contest>)> {
+        let body = json!({
+            "query": CONTEST_LIST_QUERY,
+            "variables": { "pageNo": 1, "numPerPage": PAST_CONTESTS }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send contest list request")?;
 
-        let totals = profile.all_questions_count.unwrap_or_default();
+        let data: GraphQLResponse<ContestListData> = resp
+            .json()
+            .await
+            .context("Failed to parse contest list response")?;
+
+        let (mut upcoming, past) = data.field(
+            |d| Some((d.upcoming_contests?, d.past_contests?.data)),
+            "No contest data in response",
+        )?;
+        upcoming.sort_by_key(|c| c.start_time);
+        Ok((upcoming, past))
+    }
+
+    /// A contest's problems, in contest order.
+    pub async fn fetch_contest_problems(&self, slug: &str) -> Result<Vec<ContestQuestion>> {
+        let url = self.site.url(&LEETCODE_CONTEST_INFO.replace("{slug}", slug));
+        let resp = self
+            .auth_request(self.client.get(&url))
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send contest request")?;
 
-        fn find_count(items: &[DifficultyCount], diff: &str) -> i32 {
-            items.iter().find(|d| d.difficulty == diff).map(|d| d.count).unwrap_or(0)
+        let status = resp.status();
+        if !status.is_success() {
+            bail!("Failed to load contest: HTTP {status}");
         }
 
-        Ok(UserStats {
-            username: username.to_string(),
-            easy_solved: find_count(&solved, "Easy"),
-            easy_total: find_count(&totals, "Easy"),
-            medium_solved: find_count(&solved, "Medium"),
-            medium_total: find_count(&totals, "Medium"),
-            hard_solved: find_count(&solved, "Hard"),
-            hard_total: find_count(&totals, "Hard"),
-        })
+        let info: ContestInfo = resp
+            .json()
+            .await
+            .context("Failed to parse contest response")?;
+        Ok(info.questions)
+    }
+
+    /// Rank and solve count in every contest `username` has taken part in.
+    pub async fn fetch_contest_history(&self, username: &str) -> Result<Vec<ContestResult>> {
+        let body = json!({
+            "query": CONTEST_HISTORY_QUERY,
+            "variables": { "username": username }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send contest history request")?;
+
+        let data: GraphQLResponse<ContestHistoryData> = resp
+            .json()
+            .await
+            .context("Failed to parse contest history response")?;
+
+        let history = data.field(
+            |d| d.user_contest_ranking_history,
+            format!("No contest history for \"{username}\""),
+        )?;
+        Ok(history.into_iter().filter(|r| r.attended).collect())
+    }
+
+    /// Study plans the user has started and not yet finished.
+    pub async fn fetch_study_plans(&self) -> Result<Vec<StudyPlan>> {
+        let body = json!({
+            "query": STUDY_PLAN_PROGRESS_QUERY,
+            "variables": { "onGoing": true }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send study plan request")?;
+        let resp = self.check_session(resp)?;
+
+        let data: GraphQLResponse<StudyPlanProgressData> = resp
+            .json()
+            .await
+            .context("Failed to parse study plan response")?;
+
+        data.field(
+            |d| d.study_plan_v2_progress_list.map(|l| l.study_plans),
+            "No study plan data in response",
+        )
+    }
+
+    /// A study plan's groups and problems, with the user's progress.
+    pub async fn fetch_study_plan(&self, slug: &str) -> Result<StudyPlanDetail> {
+        let body = json!({
+            "query": STUDY_PLAN_DETAIL_QUERY,
+            "variables": { "slug": slug }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send study plan request")?;
+
+        let data: GraphQLResponse<StudyPlanDetailData> = resp
+            .json()
+            .await
+            .context("Failed to parse study plan response")?;
+
+        let mut plan = data.field(
+            |d| d.study_plan_v2_detail,
+            format!("Study plan \"{slug}\" not found"),
+        )?;
+        for q in plan.plan_sub_groups.iter_mut().flat_map(|g| &mut g.questions) {
+            normalize_problem(&mut q.difficulty, &mut q.status);
+        }
+        Ok(plan)
+    }
+
+    /// Every company with tagged problems, most problems first.
+    pub async fn fetch_companies(&self) -> Result<Vec<CompanyTag>> {
+        let body = json!({
+            "query": COMPANY_TAGS_QUERY,
+            "variables": {}
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send company list request")?;
+
+        let data: GraphQLResponse<CompanyTagsData> = resp
+            .json()
+            .await
+            .context("Failed to parse company list response")?;
+
+        let mut companies = data.field(|d| d.company_tags, "No company data in response")?;
+        companies.sort_by_key(|c| std::cmp::Reverse(c.question_count));
+        Ok(companies)
+    }
+
+    /// Problems a company asked within `period`, most frequent first.
+    /// Premium only; LeetCode answers other accounts with an error.
+    pub async fn fetch_company_problems(
+        &self,
+        slug: &str,
+        period: CompanyPeriod,
+    ) -> Result<Vec<CompanyProblem>> {
+        let body = json!({
+            "query": COMPANY_PROBLEMS_QUERY,
+            "variables": {
+                "favoriteSlug": format!("{slug}-{}", period.slug()),
+                "limit": COMPANY_PROBLEMS_LIMIT,
+                "skip": 0,
+            }
+        });
+
+        let resp = self
+            .auth_request(self.client.post(self.site.graphql_url()))
+            .json(&body)
+            .send_retrying(&self.throttle)
+            .await
+            .context("Failed to send company problems request")?;
+        let resp = self.check_session(resp)?;
+
+        let data: GraphQLResponse<CompanyProblemsData> = resp
+            .json()
+            .await
+            .context("Failed to parse company problems response")?;
+
+        let mut problems = data.field(
+            |d| d.favorite_question_list.map(|l| l.questions),
+            format!("No problems for \"{slug}\""),
+        )?;
+        for p in &mut problems {
+            normalize_problem(&mut p.difficulty, &mut p.status);
+        }
+        problems.sort_by(|a, b| b.frequency.unwrap_or(0.0).total_cmp(&a.frequency.unwrap_or(0.0)));
+        Ok(problems)
     }
 
     pub async fn fetch_favorites(&self) -> Result<Vec<FavoriteList>> {
@@ -321,48 +944,55 @@ impl LeetCodeClient {
         });
 
         let resp = self
-            .auth_request(self.client.post(LEETCODE_GRAPHQL))
+            .auth_request(self.client.post(self.site.graphql_url()))
             .json(&body)
-            .send()
+            .send_retrying(&self.throttle)
             .await
             .context("Failed to fetch favorites")?;
+        let resp = self.check_session(resp)?;
 
         let data: GraphQLResponse<FavoritesListData> = resp
             .json()
             .await
             .context("Failed to parse favorites response")?;
 
-        let lists = data
-            .data
-            .and_then(|d| d.favorites_lists)
-            .map(|f| f.all_favorites)
-            .unwrap_or_default();
-
-        Ok(lists)
+        let errors = data.errors_message();
+        match data.data.and_then(|d| d.favorites_lists) {
+            Some(lists) => Ok(lists.all_favorites),
+            None => match errors {
+                Some(errors) => bail!("LeetCode: {errors}"),
+                None => Ok(Vec::new()),
+            },
+        }
     }
 
-    pub async fn create_favorite_list(&self, name: &str) -> Result<()> {
+    /// Create an empty list, returning its `id_hash` when the response
+    /// includes the new list.
+    pub async fn create_favorite_list(&self, name: &str) -> Result<Option<String>> {
         let resp = self
-            .auth_request(self.client.post(LEETCODE_LIST_API))
+            .auth_request(self.client.post(self.site.url(LEETCODE_LIST_API)))
             .json(&json!({ "name": name }))
-            .send()
+            .send_throttled(&self.throttle)
             .await
             .context("Failed to create list")?;
+        let resp = self.check_session(resp)?;
 
         let status = resp.status();
         if !status.is_success() {
             bail!("Failed to create list: HTTP {status}");
         }
-        Ok(())
+        let body: serde_json::Value = resp.json().await.unwrap_or_default();
+        Ok(find_id_hash(&body))
     }
 
     pub async fn delete_favorite_list(&self, id_hash: &str) -> Result<()> {
-        let url = format!("{}{}", LEETCODE_LIST_API, id_hash);
+        let url = self.site.url(&format!("{LEETCODE_LIST_API}{id_hash}"));
         let resp = self
             .auth_request(self.client.delete(&url))
-            .send()
+            .send_throttled(&self.throttle)
             .await
             .context("Failed to delete list")?;
+        let resp = self.check_session(resp)?;
 
         let status = resp.status();
         if !status.is_success() {
@@ -373,14 +1003,15 @@ impl LeetCodeClient {
 
     pub async fn add_to_favorite(&self, id_hash: &str, question_id: &str) -> Result<()> {
         let resp = self
-            .auth_request(self.client.post(LEETCODE_LIST_QUESTIONS_API))
+            .auth_request(self.client.post(self.site.url(LEETCODE_LIST_QUESTIONS_API)))
             .json(&json!({
                 "favorite_id_hash": id_hash,
                 "question_id": question_id,
             }))
-            .send()
+            .send_throttled(&self.throttle)
             .await
             .context("Failed to add to list")?;
+        let resp = self.check_session(resp)?;
 
         let status = resp.status();
         if !status.is_success() {
@@ -390,12 +1021,13 @@ impl LeetCodeClient {
     }
 
     pub async fn remove_from_favorite(&self, id_hash: &str, question_id: &str) -> Result<()> {
-        let url = format!("{}/{}/{}", LEETCODE_LIST_QUESTIONS_API, id_hash, question_id);
+        let url = self.site.url(&format!("{LEETCODE_LIST_QUESTIONS_API}/{id_hash}/{question_id}"));
         let resp = self
             .auth_request(self.client.delete(&url))
-            .send()
+            .send_throttled(&self.throttle)
             .await
             .context("Failed to remove from list")?;
+        let resp = self.check_session(resp)?;
 
         let status = resp.status();
         if !status.is_success() {
@@ -404,3 +1036,49 @@ impl LeetCodeClient {
         Ok(())
     }
 }
+
+/// Whether a GraphQL error is LeetCode refusing an unauthenticated request.
+fn is_auth_error(message: &str) -> bool {
+    let message = message.to_ascii_lowercase();
+    ["login", "log in", "authenticat", "permission"]
+        .iter()
+        .any(|word| message.contains(word))
+}
+
+fn user_stats(username: &str, solved: &[DifficultyCount], totals: &[DifficultyCount]) -> UserStats {
+    // leetcode.cn spells difficulties in capitals
+    fn find_count(items: &[DifficultyCount], diff: &str) -> i32 {
+        items
+            .iter()
+            .find(|d| d.difficulty.eq_ignore_ascii_case(diff))
+            .map(|d| d.count)
+            .unwrap_or(0)
+    }
+
+    UserStats {
+        username: username.to_string(),
+        easy_solved: find_count(solved, "Easy"),
+        easy_total: find_count(totals, "Easy"),
+        medium_solved: find_count(solved, "Medium"),
+        medium_total: find_count(totals, "Medium"),
+        hard_solved: find_count(solved, "Hard"),
+        hard_total: find_count(totals, "Hard"),
+        streak: None,
+        calendar: Default::default(),
+        contest: None,
+    }
+}
+
+/// Map the `EASY` / `AC` / `TRIED` / `NOT_STARTED` spellings used by
+/// leetcode.cn, study plans and company lists to the values the rest of the app expects.
+fn normalize_problem(difficulty: &mut String, status: &mut Option<String>) {
+    let mut chars = difficulty.chars();
+    if let Some(first) = chars.next() {
+        *difficulty = first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect();
+    }
+    *status = match status.as_deref() {
+        Some("AC" | "ac" | "FINISH" | "SOLVED") => Some("ac".to_string()),
+        Some("TRIED" | "notac" | "ATTEMPTED") => Some("notac".to_string()),
+        _ => None,
+    };
+}
@@ -1,3 +1,6 @@
 pub mod client;
+pub mod mutations;
 pub mod queries;
+pub mod site;
+pub mod throttle;
 pub mod types;
@@ -0,0 +1,165 @@
+use anyhow::Result;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+use super::client::LeetCodeClient;
+
+/// A favorite-list write. Reads are never queued.
+#[derive(Debug, Clone)]
+pub enum ListMutation {
+    Create { name: String },
+    Delete { id_hash: String },
+    Add { id_hash: String, question_id: String, list_name: String },
+    Remove { id_hash: String, question_id: String },
+}
+
+impl ListMutation {
+    async fn execute(&self, client: &LeetCodeClient) -> Result<()> {
+        match self {
+            ListMutation::Create { name } => client.create_favorite_list(name).await.map(drop),
+            ListMutation::Delete { id_hash } => client.delete_favorite_list(id_hash).await,
+            ListMutation::Add {
+                id_hash,
+                question_id,
+                ..
+            } => client.add_to_favorite(id_hash, question_id).await,
+            ListMutation::Remove {
+                id_hash,
+                question_id,
+            } => client.remove_from_favorite(id_hash, question_id).await,
+        }
+    }
+
+    pub fn success_message(&self) -> String {
+        match self {
+            ListMutation::Create { name } => format!("List \"{name}\" created"),
+            ListMutation::Delete { .. } => "List deleted".into(),
+            ListMutation::Add { list_name, .. } => format!("Added to \"{list_name}\""),
+            ListMutation::Remove { .. } => "Removed from list".into(),
+        }
+    }
+}
+
+/// Runs list mutations one at a time, at least `spacing` apart, so quick
+/// keypresses don't fire a burst of concurrent writes and trip rate limits.
+pub struct MutationQueue {
+    tx: mpsc::UnboundedSender<(LeetCodeClient, ListMutation)>,
+    pending: Arc<AtomicUsize>,
+}
+
+impl MutationQueue {
+    pub fn new<F>(spacing: Duration, on_done: F) -> Self
+    where
+        F: Fn(Result<()>, String) + Send + 'static,
+    {
+        Self::with_runner(
+            spacing,
+            |client, mutation| async move { mutation.execute(&client).await }.boxed(),
+            on_done,
+        )
+    }
+
+    /// Like `new`, with `run` performing each mutation in place of the API.
+    fn with_runner<R, F>(spacing: Duration, run: R, on_done: F) -> Self
+    where
+        R: Fn(LeetCodeClient, ListMutation) -> BoxFuture<'static, Result<()>> + Send + 'static,
+        F: Fn(Result<()>, String) + Send + 'static,
+    {
+        let (tx, mut rx) = mpsc::unbounded_channel::<(LeetCodeClient, ListMutation)>();
+        let pending = Arc::new(AtomicUsize::new(0));
+        let worker_pending = pending.clone();
+
+        tokio::spawn(async move {
+            let mut last_run: Option<Instant> = None;
+            while let Some((client, mutation)) = rx.recv().await {
+                if let Some(last) = last_run {
+                    tokio::time::sleep_until(last + spacing).await;
+                }
+                let message = mutation.success_message();
+                let result = run(client, mutation).await;
+                last_run = Some(Instant::now());
+                worker_pending.fetch_sub(1, Ordering::SeqCst);
+                on_done(result, message);
+            }
+        });
+
+        Self { tx, pending }
+    }
+
+    /// Queue a mutation against `client` (the client current at enqueue time).
+    pub fn enqueue(&self, client: LeetCodeClient, mutation: ListMutation) {
+        self.pending.fetch_add(1, Ordering::SeqCst);
+        if self.tx.send((client, mutation)).is_err() {
+            self.pending.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Number of mutations queued or in flight.
+    pub fn pending(&self) -> usize {
+        self.pending.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[tokio::test]
+    async fn mutations_run_in_order_and_spaced_apart() {
+        let spacing = Duration::from_millis(60);
+        let started = Arc::new(Mutex::new(Vec::new()));
+        let log = started.clone();
+        let (done_tx, mut done_rx) = mpsc::unbounded_channel();
+        let queue = MutationQueue::with_runner(
+            spacing,
+            move |_, mutation| {
+                log.lock()
+                    .unwrap()
+                    .push((mutation.success_message(), Instant::now()));
+                async { Ok(()) }.boxed()
+            },
+            move |result, message| {
+                let _ = done_tx.send((result.is_ok(), message));
+            },
+        );
+
+        let client = LeetCodeClient::new(None, None, &Default::default()).unwrap();
+        let mutations = [
+            ListMutation::Create { name: "dp".into() },
+            ListMutation::Add {
+                id_hash: "abc".into(),
+                question_id: "1".into(),
+                list_name: "dp".into(),
+            },
+            ListMutation::Delete {
+                id_hash: "abc".into(),
+            },
+        ];
+        for mutation in &mutations {
+            queue.enqueue(client.clone(), mutation.clone());
+        }
+        assert_eq!(queue.pending(), 3);
+
+        let mut finished = Vec::new();
+        for _ in &mutations {
+            finished.push(done_rx.recv().await.unwrap());
+        }
+        let expected: Vec<_> = mutations
+            .iter()
+            .map(|m| (true, m.success_message()))
+            .collect();
+        assert_eq!(finished, expected);
+        assert_eq!(queue.pending(), 0);
+
+        let started = started.lock().unwrap();
+        for pair in started.windows(2) {
+            assert!(pair[1].1 - pair[0].1 >= spacing);
+        }
+    }
+}
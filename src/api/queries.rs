@@ -33,6 +33,8 @@ query questionDetail($titleSlug: String!) {
     titleSlug
     difficulty
     content
+    translatedTitle
+    translatedContent
     isPaidOnly
     topicTags {
       name
@@ -47,6 +49,52 @@ query questionDetail($titleSlug: String!) {
     sampleTestCase
     hints
     status
+    stats
+    companyTagStats
+    similarQuestions
+    metaData
+  }
+}
+"#;
+
+pub const DAILY_CHALLENGE_QUERY: &str = r#"
+query questionOfToday {
+  activeDailyCodingChallengeQuestion {
+    question {
+      titleSlug
+    }
+  }
+}
+"#;
+
+pub const SUBMISSION_LIST_QUERY: &str = r#"
+query submissionList($offset: Int!, $limit: Int!, $lastKey: String, $questionSlug: String!) {
+  questionSubmissionList(offset: $offset, limit: $limit, lastKey: $lastKey, questionSlug: $questionSlug) {
+    lastKey
+    hasNext
+    submissions {
+      id
+      statusDisplay
+      lang
+      langName
+      runtime
+      memory
+      timestamp
+    }
+  }
+}
+"#;
+
+pub const SUBMISSION_DETAILS_QUERY: &str = r#"
+query submissionDetails($submissionId: Int!) {
+  submissionDetails(submissionId: $submissionId) {
+    code
+    runtimeDisplay
+    memoryDisplay
+    lang {
+      name
+      verboseName
+    }
   }
 }
 "#;
@@ -56,6 +104,7 @@ query {
   userStatus {
     isSignedIn
     username
+    isPremium
   }
 }
 "#;
@@ -91,6 +140,244 @@ query getUserProfile($username: String!) {
         count
       }
     }
+    userCalendar {
+      submissionCalendar
+    }
+  }
+  userContestRanking(username: $username) {
+    attendedContestsCount
+    rating
+    globalRanking
+    totalParticipants
+    topPercentage
+  }
+  allQuestionsCount {
+    difficulty
+    count
+  }
+}
+"#;
+
+pub const SKILL_STATS_QUERY: &str = r#"
+query skillStats($username: String!) {
+  matchedUser(username: $username) {
+    tagProblemCounts {
+      advanced {
+        tagName
+        tagSlug
+        problemsSolved
+      }
+      intermediate {
+        tagName
+        tagSlug
+        problemsSolved
+      }
+      fundamental {
+        tagName
+        tagSlug
+        problemsSolved
+      }
+    }
+  }
+}
+"#;
+
+pub const CONTEST_LIST_QUERY: &str = r#"
+query contestList($pageNo: Int, $numPerPage: Int) {
+  upcomingContests {
+    title
+    titleSlug
+    startTime
+    duration
+  }
+  pastContests(pageNo: $pageNo, numPerPage: $numPerPage) {
+    data {
+      title
+      titleSlug
+      startTime
+      duration
+    }
+  }
+}
+"#;
+
+pub const CONTEST_HISTORY_QUERY: &str = r#"
+query contestHistory($username: String!) {
+  userContestRankingHistory(username: $username) {
+    attended
+    ranking
+    problemsSolved
+    totalProblems
+    contest {
+      title
+    }
+  }
+}
+"#;
+
+pub const STUDY_PLAN_PROGRESS_QUERY: &str = r#"
+query studyPlanProgress($onGoing: Boolean!) {
+  studyPlanV2ProgressList(onGoing: $onGoing) {
+    studyPlans {
+      slug
+      name
+      questionNum
+      finishedQuestionNum
+    }
+  }
+}
+"#;
+
+pub const STUDY_PLAN_DETAIL_QUERY: &str = r#"
+query studyPlanDetail($slug: String!) {
+  studyPlanV2Detail(planSlug: $slug) {
+    name
+    planSubGroups {
+      name
+      questions {
+        title
+        titleSlug
+        frontendQuestionId: questionFrontendId
+        difficulty
+        status
+        paidOnly
+      }
+    }
+  }
+}
+"#;
+
+pub const OFFICIAL_SOLUTION_QUERY: &str = r#"
+query officialSolution($titleSlug: String!) {
+  question(titleSlug: $titleSlug) {
+    solution {
+      title
+      content
+      paidOnly
+      canSeeDetail
+    }
+  }
+}
+"#;
+
+pub const COMMUNITY_SOLUTIONS_QUERY: &str = r#"
+query communitySolutions($questionSlug: String!, $skip: Int!, $first: Int!, $orderBy: TopicSortingOption) {
+  questionSolutions(
+    filters: {questionSlug: $questionSlug, skip: $skip, first: $first, orderBy: $orderBy}
+  ) {
+    totalNum
+    solutions {
+      id
+      title
+      viewCount
+      solutionTags {
+        name
+        slug
+      }
+      post {
+        voteCount
+        creationDate
+        author {
+          username
+        }
+      }
+    }
+  }
+}
+"#;
+
+pub const COMMUNITY_SOLUTION_QUERY: &str = r#"
+query communitySolution($topicId: Int!) {
+  topic(id: $topicId) {
+    post {
+      content
+    }
+  }
+}
+"#;
+
+pub const COMPANY_TAGS_QUERY: &str = r#"
+query questionCompanyTags {
+  companyTags {
+    name
+    slug
+    questionCount
+  }
+}
+"#;
+
+pub const COMPANY_PROBLEMS_QUERY: &str = r#"
+query favoriteQuestionList($favoriteSlug: String!, $limit: Int, $skip: Int) {
+  favoriteQuestionList(favoriteSlug: $favoriteSlug, limit: $limit, skip: $skip) {
+    questions {
+      title
+      titleSlug
+      frontendQuestionId: questionFrontendId
+      difficulty
+      status
+      paidOnly
+      frequency
+    }
+  }
+}
+"#;
+
+// leetcode.cn serves the same data under older or renamed fields. These are
+// aliased into the shapes above where possible.
+
+pub const PROBLEM_LIST_QUERY_CN: &str = r#"
+query problemsetQuestionList($categorySlug: String, $limit: Int, $skip: Int, $filters: QuestionListFilterInput) {
+  problemsetQuestionList(
+    categorySlug: $categorySlug
+    limit: $limit
+    skip: $skip
+    filters: $filters
+  ) {
+    total
+    questions {
+      frontendQuestionId
+      title
+      titleSlug
+      difficulty
+      status
+      acRate
+      isPaidOnly: paidOnly
+      topicTags {
+        name
+        slug
+      }
+    }
+  }
+}
+"#;
+
+pub const DAILY_CHALLENGE_QUERY_CN: &str = r#"
+query questionOfToday {
+  todayRecord {
+    question {
+      titleSlug
+    }
+  }
+}
+"#;
+
+pub const GLOBAL_DATA_QUERY_CN: &str = r#"
+query {
+  userStatus {
+    isSignedIn
+    username: userSlug
+    isPremium
+  }
+}
+"#;
+
+pub const USER_PROGRESS_QUERY_CN: &str = r#"
+query userQuestionProgress($userSlug: String!) {
+  userProfileUserQuestionProgress(userSlug: $userSlug) {
+    numAcceptedQuestions {
+      difficulty
+      count
+    }
   }
   allQuestionsCount {
     difficulty
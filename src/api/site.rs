@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+
+/// Which LeetCode the app talks to. The Chinese site runs the same problems
+/// behind slightly different GraphQL endpoints and queries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Region {
+    #[default]
+    #[serde(rename = "leetcode.com")]
+    Com,
+    #[serde(rename = "leetcode.cn")]
+    Cn,
+}
+
+impl Region {
+    pub fn default_base(self) -> &'static str {
+        match self {
+            Self::Com => "https://leetcode.com",
+            Self::Cn => "https://leetcode.cn",
+        }
+    }
+}
+
+/// A LeetCode site: its flavor plus the base URL every request and link is
+/// built from (overridable, e.g. for a mirror).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Site {
+    pub region: Region,
+    base: String,
+}
+
+impl Default for Site {
+    fn default() -> Self {
+        Self::new(Region::default(), None)
+    }
+}
+
+impl Site {
+    pub fn new(region: Region, base_url: Option<&str>) -> Self {
+        let base = base_url
+            .filter(|b| !b.trim().is_empty())
+            .unwrap_or(region.default_base())
+            .trim()
+            .trim_end_matches('/')
+            .to_string();
+        Self { region, base }
+    }
+
+    pub fn is_cn(&self) -> bool {
+        self.region == Region::Cn
+    }
+
+    /// `path` (starting with `/`) on this site.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base)
+    }
+
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Host name the login cookies are set for.
+    pub fn host(&self) -> &str {
+        let host = self.base.split("://").nth(1).unwrap_or(&self.base);
+        host.split(['/', ':']).next().unwrap_or(host)
+    }
+
+    pub fn graphql_url(&self) -> String {
+        match self.region {
+            Region::Com => self.url("/graphql"),
+            // leetcode.cn redirects the slash-less path, dropping the body
+            Region::Cn => self.url("/graphql/"),
+        }
+    }
+
+    pub fn problem_url(&self, slug: &str) -> String {
+        self.url(&format!("/problems/{slug}/"))
+    }
+
+    pub fn login_url(&self) -> String {
+        self.url("/accounts/login/")
+    }
+}
+
+static CURRENT: RwLock<Option<Site>> = RwLock::new(None);
+
+/// The configured site. Links shown outside the API client (problem URLs in
+/// scaffolds, the login page) read this rather than threading it through.
+pub fn site() -> Site {
+    CURRENT
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_default()
+}
+
+pub fn set_site(site: Site) {
+    *CURRENT.write().unwrap_or_else(|e| e.into_inner()) = Some(site);
+}
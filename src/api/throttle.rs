@@ -0,0 +1,144 @@
+use reqwest::{RequestBuilder, Response, StatusCode};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const BASE_DELAY: Duration = Duration::from_millis(500);
+const MAX_DELAY: Duration = Duration::from_secs(8);
+
+/// Shared by every clone of a client: a token bucket limiting how fast
+/// requests go out, and how often a failed request is retried.
+#[derive(Clone)]
+pub struct Throttle {
+    bucket: Option<Arc<Mutex<Bucket>>>,
+    retries: u32,
+}
+
+struct Bucket {
+    tokens: f64,
+    capacity: f64,
+    per_sec: f64,
+    refilled: Instant,
+}
+
+impl Throttle {
+    /// `per_sec` of 0 disables rate limiting. Bursts of up to twice the
+    /// rate go out immediately.
+    pub fn new(per_sec: f64, retries: u32) -> Self {
+        let bucket = (per_sec > 0.0).then(|| {
+            let capacity = (per_sec * 2.0).max(1.0);
+            Arc::new(Mutex::new(Bucket {
+                tokens: capacity,
+                capacity,
+                per_sec,
+                refilled: Instant::now(),
+            }))
+        });
+        Self { bucket, retries }
+    }
+
+    /// Wait until a request may go out.
+    async fn acquire(&self) {
+        let Some(ref bucket) = self.bucket else {
+            return;
+        };
+        loop {
+            let wait = {
+                let mut b = bucket.lock().unwrap_or_else(|e| e.into_inner());
+                let now = Instant::now();
+                let elapsed = now.duration_since(b.refilled).as_secs_f64();
+                b.tokens = (b.tokens + elapsed * b.per_sec).min(b.capacity);
+                b.refilled = now;
+                if b.tokens >= 1.0 {
+                    b.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - b.tokens) / b.per_sec)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Exponential backoff with up to 50% random jitter, so clients that failed
+/// together don't retry in lockstep.
+fn backoff(attempt: u32) -> Duration {
+    let delay = BASE_DELAY
+        .saturating_mul(1 << attempt.min(8))
+        .min(MAX_DELAY);
+    // Sub-second clock noise is plenty random for spreading retries
+    let noise = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    delay + delay.mul_f64(f64::from(noise % 1000) / 2000.0)
+}
+
+/// The server's `Retry-After`, in seconds, capped at the longest backoff.
+fn retry_after(resp: &Response) -> Option<Duration> {
+    let secs: u64 = resp
+        .headers()
+        .get("retry-after")?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(secs).min(MAX_DELAY))
+}
+
+pub trait SendExt {
+    /// Send a read-only request, retrying network failures, timeouts,
+    /// rate-limit responses and gateway errors.
+    async fn send_retrying(self, throttle: &Throttle) -> reqwest::Result<Response>;
+
+    /// Send a request that must not run twice (run, submit, list edits).
+    /// Only retried when LeetCode can't have acted on it: the connection
+    /// failed, or it was rejected with 429.
+    async fn send_throttled(self, throttle: &Throttle) -> reqwest::Result<Response>;
+}
+
+impl SendExt for RequestBuilder {
+    async fn send_retrying(self, throttle: &Throttle) -> reqwest::Result<Response> {
+        send(self, throttle, true).await
+    }
+
+    async fn send_throttled(self, throttle: &Throttle) -> reqwest::Result<Response> {
+        send(self, throttle, false).await
+    }
+}
+
+async fn send(
+    builder: RequestBuilder,
+    throttle: &Throttle,
+    idempotent: bool,
+) -> reqwest::Result<Response> {
+    let mut attempt = 0;
+    loop {
+        throttle.acquire().await;
+        // Streaming bodies can't be replayed; every request here has a JSON body
+        let Some(request) = builder.try_clone() else {
+            return builder.send().await;
+        };
+        let last = attempt >= throttle.retries;
+        let delay = match request.send().await {
+            Ok(resp) if !last && retry_status(resp.status(), idempotent) => {
+                retry_after(&resp).unwrap_or_else(|| backoff(attempt))
+            }
+            Err(e) if !last && (e.is_connect() || (idempotent && e.is_timeout())) => {
+                backoff(attempt)
+            }
+            other => return other,
+        };
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+fn retry_status(status: StatusCode, idempotent: bool) -> bool {
+    match status {
+        StatusCode::TOO_MANY_REQUESTS => true,
+        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT => {
+            idempotent
+        }
+        _ => false,
+    }
+}
@@ -1,8 +1,47 @@
-use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Deserializer, Serialize};
 
 #[derive(Debug, Deserialize)]
 pub struct GraphQLResponse<T> {
     pub data: Option<T>,
+    #[serde(default)]
+    pub errors: Vec<GraphQLError>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GraphQLError {
+    pub message: String,
+}
+
+impl<T> GraphQLResponse<T> {
+    /// LeetCode's error messages, joined, if it sent any.
+    pub fn errors_message(&self) -> Option<String> {
+        let messages: Vec<&str> = self
+            .errors
+            .iter()
+            .map(|e| e.message.trim())
+            .filter(|m| !m.is_empty())
+            .collect();
+        (!messages.is_empty()).then(|| messages.join("; "))
+    }
+
+    /// Pull a field out of `data`. When it's missing the error is what
+    /// LeetCode reported in `errors`, or `missing` if it didn't say.
+    pub fn field<U>(
+        self,
+        get: impl FnOnce(T) -> Option<U>,
+        missing: impl std::fmt::Display,
+    ) -> anyhow::Result<U> {
+        let errors = self.errors_message();
+        match self.data.and_then(get) {
+            Some(value) => Ok(value),
+            None => match errors {
+                Some(errors) => anyhow::bail!("LeetCode: {errors}"),
+                None => anyhow::bail!("{missing}"),
+            },
+        }
+    }
 }
 
 // Problem list types
@@ -32,6 +71,56 @@ pub struct ProblemSummary {
     pub topic_tags: Vec<TopicTag>,
 }
 
+/// Problem set category, as passed to `questionList(categorySlug:)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    #[default]
+    All,
+    Algorithms,
+    Database,
+    Shell,
+    Concurrency,
+    JavaScript,
+}
+
+impl Category {
+    pub const ALL: [Category; 6] = [
+        Category::All,
+        Category::Algorithms,
+        Category::Database,
+        Category::Shell,
+        Category::Concurrency,
+        Category::JavaScript,
+    ];
+
+    pub fn slug(self) -> &'static str {
+        match self {
+            Category::All => "all-code-essentials",
+            Category::Algorithms => "algorithms",
+            Category::Database => "database",
+            Category::Shell => "shell",
+            Category::Concurrency => "concurrency",
+            Category::JavaScript => "javascript",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Category::All => "All",
+            Category::Algorithms => "Algorithms",
+            Category::Database => "Database",
+            Category::Shell => "Shell",
+            Category::Concurrency => "Concurrency",
+            Category::JavaScript => "JavaScript",
+        }
+    }
+
+    pub fn index(self) -> usize {
+        Self::ALL.iter().position(|&c| c == self).unwrap_or(0)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TopicTag {
     pub name: String,
@@ -53,6 +142,10 @@ pub struct QuestionDetail {
     pub title_slug: String,
     pub difficulty: String,
     pub content: Option<String>,
+    #[serde(default)]
+    pub translated_title: Option<String>,
+    #[serde(default)]
+    pub translated_content: Option<String>,
     pub is_paid_only: bool,
     pub topic_tags: Vec<TopicTag>,
     pub code_snippets: Option<Vec<CodeSnippet>>,
@@ -60,6 +153,172 @@ pub struct QuestionDetail {
     pub sample_test_case: Option<String>,
     pub hints: Vec<String>,
     pub status: Option<String>,
+    /// JSON-encoded acceptance counts, see `QuestionStats`.
+    #[serde(default)]
+    pub stats: Option<String>,
+    /// JSON-encoded companies that asked the problem, by period. Only sent
+    /// to premium accounts.
+    #[serde(default)]
+    pub company_tag_stats: Option<String>,
+    /// JSON-encoded related problems, see `SimilarQuestion`.
+    #[serde(default)]
+    pub similar_questions: Option<String>,
+    /// JSON-encoded signature of the solution method, see `MetaData`.
+    #[serde(default)]
+    pub meta_data: Option<String>,
+}
+
+/// The solution method's name and LeetCode's language-neutral types for
+/// its parameters and result (`integer[]`, `list<string>`, `TreeNode`, ...).
+/// Design problems describe a class instead and don't parse as this.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetaData {
+    pub name: String,
+    #[serde(default)]
+    pub params: Vec<MetaParam>,
+    #[serde(rename = "return")]
+    pub ret: MetaType,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetaParam {
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetaType {
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+/// A related problem listed under the statement.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarQuestion {
+    pub title: String,
+    pub title_slug: String,
+    pub difficulty: String,
+}
+
+/// A company in `companyTagStats`, with how often the problem came up there.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyStat {
+    pub name: String,
+    #[serde(default)]
+    pub times_encountered: i32,
+}
+
+/// All-time acceptance counts for a problem. LeetCode sends these as a
+/// JSON string inside the detail response.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionStats {
+    /// Display form, e.g. "5.2M".
+    #[serde(default)]
+    pub total_accepted: String,
+    #[serde(default)]
+    pub total_submission: String,
+    pub total_accepted_raw: u64,
+    pub total_submission_raw: u64,
+}
+
+impl QuestionStats {
+    pub fn parse(raw: &str) -> Option<Self> {
+        let stats: Self = serde_json::from_str(raw).ok()?;
+        (stats.total_submission_raw > 0).then_some(stats)
+    }
+
+    /// Acceptance rate in percent.
+    pub fn rate(&self) -> f64 {
+        self.total_accepted_raw as f64 / self.total_submission_raw as f64 * 100.0
+    }
+}
+
+impl QuestionDetail {
+    /// The example testcases, falling back to the single `sampleTestCase`
+    /// when the list is missing or only has blank entries (LeetCode
+    /// sometimes sends it empty).
+    pub fn sample_cases(&self) -> Vec<&str> {
+        let examples: Vec<&str> = self
+            .example_testcase_list
+            .iter()
+            .flatten()
+            .map(String::as_str)
+            .filter(|case| !case.trim().is_empty())
+            .collect();
+        if !examples.is_empty() {
+            return examples;
+        }
+        self.sample_test_case
+            .as_deref()
+            .filter(|case| !case.trim().is_empty())
+            .into_iter()
+            .collect()
+    }
+
+    /// Input for a sample run: the sample cases one after another.
+    pub fn sample_input(&self) -> String {
+        self.sample_cases().join("\n")
+    }
+
+    pub fn acceptance(&self) -> Option<QuestionStats> {
+        self.stats.as_deref().and_then(QuestionStats::parse)
+    }
+
+    pub fn meta(&self) -> Option<MetaData> {
+        self.meta_data
+            .as_deref()
+            .and_then(|raw| serde_json::from_str(raw).ok())
+    }
+
+    pub fn similar(&self) -> Vec<SimilarQuestion> {
+        self.similar_questions
+            .as_deref()
+            .and_then(|raw| serde_json::from_str(raw).ok())
+            .unwrap_or_default()
+    }
+
+    /// Companies that asked the problem in the last 6 months, most frequent
+    /// first. Empty without premium.
+    pub fn recent_companies(&self) -> Vec<CompanyStat> {
+        // Keyed by period: "1" is 0-6 months, "2" up to a year, "3" up to two
+        let Some(mut periods) = self
+            .company_tag_stats
+            .as_deref()
+            .and_then(|raw| serde_json::from_str::<HashMap<String, Vec<CompanyStat>>>(raw).ok())
+        else {
+            return Vec::new();
+        };
+        let mut companies = periods.remove("1").unwrap_or_default();
+        companies.sort_by_key(|c| std::cmp::Reverse(c.times_encountered));
+        companies
+    }
+
+    /// Run and submit are keyed by the internal question id; without it
+    /// LeetCode rejects the request with an unhelpful error.
+    pub fn has_question_id(&self) -> bool {
+        !self.question_id.trim().is_empty()
+    }
+
+    /// Swap in the translated title/content when `locale` asks for a
+    /// non-English description and LeetCode sent one. English stays as the
+    /// fallback for anything that wasn't translated.
+    pub fn localize(&mut self, locale: Option<&str>) {
+        let wants_translation = locale
+            .map(|l| l.trim().to_lowercase())
+            .is_some_and(|l| !l.is_empty() && !l.starts_with("en"));
+        if !wants_translation {
+            return;
+        }
+        if let Some(title) = self.translated_title.take().filter(|t| !t.trim().is_empty()) {
+            self.title = title;
+        }
+        if let Some(content) = self.translated_content.take().filter(|c| !c.trim().is_empty()) {
+            self.content = Some(content);
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -81,10 +340,60 @@ pub struct InterpretResponse {
 
 #[derive(Debug, Deserialize)]
 pub struct SubmitResponse {
-    pub submission_id: Option<u64>,
+    #[serde(default, deserialize_with = "string_or_number")]
+    pub submission_id: Option<String>,
     pub error: Option<String>,
 }
 
+/// Accept an id sent either as a JSON number or a string.
+fn string_or_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Id {
+        Number(u64),
+        Text(String),
+    }
+
+    Ok(match Option::<Id>::deserialize(deserializer)? {
+        Some(Id::Number(n)) => Some(n.to_string()),
+        Some(Id::Text(s)) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        _ => None,
+    })
+}
+
+/// Find a `submission_id` anywhere in a response body, for shapes that
+/// don't match `SubmitResponse` (e.g. nested under another object).
+pub fn find_submission_id(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Object(map) => {
+            match map.get("submission_id").or_else(|| map.get("submissionId")) {
+                Some(serde_json::Value::Number(n)) => return Some(n.to_string()),
+                Some(serde_json::Value::String(s)) if !s.is_empty() => return Some(s.clone()),
+                _ => {}
+            }
+            map.values().find_map(find_submission_id)
+        }
+        serde_json::Value::Array(items) => items.iter().find_map(find_submission_id),
+        _ => None,
+    }
+}
+
+/// Find a list's `id_hash` in a response body, e.g. the list the create
+/// endpoint sends back.
+pub fn find_id_hash(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Object(map) => match map.get("id_hash").or_else(|| map.get("idHash")) {
+            Some(serde_json::Value::String(s)) if !s.is_empty() => Some(s.clone()),
+            _ => map.values().find_map(find_id_hash),
+        },
+        serde_json::Value::Array(items) => items.iter().find_map(find_id_hash),
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Default)]
 #[serde(default)]
 pub struct CheckResponse {
@@ -103,6 +412,406 @@ pub struct CheckResponse {
     pub compile_error: Option<String>,
     pub full_compile_error: Option<String>,
     pub correct_answer: Option<bool>,
+    /// Language the code ran as, e.g. "Python3"
+    pub pretty_lang: Option<String>,
+}
+
+// Daily challenge types
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyChallengeData {
+    pub active_daily_coding_challenge_question: Option<DailyChallenge>,
+}
+
+/// leetcode.cn's daily challenge, a one-element list.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyChallengeDataCn {
+    pub today_record: Option<Vec<DailyChallenge>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DailyChallenge {
+    pub question: DailyQuestion,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyQuestion {
+    pub title_slug: String,
+}
+
+// Submission history types
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionListData {
+    pub question_submission_list: Option<SubmissionPage>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionPage {
+    #[serde(default)]
+    pub has_next: bool,
+    #[serde(default)]
+    pub submissions: Vec<SubmissionSummary>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionSummary {
+    pub id: String,
+    pub status_display: String,
+    pub lang: String,
+    #[serde(default)]
+    pub lang_name: Option<String>,
+    #[serde(default)]
+    pub runtime: String,
+    #[serde(default)]
+    pub memory: String,
+    /// Unix seconds, as a string.
+    #[serde(default)]
+    pub timestamp: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionDetailsData {
+    pub submission_details: Option<SubmissionDetail>,
+}
+
+/// A past submission with its source code.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionDetail {
+    pub code: String,
+    #[serde(default)]
+    pub runtime_display: Option<String>,
+    #[serde(default)]
+    pub memory_display: Option<String>,
+    pub lang: SubmissionLang,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionLang {
+    /// LeetCode's language slug, e.g. `python3`.
+    pub name: String,
+    #[serde(default)]
+    pub verbose_name: String,
+}
+
+// Contest types
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contest {
+    pub title: String,
+    pub title_slug: String,
+    /// Unix time.
+    pub start_time: i64,
+    /// Seconds.
+    pub duration: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContestListData {
+    pub upcoming_contests: Option<Vec<Contest>>,
+    pub past_contests: Option<PastContests>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PastContests {
+    pub data: Vec<Contest>,
+}
+
+/// `/contest/api/info/<slug>/`. Problems stay hidden until the contest starts.
+#[derive(Debug, Deserialize)]
+pub struct ContestInfo {
+    #[serde(default)]
+    pub questions: Vec<ContestQuestion>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContestQuestion {
+    pub credit: i32,
+    pub title: String,
+    pub title_slug: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContestHistoryData {
+    pub user_contest_ranking_history: Option<Vec<ContestResult>>,
+}
+
+/// The user's result in one past contest.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContestResult {
+    pub attended: bool,
+    pub ranking: i64,
+    pub problems_solved: i32,
+    pub total_problems: i32,
+    pub contest: ContestTitle,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContestTitle {
+    pub title: String,
+}
+
+// Study plan types
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StudyPlanProgressData {
+    pub study_plan_v2_progress_list: Option<StudyPlanProgressList>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StudyPlanProgressList {
+    pub study_plans: Vec<StudyPlan>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StudyPlan {
+    pub slug: String,
+    pub name: String,
+    #[serde(default)]
+    pub question_num: i32,
+    #[serde(default)]
+    pub finished_question_num: i32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StudyPlanDetailData {
+    pub study_plan_v2_detail: Option<StudyPlanDetail>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StudyPlanDetail {
+    pub name: String,
+    pub plan_sub_groups: Vec<StudyPlanGroup>,
+}
+
+impl StudyPlanDetail {
+    /// (solved, total) across every group.
+    pub fn progress(&self) -> (usize, usize) {
+        self.plan_sub_groups.iter().fold((0, 0), |(solved, total), g| {
+            let (s, t) = g.progress();
+            (solved + s, total + t)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StudyPlanGroup {
+    pub name: String,
+    pub questions: Vec<StudyPlanQuestion>,
+}
+
+impl StudyPlanGroup {
+    pub fn progress(&self) -> (usize, usize) {
+        let solved = self
+            .questions
+            .iter()
+            .filter(|q| q.status.as_deref() == Some("ac"))
+            .count();
+        (solved, self.questions.len())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StudyPlanQuestion {
+    pub title: String,
+    pub title_slug: String,
+    pub frontend_question_id: String,
+    pub difficulty: String,
+    pub status: Option<String>,
+    #[serde(default)]
+    pub paid_only: bool,
+}
+
+// Editorial types
+#[derive(Debug, Deserialize)]
+pub struct OfficialSolutionData {
+    pub question: Option<OfficialSolutionQuestion>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OfficialSolutionQuestion {
+    pub solution: Option<OfficialSolution>,
+}
+
+/// A problem's editorial. `content` is Markdown, and missing when the
+/// editorial is premium and the account isn't.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfficialSolution {
+    #[serde(default)]
+    pub title: String,
+    pub content: Option<String>,
+    #[serde(default)]
+    pub paid_only: bool,
+    #[serde(default)]
+    pub can_see_detail: Option<bool>,
+}
+
+impl OfficialSolution {
+    pub fn is_locked(&self) -> bool {
+        (self.paid_only && self.can_see_detail != Some(true))
+            || self.content.as_deref().is_none_or(|c| c.trim().is_empty())
+    }
+}
+
+// Community solution types
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommunitySolutionsData {
+    pub question_solutions: Option<CommunitySolutionList>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommunitySolutionList {
+    pub total_num: i32,
+    pub solutions: Vec<CommunitySolution>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommunitySolution {
+    pub id: i64,
+    pub title: String,
+    #[serde(default)]
+    pub view_count: i64,
+    #[serde(default)]
+    pub solution_tags: Vec<TopicTag>,
+    pub post: SolutionPost,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionPost {
+    #[serde(default)]
+    pub vote_count: i64,
+    #[serde(default)]
+    pub creation_date: i64,
+    pub author: Option<SolutionAuthor>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SolutionAuthor {
+    pub username: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommunityPostData {
+    pub topic: Option<CommunityTopic>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommunityTopic {
+    pub post: CommunityPostContent,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommunityPostContent {
+    pub content: String,
+}
+
+// Company types
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyTagsData {
+    pub company_tags: Option<Vec<CompanyTag>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyTag {
+    pub name: String,
+    pub slug: String,
+    #[serde(default)]
+    pub question_count: i32,
+}
+
+/// How far back a company's problem list reaches. Each period is its own
+/// premium list on LeetCode, e.g. `google-six-months`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompanyPeriod {
+    ThirtyDays,
+    ThreeMonths,
+    #[default]
+    SixMonths,
+    MoreThanSixMonths,
+    All,
+}
+
+impl CompanyPeriod {
+    pub const ALL: [CompanyPeriod; 5] = [
+        CompanyPeriod::ThirtyDays,
+        CompanyPeriod::ThreeMonths,
+        CompanyPeriod::SixMonths,
+        CompanyPeriod::MoreThanSixMonths,
+        CompanyPeriod::All,
+    ];
+
+    pub fn slug(self) -> &'static str {
+        match self {
+            CompanyPeriod::ThirtyDays => "thirty-days",
+            CompanyPeriod::ThreeMonths => "three-months",
+            CompanyPeriod::SixMonths => "six-months",
+            CompanyPeriod::MoreThanSixMonths => "more-than-six-months",
+            CompanyPeriod::All => "all",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CompanyPeriod::ThirtyDays => "30 days",
+            CompanyPeriod::ThreeMonths => "3 months",
+            CompanyPeriod::SixMonths => "6 months",
+            CompanyPeriod::MoreThanSixMonths => "over 6 months",
+            CompanyPeriod::All => "all time",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|&p| p == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyProblemsData {
+    pub favorite_question_list: Option<CompanyProblemList>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CompanyProblemList {
+    pub questions: Vec<CompanyProblem>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyProblem {
+    pub title: String,
+    pub title_slug: String,
+    pub frontend_question_id: String,
+    pub difficulty: String,
+    pub status: Option<String>,
+    #[serde(default)]
+    pub paid_only: bool,
+    /// How often it was asked, 0-100.
+    #[serde(default)]
+    pub frequency: Option<f64>,
 }
 
 // User status types
@@ -117,6 +826,8 @@ pub struct UserStatusData {
 pub struct UserStatus {
     pub is_signed_in: bool,
     pub username: Option<String>,
+    #[serde(default)]
+    pub is_premium: Option<bool>,
 }
 
 // User profile types
@@ -124,6 +835,9 @@ pub struct UserStatus {
 #[serde(rename_all = "camelCase")]
 pub struct UserProfileData {
     pub matched_user: Option<MatchedUser>,
+    /// Null for users who never took part in a contest.
+    #[serde(default)]
+    pub user_contest_ranking: Option<ContestRanking>,
     pub all_questions_count: Option<Vec<DifficultyCount>>,
 }
 
@@ -131,6 +845,16 @@ pub struct UserProfileData {
 #[serde(rename_all = "camelCase")]
 pub struct MatchedUser {
     pub submit_stats: Option<SubmitStats>,
+    #[serde(default)]
+    pub user_calendar: Option<UserCalendar>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserCalendar {
+    /// JSON object of UTC-midnight unix timestamps to submission counts,
+    /// covering the past year.
+    pub submission_calendar: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -139,6 +863,19 @@ pub struct SubmitStats {
     pub ac_submission_num: Vec<DifficultyCount>,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProgressDataCn {
+    pub user_profile_user_question_progress: Option<UserQuestionProgressCn>,
+    pub all_questions_count: Option<Vec<DifficultyCount>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserQuestionProgressCn {
+    pub num_accepted_questions: Vec<DifficultyCount>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct DifficultyCount {
     pub difficulty: String,
@@ -180,6 +917,45 @@ pub struct FavoriteQuestion {
     pub title_slug: String,
 }
 
+// Skill types
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillStatsData {
+    pub matched_user: Option<SkillStatsUser>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillStatsUser {
+    pub tag_problem_counts: Option<TagProblemCounts>,
+}
+
+/// Solved problems per topic, in LeetCode's three skill levels.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TagProblemCounts {
+    pub fundamental: Vec<TagCount>,
+    pub intermediate: Vec<TagCount>,
+    pub advanced: Vec<TagCount>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagCount {
+    pub tag_name: String,
+    pub tag_slug: String,
+    pub problems_solved: i32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContestRanking {
+    pub attended_contests_count: i32,
+    pub rating: f64,
+    pub global_ranking: i64,
+    pub total_participants: i64,
+    pub top_percentage: Option<f64>,
+}
+
 // Aggregated user stats
 #[derive(Debug, Clone)]
 pub struct UserStats {
@@ -190,4 +966,189 @@ pub struct UserStats {
     pub medium_total: i32,
     pub hard_solved: i32,
     pub hard_total: i32,
+    /// None when the submission calendar isn't available (leetcode.cn).
+    pub streak: Option<Streak>,
+    /// Submissions per UTC day (days since the epoch) over the past year;
+    /// empty without the calendar.
+    pub calendar: BTreeMap<i64, u32>,
+    /// None for users without a rated contest, and on leetcode.cn.
+    pub contest: Option<ContestRanking>,
+}
+
+/// Consecutive days with at least one submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Streak {
+    /// Run ending today, or yesterday if nothing was submitted yet today.
+    pub current: u32,
+    /// Longest run within the calendar's year.
+    pub longest: u32,
+}
+
+impl Streak {
+    /// Streaks from a `submissionCalendar` JSON string, as of `now` (unix
+    /// seconds). Days are UTC, as on LeetCode.
+    pub fn from_calendar(raw: &str, now: i64) -> Option<Self> {
+        let days: Vec<i64> = calendar_days(raw)?
+            .into_iter()
+            .filter(|&(_, count)| count > 0)
+            .map(|(day, _)| day)
+            .collect();
+
+        let mut longest = 0;
+        let mut run = 0;
+        let mut prev = None;
+        for &day in &days {
+            run = if prev == Some(day - 1) { run + 1 } else { 1 };
+            longest = longest.max(run);
+            prev = Some(day);
+        }
+
+        let today = now.div_euclid(86_400);
+        let current = match days.last() {
+            Some(&last) if last >= today - 1 => run,
+            _ => 0,
+        };
+        Some(Self { current, longest })
+    }
+}
+
+/// Submissions per UTC day, from a `submissionCalendar` JSON string keyed by
+/// midnight timestamps. Days are counted from the epoch.
+pub fn calendar_days(raw: &str) -> Option<BTreeMap<i64, u32>> {
+    let calendar: HashMap<String, serde_json::Value> = serde_json::from_str(raw).ok()?;
+    let mut days = BTreeMap::new();
+    for (ts, count) in calendar {
+        if let (Ok(ts), Some(count)) = (ts.parse::<i64>(), count.as_u64()) {
+            *days.entry(ts.div_euclid(86_400)).or_insert(0) += count as u32;
+        }
+    }
+    Some(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// A detail with the required fields, plus `extra`.
+    fn detail(extra: serde_json::Value) -> QuestionDetail {
+        let mut value = json!({
+            "questionId": "1",
+            "frontendQuestionId": "1",
+            "title": "Two Sum",
+            "titleSlug": "two-sum",
+            "difficulty": "Easy",
+            "isPaidOnly": false,
+            "topicTags": [],
+            "hints": [],
+            "content": "<p>Given an array</p>",
+        });
+        if let (Some(value), Some(extra)) = (value.as_object_mut(), extra.as_object()) {
+            value.extend(extra.clone());
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn submission_id(body: serde_json::Value) -> Option<String> {
+        serde_json::from_value::<SubmitResponse>(body).unwrap().submission_id
+    }
+
+    #[test]
+    fn submission_id_is_numeric_or_string() {
+        assert_eq!(submission_id(json!({ "submission_id": 1234567 })).as_deref(), Some("1234567"));
+        assert_eq!(
+            submission_id(json!({ "submission_id": " 1234567 " })).as_deref(),
+            Some("1234567")
+        );
+        assert_eq!(submission_id(json!({ "submission_id": "" })), None);
+        assert_eq!(submission_id(json!({ "error": "Too soon" })), None);
+    }
+
+    #[test]
+    fn nested_submission_id_is_found_in_the_body() {
+        let body = json!({ "data": { "submit": { "submissionId": "987" } } });
+        assert_eq!(submission_id(body.clone()), None);
+        assert_eq!(find_submission_id(&body).as_deref(), Some("987"));
+        assert_eq!(find_submission_id(&json!([{ "submission_id": 42 }])).as_deref(), Some("42"));
+        assert_eq!(find_submission_id(&json!({ "status": "ok" })), None);
+    }
+
+    #[test]
+    fn translated_content_is_chosen_for_a_non_english_locale() {
+        let translated = json!({
+            "translatedTitle": "两数之和",
+            "translatedContent": "<p>给定一个数组</p>",
+        });
+
+        let mut zh = detail(translated.clone());
+        zh.localize(Some("zh-CN"));
+        assert_eq!(zh.title, "两数之和");
+        assert_eq!(zh.content.as_deref(), Some("<p>给定一个数组</p>"));
+
+        for locale in [None, Some("en"), Some("")] {
+            let mut english = detail(translated.clone());
+            english.localize(locale);
+            assert_eq!(english.title, "Two Sum");
+            assert_eq!(english.content.as_deref(), Some("<p>Given an array</p>"));
+        }
+
+        // Untranslated fields fall back to English
+        let mut partial = detail(json!({
+            "translatedTitle": "两数之和",
+            "translatedContent": "",
+        }));
+        partial.localize(Some("zh-CN"));
+        assert_eq!(partial.title, "两数之和");
+        assert_eq!(partial.content.as_deref(), Some("<p>Given an array</p>"));
+    }
+
+    #[test]
+    fn created_list_id_is_read_from_the_response() {
+        let body = json!({ "id_hash": "xyz789", "name": "dp", "questions": [] });
+        assert_eq!(find_id_hash(&body).as_deref(), Some("xyz789"));
+        let nested = json!({ "data": { "favorite": { "idHash": "abc" } } });
+        assert_eq!(find_id_hash(&nested).as_deref(), Some("abc"));
+        assert_eq!(find_id_hash(&json!({ "success": true })), None);
+    }
+
+    #[test]
+    fn acceptance_stats_parse_from_the_embedded_json() {
+        // As LeetCode sends it: a JSON document inside a string field
+        let raw = r#"{"totalAccepted": "5.2M", "totalSubmission": "9.8M",
+            "totalAcceptedRaw": 5200000, "totalSubmissionRaw": 9800000, "acRate": "53.1%"}"#;
+        let stats = detail(json!({ "stats": raw })).acceptance().unwrap();
+        assert_eq!(stats.total_accepted, "5.2M");
+        assert_eq!(stats.total_submission, "9.8M");
+        assert_eq!(stats.total_accepted_raw, 5_200_000);
+        assert!((stats.rate() - 53.06).abs() < 0.01);
+
+        assert_eq!(detail(json!({})).acceptance(), None);
+        assert_eq!(QuestionStats::parse("not json"), None);
+        let no_submissions = r#"{"totalAcceptedRaw": 0, "totalSubmissionRaw": 0}"#;
+        assert_eq!(QuestionStats::parse(no_submissions), None);
+    }
+
+    #[test]
+    fn sample_input_falls_back_to_the_single_sample() {
+        let sample = "[2,7,11,15]\n9";
+        let empty_list = detail(json!({"exampleTestcaseList": [], "sampleTestCase": sample}));
+        assert_eq!(empty_list.sample_input(), sample);
+        let no_list = detail(json!({"sampleTestCase": sample}));
+        assert_eq!(no_list.sample_input(), sample);
+        assert_eq!(detail(json!({"sampleTestCase": "  \n"})).sample_input(), "");
+    }
+
+    #[test]
+    fn sample_input_skips_whitespace_only_examples() {
+        let examples = detail(json!({
+            "exampleTestcaseList": ["[3,3]\n6", " \n ", "", "[3,2,4]\n6"],
+            "sampleTestCase": "[2,7,11,15]\n9",
+        }));
+        assert_eq!(examples.sample_input(), "[3,3]\n6\n[3,2,4]\n6");
+        let blank = detail(json!({
+            "exampleTestcaseList": ["", "\n"],
+            "sampleTestCase": "[2,7,11,15]\n9",
+        }));
+        assert_eq!(blank.sample_input(), "[2,7,11,15]\n9");
+    }
 }
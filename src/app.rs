@@ -2,25 +2,65 @@ use anyhow::Result;
 use crossterm::event::KeyCode;
 use ratatui::{
     Frame,
+    buffer::Buffer,
     layout::Rect,
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
-use std::path::PathBuf;
-use std::process::Command;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::sync::mpsc;
 
-use crate::api::client::LeetCodeClient;
-use crate::api::types::{CheckResponse, FavoriteList, ProblemSummary, QuestionDetail, UserStats};
-use crate::config::Config;
+use crate::api::client::{AuthExpired, LeetCodeClient};
+use crate::api::mutations::{ListMutation, MutationQueue};
+use crate::api::site::site;
+use crate::attempts::{Attempt, AttemptKind, Attempts};
+use crate::browser_cookies::{self, CookieCandidate};
+use crate::api::types::{
+    Category, CheckResponse, CommunitySolution, SubmissionDetail, CompanyPeriod, CompanyProblem, CompanyTag, Contest, OfficialSolution, ContestQuestion, ContestResult, FavoriteList,
+    ProblemSummary, QuestionDetail, StudyPlan, StudyPlanDetail, SubmissionPage, TagProblemCounts,
+    UserStats,
+};
+use crate::cli::StartScreen;
+use crate::clipboard;
+use crate::gist;
+use crate::list_io::{self, ImportReport, ImportTarget};
+use crate::local_test::{self, CaseOutcome, LocalCommand};
+use crate::config::{self, Config, ResultView};
+use crate::contest::VirtualContest;
 use crate::event::{Event, EventHandler};
+use crate::notes;
+use crate::practice::{self, Pomodoro, PracticeTimer, SolveTimes};
+use crate::prefetch::Prefetcher;
 use crate::scaffold;
+use crate::scaffold::batch::{self, BatchReport};
+use crate::testcases;
+use crate::ui::analytics::{self, AnalyticsAction, AnalyticsState};
+use crate::ui::code_view::{self, CodeViewAction, CodeViewState};
+use crate::ui::companies::{self, CompaniesAction, CompaniesState};
+use crate::ui::contests::{self, ContestsAction, ContestsState};
 use crate::ui::detail::{self, DetailAction, DetailState};
+use crate::ui::diff::{self, DiffAction, DiffState};
+use crate::ui::editor::{self, EditorAction, EditorState};
+use crate::ui::editorial::{self, EditorialAction, EditorialState};
+use crate::ui::graphics;
+use crate::ui::history::{self, HistoryAction, HistoryState};
+use crate::ui::hyperlink::{self, Hyperlinks};
 use crate::ui::home::{self, HomeAction, HomeState};
 use crate::ui::lists::{self, ListsAction, ListsState};
+use crate::ui::local_test::{self as local_test_ui, LocalTestAction, LocalTestState};
 use crate::ui::result::{self, ResultAction, ResultData, ResultKind, ResultState};
+use crate::ui::scratchpad::{self, SavedScratchpad, ScratchpadAction, ScratchpadState};
 use crate::ui::setup::{self, SetupAction, SetupState};
+use crate::ui::skills::{self, SkillsAction, SkillsState};
+use crate::ui::solutions::{self, SolutionsAction, SolutionsState};
+use crate::ui::study_plans::{self, StudyPlansAction, StudyPlansState};
+use crate::ui::submissions::{self, SubmissionsAction, SubmissionsState};
+use crate::ui::theme;
 
 pub enum Screen {
     Setup(SetupState),
@@ -28,25 +68,155 @@ pub enum Screen {
     Detail(DetailState),
     Result(ResultState),
     Lists(ListsState),
+    Scratchpad(ScratchpadState),
+    Submissions(SubmissionsState),
+    CodeView(CodeViewState),
+    Diff(DiffState),
+    LocalTest(LocalTestState),
+    Contests(ContestsState),
+    StudyPlans(StudyPlansState),
+    Companies(CompaniesState),
+    Editorial(EditorialState),
+    Solutions(SolutionsState),
+    Skills(SkillsState),
+    History(HistoryState),
+    Analytics(AnalyticsState),
+    Editor(EditorState),
 }
 
 pub enum ApiResult {
     ProblemBatch {
+        category: Category,
         problems: Vec<ProblemSummary>,
         total: i32,
         done: bool,
     },
     Detail(Result<QuestionDetail>),
+    Prefetched {
+        slug: String,
+        result: Result<QuestionDetail>,
+    },
     RunResult(Result<CheckResponse>),
     SubmitResult(Result<CheckResponse>),
     UserStats(Option<UserStats>),
+    CompareStats(Result<UserStats>),
     SearchResult(Result<(Vec<ProblemSummary>, i32)>),
     ProblemFetchError(String),
     Favorites(Result<Vec<FavoriteList>>),
     ListMutation(Result<()>, String), // (result, success_message)
     PopupFavorites(Result<Vec<FavoriteList>>),
+    DownloadProgress { done: usize, total: usize },
+    DownloadDone(BatchReport),
+    ImportProgress { done: usize, total: usize },
+    ImportDone(Result<ImportReport>),
+    Submissions {
+        slug: String,
+        page: usize,
+        result: Result<SubmissionPage>,
+    },
+    SubmissionCode {
+        id: String,
+        result: Result<SubmissionDetail>,
+    },
+    LastAccepted {
+        slug: String,
+        /// Compare it with the local solution instead of pulling it
+        diff: bool,
+        result: Result<Option<SubmissionDetail>>,
+    },
+    LocalTest {
+        /// See `App::local_test_run`
+        run: u64,
+        event: LocalTestEvent,
+    },
+    Contests(Result<(Vec<Contest>, Vec<Contest>)>),
+    ContestHistory(Vec<ContestResult>),
+    ContestProblems {
+        slug: String,
+        result: Result<Vec<ContestQuestion>>,
+    },
+    StudyPlans(Result<Vec<StudyPlan>>),
+    StudyPlan {
+        slug: String,
+        result: Result<StudyPlanDetail>,
+    },
+    Companies(Result<(Vec<CompanyTag>, bool)>),
+    Editorial {
+        slug: String,
+        result: Result<Option<OfficialSolution>>,
+    },
+    CommunitySolutions {
+        slug: String,
+        page: usize,
+        result: Result<(Vec<CommunitySolution>, i32)>,
+    },
+    CommunityPost {
+        id: i64,
+        result: Result<String>,
+    },
+    CompanyProblems {
+        slug: String,
+        period: CompanyPeriod,
+        result: Result<Vec<CompanyProblem>>,
+    },
+    SkillStats(Result<TagProblemCounts>),
+    Gist(Result<String>),
+    Image {
+        url: String,
+        result: Result<Vec<u8>>,
+    },
+}
+
+impl ApiResult {
+    /// The error carried by a failed result, if any.
+    fn error(&self) -> Option<&anyhow::Error> {
+        match self {
+            ApiResult::Detail(Err(e))
+            | ApiResult::RunResult(Err(e))
+            | ApiResult::SubmitResult(Err(e))
+            | ApiResult::CompareStats(Err(e))
+            | ApiResult::SearchResult(Err(e))
+            | ApiResult::Favorites(Err(e))
+            | ApiResult::ListMutation(Err(e), _)
+            | ApiResult::PopupFavorites(Err(e))
+            | ApiResult::ImportDone(Err(e))
+            | ApiResult::Submissions { result: Err(e), .. }
+            | ApiResult::SubmissionCode { result: Err(e), .. }
+            | ApiResult::LastAccepted { result: Err(e), .. }
+            | ApiResult::Contests(Err(e))
+            | ApiResult::ContestProblems { result: Err(e), .. }
+            | ApiResult::StudyPlans(Err(e))
+            | ApiResult::StudyPlan { result: Err(e), .. }
+            | ApiResult::Companies(Err(e))
+            | ApiResult::CompanyProblems { result: Err(e), .. }
+            | ApiResult::Editorial { result: Err(e), .. }
+            | ApiResult::CommunitySolutions { result: Err(e), .. }
+            | ApiResult::CommunityPost { result: Err(e), .. }
+            | ApiResult::SkillStats(Err(e))
+            | ApiResult::Gist(Err(e)) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Progress of a local test run, reported per case as it finishes.
+pub enum LocalTestEvent {
+    BuildFailed(String),
+    Case(usize, CaseOutcome),
 }
 
+/// Minimum gap between consecutive list mutations sent to LeetCode.
+pub const LIST_MUTATION_SPACING: Duration = Duration::from_millis(750);
+
+/// Gap between detail fetches when downloading a whole list.
+const BULK_FETCH_SPACING: Duration = Duration::from_millis(500);
+
+/// How often (in 100ms ticks) to retry the problem list while offline.
+const OFFLINE_RETRY_TICKS: u32 = 600;
+
+const MISSING_QUESTION_ID: &str =
+    "This problem was loaded without a question id, so it can't be run or submitted.\nPress R in the problem view to reload it.";
+
 pub struct AddToListPopup {
     pub lists: Vec<FavoriteList>,
     pub selected: usize,
@@ -54,54 +224,150 @@ pub struct AddToListPopup {
     pub loading: bool,
 }
 
+/// Choice between logins found in several browsers or profiles.
+pub struct CookiePicker {
+    pub logins: Vec<CookieCandidate>,
+    pub selected: usize,
+}
+
+/// Language to scaffold a problem in, picked from its snippets on `o`.
+pub struct LanguagePicker {
+    pub detail: QuestionDetail,
+    /// (LeetCode's name, configured language name) per snippet
+    pub languages: Vec<(String, String)>,
+    pub selected: usize,
+    /// Row of the configured language, when the problem has it
+    pub default: Option<usize>,
+}
+
+/// A pulled submission waiting for the go-ahead to replace a local file
+/// holding different code.
+pub struct PullConfirm {
+    pub path: PathBuf,
+    pub content: String,
+    /// Language of the submission, as LeetCode names it
+    pub lang: String,
+}
+
 pub struct App {
     pub screen: Screen,
     pub config: Option<Config>,
     pub should_quit: bool,
     pub error_overlay: Option<String>,
+    pub error_repeats: u32, // identical errors in a row, reset by any success
+    last_error: Option<String>,
     pub success_message: Option<(String, u8)>, // (message, ticks remaining)
+    pub notice_message: Option<(String, u8)>,  // informational toast, same ticks
     pub help_overlay: bool,
     pub login_prompt: bool,
+    /// The login prompt was raised by a rejected session, not first launch.
+    pub session_expired: bool,
     pub login_waiting: bool,
     pub last_opened_dir: Option<PathBuf>,
     pub add_to_list_popup: Option<AddToListPopup>,
+    pub cookie_picker: Option<CookiePicker>,
+    pub language_picker: Option<LanguagePicker>,
+    pub pull_confirm: Option<PullConfirm>,
     saved_home: Option<HomeState>,
     saved_lists: Option<ListsState>,
+    saved_contests: Option<ContestsState>,
+    saved_study_plans: Option<StudyPlansState>,
+    saved_companies: Option<CompaniesState>,
+    saved_history: Option<HistoryState>,
+    // Submission list left for a submission's code, restored on Back
+    saved_submissions: Option<SubmissionsState>,
+    saved_scratchpad: Option<ScratchpadState>,
+    // Built-in editor left for a run/submit result, restored on Back
+    saved_editor: Option<EditorState>,
+    start_screen: StartScreen,
+    practice: Option<PracticeTimer>,
+    pomodoro: Option<Pomodoro>,
+    virtual_contest: Option<VirtualContest>,
+    solve_times: SolveTimes,
+    attempts: Attempts,
+    autosave_ticks: u64,
+    offline_retry_ticks: u32,
+    prefetch: Prefetcher,
+    /// Id of the latest local test run; older runs stop and their results
+    /// are dropped. The lock keeps two runs from building at once.
+    local_test_run: Arc<AtomicU64>,
+    local_test_lock: Arc<Mutex<()>>,
+    // Set when the terminal understands OSC 8 links
+    hyperlinks: Option<Hyperlinks>,
     api_client: LeetCodeClient,
+    list_queue: MutationQueue,
     api_tx: mpsc::UnboundedSender<ApiResult>,
     api_rx: mpsc::UnboundedReceiver<ApiResult>,
 }
 
 impl App {
-    pub fn new(config: Option<Config>) -> Result<Self> {
+    pub fn new(config: Option<Config>, start_screen: StartScreen) -> Result<Self> {
         let (api_tx, api_rx) = mpsc::unbounded_channel();
         let api_client = LeetCodeClient::new(
             config.as_ref().and_then(|c| c.leetcode_session.as_deref()),
             config.as_ref().and_then(|c| c.csrf_token.as_deref()),
+            &config.as_ref().map(Config::client_options).unwrap_or_default(),
         )?;
 
         let login_prompt = config.as_ref().is_some_and(|c| !c.is_authenticated());
 
-        let screen = if config.is_some() {
-            Screen::Home(HomeState::new())
+        let queue_tx = api_tx.clone();
+        let list_queue = MutationQueue::new(LIST_MUTATION_SPACING, move |result, msg| {
+            let _ = queue_tx.send(ApiResult::ListMutation(result, msg));
+        });
+
+        let screen = if let Some(ref c) = config {
+            Screen::Home(new_home_state(c))
         } else {
             Screen::Setup(SetupState::new())
         };
 
+        let pomodoro = config
+            .as_ref()
+            .filter(|c| c.pomodoro)
+            .map(|c| Pomodoro::start(c.pomodoro_work_mins, c.pomodoro_break_mins));
+
         Ok(Self {
             screen,
             config,
             should_quit: false,
             error_overlay: None,
+            error_repeats: 0,
+            last_error: None,
             success_message: None,
+            notice_message: None,
             help_overlay: false,
             login_prompt,
+            session_expired: false,
             login_waiting: false,
             last_opened_dir: None,
             add_to_list_popup: None,
+            cookie_picker: None,
+            language_picker: None,
+            pull_confirm: None,
             saved_home: None,
             saved_lists: None,
+            saved_contests: None,
+            saved_study_plans: None,
+            saved_companies: None,
+            saved_history: None,
+            saved_submissions: None,
+            saved_scratchpad: None,
+            saved_editor: None,
+            start_screen,
+            practice: None,
+            pomodoro,
+            virtual_contest: None,
+            solve_times: SolveTimes::load(),
+            attempts: Attempts::load(),
+            autosave_ticks: 0,
+            offline_retry_ticks: 0,
+            prefetch: Prefetcher::default(),
+            local_test_run: Arc::default(),
+            local_test_lock: Arc::default(),
+            hyperlinks: hyperlink::supported().then(Hyperlinks::default),
             api_client,
+            list_queue,
             api_tx,
             api_rx,
         })
@@ -112,13 +378,27 @@ impl App {
         terminal: &mut ratatui::DefaultTerminal,
         events: &mut EventHandler,
     ) -> Result<()> {
+        if let Some(ref config) = self.config {
+            let (theme, unknown) = config.resolve_theme();
+            theme::set_theme(theme);
+            if !unknown.is_empty() {
+                self.show_error(format!("Ignoring theme_colors: {}", unknown.join(", ")));
+            }
+        }
+
         if matches!(self.screen, Screen::Home(_)) {
             self.start_fetch_problems();
             self.start_fetch_user_stats();
+            if self.config.as_ref().is_some_and(|c| c.is_authenticated()) {
+                self.start_fetch_favorites();
+            }
+            self.open_start_screen();
         }
 
         loop {
-            terminal.draw(|f| self.render(f))?;
+            let frame = terminal.draw(|f| self.render(f))?;
+            self.draw_hyperlinks(frame.buffer);
+            self.draw_inline_image();
 
             if self.should_quit {
                 break;
@@ -129,7 +409,19 @@ impl App {
                     match event? {
                         Event::Key(key) => self.handle_key(key, terminal, events)?,
                         Event::Tick => self.handle_tick(),
-                        Event::Resize(_, _) => {}
+                        Event::Resize(_, _) => {
+                            self.clear_inline_images();
+                            self.reset_hyperlinks();
+                        }
+                        Event::Focus(gained) => {
+                            if let Some(ref mut timer) = self.practice {
+                                if gained {
+                                    timer.resume();
+                                } else {
+                                    timer.pause();
+                                }
+                            }
+                        }
                     }
                 }
                 Some(api_result) = self.api_rx.recv() => {
@@ -141,6 +433,22 @@ impl App {
         Ok(())
     }
 
+    /// Jump to the screen requested on the command line. Home keeps loading
+    /// in the background so Back lands on a populated table.
+    fn open_start_screen(&mut self) {
+        match std::mem::take(&mut self.start_screen) {
+            StartScreen::Home => {}
+            StartScreen::Problem(slug) => self.start_fetch_detail(&slug),
+            StartScreen::Lists => {
+                let old = std::mem::replace(&mut self.screen, Screen::Lists(ListsState::new()));
+                if let Screen::Home(home) = old {
+                    self.saved_home = Some(home);
+                }
+                self.start_fetch_favorites();
+            }
+        }
+    }
+
     fn render(&mut self, frame: &mut Frame) {
         let area = frame.area();
 
@@ -150,6 +458,22 @@ impl App {
             Screen::Detail(state) => detail::render_detail(frame, area, state),
             Screen::Result(state) => result::render_result(frame, area, state),
             Screen::Lists(state) => lists::render_lists(frame, area, state),
+            Screen::Scratchpad(state) => scratchpad::render_scratchpad(frame, area, state),
+            Screen::Editor(state) => editor::render_editor(frame, area, state),
+            Screen::Submissions(state) => submissions::render_submissions(frame, area, state),
+            Screen::CodeView(state) => code_view::render_code_view(frame, area, state),
+            Screen::Diff(state) => diff::render_diff(frame, area, state),
+            Screen::LocalTest(state) => local_test_ui::render_local_test(frame, area, state),
+            Screen::Contests(state) => {
+                contests::render_contests(frame, area, state, self.virtual_contest.as_ref())
+            }
+            Screen::StudyPlans(state) => study_plans::render_study_plans(frame, area, state),
+            Screen::Companies(state) => companies::render_companies(frame, area, state),
+            Screen::Editorial(state) => editorial::render_editorial(frame, area, state),
+            Screen::Solutions(state) => solutions::render_solutions(frame, area, state),
+            Screen::Skills(state) => skills::render_skills(frame, area, state),
+            Screen::History(state) => history::render_history(frame, area, state),
+            Screen::Analytics(state) => analytics::render_analytics(frame, area, state),
         }
 
         // Login waiting overlay (browser redirect)
@@ -182,7 +506,12 @@ impl App {
             let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
 
             frame.render_widget(Clear, overlay_area);
-            let prompt = Paragraph::new("\nLogin to LeetCode?\nExtracts cookies from your browser.\n\n (Y) Yes  (N) No  (S) Settings")
+            let text = if self.session_expired {
+                "\nYour LeetCode session expired.\nLog in again with your browser cookies?\n\n (Y) Yes  (N) No  (S) Settings"
+            } else {
+                "\nLogin to LeetCode?\nExtracts cookies from your browser.\n\n (Y) Yes  (N) No  (S) Settings"
+            };
+            let prompt = Paragraph::new(text)
                 .block(
                     Block::default()
                         .title(" Login ")
@@ -194,6 +523,123 @@ impl App {
             frame.render_widget(prompt, overlay_area);
         }
 
+        // Browser login picker overlay
+        if let Some(ref picker) = self.cookie_picker {
+            let overlay_width = 52u16.min(area.width.saturating_sub(4));
+            let overlay_height = (picker.logins.len() as u16 + 6).min(area.height.saturating_sub(4));
+            let x = area.x + (area.width.saturating_sub(overlay_width)) / 2;
+            let y = area.y + (area.height.saturating_sub(overlay_height)) / 2;
+            let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
+
+            frame.render_widget(Clear, overlay_area);
+            let mut lines = vec![
+                Line::from(""),
+                Line::from(" Found LeetCode logins in several places:"),
+                Line::from(""),
+            ];
+            lines.extend(picker.logins.iter().enumerate().map(|(i, login)| {
+                let selected = i == picker.selected;
+                let prefix = if selected { "\u{25b8} " } else { "  " };
+                let style = if selected {
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                Line::from(Span::styled(format!(" {prefix}{}", login.label()), style))
+            }));
+            let p = Paragraph::new(lines).block(
+                Block::default()
+                    .title(" Browser Login ")
+                    .title_bottom(" Enter: Use  Esc: Cancel ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            );
+            frame.render_widget(p, overlay_area);
+        }
+
+        // Scaffold language picker overlay
+        if let Some(ref picker) = self.language_picker {
+            let overlay_width = 36u16.min(area.width.saturating_sub(4));
+            let overlay_height =
+                (picker.languages.len() as u16 + 2).min(area.height.saturating_sub(4));
+            let x = area.x + (area.width.saturating_sub(overlay_width)) / 2;
+            let y = area.y + (area.height.saturating_sub(overlay_height)) / 2;
+            let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
+
+            frame.render_widget(Clear, overlay_area);
+            let lines: Vec<Line> = picker
+                .languages
+                .iter()
+                .enumerate()
+                .map(|(i, (name, _))| {
+                    let selected = i == picker.selected;
+                    let prefix = if selected { "\u{25b8} " } else { "  " };
+                    let style = if selected {
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::White)
+                    };
+                    let mut spans = vec![Span::styled(format!(" {prefix}{name}"), style)];
+                    if picker.default == Some(i) {
+                        spans.push(Span::styled(
+                            "  (default)",
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                    }
+                    Line::from(spans)
+                })
+                .collect();
+            // Keep the selection visible when the list is taller than the popup
+            let visible = overlay_height.saturating_sub(2) as usize;
+            let scroll = picker.selected.saturating_sub(visible.saturating_sub(1));
+            let p = Paragraph::new(lines).scroll((scroll as u16, 0)).block(
+                Block::default()
+                    .title(" Scaffold in ")
+                    .title_bottom(" Enter: Scaffold  Esc: Cancel ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Cyan)),
+            );
+            frame.render_widget(p, overlay_area);
+        }
+
+        // Replace-local-file confirmation for a pulled submission
+        if let Some(ref pull) = self.pull_confirm {
+            let overlay_width = 56u16.min(area.width.saturating_sub(4));
+            let overlay_height = 7u16.min(area.height.saturating_sub(4));
+            let x = area.x + (area.width.saturating_sub(overlay_width)) / 2;
+            let y = area.y + (area.height.saturating_sub(overlay_height)) / 2;
+            let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
+
+            frame.render_widget(Clear, overlay_area);
+            let lines = vec![
+                Line::from(Span::styled(
+                    format!(" Your last accepted {} code differs from", pull.lang),
+                    Style::default().fg(Color::White),
+                )),
+                Line::from(Span::styled(
+                    format!(" {}", pull.path.display()),
+                    Style::default().fg(Color::Cyan),
+                )),
+                Line::from(""),
+                Line::from(Span::styled(
+                    " Replace the local file with it?",
+                    Style::default().fg(Color::White),
+                )),
+            ];
+            let p = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+                Block::default()
+                    .title(" Pull Accepted Code ")
+                    .title_bottom(" y: Replace  n/Esc: Keep local ")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            );
+            frame.render_widget(p, overlay_area);
+        }
+
         // Add-to-list popup overlay
         if let Some(ref popup) = self.add_to_list_popup {
             let overlay_width = 44u16.min(area.width.saturating_sub(4));
@@ -283,20 +729,104 @@ impl App {
             }
         }
 
-        // Success toast (bottom center)
-        if let Some((ref msg, _)) = self.success_message {
-            let text = format!(" \u{2714} {msg} ");
-            let w = (text.len() as u16 + 2).min(area.width.saturating_sub(4));
+        // Pending list mutations (top right)
+        let pending = self.list_queue.pending();
+        if pending > 0 {
+            let plural = if pending == 1 { "" } else { "s" };
+            let text = format!(" \u{27f3} {pending} list change{plural} pending ");
+            let w = (text.chars().count() as u16).min(area.width);
+            let indicator_area = Rect::new(area.right().saturating_sub(w), area.y, w, 1);
+            frame.render_widget(
+                Paragraph::new(text).style(Style::default().fg(Color::Black).bg(Color::Yellow)),
+                indicator_area,
+            );
+        }
+
+        // Practice timer (top right) while working on a problem
+        if let Some(ref timer) = self.practice {
+            let on_problem = match &self.screen {
+                Screen::Detail(s) => s.detail.title_slug == timer.slug,
+                Screen::Result(s) => s.detail.title_slug == timer.slug,
+                Screen::Scratchpad(s) => s.detail.title_slug == timer.slug,
+                Screen::CodeView(s) => s.detail.title_slug == timer.slug,
+                Screen::Diff(s) => s.detail.title_slug == timer.slug,
+                Screen::Editor(s) => s.detail.title_slug == timer.slug,
+                Screen::LocalTest(s) => s.detail.title_slug == timer.slug,
+                _ => false,
+            };
+            if on_problem && pending == 0 {
+                let mut text = format!(" \u{23f1} {}", practice::format_clock(timer.elapsed()));
+                if !timer.is_running() {
+                    text.push_str(" (paused)");
+                }
+                if let Some(best) = self.solve_times.get(&timer.slug) {
+                    text.push_str(&format!(" \u{b7} solved in {}", practice::format_duration(best)));
+                }
+                text.push(' ');
+                let w = (text.chars().count() as u16).min(area.width);
+                let timer_area = Rect::new(area.right().saturating_sub(w), area.y, w, 1);
+                frame.render_widget(
+                    Paragraph::new(text).style(Style::default().fg(Color::Black).bg(Color::Cyan)),
+                    timer_area,
+                );
+            }
+        }
+
+        // Pomodoro clock (bottom right, over the status bar)
+        if let Some(ref pomodoro) = self.pomodoro {
+            let (icon, color) = if pomodoro.on_break {
+                ("\u{2615}", Color::Green)
+            } else {
+                ("\u{1f345}", Color::Red)
+            };
+            let text = format!(" {icon} {} ", practice::format_clock(pomodoro.remaining()));
+            let w = (text.chars().count() as u16 + 1).min(area.width);
+            let clock_area = Rect::new(
+                area.right().saturating_sub(w),
+                area.bottom().saturating_sub(1),
+                w,
+                1,
+            );
+            frame.render_widget(
+                Paragraph::new(text).style(Style::default().fg(Color::Black).bg(color)),
+                clock_area,
+            );
+        }
+
+        // Success / notice toast (bottom center)
+        let toast = match (&self.success_message, &self.notice_message) {
+            (Some((msg, _)), _) => Some((format!(" \u{2714} {msg} "), Color::Green)),
+            (None, Some((msg, _))) => Some((format!(" \u{2139} {msg} "), Color::Yellow)),
+            (None, None) => None,
+        };
+        if let Some((text, bg)) = toast {
+            let w = (text.chars().count() as u16 + 2).min(area.width.saturating_sub(4));
             let x = area.x + (area.width.saturating_sub(w)) / 2;
             let y = area.bottom().saturating_sub(3);
             let toast_area = Rect::new(x, y, w, 1);
             frame.render_widget(Clear, toast_area);
             frame.render_widget(
-                Paragraph::new(text).style(Style::default().fg(Color::Black).bg(Color::Green)),
+                Paragraph::new(text).style(Style::default().fg(Color::Black).bg(bg)),
                 toast_area,
             );
         }
 
+        // Guest banner (top right) when browsing without credentials
+        if self.is_guest() && !matches!(self.screen, Screen::Setup(_)) {
+            let text = " Guest (read-only) ";
+            let w = (text.len() as u16).min(area.width);
+            let banner_area = Rect::new(area.right().saturating_sub(w), area.y, w, 1);
+            frame.render_widget(
+                Paragraph::new(text).style(
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Gray)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                banner_area,
+            );
+        }
+
         // Error overlay
         if let Some(ref msg) = self.error_overlay {
             let overlay_width = 50u16.min(area.width.saturating_sub(4));
@@ -306,10 +836,15 @@ impl App {
             let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
 
             frame.render_widget(Clear, overlay_area);
+            let title = if self.error_repeats > 1 {
+                format!(" Error (x{}) ", self.error_repeats)
+            } else {
+                " Error ".to_string()
+            };
             let error_block = Paragraph::new(format!("\n{msg}\n\nPress Esc to dismiss"))
                 .block(
                     Block::default()
-                        .title(" Error ")
+                        .title(title)
                         .borders(Borders::ALL)
                         .border_style(Style::default().fg(Color::Red)),
                 )
@@ -341,10 +876,24 @@ impl App {
                             ("g/G", "Jump to top / bottom"),
                             ("Enter", "View problem detail"),
                             ("o", "Scaffold & open in editor"),
+                            ("O", "Open on LeetCode in the browser"),
                             ("a", "Add to list"),
                             ("/", "Search"),
-                            ("f", "Filter by difficulty"),
+                            ("f", "Filter by difficulty, status or tags; hide Premium"),
+                            ("s", "Sort by number / title / difficulty / AC rate"),
+                            ("r", "Reverse sort order"),
+                            ("1-6/\u{2190}/\u{2192}", "Switch category"),
+                            ("t", "Stats header: gauges / full breakdown / hidden"),
+                            ("K", "Profile & topic skills"),
+                            ("H", "History of your runs and submissions"),
+                            ("A", "Analytics: problems solved over time"),
+                            ("E", "Export shown problems to CSV & JSON"),
+                            ("C", "Compare stats with another user"),
+                            ("D", "Open today's daily challenge"),
                             ("L", "Browse lists"),
+                            ("c", "Contests"),
+                            ("P", "Study plans"),
+                            ("T", "Companies (Premium)"),
                             ("S", "Settings"),
                             ("q", "Quit"),
                         ]
@@ -353,15 +902,55 @@ impl App {
                 Screen::Detail(_) => vec![
                     ("j/k/\u{2191}/\u{2193}", "Scroll"),
                     ("d/u", "Half page down / up"),
-                    ("o", "Scaffold & open in editor"),
+                    ("/", "Search the statement"),
+                    ("o", "Pick a language, scaffold & open"),
                     ("a", "Add to list"),
                     ("r", "Run code"),
                     ("s", "Submit code"),
+                    ("l", "Test locally against the examples"),
+                    ("p", "Scratchpad (custom input)"),
+                    ("y", "Copy solution file path / inline result (while a result is shown)"),
+                    ("n", "Toggle problem and notes / next match (while searching)"),
+                    ("N", "Edit notes in the editor / prev match (while searching)"),
+                    (
+                        "t",
+                        "Edit custom testcases for r / debug the failing case in the \
+                         scratchpad (while a result is shown)",
+                    ),
+                    ("E", "Edit the solution in the built-in editor"),
+                    ("O", "Open on LeetCode in the browser"),
+                    ("i", "View the statement's images"),
+                    ("T", "Pause / resume the solve timer"),
+                    ("R", "Reload problem"),
+                    ("H", "Submission history"),
+                    ("P", "Pull your last accepted code into the solution file"),
+                    ("D", "Diff the solution file against your last accepted code"),
+                    ("v", "View solution file"),
+                    (
+                        "e",
+                        "Editorial (official solution) / expand inline result output \
+                         (while a result is shown)",
+                    ),
+                    ("C", "Community solutions"),
+                    ("h", "Reveal the next hint (again to hide)"),
+                    ("S", "Jump to a similar question"),
+                    ("\u{2190}/\u{2192}", "Prev / next example"),
+                    ("1-4", "Jump to a section (numbered in the title bar)"),
+                    ("[/]", "Prev / next problem of same difficulty"),
+                    ("c", "Toggle constraints side panel"),
+                    ("J/K", "Scroll inline result"),
+                    ("x", "Close inline result"),
                     ("b/Esc", "Back to list"),
                     ("q", "Quit"),
                 ],
                 Screen::Result(_) => vec![
                     ("j/k/\u{2191}/\u{2193}", "Scroll"),
+                    ("e", "Expand / collapse long output"),
+                    ("/", "Search the result"),
+                    ("n/N", "Next / prev match"),
+                    ("y", "Copy full result"),
+                    ("t", "Debug failing case in scratchpad"),
+                    ("G", "Upload accepted solution as a gist"),
                     ("b/Esc", "Back to problem"),
                     ("q", "Quit"),
                 ],
@@ -371,6 +960,7 @@ impl App {
                             ("j/k/\u{2191}/\u{2193}", "Navigate problems"),
                             ("Enter", "View problem detail"),
                             ("d", "Remove from list"),
+                            ("D", "Download all problems"),
                             ("Esc", "Back to lists"),
                         ]
                     } else {
@@ -379,10 +969,157 @@ impl App {
                             ("Enter", "Open list"),
                             ("n", "Create new list"),
                             ("d", "Delete list"),
+                            ("e", "Export list as JSON"),
+                            ("i", "Import list from JSON / CSV"),
+                            ("I", "Import JSON / CSV into the selected list"),
+                            ("u", "Copy share URL (public lists)"),
                             ("Esc/q", "Back to home"),
                         ]
                     }
                 }
+                Screen::Submissions(_) => vec![
+                    ("j/k/\u{2191}/\u{2193}", "Navigate submissions"),
+                    ("Enter", "View the submitted code"),
+                    ("O", "Open submission in browser"),
+                    ("s", "Submit the same code again"),
+                    ("n/p/\u{2190}/\u{2192}", "Next / previous page"),
+                    ("b/Esc", "Back to problem"),
+                    ("q", "Quit"),
+                ],
+                Screen::Contests(state) => {
+                    if state.viewing.is_some() {
+                        vec![
+                            ("j/k/\u{2191}/\u{2193}", "Navigate problems"),
+                            ("Enter", "View problem detail"),
+                            ("v", "Start a virtual contest (past contests)"),
+                            ("x", "End the virtual contest"),
+                            ("b/Esc", "Back to contests"),
+                            ("q", "Quit"),
+                        ]
+                    } else {
+                        vec![
+                            ("j/k/\u{2191}/\u{2193}", "Navigate contests"),
+                            ("Enter", "Open contest problems"),
+                            ("r", "Refresh"),
+                            ("b/Esc/q", "Back to home"),
+                        ]
+                    }
+                }
+                Screen::StudyPlans(state) => {
+                    if state.viewing.is_some() {
+                        vec![
+                            ("j/k/\u{2191}/\u{2193}", "Navigate problems"),
+                            ("Enter", "View problem detail"),
+                            ("b/Esc", "Back to plans"),
+                            ("q", "Quit"),
+                        ]
+                    } else {
+                        vec![
+                            ("j/k/\u{2191}/\u{2193}", "Navigate plans"),
+                            ("Enter", "Open plan"),
+                            ("b/Esc/q", "Back to home"),
+                        ]
+                    }
+                }
+                Screen::Companies(state) => {
+                    if state.viewing.is_some() {
+                        vec![
+                            ("j/k/\u{2191}/\u{2193}", "Navigate problems"),
+                            ("Enter", "View problem detail"),
+                            ("p/Tab", "Next period (30 days ... all time)"),
+                            ("F", "Filter the home table to these problems"),
+                            ("b/Esc", "Back to companies"),
+                            ("q", "Quit"),
+                        ]
+                    } else {
+                        vec![
+                            ("j/k/\u{2191}/\u{2193}", "Navigate companies"),
+                            ("Enter", "Open company problems"),
+                            ("/", "Search companies"),
+                            ("b/Esc/q", "Back to home"),
+                        ]
+                    }
+                }
+                Screen::Skills(_) => vec![
+                    ("j/k/\u{2191}/\u{2193}", "Scroll"),
+                    ("d/u", "Half page down / up"),
+                    ("g/G", "Jump to top / bottom"),
+                    ("r", "Refresh"),
+                    ("b/Esc", "Back to home"),
+                    ("q", "Quit"),
+                ],
+                Screen::History(_) => vec![
+                    ("j/k/\u{2191}/\u{2193}", "Navigate"),
+                    ("g/G", "Jump to newest / oldest"),
+                    ("Enter", "Open the problem"),
+                    ("b/Esc", "Back to home"),
+                    ("q", "Quit"),
+                ],
+                Screen::Analytics(_) => vec![
+                    ("w/m/Tab", "Chart per week / per month"),
+                    ("r", "Refresh the submission calendar"),
+                    ("b/Esc", "Back to home"),
+                    ("q", "Quit"),
+                ],
+                Screen::LocalTest(_) => vec![
+                    ("j/k/\u{2191}/\u{2193}", "Select case"),
+                    ("r", "Run the cases again"),
+                    ("b/Esc", "Back to problem"),
+                    ("q", "Quit"),
+                ],
+                Screen::Solutions(state) => {
+                    if state.viewing.is_some() {
+                        vec![
+                            ("j/k/\u{2191}/\u{2193}", "Scroll"),
+                            ("d/u", "Half page down / up"),
+                            ("g/G", "Jump to top / bottom"),
+                            ("b/Esc", "Back to solutions"),
+                            ("q", "Quit"),
+                        ]
+                    } else {
+                        vec![
+                            ("j/k/\u{2191}/\u{2193}", "Navigate solutions"),
+                            ("Enter", "Read solution"),
+                            ("n/p/\u{2190}/\u{2192}", "Next / previous page"),
+                            ("b/Esc", "Back to problem"),
+                            ("q", "Quit"),
+                        ]
+                    }
+                }
+                Screen::CodeView(_) | Screen::Editorial(_) => vec![
+                    ("j/k/\u{2191}/\u{2193}", "Scroll"),
+                    ("d/u", "Half page down / up"),
+                    ("g/G", "Jump to top / bottom"),
+                    ("b/Esc", "Back to problem"),
+                    ("q", "Quit"),
+                ],
+                Screen::Diff(_) => vec![
+                    ("j/k/\u{2191}/\u{2193}", "Scroll"),
+                    ("d/u", "Half page down / up"),
+                    ("n/N", "Next / previous change"),
+                    ("g/G", "Jump to top / bottom"),
+                    ("b/Esc", "Back to problem"),
+                    ("q", "Quit"),
+                ],
+                Screen::Scratchpad(_) => vec![
+                    ("Ctrl+N/Ctrl+P", "Next / previous field"),
+                    ("Tab", "Indent (in the code), next field (elsewhere)"),
+                    ("Shift+Tab", "Dedent the line (in the code)"),
+                    ("\u{2190}/\u{2192}/\u{2191}/\u{2193} Home/End", "Move the cursor"),
+                    ("\u{2190}/\u{2192}", "Change language"),
+                    ("Ctrl+R", "Run against the problem"),
+                    ("Esc", "Back to problem"),
+                ],
+                Screen::Editor(_) => vec![
+                    ("i/a/o", "Insert before / after / on a new line"),
+                    ("Esc", "Back to normal mode"),
+                    ("h/j/k/l w/b", "Move"),
+                    ("x dd yy p", "Delete char / line, yank, paste"),
+                    ("u/Ctrl+R", "Undo / redo"),
+                    (":w Ctrl+S", "Save"),
+                    ("R/S", "Save and run / submit"),
+                    (":q :q!", "Back (discarding changes)"),
+                ],
                 Screen::Setup(_) => vec![
                     ("Tab/\u{2193}", "Next field"),
                     ("Shift+Tab/\u{2191}", "Previous field"),
@@ -409,7 +1146,10 @@ impl App {
                 .collect();
 
             let overlay_height = (lines.len() as u16 + 4).min(area.height.saturating_sub(4));
-            let overlay_width = 48u16.min(area.width.saturating_sub(4));
+            // Wide enough for the longest entry: indent, key, gap, borders
+            let max_desc_len = help_text.iter().map(|(_, d)| d.chars().count()).max().unwrap_or(0);
+            let content_width = (max_key_len + max_desc_len + 6) as u16;
+            let overlay_width = content_width.max(48).min(area.width.saturating_sub(4));
             let x = area.x + (area.width.saturating_sub(overlay_width)) / 2;
             let y = area.y + (area.height.saturating_sub(overlay_height)) / 2;
             let overlay_area = Rect::new(x, y, overlay_width, overlay_height);
@@ -449,6 +1189,10 @@ impl App {
             && !self.login_waiting
             && self.error_overlay.is_none()
             && self.add_to_list_popup.is_none()
+            && self.cookie_picker.is_none()
+            && self.language_picker.is_none()
+            && self.pull_confirm.is_none()
+            && !matches!(self.screen, Screen::Scratchpad(_) | Screen::Editor(_))
         {
             self.help_overlay = !self.help_overlay;
             return Ok(());
@@ -473,14 +1217,17 @@ impl App {
             match key.code {
                 KeyCode::Char('y') | KeyCode::Char('Y') => {
                     self.login_prompt = false;
+                    self.session_expired = false;
                     self.browser_login();
                     self.start_fetch_user_stats();
                 }
                 KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
                     self.login_prompt = false;
+                    self.session_expired = false;
                 }
                 KeyCode::Char('s') | KeyCode::Char('S') => {
                     self.login_prompt = false;
+                    self.session_expired = false;
                     let setup_state = match &self.config {
                         Some(c) => SetupState::from_config(c),
                         None => SetupState::new(),
@@ -502,6 +1249,7 @@ impl App {
         if self.success_message.is_some() {
             self.success_message = None;
         }
+        self.notice_message = None;
 
         // Dismiss error overlay on Esc or q
         if self.error_overlay.is_some() {
@@ -512,6 +1260,75 @@ impl App {
             return Ok(());
         }
 
+        // Handle browser login picker
+        if let Some(ref mut picker) = self.cookie_picker {
+            let count = picker.logins.len();
+            match key.code {
+                KeyCode::Esc => self.cookie_picker = None,
+                KeyCode::Char('j') | KeyCode::Down => {
+                    picker.selected = (picker.selected + 1) % count;
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    picker.selected = (picker.selected + count - 1) % count;
+                }
+                KeyCode::Enter => {
+                    if let Some(picker) = self.cookie_picker.take() {
+                        let login = picker.logins[picker.selected].clone();
+                        self.apply_login_cookies(Some(login.session), Some(login.csrf));
+                    }
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // Handle scaffold language picker
+        if let Some(ref mut picker) = self.language_picker {
+            let count = picker.languages.len();
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => self.language_picker = None,
+                KeyCode::Char('j') | KeyCode::Down => {
+                    picker.selected = (picker.selected + 1) % count;
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    picker.selected = (picker.selected + count - 1) % count;
+                }
+                KeyCode::Char('g') | KeyCode::Home => picker.selected = 0,
+                KeyCode::Char('G') | KeyCode::End => picker.selected = count - 1,
+                KeyCode::Enter => {
+                    if let Some(picker) = self.language_picker.take() {
+                        let language = picker.languages[picker.selected].1.clone();
+                        self.do_scaffold_and_edit(&picker.detail, &language, terminal, events)?;
+                    }
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        // Handle the pulled-code confirmation
+        if let Some(pull) = self.pull_confirm.take() {
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    match std::fs::write(&pull.path, pull.content) {
+                        Ok(()) => self.show_success(
+                            format!("Pulled your last accepted code into {}", pull.path.display()),
+                            30,
+                        ),
+                        Err(e) => self.show_error(format!(
+                            "Failed to write {}: {e}",
+                            pull.path.display()
+                        )),
+                    }
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc | KeyCode::Char('q') => {
+                    self.notice_message = Some(("Kept the local file".to_string(), 12));
+                }
+                _ => self.pull_confirm = Some(pull),
+            }
+            return Ok(());
+        }
+
         // Handle add-to-list popup
         if let Some(ref mut popup) = self.add_to_list_popup {
             match key.code {
@@ -564,24 +1381,27 @@ impl App {
                         } else {
                             Some(state.fields[4].clone())
                         };
+                        // Keep settings that aren't editable in the form
                         let config = Config {
                             workspace_dir: state.fields[0].clone(),
                             language: state.fields[1].clone(),
                             editor: state.fields[2].clone(),
                             leetcode_session: session,
                             csrf_token: csrf,
+                            ..self.config.clone().unwrap_or_default()
                         };
                         if let Err(e) = config.save() {
-                            self.error_overlay = Some(format!("Failed to save config: {e}"));
+                            self.show_error(format!("Failed to save config: {e}"));
                         } else {
                             if let Ok(client) = LeetCodeClient::new(
                                 config.leetcode_session.as_deref(),
                                 config.csrf_token.as_deref(),
+                                &config.client_options(),
                             ) {
                                 self.api_client = client;
                             }
+                            self.screen = Screen::Home(new_home_state(&config));
                             self.config = Some(config);
-                            self.screen = Screen::Home(HomeState::new());
                             self.start_fetch_problems();
                             self.start_fetch_user_stats();
                         }
@@ -590,16 +1410,7 @@ impl App {
                 SetupAction::Cancel => {
                     self.restore_home();
                 }
-                SetupAction::BrowserLogin => {
-                    self.browser_login();
-                    if let Screen::Setup(ref mut s) = self.screen {
-                        if let Some(ref config) = self.config {
-                            s.fields[3] = config.leetcode_session.clone().unwrap_or_default();
-                            s.fields[4] = config.csrf_token.clone().unwrap_or_default();
-                            s.authenticated = config.is_authenticated();
-                        }
-                    }
-                }
+                SetupAction::BrowserLogin => self.browser_login(),
                 SetupAction::Quit => self.should_quit = true,
                 SetupAction::None => {}
             }
@@ -610,7 +1421,11 @@ impl App {
             Screen::Home(state) => match state.handle_key(key) {
                 HomeAction::Quit => self.should_quit = true,
                 HomeAction::OpenDetail(slug) => {
-                    self.start_fetch_detail(&slug);
+                    if let Some(detail) = self.prefetch.get(&slug) {
+                        self.handle_api_result(ApiResult::Detail(Ok(detail)));
+                    } else if !self.prefetch.open_when_ready(&slug) {
+                        self.start_fetch_detail(&slug);
+                    }
                 }
                 HomeAction::Scaffold(slug) => {
                     self.start_fetch_detail_for_scaffold(&slug, terminal)?;
@@ -618,6 +1433,7 @@ impl App {
                 HomeAction::SearchFetch(query) => {
                     self.start_search_fetch(&query);
                 }
+                HomeAction::Lists if self.guest_blocked("Lists") => {}
                 HomeAction::Lists => {
                     // Save home state and switch to lists
                     let old = std::mem::replace(&mut self.screen, Screen::Lists(ListsState::new()));
@@ -626,37 +1442,203 @@ impl App {
                     }
                     self.start_fetch_favorites();
                 }
-                HomeAction::AddToList(question_id) => {
-                    self.open_add_to_list_popup(question_id);
+                HomeAction::Contests => {
+                    let old =
+                        std::mem::replace(&mut self.screen, Screen::Contests(ContestsState::new()));
+                    if let Screen::Home(home) = old {
+                        self.saved_home = Some(home);
+                    }
+                    self.start_fetch_contests();
                 }
-                HomeAction::Settings => {
-                    let setup_state = match &self.config {
-                        Some(c) => SetupState::from_config(c),
-                        None => SetupState::new(),
-                    };
-                    self.screen = Screen::Setup(setup_state);
+                HomeAction::StudyPlans => {
+                    let old = std::mem::replace(
+                        &mut self.screen,
+                        Screen::StudyPlans(StudyPlansState::new()),
+                    );
+                    if let Screen::Home(home) = old {
+                        self.saved_home = Some(home);
+                    }
+                    self.start_fetch_study_plans();
                 }
-                HomeAction::None => {}
-            },
-            Screen::Detail(state) => {
-                let action = state.handle_key(key);
-                match action {
-                    DetailAction::Back => {
-                        if let Some(lists) = self.saved_lists.take() {
-                            self.screen = Screen::Lists(lists);
-                        } else {
-                            self.restore_home();
-                        }
+                HomeAction::Companies => {
+                    let old = std::mem::replace(
+                        &mut self.screen,
+                        Screen::Companies(CompaniesState::new()),
+                    );
+                    if let Screen::Home(home) = old {
+                        self.saved_home = Some(home);
                     }
-                    DetailAction::Quit => self.should_quit = true,
-                    DetailAction::Scaffold(_) => {
-                        let detail = if let Screen::Detail(s) = &self.screen {
-                            s.detail.clone()
-                        } else {
-                            unreachable!()
-                        };
-                        self.do_scaffold_and_edit(&detail, terminal, events)?;
+                    self.start_fetch_companies();
+                }
+                HomeAction::Skills if self.guest_blocked("Topic stats") => {}
+                HomeAction::Skills => {
+                    let old = std::mem::replace(
+                        &mut self.screen,
+                        Screen::Skills(SkillsState::new(&[], None)),
+                    );
+                    if let Screen::Home(home) = old {
+                        let state = SkillsState::new(&home.problems, home.user_stats.clone());
+                        self.screen = Screen::Skills(state);
+                        self.saved_home = Some(home);
+                    }
+                    self.start_fetch_skill_stats();
+                }
+                HomeAction::History => {
+                    let state = HistoryState::new(self.attempts.newest_first());
+                    let old = std::mem::replace(&mut self.screen, Screen::History(state));
+                    if let Screen::Home(home) = old {
+                        self.saved_home = Some(home);
+                    }
+                }
+                HomeAction::Analytics => {
+                    let mut attempts = self.attempts.newest_first();
+                    attempts.reverse();
+                    let old = std::mem::replace(
+                        &mut self.screen,
+                        Screen::Analytics(AnalyticsState::new(&[], Default::default())),
+                    );
+                    if let Screen::Home(home) = old {
+                        let calendar = home
+                            .user_stats
+                            .as_ref()
+                            .map(|s| s.calendar.clone())
+                            .unwrap_or_default();
+                        let mut state = AnalyticsState::new(&attempts, calendar);
+                        state.loading = !self.is_guest();
+                        self.screen = Screen::Analytics(state);
+                        self.saved_home = Some(home);
+                    }
+                    if !self.is_guest() {
+                        self.start_fetch_user_stats();
+                    }
+                }
+                HomeAction::OpenInBrowser(slug) => {
+                    self.open_in_browser(&site().problem_url(&slug));
+                }
+                HomeAction::ExportProblems => {
+                    let workspace = self.config.clone().unwrap_or_default().expanded_workspace();
+                    let result = match self.screen {
+                        Screen::Home(ref home) => {
+                            let problems = home.filtered_problems();
+                            list_io::write_problem_export(&workspace, &problems)
+                                .map(|path| (problems.len(), path))
+                        }
+                        _ => return Ok(()),
+                    };
+                    match result {
+                        Ok((count, path)) => self.show_success(
+                            format!("Exported {count} problems to {} (+ .json)", path.display()),
+                            20,
+                        ),
+                        Err(e) => self.show_error(format!("Export failed: {e}")),
+                    }
+                }
+                HomeAction::AddToList(_) if self.guest_blocked("Adding to a list") => {}
+                HomeAction::AddToList(question_id) => {
+                    self.open_add_to_list_popup(question_id);
+                }
+                HomeAction::ToggleStats { show, expanded } => {
+                    if let Some(ref mut config) = self.config {
+                        config.show_stats = show;
+                        config.stats_expanded = expanded;
+                        if let Err(e) = config.save() {
+                            self.show_error(format!("Failed to save config: {e}"));
+                        }
+                    }
+                }
+                HomeAction::SwitchCategory(category) => {
+                    if let Some(ref mut config) = self.config {
+                        config.category = category;
+                        if let Err(e) = config.save() {
+                            self.show_error(format!("Failed to save config: {e}"));
+                        }
+                    }
+                    self.start_fetch_problems();
+                }
+                HomeAction::CompareUser(username) => {
+                    self.start_fetch_compare_stats(&username);
+                }
+                HomeAction::DailyChallenge => self.start_fetch_daily(),
+                HomeAction::Settings => {
+                    let setup_state = match &self.config {
+                        Some(c) => SetupState::from_config(c),
+                        None => SetupState::new(),
+                    };
+                    self.screen = Screen::Setup(setup_state);
+                }
+                HomeAction::None => {}
+            },
+            Screen::Detail(state) => {
+                let action = state.handle_key(key);
+                match action {
+                    DetailAction::Back => {
+                        if let Some(lists) = self.saved_lists.take() {
+                            self.screen = Screen::Lists(lists);
+                        } else if let Some(contests) = self.saved_contests.take() {
+                            self.screen = Screen::Contests(contests);
+                        } else if let Some(plans) = self.saved_study_plans.take() {
+                            self.screen = Screen::StudyPlans(plans);
+                        } else if let Some(companies) = self.saved_companies.take() {
+                            self.screen = Screen::Companies(companies);
+                        } else if let Some(history) = self.saved_history.take() {
+                            self.screen = Screen::History(history);
+                        } else {
+                            self.restore_home();
+                        }
+                    }
+                    DetailAction::Quit => self.should_quit = true,
+                    DetailAction::Scaffold(_) => {
+                        let detail = if let Screen::Detail(s) = &self.screen {
+                            s.detail.clone()
+                        } else {
+                            unreachable!()
+                        };
+                        self.open_language_picker(detail, terminal, events)?;
                     }
+                    DetailAction::EditNotes => self.edit_notes(terminal, events),
+                    DetailAction::EditTestcases => {
+                        let detail = state.detail.clone();
+                        self.edit_testcases(&detail, terminal, events);
+                    }
+                    DetailAction::EditBuiltin => {
+                        let detail = state.detail.clone();
+                        self.open_editor(detail);
+                    }
+                    DetailAction::OpenInBrowser(slug) => {
+                        self.open_in_browser(&site().problem_url(&slug));
+                    }
+                    DetailAction::ViewImages => {
+                        let protocol = self
+                            .config
+                            .as_ref()
+                            .is_some_and(|c| c.inline_images)
+                            .then(graphics::detect)
+                            .flatten();
+                        match protocol {
+                            Some(protocol) => {
+                                if let Some(url) = state.open_images(protocol) {
+                                    self.start_fetch_image(url);
+                                }
+                            }
+                            None => {
+                                let url = state.images[0].clone();
+                                self.open_in_browser(&url);
+                                let msg = "Opened image 1 in the browser \u{2014} inline images need kitty, iTerm2 or WezTerm";
+                                self.notice_message = Some((msg.into(), 25));
+                            }
+                        }
+                    }
+                    DetailAction::LoadImage(url) => {
+                        self.clear_inline_images();
+                        self.start_fetch_image(url);
+                    }
+                    DetailAction::CloseImages => {
+                        self.clear_inline_images();
+                        let _ = terminal.clear();
+                        self.reset_hyperlinks();
+                    }
+                    DetailAction::OpenUrl(url) => self.open_in_browser(&url),
+                    DetailAction::ToggleTimer => self.toggle_practice_timer(),
                     DetailAction::RunCode => {
                         let detail = if let Screen::Detail(s) = &self.screen {
                             s.detail.clone()
@@ -673,18 +1655,136 @@ impl App {
                         };
                         self.start_submit_code(&detail);
                     }
+                    DetailAction::AddToList(_) if self.guest_blocked("Adding to a list") => {}
                     DetailAction::AddToList(question_id) => {
                         self.open_add_to_list_popup(question_id);
                     }
+                    DetailAction::Scratchpad => {
+                        let detail = if let Screen::Detail(s) = &self.screen {
+                            s.detail.clone()
+                        } else {
+                            unreachable!()
+                        };
+                        let lang = self.lang_slug().to_string();
+                        self.screen = Screen::Scratchpad(ScratchpadState::new(
+                            detail,
+                            &lang,
+                            SavedScratchpad::load(),
+                        ));
+                    }
+                    DetailAction::OpenDetail(slug) | DetailAction::Reload(slug) => {
+                        self.start_fetch_detail(&slug);
+                    }
+                    DetailAction::Submissions if self.guest_blocked("Submission history") => {}
+                    DetailAction::Submissions => {
+                        let detail = if let Screen::Detail(s) = &self.screen {
+                            s.detail.clone()
+                        } else {
+                            unreachable!()
+                        };
+                        let slug = detail.title_slug.clone();
+                        self.screen = Screen::Submissions(SubmissionsState::new(detail));
+                        self.start_fetch_submissions(&slug, 0);
+                    }
+                    DetailAction::PullAccepted if self.guest_blocked("Pulling submissions") => {}
+                    DetailAction::PullAccepted => {
+                        let slug = if let Screen::Detail(s) = &self.screen {
+                            s.detail.title_slug.clone()
+                        } else {
+                            unreachable!()
+                        };
+                        self.notice_message =
+                            Some(("Fetching your last accepted submission...".to_string(), 20));
+                        self.start_fetch_last_accepted(&slug, false);
+                    }
+                    DetailAction::DiffAccepted if self.guest_blocked("Comparing submissions") => {}
+                    DetailAction::DiffAccepted => {
+                        let slug = if let Screen::Detail(s) = &self.screen {
+                            s.detail.title_slug.clone()
+                        } else {
+                            unreachable!()
+                        };
+                        self.notice_message =
+                            Some(("Fetching your last accepted submission...".to_string(), 20));
+                        self.start_fetch_last_accepted(&slug, true);
+                    }
+                    DetailAction::LocalTest => {
+                        let detail = if let Screen::Detail(s) = &self.screen {
+                            s.detail.clone()
+                        } else {
+                            unreachable!()
+                        };
+                        self.open_local_test(detail);
+                    }
+                    DetailAction::ViewSolution => {
+                        let detail = if let Screen::Detail(s) = &self.screen {
+                            s.detail.clone()
+                        } else {
+                            unreachable!()
+                        };
+                        self.open_solution_view(detail);
+                    }
+                    DetailAction::OpenEditorial => {
+                        let detail = if let Screen::Detail(s) = &self.screen {
+                            s.detail.clone()
+                        } else {
+                            unreachable!()
+                        };
+                        let slug = detail.title_slug.clone();
+                        self.screen = Screen::Editorial(EditorialState::new(detail));
+                        self.start_fetch_editorial(&slug);
+                    }
+                    DetailAction::CommunitySolutions => {
+                        let detail = if let Screen::Detail(s) = &self.screen {
+                            s.detail.clone()
+                        } else {
+                            unreachable!()
+                        };
+                        let slug = detail.title_slug.clone();
+                        self.screen = Screen::Solutions(SolutionsState::new(detail));
+                        self.start_fetch_community_solutions(&slug, 0);
+                    }
+                    DetailAction::CopyPath => {
+                        let detail = if let Screen::Detail(s) = &self.screen {
+                            s.detail.clone()
+                        } else {
+                            unreachable!()
+                        };
+                        self.copy_solution_path(&detail);
+                    }
+                    DetailAction::CopyResult(text) => match clipboard::copy(&text) {
+                        Ok(()) => self.show_success("Result copied to clipboard".into(), 12),
+                        Err(e) => self.show_error(format!("Failed to copy: {e}")),
+                    },
+                    DetailAction::DebugInput(input) => self.open_failing_case(&input),
                     DetailAction::None => {}
                 }
             }
             Screen::Result(state) => match state.handle_key(key) {
                 ResultAction::Back => {
-                    let detail = state.detail.clone();
-                    self.screen = Screen::Detail(DetailState::new(detail));
+                    let editor = self
+                        .saved_editor
+                        .take()
+                        .filter(|e| e.detail.title_slug == state.detail.title_slug);
+                    if let Some(pad) = self.saved_scratchpad.take() {
+                        self.screen = Screen::Scratchpad(pad);
+                    } else if let Some(editor) = editor {
+                        self.screen = Screen::Editor(editor);
+                    } else {
+                        let detail = state.detail.clone();
+                        self.screen = Screen::Detail(self.new_detail_state(detail));
+                    }
                 }
                 ResultAction::Quit => self.should_quit = true,
+                ResultAction::Copy(text) => match clipboard::copy(&text) {
+                    Ok(()) => self.show_success("Result copied to clipboard".into(), 12),
+                    Err(e) => self.show_error(format!("Failed to copy: {e}")),
+                },
+                ResultAction::DebugInput(input) => self.open_failing_case(&input),
+                ResultAction::UploadGist => {
+                    let detail = state.detail.clone();
+                    self.start_upload_gist(&detail);
+                }
                 ResultAction::None => {}
             },
             Screen::Lists(state) => {
@@ -708,9 +1808,226 @@ impl App {
                     } => {
                         self.start_remove_from_list(&id_hash, &question_id);
                     }
+                    ListsAction::DownloadAll(slugs) => {
+                        state.download = Some((0, slugs.len()));
+                        self.start_download_all(slugs);
+                    }
+                    ListsAction::ExportJson(idx) => {
+                        let list = state.lists[idx].clone();
+                        let workspace =
+                            self.config.clone().unwrap_or_default().expanded_workspace();
+                        match list_io::write_export(&workspace, &list) {
+                            Ok(path) => {
+                                self.show_success(format!("Exported to {}", path.display()), 20)
+                            }
+                            Err(e) => self.show_error(format!("Export failed: {e}")),
+                        }
+                    }
+                    ListsAction::CopyShareUrl(idx) => {
+                        let list = state.lists[idx].clone();
+                        match list_io::share_url(&list) {
+                            Some(url) => match clipboard::copy(&url) {
+                                Ok(()) => self.show_success(format!("Copied {url}"), 12),
+                                Err(e) => self.show_error(format!("Failed to copy: {e}")),
+                            },
+                            None => {
+                                let msg = "Private lists have no share URL \u{2014} press e to export JSON";
+                                self.notice_message = Some((msg.into(), 20));
+                            }
+                        }
+                    }
+                    ListsAction::ImportFile { path, into } => {
+                        let target = into.and_then(|i| state.lists.get(i)).map(|list| {
+                            ImportTarget::Existing {
+                                id_hash: list.id_hash.clone(),
+                                name: list.name.clone(),
+                            }
+                        });
+                        self.start_import_list(&path, target);
+                    }
                     ListsAction::None => {}
                 }
             }
+            Screen::Scratchpad(state) => match state.handle_key(key) {
+                ScratchpadAction::Back => {
+                    state.save();
+                    let detail = state.detail.clone();
+                    self.screen = Screen::Detail(self.new_detail_state(detail));
+                }
+                ScratchpadAction::Quit => {
+                    state.save();
+                    self.should_quit = true;
+                }
+                ScratchpadAction::Run => self.start_scratchpad_run(),
+                ScratchpadAction::None => {}
+            },
+            Screen::Submissions(state) => match state.handle_key(key) {
+                SubmissionsAction::Back => {
+                    let detail = state.detail.clone();
+                    self.screen = Screen::Detail(self.new_detail_state(detail));
+                }
+                SubmissionsAction::Quit => self.should_quit = true,
+                SubmissionsAction::View(id) => {
+                    self.notice_message = Some(("Fetching the submitted code...".to_string(), 20));
+                    self.start_fetch_submission_code(&id);
+                }
+                SubmissionsAction::Open(id) => {
+                    self.open_in_browser(&site().url(&format!("/submissions/detail/{id}/")));
+                }
+                SubmissionsAction::Resubmit(id) => {
+                    let detail = state.detail.clone();
+                    self.start_resubmit(&detail, &id);
+                }
+                SubmissionsAction::FetchPage(page) => {
+                    let slug = state.detail.title_slug.clone();
+                    self.start_fetch_submissions(&slug, page);
+                }
+                SubmissionsAction::None => {}
+            },
+            Screen::LocalTest(state) => match state.handle_key(key) {
+                LocalTestAction::Back => {
+                    let detail = state.detail.clone();
+                    // Stop the run after the case in progress
+                    self.local_test_run.fetch_add(1, Ordering::SeqCst);
+                    self.screen = Screen::Detail(self.new_detail_state(detail));
+                }
+                LocalTestAction::Quit => self.should_quit = true,
+                LocalTestAction::Rerun => {
+                    state.reset();
+                    let detail = state.detail.clone();
+                    self.start_local_test(&detail);
+                }
+                LocalTestAction::None => {}
+            },
+            Screen::Contests(state) => match state.handle_key(key) {
+                ContestsAction::Back => self.restore_home(),
+                ContestsAction::Quit => self.should_quit = true,
+                ContestsAction::Refresh => self.start_fetch_contests(),
+                ContestsAction::OpenContest(slug) => self.start_fetch_contest_problems(&slug),
+                ContestsAction::OpenDetail(slug) => self.start_fetch_detail(&slug),
+                ContestsAction::StartVirtual(..) if self.guest_blocked("Virtual contests") => {}
+                ContestsAction::StartVirtual(contest, problems) => {
+                    self.start_virtual_contest(contest, problems);
+                }
+                ContestsAction::EndVirtual => self.end_virtual_contest(),
+                ContestsAction::None => {}
+            },
+            Screen::StudyPlans(state) => match state.handle_key(key) {
+                StudyPlansAction::Back => self.restore_home(),
+                StudyPlansAction::Quit => self.should_quit = true,
+                StudyPlansAction::OpenPlan(slug) => self.start_fetch_study_plan(&slug),
+                StudyPlansAction::OpenDetail(slug) => self.start_fetch_detail(&slug),
+                StudyPlansAction::None => {}
+            },
+            Screen::History(state) => match state.handle_key(key) {
+                HistoryAction::Back => self.restore_home(),
+                HistoryAction::Quit => self.should_quit = true,
+                HistoryAction::OpenDetail(slug) => self.start_fetch_detail(&slug),
+                HistoryAction::None => {}
+            },
+            Screen::Analytics(state) => match state.handle_key(key) {
+                AnalyticsAction::Back => self.restore_home(),
+                AnalyticsAction::Quit => self.should_quit = true,
+                AnalyticsAction::Refresh => self.start_fetch_user_stats(),
+                AnalyticsAction::None => {}
+            },
+            Screen::Skills(state) => match state.handle_key(key) {
+                SkillsAction::Back => self.restore_home(),
+                SkillsAction::Quit => self.should_quit = true,
+                SkillsAction::Refresh => {
+                    self.start_fetch_user_stats();
+                    self.start_fetch_skill_stats();
+                }
+                SkillsAction::None => {}
+            },
+            Screen::Companies(state) => match state.handle_key(key) {
+                CompaniesAction::Back => self.restore_home(),
+                CompaniesAction::Quit => self.should_quit = true,
+                CompaniesAction::OpenCompany(slug, period) => {
+                    self.start_fetch_company_problems(&slug, period)
+                }
+                CompaniesAction::OpenDetail(slug) => self.start_fetch_detail(&slug),
+                CompaniesAction::FilterHome(company) => {
+                    let name = company.name.clone();
+                    self.restore_home();
+                    if let Screen::Home(ref mut home) = self.screen {
+                        home.set_company_filter(company);
+                    }
+                    self.show_success(format!("Showing {name} problems (f to clear)"), 20);
+                }
+                CompaniesAction::None => {}
+            },
+            Screen::Editor(state) => match state.handle_key(key) {
+                EditorAction::Back => {
+                    let detail = state.detail.clone();
+                    self.screen = Screen::Detail(self.new_detail_state(detail));
+                }
+                EditorAction::Save => {
+                    self.save_editor();
+                }
+                EditorAction::SaveAndBack => {
+                    let detail = state.detail.clone();
+                    if self.save_editor() {
+                        self.screen = Screen::Detail(self.new_detail_state(detail));
+                    }
+                }
+                EditorAction::Run => {
+                    let detail = state.detail.clone();
+                    if self.save_editor() {
+                        self.start_run_code(&detail);
+                    }
+                }
+                EditorAction::Submit => {
+                    let detail = state.detail.clone();
+                    if self.save_editor() {
+                        self.start_submit_code(&detail);
+                    }
+                }
+                EditorAction::None => {}
+            },
+            Screen::CodeView(state) => match state.handle_key(key) {
+                CodeViewAction::Back => {
+                    let detail = state.detail.clone();
+                    if let Some(submissions) = self.saved_submissions.take()
+                        && submissions.detail.title_slug == detail.title_slug
+                    {
+                        self.screen = Screen::Submissions(submissions);
+                    } else {
+                        self.screen = Screen::Detail(self.new_detail_state(detail));
+                    }
+                }
+                CodeViewAction::Quit => self.should_quit = true,
+                CodeViewAction::None => {}
+            },
+            Screen::Diff(state) => match state.handle_key(key) {
+                DiffAction::Back => {
+                    let detail = state.detail.clone();
+                    self.screen = Screen::Detail(self.new_detail_state(detail));
+                }
+                DiffAction::Quit => self.should_quit = true,
+                DiffAction::None => {}
+            },
+            Screen::Editorial(state) => match state.handle_key(key) {
+                EditorialAction::Back => {
+                    let detail = state.detail.clone();
+                    self.screen = Screen::Detail(self.new_detail_state(detail));
+                }
+                EditorialAction::Quit => self.should_quit = true,
+                EditorialAction::None => {}
+            },
+            Screen::Solutions(state) => match state.handle_key(key) {
+                SolutionsAction::Back => {
+                    let detail = state.detail.clone();
+                    self.screen = Screen::Detail(self.new_detail_state(detail));
+                }
+                SolutionsAction::Quit => self.should_quit = true,
+                SolutionsAction::OpenPost(id) => self.start_fetch_community_post(id),
+                SolutionsAction::FetchPage(page) => {
+                    let slug = state.detail.title_slug.clone();
+                    self.start_fetch_community_solutions(&slug, page);
+                }
+                SolutionsAction::None => {}
+            },
             Screen::Setup(_) => {} // handled above
         }
 
@@ -718,6 +2035,41 @@ impl App {
     }
 
     fn handle_tick(&mut self) {
+        self.tick_autosave();
+
+        // While offline, periodically try to refresh the problem list
+        if let Screen::Home(ref state) = self.screen
+            && state.offline
+            && !state.loading
+        {
+            self.offline_retry_ticks += 1;
+            if self.offline_retry_ticks >= OFFLINE_RETRY_TICKS {
+                self.offline_retry_ticks = 0;
+                self.start_fetch_problems();
+            }
+        }
+
+        if let Some(ref mut pomodoro) = self.pomodoro
+            && pomodoro.tick()
+        {
+            let msg = if pomodoro.on_break {
+                "Pomodoro done \u{2014} take a break"
+            } else {
+                "Break over \u{2014} back to work"
+            };
+            practice::notify(msg);
+            self.notice_message = Some((msg.to_string(), 50));
+        }
+
+        // Fetch the detail of the problem the home selection rests on
+        let selected = match self.screen {
+            Screen::Home(ref state) => state.selected_problem().map(|p| p.title_slug.clone()),
+            _ => None,
+        };
+        if let Some(slug) = self.prefetch.tick(selected.as_deref()) {
+            self.start_prefetch_detail(&slug);
+        }
+
         // Auto-dismiss success messages
         if let Some((_, ref mut ticks)) = self.success_message {
             if *ticks == 0 {
@@ -726,6 +2078,13 @@ impl App {
                 *ticks -= 1;
             }
         }
+        if let Some((_, ref mut ticks)) = self.notice_message {
+            if *ticks == 0 {
+                self.notice_message = None;
+            } else {
+                *ticks -= 1;
+            }
+        }
 
         match &mut self.screen {
             Screen::Home(state) => {
@@ -734,36 +2093,118 @@ impl App {
             Screen::Result(state) => {
                 state.spinner_frame = state.spinner_frame.wrapping_add(1);
             }
+            Screen::Detail(DetailState {
+                inline_result: Some(state),
+                ..
+            }) => {
+                state.spinner_frame = state.spinner_frame.wrapping_add(1);
+            }
             Screen::Lists(state) => {
                 state.spinner_frame = state.spinner_frame.wrapping_add(1);
             }
+            Screen::Submissions(state) => {
+                state.spinner_frame = state.spinner_frame.wrapping_add(1);
+            }
+            Screen::LocalTest(state) => {
+                state.spinner_frame = state.spinner_frame.wrapping_add(1);
+            }
+            Screen::Contests(state) => {
+                state.spinner_frame = state.spinner_frame.wrapping_add(1);
+            }
+            Screen::StudyPlans(state) => {
+                state.spinner_frame = state.spinner_frame.wrapping_add(1);
+            }
+            Screen::Companies(state) => {
+                state.spinner_frame = state.spinner_frame.wrapping_add(1);
+            }
+            Screen::Editorial(state) => {
+                state.spinner_frame = state.spinner_frame.wrapping_add(1);
+            }
+            Screen::Skills(state) => {
+                state.spinner_frame = state.spinner_frame.wrapping_add(1);
+            }
+            Screen::Solutions(state) => {
+                state.spinner_frame = state.spinner_frame.wrapping_add(1);
+            }
             _ => {}
         }
+
+        // Announce the end of a virtual contest once, wherever the user is
+        let finished = self
+            .virtual_contest
+            .as_mut()
+            .filter(|v| v.is_over() && !v.finish_reported)
+            .map(|v| {
+                v.finish_reported = true;
+                virtual_summary("Virtual contest over", v)
+            });
+        if let Some(msg) = finished {
+            self.show_success(msg, 50);
+        }
+    }
+
+    /// Periodically flush unsaved edits so a crash doesn't lose them. Ticks
+    /// are 100ms; `autosave_secs = 0` leaves saving to leave/quit only.
+    fn tick_autosave(&mut self) {
+        let interval = self.config.as_ref().map_or(0, |c| c.autosave_secs) * 10;
+        if interval == 0 {
+            return;
+        }
+        self.autosave_ticks += 1;
+        if self.autosave_ticks < interval {
+            return;
+        }
+        self.autosave_ticks = 0;
+        let pad = match self.screen {
+            Screen::Scratchpad(ref mut s) => Some(s),
+            _ => self.saved_scratchpad.as_mut(),
+        };
+        if let Some(pad) = pad {
+            pad.autosave();
+        }
     }
 
     fn handle_api_result(&mut self, result: ApiResult) {
+        let expired = result.error().is_some_and(|e| e.is::<AuthExpired>());
+        self.apply_api_result(result);
+        // Offer to log in again instead of leaving a dead-end error
+        if expired && !self.login_waiting {
+            self.error_overlay = None;
+            self.login_prompt = true;
+            self.session_expired = true;
+        }
+    }
+
+    fn apply_api_result(&mut self, result: ApiResult) {
+        if let ApiResult::SubmitResult(Ok(ref resp)) = result {
+            self.record_contest_submission(resp);
+        }
+        match result {
+            ApiResult::RunResult(Ok(ref resp)) => self.record_attempt(AttemptKind::Run, resp),
+            ApiResult::SubmitResult(Ok(ref resp)) => self.record_attempt(AttemptKind::Submit, resp),
+            _ => {}
+        }
         match result {
             ApiResult::ProblemBatch {
+                category,
                 problems,
                 total,
                 done,
             } => {
-                // Resolve target: active Home screen or saved_home
-                let state = if let Screen::Home(ref mut s) = self.screen {
-                    Some(s)
-                } else {
-                    self.saved_home.as_mut()
-                };
+                // Resolve target: active Home screen or saved_home, ignoring
+                // batches still arriving for a category we switched away from
+                let state = self.home_state_mut().filter(|s| s.category == category);
                 if let Some(state) = state {
                     state.loading_buffer.extend(problems);
                     state.total_problems = total;
                     if done {
                         state.loading = false;
+                        state.offline = false;
                         state.problems = std::mem::take(&mut state.loading_buffer);
                         state.rebuild_filter();
                         let problems = state.problems.clone();
                         tokio::spawn(async move {
-                            save_problems_cache(&problems);
+                            save_problems_cache(category, &problems);
                         });
                     } else if state.problems.is_empty() {
                         // No cache — show what we have so far
@@ -774,38 +2215,279 @@ impl App {
                 }
             }
             ApiResult::ProblemFetchError(e) => {
-                let state = if let Screen::Home(ref mut s) = self.screen {
-                    Some(s)
-                } else {
-                    self.saved_home.as_mut()
-                };
-                if let Some(state) = state {
+                if let Some(state) = self.home_state_mut() {
                     state.loading = false;
-                    state.error_message = Some(e);
+                    state.loading_buffer.clear();
+                    // With a cached list, keep working offline and retry later
+                    if state.problems.is_empty() {
+                        state.error_message = Some(e);
+                    } else {
+                        state.offline = true;
+                    }
                 }
+                self.offline_retry_ticks = 0;
             }
-            ApiResult::Detail(Ok(detail)) => {
+            ApiResult::Detail(Ok(mut detail)) => {
                 // Save current screen state before switching to detail
-                let old =
-                    std::mem::replace(&mut self.screen, Screen::Detail(DetailState::new(detail)));
+                let old = std::mem::replace(&mut self.screen, Screen::Home(HomeState::new()));
                 match old {
                     Screen::Home(home) => self.saved_home = Some(home),
                     Screen::Lists(lists) => self.saved_lists = Some(lists),
+                    Screen::Contests(contests) => self.saved_contests = Some(contests),
+                    Screen::StudyPlans(plans) => self.saved_study_plans = Some(plans),
+                    Screen::Companies(companies) => self.saved_companies = Some(companies),
+                    Screen::History(history) => self.saved_history = Some(history),
                     _ => {}
                 }
+                detail.localize(self.config.as_ref().and_then(|c| c.locale.as_deref()));
+                self.start_practice(&detail.title_slug);
+                self.screen = Screen::Detail(self.new_detail_state(detail));
             }
             ApiResult::Detail(Err(e)) => {
-                self.error_overlay = Some(format!("Failed to load problem: {e}"));
+                self.show_error(format!("Failed to load problem: {e}"));
+            }
+            ApiResult::Prefetched { slug, result } => {
+                // Failures only matter if Enter was pressed meanwhile
+                if self.prefetch.finish(&slug, result.as_ref().ok().cloned()) {
+                    self.handle_api_result(ApiResult::Detail(result));
+                }
+            }
+            ApiResult::SubmitResult(Ok(ref resp)) if resp.status_code == Some(10) => {
+                self.record_solve();
+                self.mark_solved();
+                if let Some(state) = self.result_state_mut() {
+                    state.set_result(ResultData::from_check(resp));
+                }
+                if self.config.as_ref().is_some_and(|c| c.gist_on_accept)
+                    && let Some(detail) = self.result_state_mut().map(|s| s.detail.clone())
+                {
+                    self.start_upload_gist(&detail);
+                }
+            }
+            ApiResult::Gist(Ok(url)) => match clipboard::copy(&url) {
+                Ok(()) => self.show_success(format!("Gist uploaded: {url} (copied)"), 50),
+                Err(_) => self.show_success(format!("Gist uploaded: {url}"), 50),
+            },
+            ApiResult::Gist(Err(e)) => self.show_error(format!("Gist upload failed: {e}")),
+            ApiResult::Image { url, result } => {
+                if let Screen::Detail(ref mut state) = self.screen {
+                    state.set_image(&url, result.map_err(|e| format!("{e}")));
+                }
             }
             ApiResult::RunResult(res) | ApiResult::SubmitResult(res) => {
-                if let Screen::Result(ref mut state) = self.screen {
+                if let Some(state) = self.result_state_mut() {
                     match res {
                         Ok(resp) => state.set_result(ResultData::from_check(&resp)),
                         Err(e) => state.set_error(format!("{e}")),
                     }
                 }
             }
+            ApiResult::Submissions { slug, page, result } => {
+                // Ignore pages for a problem we've navigated away from
+                let Screen::Submissions(ref mut state) = self.screen else {
+                    return;
+                };
+                if state.detail.title_slug != slug {
+                    return;
+                }
+                match result {
+                    Ok(p) => state.set_page(page, p.submissions, p.has_next),
+                    Err(e) => {
+                        state.loading = false;
+                        state.error_message = Some(format!("{e}"));
+                    }
+                }
+            }
+            ApiResult::LastAccepted { slug, diff, result } => {
+                self.notice_message = None;
+                let submission = match result {
+                    Ok(Some(submission)) => submission,
+                    Ok(None) => {
+                        self.show_error("No accepted submission for this problem yet".to_string());
+                        return;
+                    }
+                    Err(e) => {
+                        self.show_error(format!("Failed to fetch your last accepted submission: {e}"));
+                        return;
+                    }
+                };
+                // Ignore code for a problem we've navigated away from
+                let Screen::Detail(ref state) = self.screen else {
+                    return;
+                };
+                if state.detail.title_slug != slug {
+                    return;
+                }
+                let detail = state.detail.clone();
+                if diff {
+                    self.open_accepted_diff(detail, &submission);
+                } else {
+                    self.pull_submission(&detail, &submission);
+                }
+            }
+            ApiResult::SubmissionCode { id, result } => {
+                self.notice_message = None;
+                let submission = match result {
+                    Ok(submission) => submission,
+                    Err(e) => {
+                        self.show_error(format!("Failed to fetch submission {id}: {e}"));
+                        return;
+                    }
+                };
+                // Ignore code for a list we've navigated away from
+                let Screen::Submissions(ref list) = self.screen else {
+                    return;
+                };
+                let detail = list.detail.clone();
+                let status = list
+                    .submissions
+                    .iter()
+                    .find(|s| s.id == id)
+                    .map(|s| s.status_display.clone())
+                    .unwrap_or_default();
+                let mut title = format!("{}. {}", detail.frontend_question_id, detail.title);
+                for part in [
+                    Some(status),
+                    Some(submission.lang.verbose_name.clone()),
+                    submission.runtime_display.clone(),
+                    submission.memory_display.clone(),
+                ]
+                .into_iter()
+                .flatten()
+                .filter(|p| !p.is_empty() && p != "N/A")
+                {
+                    title.push_str("  \u{00b7} ");
+                    title.push_str(&part);
+                }
+                let mut view =
+                    CodeViewState::new(detail, title, &submission.code, &submission.lang.name);
+                view.label = "Submission";
+                if let Screen::Submissions(list) =
+                    std::mem::replace(&mut self.screen, Screen::CodeView(view))
+                {
+                    self.saved_submissions = Some(list);
+                }
+            }
+            ApiResult::Contests(result) => {
+                let Some(state) = self.contests_state_mut() else {
+                    return;
+                };
+                match result {
+                    Ok((upcoming, past)) => state.set_contests(upcoming, past),
+                    Err(e) => {
+                        state.loading = false;
+                        state.error_message = Some(format!("{e}"));
+                    }
+                }
+            }
+            ApiResult::ContestHistory(history) => {
+                if let Some(state) = self.contests_state_mut() {
+                    state.set_history(history);
+                }
+            }
+            ApiResult::ContestProblems { slug, result } => {
+                if let Some(state) = self.contests_state_mut() {
+                    state.set_problems(&slug, result.map_err(|e| format!("{e}")));
+                }
+            }
+            ApiResult::StudyPlans(result) => {
+                let Screen::StudyPlans(ref mut state) = self.screen else {
+                    return;
+                };
+                match result {
+                    Ok(plans) => state.set_plans(plans),
+                    Err(e) => {
+                        // The featured plans are still worth showing
+                        state.set_plans(Vec::new());
+                        state.error_message = Some(format!("{e}"));
+                    }
+                }
+            }
+            ApiResult::StudyPlan { slug, result } => {
+                if let Screen::StudyPlans(ref mut state) = self.screen {
+                    state.set_plan(&slug, result.map_err(|e| format!("{e}")));
+                }
+            }
+            ApiResult::Companies(result) => {
+                let Screen::Companies(ref mut state) = self.screen else {
+                    return;
+                };
+                match result {
+                    Ok((companies, premium)) => state.set_companies(companies, premium),
+                    Err(e) => {
+                        state.loading = false;
+                        state.error_message = Some(format!("{e}"));
+                    }
+                }
+            }
+            ApiResult::CompanyProblems {
+                slug,
+                period,
+                result,
+            } => {
+                if let Screen::Companies(ref mut state) = self.screen {
+                    state.set_problems(&slug, period, result.map_err(|e| format!("{e}")));
+                }
+            }
+            ApiResult::SkillStats(result) => {
+                if let Screen::Skills(ref mut state) = self.screen {
+                    state.set_skills(result.map_err(|e| format!("{e}")));
+                }
+            }
+            ApiResult::Editorial { slug, result } => {
+                let options = self.config.as_ref().map(|c| c.render_options()).unwrap_or_default();
+                let Screen::Editorial(ref mut state) = self.screen else {
+                    return;
+                };
+                if state.detail.title_slug == slug {
+                    state.set_editorial(result.map_err(|e| format!("{e}")), options);
+                }
+            }
+            ApiResult::CommunitySolutions { slug, page, result } => {
+                let Screen::Solutions(ref mut state) = self.screen else {
+                    return;
+                };
+                if state.detail.title_slug != slug {
+                    return;
+                }
+                match result {
+                    Ok((solutions, total)) => state.set_page(page, solutions, total),
+                    Err(e) => {
+                        state.loading = false;
+                        state.error_message = Some(format!("{e}"));
+                    }
+                }
+            }
+            ApiResult::CommunityPost { id, result } => {
+                let options = self.config.as_ref().map(|c| c.render_options()).unwrap_or_default();
+                if let Screen::Solutions(ref mut state) = self.screen {
+                    state.set_post(id, result.map_err(|e| format!("{e}")), options);
+                }
+            }
+            ApiResult::LocalTest { run, event } => {
+                let Screen::LocalTest(ref mut state) = self.screen else {
+                    return;
+                };
+                if run != self.local_test_run.load(Ordering::SeqCst) {
+                    return;
+                }
+                match event {
+                    LocalTestEvent::BuildFailed(e) => state.build_error = Some(e),
+                    LocalTestEvent::Case(i, outcome) => state.set_outcome(i, outcome),
+                }
+            }
             ApiResult::UserStats(stats) => {
+                if let Screen::Skills(ref mut state) = self.screen
+                    && stats.is_some()
+                {
+                    state.stats = stats.clone();
+                }
+                if let Screen::Analytics(ref mut state) = self.screen {
+                    state.loading = false;
+                    if let Some(stats) = &stats {
+                        state.calendar = stats.calendar.clone();
+                    }
+                }
                 let state = if let Screen::Home(ref mut s) = self.screen {
                     Some(s)
                 } else {
@@ -815,17 +2497,35 @@ impl App {
                     state.user_stats = stats;
                 }
             }
+            ApiResult::CompareStats(result) => {
+                let state = if let Screen::Home(ref mut s) = self.screen {
+                    Some(s)
+                } else {
+                    self.saved_home.as_mut()
+                };
+                match result {
+                    Ok(stats) => {
+                        if let Some(state) = state {
+                            state.compare_stats = Some(stats);
+                        }
+                    }
+                    Err(e) => self.show_error(format!("{e}")),
+                }
+            }
             ApiResult::SearchResult(Ok((problems, _))) => {
                 if let Some(p) = problems.first() {
                     self.start_fetch_detail(&p.title_slug.clone());
                 } else {
-                    self.error_overlay = Some("Problem not found.".to_string());
+                    self.show_error("Problem not found.".to_string());
                 }
             }
             ApiResult::SearchResult(Err(e)) => {
-                self.error_overlay = Some(format!("Search failed: {e}"));
+                self.show_error(format!("Search failed: {e}"));
             }
             ApiResult::Favorites(Ok(lists)) => {
+                if let Some(home) = self.home_state_mut() {
+                    home.set_favorites(&lists);
+                }
                 if let Screen::Lists(ref mut state) = self.screen {
                     state.lists = lists;
                     state.loading = false;
@@ -842,15 +2542,19 @@ impl App {
                 }
             }
             ApiResult::ListMutation(Ok(()), msg) => {
-                self.success_message = Some((msg, 12)); // ~2 seconds at 5 ticks/sec
-                if matches!(self.screen, Screen::Lists(_)) {
+                self.show_success(msg, 12); // ~2 seconds at 5 ticks/sec
+                // Refresh once the queue drains rather than after every write
+                if self.list_queue.pending() == 0 {
                     self.start_fetch_favorites();
                 }
             }
             ApiResult::ListMutation(Err(e), _) => {
-                self.error_overlay = Some(format!("{e}"));
+                self.show_error(format!("{e}"));
             }
             ApiResult::PopupFavorites(Ok(lists)) => {
+                if let Some(home) = self.home_state_mut() {
+                    home.set_favorites(&lists);
+                }
                 if let Some(ref mut popup) = self.add_to_list_popup {
                     popup.lists = lists;
                     popup.loading = false;
@@ -858,16 +2562,185 @@ impl App {
             }
             ApiResult::PopupFavorites(Err(e)) => {
                 self.add_to_list_popup = None;
-                self.error_overlay = Some(format!("Failed to load lists: {e}"));
+                self.show_error(format!("Failed to load lists: {e}"));
+            }
+            ApiResult::ImportProgress { done, total } => {
+                if let Some(state) = self.lists_state_mut() {
+                    state.import = Some((done, total));
+                }
+            }
+            ApiResult::ImportDone(Ok(report)) => {
+                if let Some(state) = self.lists_state_mut() {
+                    state.import = None;
+                }
+                if report.is_clean() {
+                    self.show_success(report.summary(), 20);
+                } else {
+                    self.show_error(report.summary());
+                }
+                if matches!(self.screen, Screen::Lists(_)) {
+                    self.start_fetch_favorites();
+                }
+            }
+            ApiResult::ImportDone(Err(e)) => {
+                if let Some(state) = self.lists_state_mut() {
+                    state.import = None;
+                }
+                self.show_error(format!("Import failed: {e}"));
+            }
+            ApiResult::DownloadProgress { done, total } => {
+                if let Some(state) = self.lists_state_mut() {
+                    state.download = Some((done, total));
+                }
+            }
+            ApiResult::DownloadDone(report) => {
+                if let Some(state) = self.lists_state_mut() {
+                    state.download = None;
+                }
+                if report.failed.is_empty() {
+                    self.show_success(report.summary(), 20);
+                } else {
+                    let failures: Vec<String> = report
+                        .failed
+                        .iter()
+                        .map(|(slug, e)| format!("{slug}: {e}"))
+                        .collect();
+                    self.show_error(format!(
+                        "{}\n\nFailed:\n{}",
+                        report.summary(),
+                        failures.join("\n")
+                    ));
+                }
             }
         }
     }
 
+    /// Show an error, collapsing repeats of the same message into a counter
+    /// instead of flashing a fresh overlay for each one.
+    fn show_error(&mut self, msg: String) {
+        if self.error_repeats > 0 && self.last_error.as_deref() == Some(msg.as_str()) {
+            self.error_repeats += 1;
+        } else {
+            self.error_repeats = 1;
+            self.last_error = Some(msg.clone());
+        }
+        self.error_overlay = Some(msg);
+    }
+
+    fn show_success(&mut self, msg: String, ticks: u8) {
+        self.error_repeats = 0;
+        self.last_error = None;
+        self.success_message = Some((msg, ticks));
+    }
+
+    /// Pause or resume the timer of the problem on screen.
+    fn toggle_practice_timer(&mut self) {
+        let Screen::Detail(ref state) = self.screen else {
+            return;
+        };
+        match self.practice {
+            Some(ref mut timer) if timer.slug == state.detail.title_slug => {
+                let msg = if timer.toggle_hold() { "Timer resumed" } else { "Timer paused" };
+                self.show_success(msg.to_string(), 10);
+            }
+            _ => self.notice_message = Some(("No timer running for this problem".to_string(), 20)),
+        }
+    }
+
+    /// Start timing `slug` unless it is already the problem being timed.
+    fn start_practice(&mut self, slug: &str) {
+        let enabled = self.config.as_ref().is_some_and(|c| c.practice_timer) && !self.is_guest();
+        if enabled && self.practice.as_ref().is_none_or(|t| t.slug != slug) {
+            self.practice = Some(PracticeTimer::start(slug));
+        }
+    }
+
+    /// Store the time taken for an accepted submission of the timed problem.
+    fn record_solve(&mut self) {
+        let Some(state) = self.result_state_mut() else {
+            return;
+        };
+        let slug = state.detail.title_slug.clone();
+        if let Some(timer) = self.practice.take_if(|t| t.slug == slug) {
+            let took = timer.elapsed();
+            self.solve_times.record(&slug, took);
+            self.solve_times.save();
+            self.show_success(format!("Solved in {}", practice::format_duration(took)), 20);
+        }
+    }
+
+    /// Flip the accepted problem to solved in the home list and its cache,
+    /// so the status is right even before the next refresh (or offline).
+    fn mark_solved(&mut self) {
+        let Some(state) = self.result_state_mut() else {
+            return;
+        };
+        let slug = state.detail.title_slug.clone();
+        self.prefetch.invalidate(&slug);
+        if let Some(home) = self.home_state_mut()
+            && home.mark_solved(&slug)
+            && !home.loading
+        {
+            save_problems_cache(home.category, &home.problems);
+        }
+    }
+
+    /// No credentials: browsing works, anything that writes or runs code doesn't.
+    fn is_guest(&self) -> bool {
+        self.config.as_ref().is_some_and(|c| !c.is_authenticated())
+    }
+
+    /// In guest mode, explain why `what` is unavailable and return true.
+    fn guest_blocked(&mut self, what: &str) -> bool {
+        if !self.is_guest() {
+            return false;
+        }
+        self.notice_message = Some((
+            format!("{what} needs a LeetCode login \u{2014} press S on the home screen to sign in"),
+            20,
+        ));
+        true
+    }
+
+    fn home_state_mut(&mut self) -> Option<&mut HomeState> {
+        if let Screen::Home(ref mut s) = self.screen {
+            Some(s)
+        } else {
+            self.saved_home.as_mut()
+        }
+    }
+
+    fn lists_state_mut(&mut self) -> Option<&mut ListsState> {
+        if let Screen::Lists(ref mut s) = self.screen {
+            Some(s)
+        } else {
+            self.saved_lists.as_mut()
+        }
+    }
+
+    fn new_detail_state(&mut self, detail: QuestionDetail) -> DetailState {
+        let options = self.config.as_ref().map(|c| c.render_options()).unwrap_or_default();
+        let mut state = DetailState::new(detail, options);
+        if let Some(ref config) = self.config {
+            state.set_notes(notes::load(&config.expanded_workspace(), &state.detail.title_slug));
+        }
+        state.attempts = self.attempts.for_problem(&state.detail.title_slug);
+        // Problems opened from the home table can step through their difficulty band
+        if self.saved_lists.is_none() {
+            state.nav = self
+                .saved_home
+                .as_mut()
+                .and_then(|h| h.difficulty_nav(&state.detail.title_slug));
+        }
+        state
+    }
+
     fn restore_home(&mut self) {
         if let Some(home) = self.saved_home.take() {
             self.screen = Screen::Home(home);
         } else {
-            self.screen = Screen::Home(HomeState::new());
+            let config = self.config.clone().unwrap_or_default();
+            self.screen = Screen::Home(new_home_state(&config));
             self.start_fetch_problems();
         }
     }
@@ -878,7 +2751,8 @@ impl App {
             state.error_message = None;
 
             // Load cached problems for instant display
-            if let Some(cached) = load_cached_problems() {
+            let category = state.category;
+            if let Some(cached) = load_cached_problems(category) {
                 state.total_problems = cached.len() as i32;
                 state.problems = cached;
                 state.rebuild_filter();
@@ -895,12 +2769,13 @@ impl App {
             tokio::spawn(async move {
                 let mut skip: i32 = 0;
                 loop {
-                    let result = client.fetch_problems(BATCH, skip, None, None).await;
+                    let result = client.fetch_problems(category, BATCH, skip, None, None).await;
                     match result {
                         Ok((batch, total)) => {
                             let done = (batch.len() as i32) < BATCH
                                 || skip + (batch.len() as i32) >= total;
                             let _ = tx.send(ApiResult::ProblemBatch {
+                                category,
                                 problems: batch,
                                 total,
                                 done,
@@ -920,62 +2795,236 @@ impl App {
         }
     }
 
-    fn start_search_fetch(&self, query: &str) {
+    fn start_search_fetch(&self, query: &str) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        let query = query.to_string();
+
+        tokio::spawn(async move {
+            let result = client
+                .fetch_problems(Category::All, 1, 0, None, Some(&query))
+                .await;
+            let _ = tx.send(ApiResult::SearchResult(result));
+        });
+    }
+
+    fn contests_state_mut(&mut self) -> Option<&mut ContestsState> {
+        if let Screen::Contests(ref mut s) = self.screen {
+            Some(s)
+        } else {
+            self.saved_contests.as_mut()
+        }
+    }
+
+    fn start_fetch_contests(&self) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        let signed_in = !self.is_guest();
+
+        tokio::spawn(async move {
+            let result = client.fetch_contests().await;
+            let _ = tx.send(ApiResult::Contests(result));
+            if !signed_in {
+                return;
+            }
+            // Past results are a nicety; without them the column stays empty
+            if let Some(name) = client.fetch_username().await
+                && let Ok(history) = client.fetch_contest_history(&name).await
+            {
+                let _ = tx.send(ApiResult::ContestHistory(history));
+            }
+        });
+    }
+
+    fn start_fetch_contest_problems(&self, slug: &str) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        let slug = slug.to_string();
+
+        tokio::spawn(async move {
+            let result = client.fetch_contest_problems(&slug).await;
+            let _ = tx.send(ApiResult::ContestProblems { slug, result });
+        });
+    }
+
+    fn start_fetch_study_plans(&mut self) {
+        // Enrollment needs an account; guests get the featured plans
+        if self.is_guest() {
+            if let Screen::StudyPlans(ref mut state) = self.screen {
+                state.set_plans(Vec::new());
+            }
+            return;
+        }
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+
+        tokio::spawn(async move {
+            let result = client.fetch_study_plans().await;
+            let _ = tx.send(ApiResult::StudyPlans(result));
+        });
+    }
+
+    fn start_fetch_study_plan(&self, slug: &str) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        let slug = slug.to_string();
+
+        tokio::spawn(async move {
+            let result = client.fetch_study_plan(&slug).await;
+            let _ = tx.send(ApiResult::StudyPlan { slug, result });
+        });
+    }
+
+    fn start_fetch_editorial(&self, slug: &str) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        let slug = slug.to_string();
+
+        tokio::spawn(async move {
+            let result = client.fetch_editorial(&slug).await;
+            let _ = tx.send(ApiResult::Editorial { slug, result });
+        });
+    }
+
+    fn start_fetch_community_solutions(&self, slug: &str, page: usize) {
         let client = self.api_client.clone();
         let tx = self.api_tx.clone();
-        let query = query.to_string();
+        let slug = slug.to_string();
 
         tokio::spawn(async move {
-            let result = client.fetch_problems(1, 0, None, Some(&query)).await;
-            let _ = tx.send(ApiResult::SearchResult(result));
+            let skip = page * solutions::PAGE_SIZE;
+            let result = client
+                .fetch_community_solutions(&slug, skip, solutions::PAGE_SIZE)
+                .await;
+            let _ = tx.send(ApiResult::CommunitySolutions { slug, page, result });
         });
     }
 
-    fn start_fetch_favorites(&self) {
+    fn start_fetch_community_post(&self, id: i64) {
         let client = self.api_client.clone();
         let tx = self.api_tx.clone();
 
         tokio::spawn(async move {
-            let result = client.fetch_favorites().await;
-            let _ = tx.send(ApiResult::Favorites(result));
+            let result = client.fetch_community_solution(id).await;
+            let _ = tx.send(ApiResult::CommunityPost { id, result });
         });
     }
 
-    fn start_create_list(&self, name: &str) {
+    fn start_fetch_companies(&self) {
         let client = self.api_client.clone();
         let tx = self.api_tx.clone();
-        let name = name.to_string();
+        let signed_in = !self.is_guest();
 
         tokio::spawn(async move {
-            let msg = format!("List \"{}\" created", name);
-            let result = client.create_favorite_list(&name).await;
-            let _ = tx.send(ApiResult::ListMutation(result, msg));
+            let (companies, premium) = tokio::join!(client.fetch_companies(), async {
+                signed_in && client.fetch_is_premium().await
+            });
+            let _ = tx.send(ApiResult::Companies(companies.map(|c| (c, premium))));
         });
     }
 
-    fn start_delete_list(&self, id_hash: &str) {
+    fn start_fetch_company_problems(&self, slug: &str, period: CompanyPeriod) {
         let client = self.api_client.clone();
         let tx = self.api_tx.clone();
-        let id_hash = id_hash.to_string();
+        let slug = slug.to_string();
 
         tokio::spawn(async move {
-            let result = client.delete_favorite_list(&id_hash).await;
-            let _ = tx.send(ApiResult::ListMutation(result, "List deleted".into()));
+            let result = client.fetch_company_problems(&slug, period).await;
+            let _ = tx.send(ApiResult::CompanyProblems {
+                slug,
+                period,
+                result,
+            });
         });
     }
 
-    fn start_remove_from_list(&self, id_hash: &str, question_id: &str) {
+    fn start_virtual_contest(&mut self, contest: Contest, problems: Vec<ContestQuestion>) {
+        if self.virtual_contest.as_ref().is_some_and(|v| !v.is_over()) {
+            self.show_error("A virtual contest is already running.\nPress x to end it first.".into());
+            return;
+        }
+        let v = VirtualContest::start(contest, problems);
+        let msg = format!(
+            "Virtual {} started: {} on the clock",
+            v.contest.title,
+            practice::format_duration(v.duration())
+        );
+        self.virtual_contest = Some(v);
+        self.show_success(msg, 30);
+    }
+
+    fn end_virtual_contest(&mut self) {
+        if let Some(v) = self.virtual_contest.take() {
+            self.show_success(virtual_summary("Virtual contest ended", &v), 50);
+        }
+    }
+
+    /// Add a finished run or submission to the local history, and to the
+    /// problem's summary when it's open in detail.
+    fn record_attempt(&mut self, kind: AttemptKind, resp: &CheckResponse) {
+        let Some(detail) = self.result_state_mut().map(|s| s.detail.clone()) else {
+            return;
+        };
+        let workspace = self.config.clone().unwrap_or_default().expanded_workspace();
+        let attempt = Attempt::new(&detail, kind, resp, self.lang_slug(), &workspace);
+        self.attempts.record(attempt);
+        if let Screen::Detail(ref mut state) = self.screen {
+            state.attempts = self.attempts.for_problem(&state.detail.title_slug);
+        }
+    }
+
+    /// Count a submission verdict toward the running virtual contest.
+    fn record_contest_submission(&mut self, resp: &CheckResponse) {
+        // LeetCode doesn't penalize compile errors
+        if resp.status_code == Some(20) {
+            return;
+        }
+        let Some(slug) = self.result_state_mut().map(|s| s.detail.title_slug.clone()) else {
+            return;
+        };
+        if let Some(ref mut v) = self.virtual_contest {
+            v.record(&slug, resp.status_code == Some(10));
+        }
+    }
+
+    fn start_fetch_favorites(&self) {
         let client = self.api_client.clone();
         let tx = self.api_tx.clone();
-        let id_hash = id_hash.to_string();
-        let question_id = question_id.to_string();
 
         tokio::spawn(async move {
-            let result = client.remove_from_favorite(&id_hash, &question_id).await;
-            let _ = tx.send(ApiResult::ListMutation(result, "Removed from list".into()));
+            let result = client.fetch_favorites().await;
+            let _ = tx.send(ApiResult::Favorites(result));
         });
     }
 
+    fn start_create_list(&self, name: &str) {
+        self.list_queue.enqueue(
+            self.api_client.clone(),
+            ListMutation::Create {
+                name: name.to_string(),
+            },
+        );
+    }
+
+    fn start_delete_list(&self, id_hash: &str) {
+        self.list_queue.enqueue(
+            self.api_client.clone(),
+            ListMutation::Delete {
+                id_hash: id_hash.to_string(),
+            },
+        );
+    }
+
+    fn start_remove_from_list(&self, id_hash: &str, question_id: &str) {
+        self.list_queue.enqueue(
+            self.api_client.clone(),
+            ListMutation::Remove {
+                id_hash: id_hash.to_string(),
+                question_id: question_id.to_string(),
+            },
+        );
+    }
+
     fn open_add_to_list_popup(&mut self, question_id: String) {
         self.add_to_list_popup = Some(AddToListPopup {
             lists: Vec::new(),
@@ -993,15 +3042,119 @@ impl App {
     }
 
     fn start_add_to_list(&self, id_hash: &str, question_id: &str, list_name: &str) {
+        self.list_queue.enqueue(
+            self.api_client.clone(),
+            ListMutation::Add {
+                id_hash: id_hash.to_string(),
+                question_id: question_id.to_string(),
+                list_name: list_name.to_string(),
+            },
+        );
+    }
+
+    /// Import the problems in the file at `path` into `target`, or a new
+    /// list named after the file.
+    fn start_import_list(&mut self, path: &str, target: Option<ImportTarget>) {
+        let path = if let Some(rest) = path.strip_prefix("~/") {
+            dirs::home_dir().unwrap_or_default().join(rest)
+        } else {
+            PathBuf::from(path)
+        };
+        let text = match std::fs::read_to_string(&path) {
+            Ok(t) => t,
+            Err(e) => {
+                self.show_error(format!("Failed to read {}: {e}", path.display()));
+                return;
+            }
+        };
+        let (name, entries) = match list_io::parse_import(&text) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                self.show_error(format!("{e}"));
+                return;
+            }
+        };
+        let target = target.unwrap_or_else(|| {
+            ImportTarget::New(name.unwrap_or_else(|| {
+                path.file_stem()
+                    .map(|s| s.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| "Imported".to_string())
+            }))
+        });
+
+        // Resolve ids/slugs against the problem table, or the cache if home
+        // hasn't loaded yet
+        let problems = match self.saved_home.as_ref().filter(|h| !h.problems.is_empty()) {
+            Some(home) => home.problems.clone(),
+            None => load_cached_problems(Category::All).unwrap_or_default(),
+        };
+        let plan = list_io::plan_import(&entries, &problems);
+        if plan.questions.is_empty() {
+            self.show_error(format!(
+                "No problems in {} matched the problem set",
+                path.display()
+            ));
+            return;
+        }
+
+        let total = plan.questions.len();
+        if let Some(state) = self.lists_state_mut() {
+            state.import = Some((0, total));
+        }
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        tokio::spawn(async move {
+            let progress_tx = tx.clone();
+            let progress = move |done, total| {
+                let _ = progress_tx.send(ApiResult::ImportProgress { done, total });
+            };
+            let result =
+                list_io::run_import(&client, target, plan, LIST_MUTATION_SPACING, progress).await;
+            let _ = tx.send(ApiResult::ImportDone(result));
+        });
+    }
+
+    fn start_download_all(&mut self, slugs: Vec<String>) {
+        let Some(config) = self.config.clone() else {
+            self.show_error("No config loaded".to_string());
+            if let Some(state) = self.lists_state_mut() {
+                state.download = None;
+            }
+            return;
+        };
         let client = self.api_client.clone();
         let tx = self.api_tx.clone();
-        let id_hash = id_hash.to_string();
-        let question_id = question_id.to_string();
-        let msg = format!("Added to \"{}\"", list_name);
 
         tokio::spawn(async move {
-            let result = client.add_to_favorite(&id_hash, &question_id).await;
-            let _ = tx.send(ApiResult::ListMutation(result, msg));
+            let workspace = config.expanded_workspace();
+            std::fs::create_dir_all(&workspace).ok();
+            let progress_tx = tx.clone();
+            let report = batch::scaffold_batch(
+                &slugs,
+                BULK_FETCH_SPACING,
+                |slug| {
+                    let client = client.clone();
+                    async move { client.fetch_problem_detail(&slug).await }
+                },
+                |detail| {
+                    scaffold::scaffold_languages(
+                        &workspace,
+                        detail,
+                        &config.language,
+                        &config.extra_languages,
+                        config.rust_layout,
+                    )
+                    .map(|(path, _)| path)
+                },
+                |report| {
+                    let _ = progress_tx.send(ApiResult::DownloadProgress {
+                        done: report.done,
+                        total: report.total,
+                    });
+                },
+            )
+            .await;
+            let _ = tx.send(ApiResult::DownloadDone(report));
         });
     }
 
@@ -1019,6 +3172,30 @@ impl App {
         });
     }
 
+    fn start_fetch_skill_stats(&self) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+
+        tokio::spawn(async move {
+            let result = match client.fetch_username().await {
+                Some(name) => client.fetch_skill_stats(&name).await,
+                None => Err(anyhow::anyhow!("Couldn't determine the signed-in user")),
+            };
+            let _ = tx.send(ApiResult::SkillStats(result));
+        });
+    }
+
+    fn start_fetch_compare_stats(&self, username: &str) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        let username = username.to_string();
+
+        tokio::spawn(async move {
+            let result = client.fetch_user_stats(&username).await;
+            let _ = tx.send(ApiResult::CompareStats(result));
+        });
+    }
+
     fn start_fetch_detail(&self, slug: &str) {
         let client = self.api_client.clone();
         let tx = self.api_tx.clone();
@@ -1030,6 +3207,158 @@ impl App {
         });
     }
 
+    fn start_prefetch_detail(&self, slug: &str) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        let slug = slug.to_string();
+
+        tokio::spawn(async move {
+            let result = client.fetch_problem_detail(&slug).await;
+            let _ = tx.send(ApiResult::Prefetched { slug, result });
+        });
+    }
+
+    fn start_fetch_submission_code(&self, id: &str) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        let id = id.to_string();
+
+        tokio::spawn(async move {
+            let result = client.fetch_submission_detail(&id).await;
+            let _ = tx.send(ApiResult::SubmissionCode { id, result });
+        });
+    }
+
+    fn start_fetch_last_accepted(&self, slug: &str, diff: bool) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        let slug = slug.to_string();
+
+        tokio::spawn(async move {
+            let result = client.fetch_last_accepted(&slug).await;
+            let _ = tx.send(ApiResult::LastAccepted { slug, diff, result });
+        });
+    }
+
+    /// Show what changed in the local solution since `submission`, the
+    /// last accepted one. Only the submittable code is compared, so the
+    /// scaffolding around it doesn't show up.
+    fn open_accepted_diff(&mut self, detail: QuestionDetail, submission: &SubmissionDetail) {
+        let Some(config) = self.config.as_ref() else {
+            self.show_error("No config loaded".to_string());
+            return;
+        };
+        let Some(language) = scaffold::language_for_slug(&submission.lang.name) else {
+            self.show_error(format!(
+                "Your last accepted submission is in {}, which has no solution file",
+                submission.lang.verbose_name
+            ));
+            return;
+        };
+        let path = scaffold::solution_path(&config.expanded_workspace(), &detail, language);
+        let compared = std::fs::read_to_string(&path)
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "No local {} solution to compare: {e}\nPull your accepted code with 'P'",
+                    submission.lang.verbose_name
+                )
+            })
+            .and_then(|local| {
+                let lib = path.ends_with("src/lib.rs");
+                let accepted =
+                    scaffold::source_with_code(&detail, language, &submission.code, lib)?;
+                Ok((
+                    scaffold::submittable_code(&accepted, language)?,
+                    scaffold::submittable_code(&local, language)?,
+                ))
+            });
+        let (accepted, local) = match compared {
+            Ok(pair) => pair,
+            Err(e) => {
+                self.show_error(format!("{e}"));
+                return;
+            }
+        };
+        let title = format!(
+            "{}. {}  last accepted \u{2192} {}",
+            detail.frontend_question_id,
+            detail.title,
+            path.display()
+        );
+        self.screen = Screen::Diff(DiffState::new(detail, title, &accepted, &local));
+    }
+
+    /// Write a pulled submission into the workspace, asking first when it
+    /// would replace different local code.
+    fn pull_submission(&mut self, detail: &QuestionDetail, submission: &SubmissionDetail) {
+        let Some(config) = self.config.as_ref() else {
+            self.show_error("No config loaded".to_string());
+            return;
+        };
+        let Some(language) = scaffold::language_for_slug(&submission.lang.name) else {
+            self.show_error(format!(
+                "Your last accepted submission is in {}, which can't be scaffolded",
+                submission.lang.verbose_name
+            ));
+            return;
+        };
+        let pulled = scaffold::pull(
+            &config.expanded_workspace(),
+            detail,
+            language,
+            &config.language,
+            &submission.code,
+            config.rust_layout,
+        );
+        match pulled {
+            Ok(scaffold::Pull::Written(path)) => self.show_success(
+                format!("Pulled your last accepted code into {}", path.display()),
+                30,
+            ),
+            Ok(scaffold::Pull::Unchanged(path)) => self.show_success(
+                format!("{} already has your last accepted code", path.display()),
+                30,
+            ),
+            Ok(scaffold::Pull::Differs { path, content }) => {
+                self.pull_confirm = Some(PullConfirm {
+                    path,
+                    content,
+                    lang: submission.lang.verbose_name.clone(),
+                });
+            }
+            Err(e) => self.show_error(format!("Failed to pull the submission: {e}")),
+        }
+    }
+
+    fn start_fetch_submissions(&self, slug: &str, page: usize) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        let slug = slug.to_string();
+
+        tokio::spawn(async move {
+            let offset = page * submissions::PAGE_SIZE;
+            let result = client
+                .fetch_submissions(&slug, offset, submissions::PAGE_SIZE)
+                .await;
+            let _ = tx.send(ApiResult::Submissions { slug, page, result });
+        });
+    }
+
+    /// Look up today's daily challenge and open it like any other problem.
+    fn start_fetch_daily(&mut self) {
+        self.notice_message = Some(("Loading today's daily challenge\u{2026}".to_string(), 20));
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+
+        tokio::spawn(async move {
+            let result = match client.fetch_daily_challenge().await {
+                Ok(daily) => client.fetch_problem_detail(&daily.question.title_slug).await,
+                Err(e) => Err(e.context("Failed to find today's daily challenge")),
+            };
+            let _ = tx.send(ApiResult::Detail(result));
+        });
+    }
+
     fn start_fetch_detail_for_scaffold(
         &mut self,
         slug: &str,
@@ -1046,23 +3375,176 @@ impl App {
         Ok(())
     }
 
+    /// Show the scaffolded solution file read-only, without leaving the TUI.
+    fn open_solution_view(&mut self, detail: QuestionDetail) {
+        let Some(config) = self.config.as_ref() else {
+            self.show_error("No config loaded".to_string());
+            return;
+        };
+        let path = scaffold::solution_path(&config.expanded_workspace(), &detail, &config.language);
+        let code = match std::fs::read_to_string(&path) {
+            Ok(code) => code,
+            Err(e) => {
+                self.show_error(format!(
+                    "Failed to read {}: {e}\nScaffold the problem first with 'o'",
+                    path.display()
+                ));
+                return;
+            }
+        };
+        let title = format!("{}. {}  {}", detail.frontend_question_id, detail.title, path.display());
+        let lang = self.lang_slug().to_string();
+        self.screen = Screen::CodeView(CodeViewState::new(detail, title, &code, &lang));
+    }
+
+    /// Open the solution file in the built-in editor, scaffolding it first
+    /// if needed. Edits left for a run come back as they were.
+    fn open_editor(&mut self, detail: QuestionDetail) {
+        let Some(config) = self.config.clone() else {
+            self.show_error("No config loaded".to_string());
+            return;
+        };
+        if let Some(editor) = self.saved_editor.take()
+            && editor.detail.title_slug == detail.title_slug
+        {
+            self.screen = Screen::Editor(editor);
+            return;
+        }
+        let workspace = config.expanded_workspace();
+        std::fs::create_dir_all(&workspace).ok();
+        let path = match scaffold::scaffold_problem(&workspace, &detail, &config.language, config.rust_layout) {
+            Ok(path) => path,
+            Err(e) => {
+                self.show_error(format!("Scaffold failed: {e}"));
+                return;
+            }
+        };
+        let code = match std::fs::read_to_string(&path) {
+            Ok(code) => code,
+            Err(e) => {
+                self.show_error(format!("Failed to read {}: {e}", path.display()));
+                return;
+            }
+        };
+        self.start_practice(&detail.title_slug);
+        self.last_opened_dir = Some(scaffold::problem_dir(&workspace, &detail));
+        let lang = self.lang_slug().to_string();
+        self.screen = Screen::Editor(EditorState::new(detail, path, &code, &lang));
+    }
+
+    /// Write the built-in editor's buffer to its file. False if it failed.
+    fn save_editor(&mut self) -> bool {
+        let Screen::Editor(ref mut state) = self.screen else {
+            return false;
+        };
+        match std::fs::write(&state.path, state.text()) {
+            Ok(()) => {
+                state.mark_saved();
+                true
+            }
+            Err(e) => {
+                let msg = format!("Failed to write {}: {e}", state.path.display());
+                self.show_error(msg);
+                false
+            }
+        }
+    }
+
+    fn open_local_test(&mut self, detail: QuestionDetail) {
+        let Some(config) = self.config.as_ref() else {
+            self.show_error("No config loaded".to_string());
+            return;
+        };
+        let dir = scaffold::project_dir(&config.expanded_workspace(), &detail, &config.language);
+        let Some(command) = LocalCommand::for_config(config, &dir) else {
+            self.show_error(format!(
+                "No local test command for {}.\nSet one under [local_test.{}] in the config.",
+                config.language, config.language
+            ));
+            return;
+        };
+        let path = scaffold::solution_path(&config.expanded_workspace(), &detail, &config.language);
+        if !path.exists() {
+            self.show_error(format!(
+                "{} isn't scaffolded yet.\nPress 'o' to scaffold it first.",
+                detail.title
+            ));
+            return;
+        }
+        let cases = local_test::cases(&detail);
+        self.screen = Screen::LocalTest(LocalTestState::new(
+            detail.clone(),
+            command.run.clone(),
+            cases,
+        ));
+        self.start_local_test(&detail);
+    }
+
+    /// Build once, then run the example cases one by one on a blocking
+    /// thread, reporting each result as it comes in.
+    fn start_local_test(&self, detail: &QuestionDetail) {
+        let Some(config) = self.config.as_ref() else {
+            return;
+        };
+        let dir = scaffold::project_dir(&config.expanded_workspace(), detail, &config.language);
+        let Some(command) = LocalCommand::for_config(config, &dir) else {
+            return;
+        };
+        let cases = local_test::cases(detail);
+        let current = self.local_test_run.clone();
+        let run = current.fetch_add(1, Ordering::SeqCst) + 1;
+        let lock = self.local_test_lock.clone();
+        let tx = self.api_tx.clone();
+
+        tokio::task::spawn_blocking(move || {
+            // Wait for an older run to notice it's been replaced
+            let _running = lock.lock().unwrap_or_else(|e| e.into_inner());
+            let superseded = || current.load(Ordering::SeqCst) != run;
+            let send = |event| {
+                let _ = tx.send(ApiResult::LocalTest { run, event });
+            };
+            if superseded() {
+                return;
+            }
+            if let Err(e) = local_test::build(&dir, &command) {
+                send(LocalTestEvent::BuildFailed(format!("{e:#}")));
+                return;
+            }
+            for (i, case) in cases.iter().enumerate() {
+                if superseded() {
+                    return;
+                }
+                send(LocalTestEvent::Case(i, local_test::run_case(&dir, &command, case)));
+            }
+        });
+    }
+
+    fn copy_solution_path(&mut self, detail: &QuestionDetail) {
+        let Some(config) = self.config.as_ref() else {
+            self.show_error("No config loaded".to_string());
+            return;
+        };
+        let path = scaffold::solution_path(&config.expanded_workspace(), detail, &config.language);
+        if !path.exists() {
+            self.show_error(format!(
+                "{} isn't scaffolded yet.\nPress 'o' to scaffold it first.",
+                detail.title
+            ));
+            return;
+        }
+        match clipboard::copy(&path.display().to_string()) {
+            Ok(()) => self.show_success(format!("Copied {}", path.display()), 12),
+            Err(e) => self.show_error(format!("Failed to copy: {e}")),
+        }
+    }
+
     fn read_user_code(&self, detail: &QuestionDetail) -> Result<String> {
         let config = self
             .config
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("No config loaded"))?;
-        let workspace = config.expanded_workspace();
-        let dir_name = format!("{}-{}", detail.frontend_question_id, detail.title_slug);
-        let file_path = match config.language.as_str() {
-            "rust" => workspace.join(&dir_name).join("src").join("main.rs"),
-            "python3" | "python" => workspace.join(&dir_name).join("solution.py"),
-            "cpp" | "c++" => workspace.join(&dir_name).join("solution.cpp"),
-            "java" => workspace.join(&dir_name).join("Solution.java"),
-            "javascript" => workspace.join(&dir_name).join("solution.js"),
-            "typescript" => workspace.join(&dir_name).join("solution.ts"),
-            "go" | "golang" => workspace.join(&dir_name).join("solution.go"),
-            _ => workspace.join(&dir_name).join("src").join("main.rs"),
-        };
+        let file_path =
+            scaffold::solution_path(&config.expanded_workspace(), detail, &config.language);
 
         let content = std::fs::read_to_string(&file_path).map_err(|e| {
             anyhow::anyhow!(
@@ -1071,66 +3553,100 @@ impl App {
             )
         })?;
 
-        if config.language.eq_ignore_ascii_case("rust") {
-            return extract_rust_solution(&content);
-        }
+        scaffold::submittable_code(&content, &config.language)
+    }
+
+    /// Upload the solution file for `detail` to a secret gist; the URL is
+    /// copied once it's up.
+    fn start_upload_gist(&mut self, detail: &QuestionDetail) {
+        let Some(config) = self.config.as_ref() else {
+            self.show_error("No config loaded".to_string());
+            return;
+        };
+        let Some(token) = config.github_token() else {
+            self.show_error(
+                "No GitHub token.\nSet github_token in config.toml (with the gist scope)\nor store it in the keyring as leetui/github_token\nwith credential_store = \"keyring\"."
+                    .to_string(),
+            );
+            return;
+        };
+        let options = config.client_options();
+        let ext = scaffold::solution_path(&config.expanded_workspace(), detail, &config.language)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("txt")
+            .to_string();
+        let code = match self.read_user_code(detail) {
+            Ok(c) => c,
+            Err(e) => {
+                self.show_error(format!("{e}"));
+                return;
+            }
+        };
+
+        let filename = format!("{}-{}.{ext}", detail.frontend_question_id, detail.title_slug);
+        let description = format!(
+            "LeetCode {}. {} ({}) \u{b7} {}",
+            detail.frontend_question_id,
+            detail.title,
+            detail.difficulty,
+            site().problem_url(&detail.title_slug)
+        );
+        self.notice_message = Some(("Uploading gist...".to_string(), 30));
 
-        Ok(content)
+        let tx = self.api_tx.clone();
+        tokio::spawn(async move {
+            let result = gist::upload(&options, &token, &filename, &description, &code).await;
+            let _ = tx.send(ApiResult::Gist(result));
+        });
     }
 
     fn lang_slug(&self) -> &str {
-        let config = self.config.as_ref();
-        match config.map(|c| c.language.as_str()) {
-            Some("rust") => "rust",
-            Some("python3") => "python3",
-            Some("python") => "python3",
-            Some("cpp" | "c++") => "cpp",
-            Some("java") => "java",
-            Some("javascript") => "javascript",
-            Some("typescript") => "typescript",
-            Some("go" | "golang") => "golang",
-            _ => "rust",
-        }
+        scaffold::lang_slug(self.config.as_ref().map_or("rust", |c| c.language.as_str()))
     }
 
     fn start_run_code(&mut self, detail: &QuestionDetail) {
-        let config = match &self.config {
-            Some(c) => c,
-            None => {
-                self.error_overlay = Some("No config loaded".to_string());
-                return;
-            }
-        };
+        if self.config.is_none() {
+            self.show_error("No config loaded".to_string());
+            return;
+        }
+
+        if self.guest_blocked("Running code") {
+            return;
+        }
 
-        if !config.is_authenticated() {
-            self.error_overlay = Some("Authentication required.\nPress S for settings, or use Ctrl+L in settings for auto-login.".to_string());
+        if !detail.has_question_id() {
+            self.show_error(MISSING_QUESTION_ID.to_string());
+            return;
+        }
+
+        if let Some(config) = &self.config
+            && !scaffold::has_code(&config.expanded_workspace(), detail, &config.language)
+        {
+            self.show_error(
+                "This problem has no code template (it may be premium-only).\nNothing to run."
+                    .to_string(),
+            );
             return;
         }
 
         let code = match self.read_user_code(detail) {
             Ok(c) => c,
             Err(e) => {
-                self.error_overlay = Some(format!("{e}"));
+                self.show_error(format!("{e}"));
                 return;
             }
         };
 
-        // Get test input from example testcases
-        let data_input = detail
-            .example_testcase_list
-            .as_ref()
-            .and_then(|v| {
-                if v.is_empty() {
-                    None
-                } else {
-                    Some(v.join("\n"))
-                }
-            })
-            .or_else(|| detail.sample_test_case.clone())
-            .unwrap_or_default();
+        let data_input = match self.config.as_ref() {
+            Some(config) => {
+                testcases::run_input(&config.expanded_workspace(), detail, &config.language)
+            }
+            None => detail.sample_input(),
+        };
 
         let title = format!("{}. {}", detail.frontend_question_id, detail.title);
-        self.screen = Screen::Result(ResultState::new(ResultKind::Run, title, detail.clone()));
+        self.open_result(ResultKind::Run, title, detail.clone());
 
         let client = self.api_client.clone();
         let tx = self.api_tx.clone();
@@ -1151,29 +3667,40 @@ impl App {
     }
 
     fn start_submit_code(&mut self, detail: &QuestionDetail) {
-        let config = match &self.config {
-            Some(c) => c,
-            None => {
-                self.error_overlay = Some("No config loaded".to_string());
-                return;
-            }
-        };
+        if self.config.is_none() {
+            self.show_error("No config loaded".to_string());
+            return;
+        }
+
+        if self.guest_blocked("Submitting") {
+            return;
+        }
+
+        if !detail.has_question_id() {
+            self.show_error(MISSING_QUESTION_ID.to_string());
+            return;
+        }
 
-        if !config.is_authenticated() {
-            self.error_overlay = Some("Authentication required.\nPress S for settings, or use Ctrl+L in settings for auto-login.".to_string());
+        if let Some(config) = &self.config
+            && !scaffold::has_code(&config.expanded_workspace(), detail, &config.language)
+        {
+            self.show_error(
+                "This problem has no code template (it may be premium-only).\nNothing to submit."
+                    .to_string(),
+            );
             return;
         }
 
         let code = match self.read_user_code(detail) {
             Ok(c) => c,
             Err(e) => {
-                self.error_overlay = Some(format!("{e}"));
+                self.show_error(format!("{e}"));
                 return;
             }
         };
 
         let title = format!("{}. {}", detail.frontend_question_id, detail.title);
-        self.screen = Screen::Result(ResultState::new(ResultKind::Submit, title, detail.clone()));
+        self.open_result(ResultKind::Submit, title, detail.clone());
 
         let client = self.api_client.clone();
         let tx = self.api_tx.clone();
@@ -1193,16 +3720,195 @@ impl App {
         });
     }
 
+    /// Submit the code of past submission `id` again, in the language it was
+    /// written in. Handy for checking whether a TLE was just a slow judge.
+    fn start_resubmit(&mut self, detail: &QuestionDetail, id: &str) {
+        if self.guest_blocked("Submitting") {
+            return;
+        }
+
+        if !detail.has_question_id() {
+            self.show_error(MISSING_QUESTION_ID.to_string());
+            return;
+        }
+
+        let title = format!(
+            "{}. {} \u{b7} resubmitting #{id}",
+            detail.frontend_question_id, detail.title
+        );
+        self.open_result(ResultKind::Submit, title, detail.clone());
+
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        let slug = detail.title_slug.clone();
+        let question_id = detail.question_id.clone();
+        let id = id.to_string();
+
+        tokio::spawn(async move {
+            let result = async {
+                let old = client.fetch_submission_detail(&id).await?;
+                let submission_id = client
+                    .submit_code(&slug, &question_id, &old.lang.name, &old.code)
+                    .await?;
+                client.poll_result(&submission_id).await
+            }
+            .await;
+            let _ = tx.send(ApiResult::SubmitResult(result));
+        });
+    }
+
+    /// Show a pending run/submit result, either as its own screen or, with
+    /// `result_view = "inline"`, in a pane under the problem in detail.
+    fn open_result(&mut self, kind: ResultKind, title: String, detail: QuestionDetail) {
+        let inline = self
+            .config
+            .as_ref()
+            .is_some_and(|c| c.result_view == ResultView::Inline);
+        let state = ResultState::new(kind, title, detail);
+        match self.screen {
+            Screen::Detail(ref mut d) if inline => d.inline_result = Some(Box::new(state)),
+            _ => {
+                let old = std::mem::replace(&mut self.screen, Screen::Result(state));
+                if let Screen::Editor(editor) = old {
+                    self.saved_editor = Some(editor);
+                }
+            }
+        }
+    }
+
+    /// The result waiting on a run/submit, wherever it's being shown.
+    fn result_state_mut(&mut self) -> Option<&mut ResultState> {
+        match self.screen {
+            Screen::Result(ref mut s) => Some(s),
+            Screen::Detail(ref mut d) => d.inline_result.as_deref_mut(),
+            _ => None,
+        }
+    }
+
+    /// Load a failing case into the scratchpad so it can be re-run as-is.
+    /// A scratchpad run goes back to its own code; a submission from detail
+    /// brings the solution file along.
+    fn open_failing_case(&mut self, input: &str) {
+        let mut pad = match self.saved_scratchpad.take() {
+            Some(pad) => pad,
+            None => {
+                let detail = match self.screen {
+                    Screen::Result(ref state) => state.detail.clone(),
+                    // A result shown inline
+                    Screen::Detail(ref state) => state.detail.clone(),
+                    _ => return,
+                };
+                let lang = self.lang_slug().to_string();
+                let mut pad = ScratchpadState::new(detail, &lang, None);
+                match self.read_user_code(&pad.detail) {
+                    Ok(code) => pad.set_code(code),
+                    Err(e) => self.show_error(format!("{e}")),
+                }
+                pad
+            }
+        };
+        pad.set_input(input);
+        self.screen = Screen::Scratchpad(pad);
+    }
+
+    fn start_scratchpad_run(&mut self) {
+        if self.guest_blocked("Running code") {
+            return;
+        }
+
+        let (request, title, detail) = match &mut self.screen {
+            Screen::Scratchpad(state) => {
+                state.save();
+                let d = &state.detail;
+                let title = format!("{}. {} (scratchpad)", d.frontend_question_id, d.title);
+                (state.run_request(), title, d.clone())
+            }
+            _ => return,
+        };
+
+        if !detail.has_question_id() {
+            self.show_error(MISSING_QUESTION_ID.to_string());
+            return;
+        }
+
+        let old = std::mem::replace(
+            &mut self.screen,
+            Screen::Result(ResultState::new(ResultKind::Run, title, detail)),
+        );
+        if let Screen::Scratchpad(pad) = old {
+            self.saved_scratchpad = Some(pad);
+        }
+
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let interpret_id = client
+                    .run_code(
+                        &request.slug,
+                        &request.question_id,
+                        &request.lang,
+                        &request.typed_code,
+                        &request.data_input,
+                    )
+                    .await?;
+                client.poll_result(&interpret_id).await
+            }
+            .await;
+            let _ = tx.send(ApiResult::RunResult(result));
+        });
+    }
+
+    /// Offer the problem's languages before scaffolding, the configured one
+    /// selected. Problems without snippets are scaffolded right away.
+    fn open_language_picker(
+        &mut self,
+        detail: QuestionDetail,
+        terminal: &mut ratatui::DefaultTerminal,
+        events: &EventHandler,
+    ) -> Result<()> {
+        let Some(language) = self.config.as_ref().map(|c| c.language.clone()) else {
+            self.show_error("No config loaded".to_string());
+            return Ok(());
+        };
+        let languages: Vec<(String, String)> = detail
+            .code_snippets
+            .iter()
+            .flatten()
+            .filter_map(|s| {
+                scaffold::language_for_slug(&s.lang_slug).map(|l| (s.lang.clone(), l.to_string()))
+            })
+            .collect();
+        if languages.is_empty() {
+            return self.do_scaffold_and_edit(&detail, &language, terminal, events);
+        }
+        let default = languages
+            .iter()
+            .position(|(_, l)| scaffold::lang_slug(l) == scaffold::lang_slug(&language));
+        self.language_picker = Some(LanguagePicker {
+            detail,
+            languages,
+            selected: default.unwrap_or(0),
+            default,
+        });
+        Ok(())
+    }
+
+    /// Scaffold `detail` in `language` and open it. The configured extra
+    /// languages come along only for the configured language, so a one-off
+    /// pick stays one file.
     fn do_scaffold_and_edit(
         &mut self,
         detail: &QuestionDetail,
+        language: &str,
         terminal: &mut ratatui::DefaultTerminal,
         events: &EventHandler,
     ) -> Result<()> {
         let config = match &self.config {
             Some(c) => c.clone(),
             None => {
-                self.error_overlay = Some("No config loaded".to_string());
+                self.show_error("No config loaded".to_string());
                 return Ok(());
             }
         };
@@ -1210,237 +3916,518 @@ impl App {
         let workspace = config.expanded_workspace();
         std::fs::create_dir_all(&workspace).ok();
 
-        match scaffold::scaffold_problem(&workspace, detail, &config.language) {
-            Ok(file_path) => {
-                let project_dir = file_path
-                    .parent()
-                    .and_then(|p| p.parent())
-                    .unwrap_or(&workspace);
-                self.last_opened_dir = Some(project_dir.to_path_buf());
+        let extra = if language == config.language {
+            config.extra_languages.as_slice()
+        } else {
+            &[]
+        };
+        match scaffold::scaffold_languages(
+            &workspace,
+            detail,
+            language,
+            extra,
+            config.rust_layout,
+        ) {
+            Ok((file_path, others)) => {
+                self.start_practice(&detail.title_slug);
+                let project_dir = scaffold::problem_dir(&workspace, detail);
+                let mut msg = format!("Scaffolded {}", file_path.display());
+                if !others.is_empty() {
+                    msg.push_str(&format!(" (+{} more)", others.len()));
+                }
+                self.show_success(msg, 30);
+
+                config.after_scaffold.apply(
+                    self,
+                    |app| app.last_opened_dir = Some(project_dir.clone()),
+                    |app| {
+                        let line = std::fs::read_to_string(&file_path)
+                            .ok()
+                            .and_then(|code| scaffold::solution_line(&code, language));
+                        app.run_editor(&file_path, &project_dir, line, false, terminal, events);
+                    },
+                );
+            }
+            Err(e) => {
+                self.show_error(format!("Scaffold failed: {e}"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Open the problem's notes in the editor, creating them if needed, then
+    /// show them in the detail view and flag the problem on the home table.
+    fn edit_notes(&mut self, terminal: &mut ratatui::DefaultTerminal, events: &EventHandler) {
+        let Some(config) = self.config.clone() else {
+            self.show_error("No config loaded".to_string());
+            return;
+        };
+        let Screen::Detail(ref state) = self.screen else {
+            return;
+        };
+        let slug = state.detail.title_slug.clone();
+        let title = format!("{}. {}", state.detail.frontend_question_id, state.detail.title);
+        let workspace = config.expanded_workspace();
+
+        let path = match notes::ensure(&workspace, &slug, &title) {
+            Ok(path) => path,
+            Err(e) => {
+                self.show_error(format!("Failed to create notes: {e}"));
+                return;
+            }
+        };
+        let dir = path.parent().map_or_else(|| workspace.clone(), Path::to_path_buf);
+        // Wait for GUI editors too, so the notes can be reloaded after
+        self.run_editor(&path, &dir, None, true, terminal, events);
+
+        let notes = notes::load(&workspace, &slug);
+        let has_notes = notes.is_some();
+        if let Screen::Detail(ref mut state) = self.screen {
+            state.set_notes(notes);
+            if has_notes && !state.showing_notes {
+                state.toggle_notes();
+            }
+        }
+        if let Some(home) = self.home_state_mut() {
+            if has_notes {
+                home.noted_slugs.insert(slug);
+            } else {
+                home.noted_slugs.remove(&slug);
+            }
+        }
+    }
+
+    /// Open the problem's custom testcases in the editor, creating the file
+    /// with a note on its format if needed. `r` picks them up from there.
+    fn edit_testcases(
+        &mut self,
+        detail: &QuestionDetail,
+        terminal: &mut ratatui::DefaultTerminal,
+        events: &EventHandler,
+    ) {
+        let Some(config) = self.config.clone() else {
+            self.show_error("No config loaded".to_string());
+            return;
+        };
+        let workspace = config.expanded_workspace();
+        let path = match testcases::ensure(&workspace, detail, &config.language) {
+            Ok(path) => path,
+            Err(e) => {
+                self.show_error(format!("Failed to create testcases: {e}"));
+                return;
+            }
+        };
+        let dir = path.parent().map_or_else(|| workspace.clone(), Path::to_path_buf);
+        self.run_editor(&path, &dir, None, false, terminal, events);
+    }
 
-                // Pause event reader so editor gets exclusive stdin access
-                events.pause();
-                ratatui::restore();
+    /// Open `file` in the configured editor, at `line` when given. Terminal
+    /// editors get the terminal until they exit. GUI editors (VS Code,
+    /// Sublime, ...) open alongside the TUI, and are only waited on when
+    /// `wait` is set.
+    fn run_editor(
+        &mut self,
+        file: &Path,
+        dir: &Path,
+        line: Option<usize>,
+        wait: bool,
+        terminal: &mut ratatui::DefaultTerminal,
+        events: &EventHandler,
+    ) {
+        let editor = self.config.as_ref().map_or("vim", |c| c.editor.as_str()).to_string();
+        let mut launch = crate::editor::launch(&editor, file, line, wait);
+        launch.command.current_dir(dir);
 
-                let status = Command::new(&config.editor)
-                    .arg(&file_path)
-                    .current_dir(project_dir)
-                    .status();
+        let status = if launch.in_terminal {
+            // Pause event reader so editor gets exclusive stdin access
+            events.pause();
+            crossterm::execute!(std::io::stdout(), crossterm::event::DisableFocusChange).ok();
+            ratatui::restore();
 
-                *terminal = ratatui::init();
-                events.resume();
+            let status = launch.command.status();
 
-                match status {
-                    Ok(s) if s.success() => {}
-                    Ok(s) => {
-                        self.error_overlay = Some(format!("Editor exited with status: {}", s));
+            *terminal = ratatui::init();
+            crossterm::execute!(std::io::stdout(), crossterm::event::EnableFocusChange).ok();
+            self.reset_hyperlinks();
+            events.resume();
+            status
+        } else {
+            launch
+                .command
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null());
+            if !launch.waits {
+                match launch.command.spawn() {
+                    Ok(mut child) => {
+                        // Reap it whenever it exits
+                        std::thread::spawn(move || child.wait());
+                        let name = file.file_name().unwrap_or_default().to_string_lossy();
+                        self.show_success(format!("Opened {name} in {editor}"), 20);
                     }
                     Err(e) => {
-                        self.error_overlay = Some(format!(
-                            "Failed to launch editor '{}': {}",
-                            config.editor, e
-                        ));
+                        self.show_error(format!("Failed to launch editor '{}': {}", editor, e));
                     }
                 }
+                return;
+            }
+            // The TUI can't redraw until the editor returns; say why
+            self.notice_message = Some((format!("Waiting for {editor} to close the file\u{2026}"), 1));
+            let _ = terminal.draw(|f| self.render(f));
+            launch.command.status()
+        };
+
+        match status {
+            Ok(s) if s.success() => {}
+            Ok(s) => {
+                self.show_error(format!("Editor exited with status: {}", s));
             }
             Err(e) => {
-                self.error_overlay = Some(format!("Scaffold failed: {e}"));
+                self.show_error(format!("Failed to launch editor '{}': {}", editor, e));
             }
         }
+    }
 
-        Ok(())
+    fn start_fetch_image(&self, url: String) {
+        let client = self.api_client.clone();
+        let tx = self.api_tx.clone();
+        tokio::spawn(async move {
+            let result = client.fetch_image(&url).await;
+            let _ = tx.send(ApiResult::Image { url, result });
+        });
     }
 
-    fn browser_login(&mut self) {
-        let domains = vec!["leetcode.com".to_string()];
-        let cookies = match rookie::load(Some(domains)) {
-            Ok(c) => c,
-            Err(_) => {
-                let _ = Command::new("open")
-                    .arg("https://leetcode.com/accounts/login/")
-                    .spawn();
-                self.login_waiting = true;
-                return;
+    /// Draw the image viewer's image once its frame is on screen.
+    fn draw_inline_image(&mut self) {
+        let Screen::Detail(ref mut state) = self.screen else {
+            return;
+        };
+        let Some(ref mut view) = state.image_view else {
+            return;
+        };
+        if view.drawn || view.area.is_empty() {
+            return;
+        }
+        if let Some(Ok(ref image)) = view.image {
+            view.drawn = true;
+            if let Err(e) = graphics::draw(&mut std::io::stdout(), view.protocol, image, view.area)
+            {
+                view.image = Some(Err(e.to_string()));
             }
+        }
+    }
+
+    /// Wrap the links on the frame just drawn in OSC 8 escapes, so they can
+    /// be clicked.
+    fn draw_hyperlinks(&mut self, buffer: &Buffer) {
+        if !self.config.as_ref().is_none_or(|c| c.hyperlinks) {
+            return;
+        }
+        let Some(ref mut hyperlinks) = self.hyperlinks else {
+            return;
         };
+        let links = match self.screen {
+            Screen::Home(ref state) => home::links(state, buffer),
+            Screen::Detail(ref state) => detail::links(state, buffer),
+            _ => Vec::new(),
+        };
+        let _ = hyperlinks.emit(&mut std::io::stdout(), buffer, &links);
+    }
 
-        let session = cookies
-            .iter()
-            .find(|c| c.name == "LEETCODE_SESSION")
-            .map(|c| c.value.clone());
-        let csrf = cookies
-            .iter()
-            .find(|c| c.name == "csrftoken")
-            .map(|c| c.value.clone());
+    /// Forget which links are on screen once the terminal was redrawn from
+    /// scratch.
+    fn reset_hyperlinks(&mut self) {
+        if let Some(ref mut hyperlinks) = self.hyperlinks {
+            hyperlinks.reset();
+        }
+    }
+
+    /// Remove drawn images from the screen; the viewer redraws its own on
+    /// the next frame.
+    fn clear_inline_images(&mut self) {
+        if let Screen::Detail(ref mut state) = self.screen
+            && let Some(ref mut view) = state.image_view
+        {
+            view.drawn = false;
+        }
+        if let Some(protocol) = graphics::detect() {
+            let _ = graphics::clear(&mut std::io::stdout(), protocol);
+        }
+    }
+
+    fn open_in_browser(&mut self, url: &str) {
+        if let Err(e) = open::that_detached(url) {
+            self.show_error(format!("Failed to open {url}: {e}"));
+        }
+    }
 
-        if session.is_none() || csrf.is_none() {
+    fn browser_login(&mut self) {
+        let (logins, _) = browser_cookies::find_logins();
+        if logins.is_empty() {
             // No cookies found — open browser and wait for retry
-            let _ = Command::new("open")
-                .arg("https://leetcode.com/accounts/login/")
-                .spawn();
+            self.open_in_browser(&site().login_url());
             self.login_waiting = true;
             return;
         }
-
-        self.apply_login_cookies(session, csrf);
+        self.use_logins(logins);
     }
 
     fn retry_browser_login(&mut self) {
         self.login_waiting = false;
 
-        let domains = vec!["leetcode.com".to_string()];
-        let cookies = match rookie::load(Some(domains)) {
-            Ok(c) => c,
-            Err(e) => {
-                self.error_overlay = Some(format!(
-                    "Still can't extract cookies: {e}\n\nMake sure you logged into leetcode.com,\nthen press Enter to retry."
-                ));
-                self.login_waiting = true;
-                return;
-            }
-        };
-
-        let session = cookies
-            .iter()
-            .find(|c| c.name == "LEETCODE_SESSION")
-            .map(|c| c.value.clone());
-        let csrf = cookies
-            .iter()
-            .find(|c| c.name == "csrftoken")
-            .map(|c| c.value.clone());
-
-        if session.is_none() || csrf.is_none() {
-            self.error_overlay = Some(
-                "Could not find LeetCode cookies.\n\nLog into leetcode.com in your browser,\nthen press Enter to retry.".to_string()
-            );
+        let (logins, errors) = browser_cookies::find_logins();
+        if logins.is_empty() {
+            let detail = if errors.is_empty() {
+                String::new()
+            } else {
+                format!("\n\n{}", errors.join("\n"))
+            };
+            self.show_error(format!(
+                "Could not find LeetCode cookies.\n\nLog into {} in your browser,\nthen press Enter to retry.{detail}",
+                site().host()
+            ));
             self.login_waiting = true;
             return;
         }
+        self.use_logins(logins);
+    }
 
-        self.apply_login_cookies(session, csrf);
+    /// Apply the only login found, or let the user pick between several.
+    fn use_logins(&mut self, mut logins: Vec<CookieCandidate>) {
+        if logins.len() == 1 {
+            let login = logins.remove(0);
+            self.apply_login_cookies(Some(login.session), Some(login.csrf));
+        } else {
+            self.cookie_picker = Some(CookiePicker {
+                logins,
+                selected: 0,
+            });
+        }
     }
 
     fn apply_login_cookies(&mut self, session: Option<String>, csrf: Option<String>) {
+        // Show the new cookies if the settings form is open
+        if let Screen::Setup(ref mut s) = self.screen {
+            s.fields[3] = session.clone().unwrap_or_default();
+            s.fields[4] = csrf.clone().unwrap_or_default();
+            s.authenticated = true;
+        }
+
         // Update config
         if let Some(ref mut config) = self.config {
             config.leetcode_session = session.clone();
             config.csrf_token = csrf.clone();
             if let Err(e) = config.save() {
-                self.error_overlay = Some(format!("Cookies found but failed to save config: {e}"));
+                self.show_error(format!("Cookies found but failed to save config: {e}"));
                 return;
             }
         }
 
         // Recreate client with new credentials
-        match LeetCodeClient::new(session.as_deref(), csrf.as_deref()) {
+        let options = self
+            .config
+            .as_ref()
+            .map(Config::client_options)
+            .unwrap_or_default();
+        match LeetCodeClient::new(session.as_deref(), csrf.as_deref(), &options) {
             Ok(client) => {
                 self.api_client = client;
                 self.start_fetch_problems();
                 self.start_fetch_user_stats();
             }
             Err(e) => {
-                self.error_overlay = Some(format!("Failed to create client: {e}"));
+                self.show_error(format!("Failed to create client: {e}"));
             }
         }
     }
 }
 
-fn load_cached_problems() -> Option<Vec<ProblemSummary>> {
-    let path = Config::cache_path();
+/// "Virtual contest over: score 12, 3/4 solved, finish time 1:02:03".
+fn virtual_summary(prefix: &str, v: &VirtualContest) -> String {
+    format!(
+        "{prefix}: score {}, {}/{} solved, finish time {}",
+        v.score(),
+        v.solved_count(),
+        v.problems.len(),
+        practice::format_clock(v.finish_time())
+    )
+}
+
+fn new_home_state(config: &Config) -> HomeState {
+    let mut state = HomeState::new();
+    state.show_stats = config.show_stats;
+    state.stats_expanded = config.stats_expanded;
+    state.max_title_width = config.max_title_width;
+    state.category = config.category;
+    state.noted_slugs = notes::noted_slugs(&config.expanded_workspace());
+    state
+}
+
+pub fn load_cached_problems(category: Category) -> Option<Vec<ProblemSummary>> {
+    let path = Config::cache_path(category);
     let data = std::fs::read_to_string(path).ok()?;
     serde_json::from_str(&data).ok()
 }
 
-fn save_problems_cache(problems: &[ProblemSummary]) {
-    let path = Config::cache_path();
+fn save_problems_cache(category: Category, problems: &[ProblemSummary]) {
+    let path = Config::cache_path(category);
     if let Ok(data) = serde_json::to_string(problems) {
-        let _ = std::fs::write(path, data);
+        let _ = config::write_atomic(&path, &data);
     }
 }
 
-/// Extract the solution portion of a Rust file using tree-sitter.
-///
-/// Walks top-level AST nodes and keeps everything except:
-/// - Leading line comments (problem description)
-/// - `struct Solution;` (LSP shim we added)
-/// - `fn main() { ... }`
-/// - `#[cfg(test)] mod tests { ... }`
-fn extract_rust_solution(content: &str) -> Result<String> {
-    let mut parser = tree_sitter::Parser::new();
-    let language = tree_sitter_rust::LANGUAGE;
-    parser
-        .set_language(&language.into())
-        .map_err(|e| anyhow::anyhow!("Failed to set tree-sitter language: {e}"))?;
-
-    let tree = parser
-        .parse(content, None)
-        .ok_or_else(|| anyhow::anyhow!("Failed to parse Rust file"))?;
-
-    let root = tree.root_node();
-    let mut parts: Vec<&str> = Vec::new();
-    let mut in_leading_comments = true;
-    let mut skip_next = false;
-
-    let mut cursor = root.walk();
-    for child in root.children(&mut cursor) {
-        // If the previous node was #[cfg(test)], skip this node (the mod item)
-        if skip_next {
-            skip_next = false;
-            continue;
-        }
-
-        let kind = child.kind();
-        let text = &content[child.byte_range()];
-
-        // Skip leading line comments (problem description block)
-        if in_leading_comments && kind == "line_comment" {
-            continue;
-        }
-        if kind != "line_comment" {
-            in_leading_comments = false;
-        }
-
-        // Skip empty `struct Solution` in any form: `struct Solution;`, `struct Solution {}`, etc.
-        // These are LSP shims — LeetCode provides its own.
-        if kind == "struct_item" {
-            if let Some(name_node) = child.child_by_field_name("name") {
-                let name = &content[name_node.byte_range()];
-                if name == "Solution" {
-                    let has_fields = child.child_by_field_name("body").is_some_and(|body| {
-                        let mut bc = body.walk();
-                        body.children(&mut bc)
-                            .any(|c| c.kind() == "field_declaration")
-                    });
-                    if !has_fields {
-                        continue;
-                    }
-                }
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::scratchpad::ScratchpadField;
+    use crossterm::event::KeyEvent;
+
+    fn app(start_screen: StartScreen) -> App {
+        App::new(Some(Config::default()), start_screen).unwrap()
+    }
+
+    fn detail() -> QuestionDetail {
+        serde_json::from_value(serde_json::json!({
+            "questionId": "1",
+            "frontendQuestionId": "1",
+            "title": "Two Sum",
+            "titleSlug": "two-sum",
+            "difficulty": "Easy",
+            "isPaidOnly": false,
+            "topicTags": [],
+            "hints": [],
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn lists_flag_starts_on_the_lists_screen() {
+        let mut app = app(StartScreen::Lists);
+        assert!(matches!(app.screen, Screen::Home(_)));
+        app.open_start_screen();
+        assert!(matches!(app.screen, Screen::Lists(_)));
+        // Back lands on home
+        assert!(app.saved_home.is_some());
+        assert_eq!(app.start_screen, StartScreen::Home);
+    }
+
+    #[tokio::test]
+    async fn identical_errors_collapse_into_a_count() {
+        let mut app = app(StartScreen::Home);
+        for _ in 0..3 {
+            app.show_error("Network error".to_string());
         }
+        assert_eq!(app.error_overlay.as_deref(), Some("Network error"));
+        assert_eq!(app.error_repeats, 3);
+        let backend = ratatui::backend::TestBackend::new(80, 20);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        let screen: String = buffer.content().iter().map(|c| c.symbol()).collect();
+        assert_eq!(screen.matches("Network error").count(), 1);
+        assert!(screen.contains(" Error (x3) "));
+
+        app.show_error("Timed out".to_string());
+        assert_eq!(app.error_repeats, 1);
+
+        app.show_success("Loaded".to_string(), 10);
+        app.show_error("Timed out".to_string());
+        assert_eq!(app.error_repeats, 1);
+    }
 
-        // Skip `fn main() { ... }`
-        if kind == "function_item" {
-            if let Some(name_node) = child.child_by_field_name("name") {
-                if &content[name_node.byte_range()] == "main" {
-                    continue;
+    #[tokio::test]
+    async fn guest_mode_blocks_submit_with_a_notice() {
+        let mut app = app(StartScreen::Home);
+        assert!(app.is_guest());
+        app.start_submit_code(&detail());
+
+        let (notice, _) = app.notice_message.as_ref().unwrap();
+        assert_eq!(
+            notice,
+            "Submitting needs a LeetCode login \u{2014} press S on the home screen to sign in"
+        );
+        assert!(app.error_overlay.is_none());
+        assert!(matches!(app.screen, Screen::Home(_)));
+
+        let config = app.config.as_mut().unwrap();
+        config.leetcode_session = Some("session".to_string());
+        config.csrf_token = Some("csrf".to_string());
+        assert!(!app.guest_blocked("Submitting"));
+    }
+
+    #[tokio::test]
+    async fn empty_question_id_stops_run_and_submit_before_the_request() {
+        let mut app = app(StartScreen::Home);
+        let config = app.config.as_mut().unwrap();
+        config.leetcode_session = Some("session".to_string());
+        config.csrf_token = Some("csrf".to_string());
+        let mut detail = detail();
+        detail.question_id = " ".to_string();
+
+        app.start_run_code(&detail);
+        assert_eq!(app.error_overlay.as_deref(), Some(MISSING_QUESTION_ID));
+        // No result screen means nothing was sent
+        assert!(matches!(app.screen, Screen::Home(_)));
+
+        app.error_overlay = None;
+        app.start_submit_code(&detail);
+        assert_eq!(app.error_overlay.as_deref(), Some(MISSING_QUESTION_ID));
+        assert!(matches!(app.screen, Screen::Home(_)));
+    }
+
+    #[tokio::test]
+    async fn result_view_setting_picks_the_post_run_screen() {
+        for (view, inline) in [(ResultView::Screen, false), (ResultView::Inline, true)] {
+            let mut app = app(StartScreen::Home);
+            app.config.as_mut().unwrap().result_view = view;
+            app.screen = Screen::Detail(app.new_detail_state(detail()));
+
+            app.open_result(ResultKind::Submit, "1. Two Sum".to_string(), detail());
+            match app.screen {
+                Screen::Detail(ref state) => {
+                    assert!(inline);
+                    assert!(state.inline_result.is_some());
                 }
+                Screen::Result(_) => assert!(!inline),
+                _ => panic!("unexpected screen after a run"),
             }
+            assert!(app.result_state_mut().is_some());
         }
+    }
 
-        // Skip `#[cfg(test)]` attribute and mark to skip the next item (mod tests)
-        if kind == "attribute_item" && text.contains("cfg") && text.contains("test") {
-            skip_next = true;
-            continue;
-        }
+    #[tokio::test]
+    async fn failing_testcase_fills_the_scratchpad_input() {
+        let mut app = app(StartScreen::Home);
+        let mut result = ResultState::new(ResultKind::Submit, "1. Two Sum".to_string(), detail());
+        result.set_result(ResultData {
+            status_msg: "Wrong Answer".to_string(),
+            status_code: 11,
+            total_correct: Some(3),
+            total_testcases: Some(10),
+            runtime: None,
+            memory: None,
+            code_output: Some(vec!["[1,0]".to_string()]),
+            expected_output: Some("[0,1]".to_string()),
+            last_testcase: Some("[3,3]\n6".to_string()),
+            compile_error: None,
+        });
+        app.screen = Screen::Result(result);
 
-        parts.push(text);
-    }
+        let Screen::Result(ref mut state) = app.screen else {
+            unreachable!()
+        };
+        let ResultAction::DebugInput(input) = state.handle_key(KeyEvent::from(KeyCode::Char('t')))
+        else {
+            panic!("t should load the failing case");
+        };
+        app.open_failing_case(&input);
 
-    let result = parts.join("\n").trim().to_string();
-    if result.is_empty() {
-        // Fallback: return original content if parsing produced nothing
-        Ok(content.to_string())
-    } else {
-        Ok(result)
+        let Screen::Scratchpad(ref pad) = app.screen else {
+            panic!("the failing case opens in the scratchpad");
+        };
+        assert_eq!(pad.data_input, "[3,3]\n6");
+        assert_eq!(pad.active_field, ScratchpadField::Input);
+        assert_eq!(pad.detail.title_slug, "two-sum");
     }
 }
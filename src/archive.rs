@@ -0,0 +1,216 @@
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::api::client::LeetCodeClient;
+use crate::api::site::site;
+use crate::api::types::{Category, ProblemSummary};
+use crate::app::load_cached_problems;
+use crate::config::Config;
+use crate::scaffold;
+
+/// Solution file names the scaffolder creates, with the language they hold.
+const SOLUTION_FILES: &[(&str, &str)] = &[
+    ("src/main.rs", "rust"),
+    ("src/lib.rs", "rust"),
+    ("solution.py", "python3"),
+    ("solution.cpp", "cpp"),
+    ("Solution.java", "java"),
+    ("solution.js", "javascript"),
+    ("solution.ts", "typescript"),
+    ("solution.go", "go"),
+];
+
+/// A solved problem as it appears in the archive.
+struct Entry {
+    id: String,
+    slug: String,
+    title: String,
+    difficulty: String,
+    topic: (String, String), // (slug, name)
+    tags: Vec<String>,
+    language: &'static str,
+    code: String,
+    runtime: Option<String>,
+}
+
+pub struct ArchiveReport {
+    pub exported: usize,
+    pub skipped: Vec<String>,
+}
+
+/// Turn every scaffolded solution in the workspace into `out`: one folder per
+/// topic holding a folder per problem (solution and a short README), plus an
+/// index README listing them all.
+pub async fn export(client: &LeetCodeClient, config: &Config, out: &Path) -> Result<ArchiveReport> {
+    let workspace = config.expanded_workspace();
+    let problems: HashMap<String, ProblemSummary> = load_cached_problems(Category::All)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|p| (p.title_slug.clone(), p))
+        .collect();
+
+    let mut dirs: Vec<(u32, String, PathBuf)> = std::fs::read_dir(&workspace)
+        .with_context(|| format!("Failed to read workspace {}", workspace.display()))?
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let (id, slug) = name.split_once('-')?;
+            let id = id.parse().ok()?;
+            Some((id, slug.to_string(), entry.path()))
+        })
+        .collect();
+    dirs.sort_by_key(|(id, _, _)| *id);
+
+    let mut entries = Vec::new();
+    let mut skipped = Vec::new();
+    for (id, slug, dir) in dirs {
+        let Some((file, language)) = SOLUTION_FILES
+            .iter()
+            .map(|&(file, lang)| (dir.join(file), lang))
+            .find(|(file, _)| file.exists())
+        else {
+            skipped.push(format!("{id}-{slug}: no solution file"));
+            continue;
+        };
+        let content = std::fs::read_to_string(&file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        let code = match scaffold::submittable_code(&content, language) {
+            Ok(code) => code,
+            Err(e) => {
+                skipped.push(format!("{id}-{slug}: {e}"));
+                continue;
+            }
+        };
+        eprintln!("{id}. {slug}");
+
+        let (title, difficulty, tags) = match problems.get(&slug) {
+            Some(p) => (p.title.clone(), p.difficulty.clone(), p.topic_tags.clone()),
+            None => match client.fetch_problem_detail(&slug).await {
+                Ok(d) => (d.title, d.difficulty, d.topic_tags),
+                Err(e) => {
+                    skipped.push(format!("{id}-{slug}: {e}"));
+                    continue;
+                }
+            },
+        };
+        let runtime = if config.is_authenticated() {
+            accepted_runtime(client, &slug).await
+        } else {
+            None
+        };
+        entries.push(Entry {
+            id: id.to_string(),
+            topic: tags
+                .first()
+                .map(|t| (t.slug.clone(), t.name.clone()))
+                .unwrap_or_else(|| ("misc".to_string(), "Misc".to_string())),
+            tags: tags.into_iter().map(|t| t.name).collect(),
+            slug,
+            title,
+            difficulty,
+            language,
+            code,
+            runtime,
+        });
+    }
+
+    for entry in &entries {
+        write_entry(out, entry)?;
+    }
+    let index = out.join("README.md");
+    std::fs::write(&index, index_readme(&entries))
+        .with_context(|| format!("Failed to write {}", index.display()))?;
+
+    Ok(ArchiveReport {
+        exported: entries.len(),
+        skipped,
+    })
+}
+
+/// Runtime of the most recent accepted submission.
+async fn accepted_runtime(client: &LeetCodeClient, slug: &str) -> Option<String> {
+    let page = client.fetch_submissions(slug, 0, 20).await.ok()?;
+    page.submissions
+        .into_iter()
+        .find(|s| s.status_display == "Accepted")
+        .map(|s| s.runtime)
+        .filter(|r| !r.is_empty())
+}
+
+fn entry_dir(entry: &Entry) -> String {
+    format!("{}/{}-{}", entry.topic.0, entry.id, entry.slug)
+}
+
+fn extension(language: &str) -> &'static str {
+    match language {
+        "python3" => "py",
+        "cpp" => "cpp",
+        "java" => "java",
+        "javascript" => "js",
+        "typescript" => "ts",
+        "go" => "go",
+        _ => "rs",
+    }
+}
+
+fn write_entry(out: &Path, entry: &Entry) -> Result<()> {
+    let dir = out.join(entry_dir(entry));
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create dir {}", dir.display()))?;
+
+    let file = dir.join(format!("solution.{}", extension(entry.language)));
+    std::fs::write(&file, &entry.code)
+        .with_context(|| format!("Failed to write {}", file.display()))?;
+
+    let mut readme = format!(
+        "# {}. {}\n\n**Difficulty:** {}  \n**Link:** {}\n",
+        entry.id,
+        entry.title,
+        entry.difficulty,
+        site().problem_url(&entry.slug)
+    );
+    if !entry.tags.is_empty() {
+        let _ = writeln!(readme, "**Topics:** {}  ", entry.tags.join(", "));
+    }
+    if let Some(ref runtime) = entry.runtime {
+        let _ = writeln!(readme, "**Runtime:** {runtime}  ");
+    }
+    let path = dir.join("README.md");
+    std::fs::write(&path, readme).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn index_readme(entries: &[Entry]) -> String {
+    let mut by_topic: Vec<(&str, Vec<&Entry>)> = Vec::new();
+    for entry in entries {
+        match by_topic.iter_mut().find(|(name, _)| *name == entry.topic.1) {
+            Some((_, list)) => list.push(entry),
+            None => by_topic.push((&entry.topic.1, vec![entry])),
+        }
+    }
+    by_topic.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut out = format!("# LeetCode Solutions\n\n{} problems solved.\n", entries.len());
+    for (topic, list) in by_topic {
+        let _ = write!(
+            out,
+            "\n## {topic}\n\n| # | Problem | Difficulty | Runtime | Solution |\n| --- | --- | --- | --- | --- |\n"
+        );
+        for e in list {
+            let _ = writeln!(
+                out,
+                "| {} | [{}]({}) | {} | {} | [{}](./{}/solution.{}) |",
+                e.id,
+                e.title,
+                site().problem_url(&e.slug),
+                e.difficulty,
+                e.runtime.as_deref().unwrap_or("\u{2014}"),
+                e.language,
+                entry_dir(e),
+                extension(e.language)
+            );
+        }
+    }
+    out
+}
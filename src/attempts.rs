@@ -0,0 +1,117 @@
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::api::types::{CheckResponse, QuestionDetail};
+use crate::config::{self, Config};
+use crate::scaffold;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AttemptKind {
+    Run,
+    Submit,
+}
+
+/// One run or submission, as judged by LeetCode.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attempt {
+    pub slug: String,
+    /// "1. Two Sum"
+    pub title: String,
+    /// "Easy", "Medium" or "Hard"; empty in attempts recorded before it was kept.
+    #[serde(default)]
+    pub difficulty: String,
+    pub kind: AttemptKind,
+    /// Unix seconds.
+    pub timestamp: u64,
+    pub verdict: String,
+    #[serde(default)]
+    pub runtime: Option<String>,
+    pub language: String,
+    /// Seconds between scaffolding the problem and this attempt, when the
+    /// problem was scaffolded (and the filesystem keeps creation times).
+    #[serde(default)]
+    pub since_scaffold: Option<u64>,
+}
+
+impl Attempt {
+    /// `language` stands in when LeetCode's response doesn't name one.
+    pub fn new(
+        detail: &QuestionDetail,
+        kind: AttemptKind,
+        resp: &CheckResponse,
+        language: &str,
+        workspace: &Path,
+    ) -> Self {
+        let now = SystemTime::now();
+        let since_scaffold = std::fs::metadata(scaffold::problem_dir(workspace, detail))
+            .and_then(|m| m.created())
+            .ok()
+            .and_then(|created| now.duration_since(created).ok())
+            .map(|d| d.as_secs());
+        Self {
+            slug: detail.title_slug.clone(),
+            title: format!("{}. {}", detail.frontend_question_id, detail.title),
+            difficulty: detail.difficulty.clone(),
+            kind,
+            timestamp: now.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+            verdict: verdict(kind, resp),
+            runtime: resp.status_runtime.clone().filter(|r| !r.is_empty() && r != "N/A"),
+            language: resp.pretty_lang.clone().unwrap_or_else(|| language.to_string()),
+            since_scaffold,
+        }
+    }
+
+    pub fn accepted(&self) -> bool {
+        self.verdict == "Accepted"
+    }
+}
+
+/// LeetCode's status, except that a run which finished but printed the wrong
+/// answer is reported as such rather than "Accepted".
+fn verdict(kind: AttemptKind, resp: &CheckResponse) -> String {
+    let status = resp.status_msg.clone().unwrap_or_else(|| "Unknown".to_string());
+    if kind == AttemptKind::Run && resp.status_code == Some(10) && resp.correct_answer == Some(false)
+    {
+        return "Wrong Answer".to_string();
+    }
+    status
+}
+
+/// Every run and submission made from leetui, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct Attempts(Vec<Attempt>);
+
+impl Attempts {
+    pub fn load() -> Self {
+        std::fs::read_to_string(Config::attempts_path())
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .map(Self)
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        if let Ok(data) = serde_json::to_string(&self.0) {
+            let _ = config::write_atomic(&Config::attempts_path(), &data);
+        }
+    }
+
+    /// Add an attempt and write the store out.
+    pub fn record(&mut self, attempt: Attempt) {
+        self.0.push(attempt);
+        self.save();
+    }
+
+    /// All attempts, newest first.
+    pub fn newest_first(&self) -> Vec<Attempt> {
+        self.0.iter().rev().cloned().collect()
+    }
+
+    /// Attempts at one problem, newest first.
+    pub fn for_problem(&self, slug: &str) -> Vec<Attempt> {
+        self.0.iter().rev().filter(|a| a.slug == slug).cloned().collect()
+    }
+}
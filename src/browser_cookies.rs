@@ -0,0 +1,88 @@
+use crate::api::site::site;
+
+/// Browsers we know how to read cookies from, in the order they're tried.
+const BROWSERS: &[&str] = &["Chrome", "Chromium", "Brave", "Edge", "Firefox", "Safari"];
+
+/// LeetCode cookies from every profile of `browser` on the current OS.
+fn load(browser: &str) -> Result<Vec<(String, String, Option<u64>)>, String> {
+    let domains = Some(vec![site().host().to_string()]);
+    let cookies = match browser {
+        "Chrome" => rookie::chrome(domains),
+        "Chromium" => rookie::chromium(domains),
+        "Brave" => rookie::brave(domains),
+        "Edge" => rookie::edge(domains),
+        "Firefox" => rookie::firefox(domains),
+        #[cfg(target_os = "macos")]
+        "Safari" => rookie::safari(domains),
+        _ => return Ok(Vec::new()),
+    };
+    cookies
+        .map(|cookies| {
+            cookies
+                .into_iter()
+                .map(|c| (c.name, c.value, c.expires))
+                .collect()
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// A LeetCode login found in a browser profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CookieCandidate {
+    pub browser: &'static str,
+    pub session: String,
+    pub csrf: String,
+    /// Unix time the session cookie expires, if it isn't a session cookie.
+    pub expires: Option<u64>,
+}
+
+impl CookieCandidate {
+    pub fn label(&self) -> String {
+        match self.expires {
+            Some(ts) => match time::OffsetDateTime::from_unix_timestamp(ts as i64) {
+                Ok(at) => format!("{} (expires {})", self.browser, at.date()),
+                Err(_) => self.browser.to_string(),
+            },
+            None => self.browser.to_string(),
+        }
+    }
+}
+
+/// Every distinct, unexpired LeetCode login across installed browsers.
+/// Browsers that can't be read (not installed, locked, no permission) are
+/// skipped; their errors are returned for when nothing is found.
+pub fn find_logins() -> (Vec<CookieCandidate>, Vec<String>) {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let mut found: Vec<CookieCandidate> = Vec::new();
+    let mut errors = Vec::new();
+    for &browser in BROWSERS {
+        let cookies = match load(browser) {
+            Ok(c) => c,
+            Err(e) => {
+                errors.push(format!("{browser}: {e}"));
+                continue;
+            }
+        };
+        // The CSRF token is a double-submit cookie, not tied to the session,
+        // so any one of the browser's tokens pairs with each of its sessions
+        let Some((_, csrf, _)) = cookies.iter().find(|(name, ..)| name == "csrftoken") else {
+            continue;
+        };
+        for (_, session, expires) in cookies.iter().filter(|(name, ..)| name == "LEETCODE_SESSION") {
+            let expired = expires.is_some_and(|ts| ts > 0 && ts < now);
+            if expired || session.is_empty() || found.iter().any(|f| &f.session == session) {
+                continue;
+            }
+            found.push(CookieCandidate {
+                browser,
+                session: session.clone(),
+                csrf: csrf.clone(),
+                expires: expires.filter(|&ts| ts > 0),
+            });
+        }
+    }
+    (found, errors)
+}
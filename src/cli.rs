@@ -0,0 +1,167 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+/// A terminal UI for browsing, solving, and submitting LeetCode problems.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// Open a problem's detail view on launch, e.g. `--open two-sum`
+    #[arg(long, value_name = "SLUG", conflicts_with = "lists")]
+    pub open: Option<String>,
+
+    /// Open the lists screen on launch
+    #[arg(long)]
+    pub lists: bool,
+
+    /// Run a single command and print the result instead of starting the TUI
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Print a problem's description
+    Show {
+        /// Problem number or slug, e.g. `1` or `two-sum`
+        problem: String,
+    },
+    /// Run a solution file against the example test cases
+    Test {
+        /// Solution file; the problem is taken from the problem link in its
+        /// header comment or its `<id>-<slug>` directory
+        file: PathBuf,
+        /// Problem number or slug, if the file doesn't say
+        #[arg(long, short)]
+        problem: Option<String>,
+        /// Language, e.g. `python3`; by default taken from the file extension
+        #[arg(long, short)]
+        lang: Option<String>,
+        /// Custom test input instead of the examples
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Submit a solution file
+    Submit {
+        /// Solution file; the problem is taken from the problem link in its
+        /// header comment or its `<id>-<slug>` directory
+        file: PathBuf,
+        /// Problem number or slug, if the file doesn't say
+        #[arg(long, short)]
+        problem: Option<String>,
+        /// Language, e.g. `python3`; by default taken from the file extension
+        #[arg(long, short)]
+        lang: Option<String>,
+    },
+    /// List problems
+    List {
+        #[arg(long, short, value_enum)]
+        difficulty: Option<Difficulty>,
+        /// Only problems whose title matches
+        #[arg(long, short)]
+        search: Option<String>,
+        #[arg(long, short = 'n', default_value_t = 50)]
+        limit: usize,
+        /// Output as a table, or CSV/JSON for spreadsheets and scripts
+        #[arg(long, short, value_enum, default_value_t = ListFormat::Table)]
+        format: ListFormat,
+    },
+    /// Export workspace solutions as a Markdown archive, one folder per topic
+    Export {
+        /// Directory to write the archive to
+        #[arg(long, short, default_value = "leetcode-solutions")]
+        out: PathBuf,
+    },
+    /// Add the problems in a JSON / CSV / text file of ids or slugs to a favorite list
+    Import {
+        file: PathBuf,
+        /// Existing list to add to; by default a new list named after the file is created
+        #[arg(long, short)]
+        list: Option<String>,
+    },
+    /// Export problems as an Anki deck: the statement on the front, your notes on the back
+    Anki {
+        /// Favorite list to export instead of every solved problem
+        #[arg(long, short)]
+        list: Option<String>,
+        /// File to write, for Anki's File > Import
+        #[arg(long, short, default_value = "leetcode-anki.txt")]
+        out: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    /// Value of the `difficulty` filter in the problem list query.
+    pub fn api_filter(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "EASY",
+            Difficulty::Medium => "MEDIUM",
+            Difficulty::Hard => "HARD",
+        }
+    }
+
+    /// As shown in `ProblemSummary::difficulty`.
+    pub fn label(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Medium => "Medium",
+            Difficulty::Hard => "Hard",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ListFormat {
+    Table,
+    Csv,
+    Json,
+}
+
+/// Screen to land on once the TUI is up.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum StartScreen {
+    #[default]
+    Home,
+    Problem(String),
+    Lists,
+}
+
+impl Cli {
+    pub fn start_screen(&self) -> StartScreen {
+        if let Some(ref slug) = self.open {
+            StartScreen::Problem(slug.trim().to_lowercase())
+        } else if self.lists {
+            StartScreen::Lists
+        } else {
+            StartScreen::Home
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start_screen(args: &[&str]) -> StartScreen {
+        Cli::try_parse_from([&["leetui"], args].concat())
+            .unwrap()
+            .start_screen()
+    }
+
+    #[test]
+    fn launch_flags_pick_the_start_screen() {
+        assert_eq!(start_screen(&[]), StartScreen::Home);
+        assert_eq!(
+            start_screen(&["--open", " Two-Sum "]),
+            StartScreen::Problem("two-sum".to_string())
+        );
+        assert_eq!(start_screen(&["--lists"]), StartScreen::Lists);
+        assert!(Cli::try_parse_from(["leetui", "--open", "two-sum", "--lists"]).is_err());
+    }
+}
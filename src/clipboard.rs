@@ -0,0 +1,51 @@
+use anyhow::{Result, bail};
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Copy text to the system clipboard.
+///
+/// Tries the platform clipboard tools first, then falls back to an OSC 52
+/// escape sequence, which most modern terminals (and tmux) understand.
+pub fn copy(text: &str) -> Result<()> {
+    let candidates: &[(&str, &[&str])] = &[
+        ("pbcopy", &[]),
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+        ("clip.exe", &[]),
+    ];
+
+    for (cmd, args) in candidates {
+        if pipe_to(cmd, args, text).is_ok() {
+            return Ok(());
+        }
+    }
+
+    osc52(text)
+}
+
+fn pipe_to(cmd: &str, args: &[&str], text: &str) -> Result<()> {
+    let mut child = Command::new(cmd)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("{cmd} exited with {status}");
+    }
+    Ok(())
+}
+
+fn osc52(text: &str) -> Result<()> {
+    let mut out = std::io::stdout();
+    write!(out, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+    out.flush()?;
+    Ok(())
+}
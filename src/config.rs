@@ -1,6 +1,81 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::api::client::{self, ClientOptions};
+use crate::api::site::{Region, Site, site};
+use crate::api::types::Category;
+use crate::credentials;
+use crate::local_test::LocalCommand;
+use crate::ui::rich_text::RenderOptions;
+use crate::ui::theme::{Theme, ThemeName};
+
+/// What to do once a problem has been scaffolded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AfterScaffold {
+    /// Open the solution file in `editor`.
+    #[default]
+    OpenEditor,
+    /// Print the project directory on exit (for the `lc` shell wrapper).
+    PrintPath,
+    /// Only show the created path.
+    Nothing,
+}
+
+impl AfterScaffold {
+    /// Carry out the behavior on `target`: `remember_dir` records the
+    /// project directory to print on exit, `open_editor` opens the solution.
+    pub fn apply<T>(
+        self,
+        target: &mut T,
+        remember_dir: impl FnOnce(&mut T),
+        open_editor: impl FnOnce(&mut T),
+    ) {
+        match self {
+            AfterScaffold::OpenEditor => {
+                remember_dir(target);
+                open_editor(target);
+            }
+            AfterScaffold::PrintPath => remember_dir(target),
+            AfterScaffold::Nothing => {}
+        }
+    }
+}
+
+/// Where run/submit verdicts are shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultView {
+    /// Switch to the full-screen result view.
+    #[default]
+    Screen,
+    /// Stay on the problem and show the verdict in a pane below it.
+    Inline,
+}
+
+/// How Rust problems are laid out in the workspace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RustLayout {
+    /// A standalone binary crate per problem.
+    #[default]
+    Project,
+    /// Library crates that are members of one cargo workspace.
+    Workspace,
+}
+
+/// Where the LeetCode cookies are kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialStore {
+    /// In plain text in `config.toml`.
+    #[default]
+    File,
+    /// In the OS keyring, falling back to `config.toml` when there is none.
+    Keyring,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -11,6 +86,149 @@ pub struct Config {
     pub leetcode_session: Option<String>,
     #[serde(default)]
     pub csrf_token: Option<String>,
+    #[serde(default)]
+    pub site: Region,
+    /// Overrides the site's base URL, e.g. for a mirror.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    /// Proxy URL for all requests, e.g. `socks5://127.0.0.1:1080`.
+    #[serde(default)]
+    pub proxy: Option<String>,
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+    /// Requests per second; 0 disables rate limiting.
+    #[serde(default = "default_rate_limit")]
+    pub rate_limit: f64,
+    #[serde(default)]
+    pub credential_store: CredentialStore,
+    #[serde(default)]
+    pub after_scaffold: AfterScaffold,
+    #[serde(default)]
+    pub rust_layout: RustLayout,
+    /// Languages each problem is also scaffolded in, besides `language`.
+    #[serde(default)]
+    pub extra_languages: Vec<String>,
+    /// Per-language overrides of the local test commands.
+    #[serde(default)]
+    pub local_test: BTreeMap<String, LocalCommand>,
+    #[serde(default = "default_true")]
+    pub show_stats: bool,
+    /// Show the full per-difficulty breakdown instead of compact gauges.
+    #[serde(default)]
+    pub stats_expanded: bool,
+    #[serde(default = "default_true")]
+    pub example_labels: bool,
+    /// Show statement images in the terminal where it can draw them.
+    #[serde(default = "default_true")]
+    pub inline_images: bool,
+    /// Make problem titles and statement links clickable where the terminal
+    /// supports OSC 8 hyperlinks.
+    #[serde(default = "default_true")]
+    pub hyperlinks: bool,
+    #[serde(default)]
+    pub max_title_width: Option<usize>,
+    #[serde(default = "default_true")]
+    pub practice_timer: bool,
+    #[serde(default)]
+    pub pomodoro: bool,
+    #[serde(default = "default_pomodoro_work_mins")]
+    pub pomodoro_work_mins: u64,
+    #[serde(default = "default_pomodoro_break_mins")]
+    pub pomodoro_break_mins: u64,
+    #[serde(default)]
+    pub category: Category,
+    #[serde(default)]
+    pub locale: Option<String>,
+    #[serde(default)]
+    pub result_view: ResultView,
+    #[serde(default = "default_autosave_secs")]
+    pub autosave_secs: u64,
+    /// GitHub token with the `gist` scope, for uploading solutions. Also
+    /// looked up in the OS keyring under `github_token` with the keyring
+    /// credential store.
+    #[serde(default)]
+    pub github_token: Option<String>,
+    #[serde(default)]
+    pub gist_on_accept: bool,
+    #[serde(default)]
+    pub theme: ThemeName,
+    /// Per-role color overrides on top of `theme`, e.g. `accent = "#ff8800"`.
+    #[serde(default)]
+    pub theme_colors: BTreeMap<String, String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_autosave_secs() -> u64 {
+    30
+}
+
+fn default_pomodoro_work_mins() -> u64 {
+    25
+}
+
+fn default_pomodoro_break_mins() -> u64 {
+    5
+}
+
+fn default_retries() -> u32 {
+    client::DEFAULT_RETRIES
+}
+
+fn default_rate_limit() -> f64 {
+    client::DEFAULT_RATE_LIMIT
+}
+
+/// Write via a temp file in the same directory and rename it over `path`,
+/// so a crash mid-write leaves the old file intact instead of a truncated one.
+pub fn write_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    std::fs::write(&tmp, contents)?;
+    std::fs::rename(&tmp, path)
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            workspace_dir: "~/leetcode".to_string(),
+            language: "rust".to_string(),
+            editor: "vim".to_string(),
+            leetcode_session: None,
+            csrf_token: None,
+            site: Region::default(),
+            base_url: None,
+            proxy: None,
+            retries: default_retries(),
+            rate_limit: default_rate_limit(),
+            credential_store: CredentialStore::default(),
+            after_scaffold: AfterScaffold::default(),
+            rust_layout: RustLayout::default(),
+            extra_languages: Vec::new(),
+            local_test: BTreeMap::new(),
+            show_stats: true,
+            stats_expanded: false,
+            example_labels: true,
+            inline_images: true,
+            hyperlinks: true,
+            max_title_width: None,
+            practice_timer: true,
+            pomodoro: false,
+            pomodoro_work_mins: default_pomodoro_work_mins(),
+            pomodoro_break_mins: default_pomodoro_break_mins(),
+            category: Category::default(),
+            locale: None,
+            result_view: ResultView::default(),
+            autosave_secs: default_autosave_secs(),
+            github_token: None,
+            gist_on_accept: false,
+            theme: ThemeName::default(),
+            theme_colors: BTreeMap::new(),
+        }
+    }
 }
 
 impl Config {
@@ -19,6 +237,24 @@ impl Config {
             && self.csrf_token.as_ref().is_some_and(|s| !s.is_empty())
     }
 
+    pub fn site(&self) -> Site {
+        Site::new(self.site, self.base_url.as_deref())
+    }
+
+    pub fn client_options(&self) -> ClientOptions {
+        ClientOptions {
+            proxy: self.proxy.clone(),
+            retries: self.retries,
+            rate_limit: self.rate_limit,
+        }
+    }
+
+    /// The configured theme with `theme_colors` applied, plus any overrides
+    /// that couldn't be parsed.
+    pub fn resolve_theme(&self) -> (Theme, Vec<String>) {
+        Theme::named(self.theme).with_overrides(&self.theme_colors)
+    }
+
     pub fn config_dir() -> PathBuf {
         dirs::home_dir()
             .expect("Could not find home directory")
@@ -29,8 +265,41 @@ impl Config {
         Self::config_dir().join("config.toml")
     }
 
-    pub fn cache_path() -> PathBuf {
-        Self::config_dir().join("problems.json")
+    /// The cached problem list of `category`. leetcode.cn has its own
+    /// (translated titles, its own statuses), e.g. `problems-cn.json`.
+    pub fn cache_path(category: Category) -> PathBuf {
+        let region = match site().region {
+            Region::Com => "",
+            Region::Cn => "-cn",
+        };
+        match category {
+            Category::All => Self::config_dir().join(format!("problems{region}.json")),
+            other => Self::config_dir().join(format!("problems-{}{region}.json", other.slug())),
+        }
+    }
+
+    /// Present once the keyring store has been used, so switching back to
+    /// the file knows to empty the keyring without probing it on every start.
+    fn keyring_marker_path() -> PathBuf {
+        Self::config_dir().join("keyring-used")
+    }
+
+    pub fn scratchpad_path() -> PathBuf {
+        Self::config_dir().join("scratchpad.json")
+    }
+
+    /// Per-language scaffold templates, e.g. `templates/rust.rs`.
+    pub fn templates_dir() -> PathBuf {
+        Self::config_dir().join("templates")
+    }
+
+    pub fn solve_times_path() -> PathBuf {
+        Self::config_dir().join("solve_times.json")
+    }
+
+    /// Every run and submission, for the history screen.
+    pub fn attempts_path() -> PathBuf {
+        Self::config_dir().join("attempts.json")
     }
 
     pub fn load() -> Result<Option<Config>> {
@@ -40,23 +309,91 @@ impl Config {
         }
         let contents = std::fs::read_to_string(&path)
             .with_context(|| format!("Failed to read config from {}", path.display()))?;
-        let config: Config =
+        let mut config: Config =
             toml::from_str(&contents).with_context(|| "Failed to parse config.toml")?;
+        let marker = Self::keyring_marker_path();
+        match config.credential_store {
+            CredentialStore::Keyring => {
+                if !marker.exists() {
+                    let _ = std::fs::write(&marker, "");
+                }
+                // Cookies still in the file (keyring unavailable when last saved) win
+                if !config.is_authenticated()
+                    && let Ok((session, csrf)) = credentials::load()
+                {
+                    config.leetcode_session = session.or(config.leetcode_session);
+                    config.csrf_token = csrf.or(config.csrf_token);
+                }
+            }
+            // Switched back to the file: move what the keyring holds there
+            CredentialStore::File if marker.exists() => {
+                if config.move_out_of_keyring().is_ok() {
+                    let _ = std::fs::remove_file(&marker);
+                }
+            }
+            CredentialStore::File => {}
+        }
         Ok(Some(config))
     }
 
+    /// Take the cookies (unless the file has its own) and the GitHub token
+    /// out of the keyring and save them in the file.
+    fn move_out_of_keyring(&mut self) -> Result<()> {
+        let (session, csrf) = credentials::load()?;
+        let github_token = credentials::github_token()?;
+        if session.is_none() && github_token.is_none() {
+            return Ok(());
+        }
+        if !self.is_authenticated() {
+            self.leetcode_session = session.or(self.leetcode_session.take());
+            self.csrf_token = csrf.or(self.csrf_token.take());
+        }
+        self.github_token = self.github_token.take().or(github_token);
+        self.save()?;
+        credentials::clear()
+    }
+
     pub fn save(&self) -> Result<()> {
         let dir = Self::config_dir();
         std::fs::create_dir_all(&dir)
             .with_context(|| format!("Failed to create config dir {}", dir.display()))?;
         let path = Self::config_path();
-        let contents =
-            toml::to_string_pretty(self).with_context(|| "Failed to serialize config")?;
-        std::fs::write(&path, contents)
+        let contents = match self.credential_store {
+            CredentialStore::Keyring => {
+                let mut on_disk = self.clone();
+                // Without a keyring the cookies stay in the file
+                if credentials::store(self.leetcode_session.as_deref(), self.csrf_token.as_deref())
+                    .is_ok()
+                {
+                    on_disk.leetcode_session = None;
+                    on_disk.csrf_token = None;
+                }
+                toml::to_string_pretty(&on_disk)
+            }
+            CredentialStore::File => toml::to_string_pretty(self),
+        }
+        .with_context(|| "Failed to serialize config")?;
+        write_atomic(&path, &contents)
             .with_context(|| format!("Failed to write config to {}", path.display()))?;
         Ok(())
     }
 
+    /// The GitHub token from the config, or else the keyring when that's
+    /// the credential store.
+    pub fn github_token(&self) -> Option<String> {
+        self.github_token.clone().filter(|t| !t.is_empty()).or_else(|| {
+            (self.credential_store == CredentialStore::Keyring)
+                .then(|| credentials::github_token().ok().flatten())
+                .flatten()
+        })
+    }
+
+    pub fn render_options(&self) -> RenderOptions {
+        RenderOptions {
+            example_labels: self.example_labels,
+        }
+    }
+
     pub fn expanded_workspace(&self) -> PathBuf {
         let expanded = if self.workspace_dir.starts_with('~') {
             let home = dirs::home_dir().expect("Could not find home directory");
@@ -67,3 +404,50 @@ impl Config {
         expanded
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn after_scaffold(behavior: AfterScaffold) -> Vec<&'static str> {
+        let mut effects = Vec::new();
+        behavior.apply(
+            &mut effects,
+            |effects| effects.push("remember dir"),
+            |effects| effects.push("open editor"),
+        );
+        effects
+    }
+
+    #[test]
+    fn after_scaffold_behaviors_trigger_their_effects() {
+        assert_eq!(
+            after_scaffold(AfterScaffold::OpenEditor),
+            ["remember dir", "open editor"]
+        );
+        assert_eq!(after_scaffold(AfterScaffold::PrintPath), ["remember dir"]);
+        assert!(after_scaffold(AfterScaffold::Nothing).is_empty());
+        assert_eq!(AfterScaffold::default(), AfterScaffold::OpenEditor);
+    }
+
+    #[test]
+    fn atomic_write_replaces_the_file_whole() {
+        let dir = std::env::temp_dir().join(format!("leetui-atomic-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("scratchpad.json");
+        std::fs::write(&path, "{\"stale\": tr").unwrap();
+
+        let saved = serde_json::json!({"title_slug": "two-sum", "code": "pass"});
+        write_atomic(&path, &serde_json::to_string_pretty(&saved).unwrap()).unwrap();
+        let read: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(read, saved);
+        // The temp file was renamed over the target, not left beside it
+        let names: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(names, ["scratchpad.json"]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::api::types::{Contest, ContestQuestion};
+
+/// Each wrong submission before an accept adds this to the finish time.
+pub const WRONG_ANSWER_PENALTY: Duration = Duration::from_secs(5 * 60);
+
+/// A past contest re-run on the clock. Submissions go through the regular
+/// problem endpoints; this only keeps the contest's score and timing.
+#[derive(Debug, Clone)]
+pub struct VirtualContest {
+    pub contest: Contest,
+    pub problems: Vec<ContestQuestion>,
+    started: Instant,
+    /// When each problem was accepted, from the start.
+    solved: HashMap<String, Duration>,
+    /// Rejected submissions per problem before it was accepted.
+    wrong: HashMap<String, u32>,
+    /// The end was announced, so it isn't announced again.
+    pub finish_reported: bool,
+}
+
+impl VirtualContest {
+    pub fn start(contest: Contest, problems: Vec<ContestQuestion>) -> Self {
+        Self {
+            contest,
+            problems,
+            started: Instant::now(),
+            solved: HashMap::new(),
+            wrong: HashMap::new(),
+            finish_reported: false,
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs(self.contest.duration.max(0) as u64)
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.duration().saturating_sub(self.started.elapsed())
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    pub fn includes(&self, slug: &str) -> bool {
+        self.problems.iter().any(|p| p.title_slug == slug)
+    }
+
+    pub fn solved_at(&self, slug: &str) -> Option<Duration> {
+        self.solved.get(slug).copied()
+    }
+
+    pub fn wrong_attempts(&self, slug: &str) -> u32 {
+        self.wrong.get(slug).copied().unwrap_or(0)
+    }
+
+    /// Count a verdict for `slug`. Ignored once the clock has run out or the
+    /// problem is already solved; returns whether it counted.
+    pub fn record(&mut self, slug: &str, accepted: bool) -> bool {
+        if self.is_over() || !self.includes(slug) || self.solved.contains_key(slug) {
+            return false;
+        }
+        if accepted {
+            self.solved.insert(slug.to_string(), self.started.elapsed());
+        } else {
+            *self.wrong.entry(slug.to_string()).or_insert(0) += 1;
+        }
+        true
+    }
+
+    /// Total credit of the accepted problems.
+    pub fn score(&self) -> i32 {
+        self.problems
+            .iter()
+            .filter(|p| self.solved.contains_key(&p.title_slug))
+            .map(|p| p.credit)
+            .sum()
+    }
+
+    /// LeetCode's finish time: the last accept, plus the penalty for wrong
+    /// submissions on solved problems.
+    pub fn finish_time(&self) -> Duration {
+        let last = self.solved.values().max().copied().unwrap_or_default();
+        let wrong: u32 = self
+            .solved
+            .keys()
+            .map(|slug| self.wrong_attempts(slug))
+            .sum();
+        last + WRONG_ANSWER_PENALTY * wrong
+    }
+
+    pub fn solved_count(&self) -> usize {
+        self.solved.len()
+    }
+}
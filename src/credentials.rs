@@ -0,0 +1,53 @@
+use anyhow::{Context, Result};
+
+/// Service name the cookies are filed under in the OS keyring.
+const SERVICE: &str = "leetui";
+const SESSION: &str = "leetcode_session";
+const CSRF: &str = "csrf_token";
+const GITHUB_TOKEN: &str = "github_token";
+
+fn entry(name: &str) -> Result<keyring::Entry> {
+    keyring::Entry::new(SERVICE, name).context("Failed to open the system keyring")
+}
+
+fn get(name: &str) -> Result<Option<String>> {
+    match entry(name)?.get_password() {
+        Ok(value) => Ok(Some(value)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(e).context("Failed to read from the system keyring"),
+    }
+}
+
+fn set(name: &str, value: Option<&str>) -> Result<()> {
+    let entry = entry(name)?;
+    let result = match value.filter(|v| !v.is_empty()) {
+        Some(value) => entry.set_password(value),
+        None => match entry.delete_credential() {
+            Err(keyring::Error::NoEntry) => Ok(()),
+            other => other,
+        },
+    };
+    result.context("Failed to write to the system keyring")
+}
+
+/// The session cookie and CSRF token stored in the keyring, if any.
+pub fn load() -> Result<(Option<String>, Option<String>)> {
+    Ok((get(SESSION)?, get(CSRF)?))
+}
+
+/// Store both cookies; `None` removes them.
+pub fn store(session: Option<&str>, csrf: Option<&str>) -> Result<()> {
+    set(SESSION, session)?;
+    set(CSRF, csrf)
+}
+
+/// GitHub token for gist uploads stored in the keyring, if any.
+pub fn github_token() -> Result<Option<String>> {
+    get(GITHUB_TOKEN)
+}
+
+/// Remove the cookies and the GitHub token from the keyring.
+pub fn clear() -> Result<()> {
+    store(None, None)?;
+    set(GITHUB_TOKEN, None)
+}
@@ -0,0 +1,114 @@
+use std::path::Path;
+use std::process::Command;
+
+/// Editors that open their own window, and how to talk to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Family {
+    /// VS Code and its forks: `--goto file:line`, `--wait`
+    VsCode,
+    /// Sublime Text and Zed: `file:line`, `--wait`
+    Sublime,
+    /// JetBrains IDEs: `--line N file`, `--wait`
+    JetBrains,
+    /// gvim/mvim: `+N file`, `-f` to stay in the foreground
+    GuiVim,
+    /// Anything else runs in the terminal: `+N file` for the editors known
+    /// to take it
+    Terminal { plus_line: bool, colon_line: bool },
+}
+
+impl Family {
+    fn of(program: &str) -> Self {
+        let name = Path::new(program)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or(program);
+        match name {
+            "code" | "code-insiders" | "codium" | "cursor" | "windsurf" => Family::VsCode,
+            "subl" | "sublime_text" | "zed" | "zeditor" => Family::Sublime,
+            "idea" | "clion" | "pycharm" | "goland" | "rustrover" | "webstorm" => {
+                Family::JetBrains
+            }
+            "gvim" | "mvim" => Family::GuiVim,
+            "vi" | "vim" | "nvim" | "nano" | "emacs" | "emacsclient" | "kak" => Family::Terminal {
+                plus_line: true,
+                colon_line: false,
+            },
+            "hx" | "helix" | "micro" => Family::Terminal {
+                plus_line: false,
+                colon_line: true,
+            },
+            _ => Family::Terminal {
+                plus_line: false,
+                colon_line: false,
+            },
+        }
+    }
+
+    fn wait_flag(self) -> Option<&'static str> {
+        match self {
+            Family::VsCode | Family::Sublime | Family::JetBrains => Some("--wait"),
+            Family::GuiVim => Some("-f"),
+            Family::Terminal { .. } => None,
+        }
+    }
+}
+
+/// A command line for the configured editor.
+pub struct Launch {
+    pub command: Command,
+    /// Runs in the terminal, which has to be handed over until it exits.
+    pub in_terminal: bool,
+    /// Blocks until the file is closed.
+    pub waits: bool,
+}
+
+/// Build the command opening `file` in `editor` (a program, possibly with
+/// arguments, like `code -n`), at `line` when known. GUI editors are told to
+/// wait for the file to close only when `wait` is set; they don't need the
+/// terminal either way.
+pub fn launch(editor: &str, file: &Path, line: Option<usize>, wait: bool) -> Launch {
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or("vim");
+    let extra: Vec<&str> = words.collect();
+    let family = Family::of(program);
+
+    let mut command = Command::new(program);
+    command.args(&extra);
+    let already_waits = family
+        .wait_flag()
+        .is_some_and(|flag| extra.contains(&flag) || (flag == "--wait" && extra.contains(&"-w")));
+    if wait
+        && !already_waits
+        && let Some(flag) = family.wait_flag()
+    {
+        command.arg(flag);
+    }
+
+    let file = file.display().to_string();
+    match (family, line) {
+        (Family::VsCode, Some(line)) => {
+            command.args(["--goto", &format!("{file}:{line}")]);
+        }
+        (Family::Sublime, Some(line))
+        | (Family::Terminal { colon_line: true, .. }, Some(line)) => {
+            command.arg(format!("{file}:{line}"));
+        }
+        (Family::JetBrains, Some(line)) => {
+            command.args(["--line", &line.to_string(), &file]);
+        }
+        (Family::GuiVim, Some(line)) | (Family::Terminal { plus_line: true, .. }, Some(line)) => {
+            command.args([format!("+{line}"), file]);
+        }
+        _ => {
+            command.arg(file);
+        }
+    }
+
+    let in_terminal = matches!(family, Family::Terminal { .. });
+    Launch {
+        command,
+        in_terminal,
+        waits: in_terminal || wait || already_waits,
+    }
+}
@@ -9,6 +9,7 @@ pub enum Event {
     Key(KeyEvent),
     Tick,
     Resize(u16, u16),
+    Focus(bool),
 }
 
 pub struct EventHandler {
@@ -46,6 +47,12 @@ impl EventHandler {
                                     break;
                                 }
                             }
+                            CrosstermEvent::FocusGained | CrosstermEvent::FocusLost => {
+                                let gained = matches!(evt, CrosstermEvent::FocusGained);
+                                if tx.send(Event::Focus(gained)).is_err() {
+                                    break;
+                                }
+                            }
                             _ => {}
                         }
                     }
@@ -0,0 +1,59 @@
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::api::client::{ClientOptions, client_builder};
+use crate::api::throttle::{SendExt, Throttle};
+
+const GISTS_API: &str = "https://api.github.com/gists";
+
+#[derive(Debug, Deserialize)]
+struct GistResponse {
+    html_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubError {
+    message: String,
+}
+
+/// Upload `content` as a secret gist holding one file, returning its URL.
+/// Goes through the proxy and rate limit in `options`.
+pub async fn upload(
+    options: &ClientOptions,
+    token: &str,
+    filename: &str,
+    description: &str,
+    content: &str,
+) -> Result<String> {
+    let body = json!({
+        "description": description,
+        "public": false,
+        "files": { filename: { "content": content } }
+    });
+
+    let client = client_builder(options)?
+        .build()
+        .context("Failed to create HTTP client")?;
+    let throttle = Throttle::new(options.rate_limit, options.retries);
+    let resp = client
+        .post(GISTS_API)
+        .bearer_auth(token)
+        .header("User-Agent", concat!("leetui/", env!("CARGO_PKG_VERSION")))
+        .header("Accept", "application/vnd.github+json")
+        .json(&body)
+        .send_throttled(&throttle)
+        .await
+        .context("Failed to send gist request")?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let message = resp
+            .json::<GitHubError>()
+            .await
+            .map_or_else(|_| status.to_string(), |e| e.message);
+        bail!("GitHub rejected the gist: {message}");
+    }
+    let gist: GistResponse = resp.json().await.context("Failed to parse gist response")?;
+    Ok(gist.html_url)
+}
@@ -0,0 +1,400 @@
+use anyhow::{Context, Result, bail};
+use std::io::IsTerminal;
+use std::path::Path;
+
+use crate::anki;
+use crate::api::client::LeetCodeClient;
+use crate::api::site::site;
+use crate::api::types::{Category, CheckResponse, QuestionDetail};
+use crate::app::{LIST_MUTATION_SPACING, load_cached_problems};
+use crate::archive;
+use crate::attempts::{Attempt, AttemptKind, Attempts};
+use crate::cli::{Command, Difficulty, ListFormat};
+use crate::config::Config;
+use crate::list_io::{self, ImportReport, ImportTarget};
+use crate::scaffold;
+use crate::ui::ansi::line_to_ansi;
+use crate::ui::result::{ResultData, ResultKind, build_result_lines};
+use crate::ui::rich_text::html_to_lines;
+
+/// Run a CLI subcommand without the TUI. Returns whether it succeeded, so
+/// `test`/`submit` can exit non-zero on a failing verdict.
+pub async fn run(command: Command, config: Option<Config>) -> Result<bool> {
+    let client = LeetCodeClient::new(
+        config.as_ref().and_then(|c| c.leetcode_session.as_deref()),
+        config.as_ref().and_then(|c| c.csrf_token.as_deref()),
+        &config.as_ref().map(Config::client_options).unwrap_or_default(),
+    )?;
+    let config = config.unwrap_or_default();
+
+    match command {
+        Command::Show { problem } => {
+            let detail = fetch_detail(&client, &config, &problem).await?;
+            show(&detail, &config);
+            Ok(true)
+        }
+        Command::Test {
+            file,
+            problem,
+            lang,
+            input,
+        } => {
+            let solution =
+                load_solution(&client, &config, &file, problem.as_deref(), lang.as_deref()).await?;
+            let Solution { detail, code, lang } = solution;
+            let input = input.unwrap_or_else(|| detail.sample_input());
+            let id = client
+                .run_code(&detail.title_slug, &detail.question_id, lang, &code, &input)
+                .await?;
+            let resp = client.poll_result(&id).await?;
+            record(&detail, AttemptKind::Run, &resp, lang, &config);
+            print_result(&resp, ResultKind::Run);
+            Ok(resp.status_code == Some(10) && resp.correct_answer != Some(false))
+        }
+        Command::Submit {
+            file,
+            problem,
+            lang,
+        } => {
+            let solution =
+                load_solution(&client, &config, &file, problem.as_deref(), lang.as_deref()).await?;
+            let Solution { detail, code, lang } = solution;
+            eprintln!(
+                "Submitting {} as {lang} to {}. {}",
+                file.display(),
+                detail.frontend_question_id,
+                detail.title
+            );
+            let id = client
+                .submit_code(&detail.title_slug, &detail.question_id, lang, &code)
+                .await?;
+            let resp = client.poll_result(&id).await?;
+            record(&detail, AttemptKind::Submit, &resp, lang, &config);
+            print_result(&resp, ResultKind::Submit);
+            Ok(resp.status_code == Some(10))
+        }
+        Command::List {
+            difficulty,
+            search,
+            limit,
+            format,
+        } => {
+            list(&client, &config, difficulty, search.as_deref(), limit, format).await?;
+            Ok(true)
+        }
+        Command::Export { out } => {
+            let report = archive::export(&client, &config, &out).await?;
+            for skipped in &report.skipped {
+                eprintln!("Skipped {skipped}");
+            }
+            println!("Exported {} solutions to {}", report.exported, out.display());
+            Ok(true)
+        }
+        Command::Import { file, list } => {
+            let report = import(&client, &config, &file, list.as_deref()).await?;
+            for title in &report.unresolved {
+                eprintln!("Not found: {title}");
+            }
+            for (title, e) in &report.failed {
+                eprintln!("Failed {title}: {e}");
+            }
+            println!("Imported {} problems into \"{}\"", report.added, report.name);
+            Ok(report.is_clean())
+        }
+        Command::Anki { list, out } => {
+            let report = anki::export(&client, &config, list.as_deref(), &out).await?;
+            for failed in &report.failed {
+                eprintln!("Failed {failed}");
+            }
+            println!("Wrote {} cards to {}", report.cards, out.display());
+            Ok(report.failed.is_empty())
+        }
+    }
+}
+
+async fn import(
+    client: &LeetCodeClient,
+    config: &Config,
+    file: &Path,
+    list: Option<&str>,
+) -> Result<ImportReport> {
+    if !config.is_authenticated() {
+        bail!("Not logged in. Start leetui and log in first (press S for settings).");
+    }
+    let text = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let (name, entries) = list_io::parse_import(&text)?;
+    let target = match list {
+        Some(wanted) => {
+            let lists = client.fetch_favorites().await?;
+            let list = lists
+                .into_iter()
+                .find(|l| l.name.eq_ignore_ascii_case(wanted))
+                .with_context(|| format!("No list named \"{wanted}\""))?;
+            ImportTarget::Existing {
+                id_hash: list.id_hash,
+                name: list.name,
+            }
+        }
+        None => ImportTarget::New(name.unwrap_or_else(|| {
+            file.file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "Imported".to_string())
+        })),
+    };
+
+    let problems = match load_cached_problems(Category::All) {
+        Some(problems) => problems,
+        None => client.fetch_problems(Category::All, 5000, 0, None, None).await?.0,
+    };
+    let plan = list_io::plan_import(&entries, &problems);
+    if plan.questions.is_empty() {
+        bail!("No problems in {} matched the problem set", file.display());
+    }
+    list_io::run_import(client, target, plan, LIST_MUTATION_SPACING, |done, total| {
+        eprint!("\rAdding {done}/{total}");
+        if done == total {
+            eprintln!();
+        }
+    })
+    .await
+}
+
+fn use_color() -> bool {
+    std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+}
+
+/// Accepts a problem number or a slug.
+async fn resolve_slug(client: &LeetCodeClient, problem: &str) -> Result<String> {
+    let problem = problem.trim();
+    if !problem.chars().all(|c| c.is_ascii_digit()) {
+        return Ok(problem.to_lowercase());
+    }
+    if let Some(p) = load_cached_problems(Category::All)
+        .unwrap_or_default()
+        .into_iter()
+        .find(|p| p.frontend_question_id == problem)
+    {
+        return Ok(p.title_slug);
+    }
+    let (found, _) = client
+        .fetch_problems(Category::All, 20, 0, None, Some(problem))
+        .await?;
+    found
+        .into_iter()
+        .find(|p| p.frontend_question_id == problem)
+        .map(|p| p.title_slug)
+        .with_context(|| format!("No problem number {problem}"))
+}
+
+async fn fetch_detail(
+    client: &LeetCodeClient,
+    config: &Config,
+    problem: &str,
+) -> Result<QuestionDetail> {
+    let slug = resolve_slug(client, problem).await?;
+    let mut detail = client.fetch_problem_detail(&slug).await?;
+    detail.localize(config.locale.as_deref());
+    Ok(detail)
+}
+
+/// A solution file ready to send.
+struct Solution {
+    detail: QuestionDetail,
+    code: String,
+    /// LeetCode language slug
+    lang: &'static str,
+}
+
+/// The problem a solution file belongs to, its language and the code to
+/// send for it. The problem comes from `problem`, else the link the
+/// scaffolder writes in the header, else the `<id>-<slug>` directory; the
+/// language from `lang`, else the extension, else the config.
+async fn load_solution(
+    client: &LeetCodeClient,
+    config: &Config,
+    file: &Path,
+    problem: Option<&str>,
+    lang: Option<&str>,
+) -> Result<Solution> {
+    if !config.is_authenticated() {
+        bail!("Not logged in. Start leetui and log in first (press S for settings).");
+    }
+    let content = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let problem = match problem {
+        Some(p) => p.to_string(),
+        None => slug_from_header(&content)
+            .or_else(|| slug_from_path(file))
+            .with_context(|| {
+                format!(
+                    "Can't tell which problem {} is for; pass --problem",
+                    file.display()
+                )
+            })?,
+    };
+    let language = lang
+        .or_else(|| language_from_path(file))
+        .unwrap_or(&config.language);
+    let detail = fetch_detail(client, config, &problem).await?;
+    if !detail.has_question_id() {
+        bail!("LeetCode didn't return an id for this problem");
+    }
+    let code = scaffold::submittable_code(&content, language)?;
+    Ok(Solution {
+        detail,
+        code,
+        lang: scaffold::lang_slug(language),
+    })
+}
+
+/// Slug from a problem link in the comments at the top of the file, as
+/// the scaffolder and the `{{url}}` template placeholder write it.
+fn slug_from_header(content: &str) -> Option<String> {
+    content.lines().take(20).find_map(|line| {
+        let (_, rest) = line.split_once("/problems/")?;
+        let slug: String = rest
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+            .collect();
+        (!slug.is_empty()).then_some(slug)
+    })
+}
+
+/// Language of a solution file, from its extension.
+fn language_from_path(file: &Path) -> Option<&'static str> {
+    Some(match file.extension()?.to_str()? {
+        "rs" => "rust",
+        "py" => "python3",
+        "cpp" | "cc" | "cxx" => "cpp",
+        "java" => "java",
+        "js" => "javascript",
+        "ts" => "typescript",
+        "go" => "go",
+        "c" => "c",
+        "cs" => "csharp",
+        "kt" => "kotlin",
+        "swift" => "swift",
+        "rb" => "ruby",
+        "scala" => "scala",
+        "php" => "php",
+        "dart" => "dart",
+        "rkt" => "racket",
+        "erl" => "erlang",
+        "ex" | "exs" => "elixir",
+        _ => return None,
+    })
+}
+
+/// Slug from the nearest `<id>-<slug>` directory above a scaffolded file.
+fn slug_from_path(file: &Path) -> Option<String> {
+    let file = std::fs::canonicalize(file).unwrap_or_else(|_| file.to_path_buf());
+    file.ancestors().skip(1).find_map(|dir| {
+        let name = dir.file_name()?.to_str()?;
+        let (id, slug) = name.split_once('-')?;
+        (!id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) && !slug.is_empty())
+            .then(|| slug.to_string())
+    })
+}
+
+fn show(detail: &QuestionDetail, config: &Config) {
+    let color = use_color();
+    println!(
+        "{}. {} [{}]",
+        detail.frontend_question_id, detail.title, detail.difficulty
+    );
+    println!("{}", site().problem_url(&detail.title_slug));
+    println!();
+    match detail.content.as_deref() {
+        Some(html) => {
+            for line in html_to_lines(html, config.render_options()) {
+                println!("{}", line_to_ansi(&line, color));
+            }
+        }
+        None => println!("(no description; the problem may be premium-only)"),
+    }
+}
+
+/// Add the verdict to the local history the TUI shows.
+fn record(
+    detail: &QuestionDetail,
+    kind: AttemptKind,
+    resp: &CheckResponse,
+    lang: &str,
+    config: &Config,
+) {
+    let attempt = Attempt::new(detail, kind, resp, lang, &config.expanded_workspace());
+    Attempts::load().record(attempt);
+}
+
+fn print_result(resp: &CheckResponse, kind: ResultKind) {
+    let color = use_color();
+    let data = ResultData::from_check(resp);
+    for line in build_result_lines(&data, kind, true) {
+        println!("{}", line_to_ansi(&line, color));
+    }
+}
+
+async fn list(
+    client: &LeetCodeClient,
+    config: &Config,
+    difficulty: Option<Difficulty>,
+    search: Option<&str>,
+    limit: usize,
+    format: ListFormat,
+) -> Result<()> {
+    let problems = match client
+        .fetch_problems(
+            config.category,
+            limit as i32,
+            0,
+            difficulty.map(Difficulty::api_filter),
+            search,
+        )
+        .await
+    {
+        Ok((problems, _)) => problems,
+        Err(e) => {
+            // Offline: filter the cached list the same way
+            let cached = load_cached_problems(config.category).ok_or(e)?;
+            eprintln!("LeetCode unreachable, showing the cached problem list");
+            let search = search.map(str::to_lowercase);
+            cached
+                .into_iter()
+                .filter(|p| difficulty.is_none_or(|d| p.difficulty == d.label()))
+                .filter(|p| {
+                    search
+                        .as_ref()
+                        .is_none_or(|q| p.title.to_lowercase().contains(q.as_str()))
+                })
+                .take(limit)
+                .collect()
+        }
+    };
+
+    match format {
+        ListFormat::Table => {}
+        ListFormat::Csv => {
+            print!("{}", list_io::problems_csv(&problems));
+            return Ok(());
+        }
+        ListFormat::Json => {
+            println!("{}", list_io::problems_json(&problems)?);
+            return Ok(());
+        }
+    }
+
+    for p in problems {
+        let status = match p.status.as_deref() {
+            Some("ac") => "\u{2714}",
+            Some("notac") => "\u{25cf}",
+            _ => " ",
+        };
+        let paid = if p.is_paid_only { " \u{1f512}" } else { "" };
+        println!(
+            "{status} {:>5}  {:<6}  {:>5.1}%  {}{paid}",
+            p.frontend_question_id, p.difficulty, p.ac_rate, p.title
+        );
+    }
+    Ok(())
+}
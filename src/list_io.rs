@@ -0,0 +1,473 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::api::client::LeetCodeClient;
+use crate::api::site::site;
+use crate::api::types::{FavoriteList, ProblemSummary};
+
+/// On-disk form of an exported favorite list, for backup and re-import.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListExport {
+    pub name: String,
+    pub questions: Vec<ExportedQuestion>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportedQuestion {
+    pub question_id: String,
+    pub title: String,
+    pub title_slug: String,
+}
+
+pub fn export_json(list: &FavoriteList) -> Result<String> {
+    let export = ListExport {
+        name: list.name.clone(),
+        questions: list
+            .questions
+            .iter()
+            .map(|q| ExportedQuestion {
+                question_id: q.question_id.clone(),
+                title: q.title.clone(),
+                title_slug: q.title_slug.clone(),
+            })
+            .collect(),
+    };
+    serde_json::to_string_pretty(&export).context("Failed to serialize list")
+}
+
+/// Public link to a list. Private lists can't be shared, so they get none.
+pub fn share_url(list: &FavoriteList) -> Option<String> {
+    list.is_public_favorite
+        .then(|| site().url(&format!("/problem-list/{}/", list.id_hash)))
+}
+
+/// Write `list` as JSON into `<workspace>/lists/`, returning the file path.
+pub fn write_export(workspace: &Path, list: &FavoriteList) -> Result<PathBuf> {
+    let dir = workspace.join("lists");
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create dir {}", dir.display()))?;
+    let path = dir.join(format!("{}.json", file_stem(&list.name)));
+    std::fs::write(&path, export_json(list)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+/// Filesystem-safe version of a list name.
+fn file_stem(name: &str) -> String {
+    let stem: String = name
+        .trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { '-' })
+        .collect();
+    let stem = stem.trim_matches('-').to_string();
+    if stem.is_empty() { "list".to_string() } else { stem }
+}
+
+/// One row of a problem set export.
+#[derive(Debug, Serialize)]
+pub struct ExportedProblem<'a> {
+    pub id: &'a str,
+    pub title: &'a str,
+    pub slug: &'a str,
+    pub difficulty: &'a str,
+    /// "solved", "attempted" or "".
+    pub status: &'static str,
+    pub ac_rate: f64,
+    pub tags: Vec<&'a str>,
+}
+
+impl<'a> ExportedProblem<'a> {
+    pub fn new(p: &'a ProblemSummary) -> Self {
+        Self {
+            id: &p.frontend_question_id,
+            title: &p.title,
+            slug: &p.title_slug,
+            difficulty: &p.difficulty,
+            status: match p.status.as_deref() {
+                Some("ac") => "solved",
+                Some("notac") => "attempted",
+                _ => "",
+            },
+            ac_rate: p.ac_rate,
+            tags: p.topic_tags.iter().map(|t| t.name.as_str()).collect(),
+        }
+    }
+}
+
+pub fn problems_json<'a>(problems: impl IntoIterator<Item = &'a ProblemSummary>) -> Result<String> {
+    let rows: Vec<ExportedProblem> = problems.into_iter().map(ExportedProblem::new).collect();
+    serde_json::to_string_pretty(&rows).context("Failed to serialize problems")
+}
+
+/// CSV with a header row; tags are joined with `;`.
+pub fn problems_csv<'a>(problems: impl IntoIterator<Item = &'a ProblemSummary>) -> String {
+    fn cell(s: &str) -> String {
+        if s.contains([',', '"', '\n']) {
+            format!("\"{}\"", s.replace('"', "\"\""))
+        } else {
+            s.to_string()
+        }
+    }
+    let mut out = String::from("id,title,slug,difficulty,status,ac_rate,tags\n");
+    for p in problems.into_iter().map(ExportedProblem::new) {
+        out.push_str(&format!(
+            "{},{},{},{},{},{:.1},{}\n",
+            cell(p.id),
+            cell(p.title),
+            cell(p.slug),
+            p.difficulty,
+            p.status,
+            p.ac_rate,
+            cell(&p.tags.join(";"))
+        ));
+    }
+    out
+}
+
+/// Write `problems` as both CSV and JSON into `<workspace>/exports/`,
+/// returning the CSV path (the JSON file sits next to it).
+pub fn write_problem_export(workspace: &Path, problems: &[&ProblemSummary]) -> Result<PathBuf> {
+    let dir = workspace.join("exports");
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create dir {}", dir.display()))?;
+    let csv = dir.join("problems.csv");
+    std::fs::write(&csv, problems_csv(problems.iter().copied()))
+        .with_context(|| format!("Failed to write {}", csv.display()))?;
+    let json = dir.join("problems.json");
+    std::fs::write(&json, problems_json(problems.iter().copied())?)
+        .with_context(|| format!("Failed to write {}", json.display()))?;
+    Ok(csv)
+}
+
+/// A problem reference read from an import file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportEntry {
+    /// Frontend id, as shown in the problem table ("1", "146", ...)
+    Id(String),
+    Slug(String),
+}
+
+impl ImportEntry {
+    fn parse(token: &str) -> Option<Self> {
+        let token = token.trim().trim_matches('"').trim();
+        if token.is_empty() {
+            return None;
+        }
+        if token.chars().all(|c| c.is_ascii_digit()) {
+            Some(ImportEntry::Id(token.to_string()))
+        } else {
+            Some(ImportEntry::Slug(token.to_lowercase()))
+        }
+    }
+
+    fn label(&self) -> &str {
+        match self {
+            ImportEntry::Id(s) | ImportEntry::Slug(s) => s,
+        }
+    }
+}
+
+/// Read an import file: our JSON export, a JSON array of ids/slugs, or a
+/// CSV/plain list with ids or slugs in its cells. Returns the list name
+/// stored in the file, if any, and the entries in order.
+pub fn parse_import(text: &str) -> Result<(Option<String>, Vec<ImportEntry>)> {
+    let trimmed = text.trim_start();
+    if trimmed.starts_with('{') {
+        let export: ListExport = serde_json::from_str(text).context("Invalid list JSON")?;
+        let entries = export
+            .questions
+            .iter()
+            .filter_map(|q| ImportEntry::parse(&q.title_slug))
+            .collect();
+        return Ok((Some(export.name), entries));
+    }
+    if trimmed.starts_with('[') {
+        let items: Vec<serde_json::Value> =
+            serde_json::from_str(text).context("Invalid JSON array")?;
+        let entries = items
+            .iter()
+            .filter_map(|v| match v {
+                serde_json::Value::Number(n) => ImportEntry::parse(&n.to_string()),
+                serde_json::Value::String(s) => ImportEntry::parse(s),
+                _ => None,
+            })
+            .collect();
+        return Ok((None, entries));
+    }
+
+    // CSV or one entry per line: read the slug or id column named in a
+    // header row, otherwise the first column
+    const HEADERS: [&str; 5] = ["slug", "title_slug", "frontend_id", "id", "question_id"];
+    let mut lines = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .peekable();
+    let header = lines.peek().map(|line| csv_cells(line)).unwrap_or_default();
+    let column = HEADERS.iter().find_map(|name| {
+        header
+            .iter()
+            .position(|cell| cell.trim().eq_ignore_ascii_case(name))
+    });
+    if column.is_some() {
+        lines.next();
+    }
+    let column = column.unwrap_or(0);
+    let entries = lines
+        .filter_map(|line| {
+            csv_cells(line)
+                .get(column)
+                .and_then(|cell| ImportEntry::parse(cell))
+        })
+        .collect();
+    Ok((None, entries))
+}
+
+/// Cells of a CSV row; quoted cells may contain commas and `""` escapes.
+fn csv_cells(line: &str) -> Vec<String> {
+    let mut cells = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                cells.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => cells.push(String::new()),
+            _ => cells.last_mut().unwrap().push(c),
+        }
+    }
+    cells
+}
+
+/// Import entries resolved against the loaded problem set.
+#[derive(Debug, Default)]
+pub struct ImportPlan {
+    /// (frontend question id, title) of each problem to add, deduplicated
+    pub questions: Vec<(String, String)>,
+    pub unresolved: Vec<String>,
+}
+
+pub fn plan_import(entries: &[ImportEntry], problems: &[ProblemSummary]) -> ImportPlan {
+    let mut plan = ImportPlan::default();
+    for entry in entries {
+        let found = problems.iter().find(|p| match entry {
+            ImportEntry::Id(id) => &p.frontend_question_id == id,
+            ImportEntry::Slug(slug) => &p.title_slug == slug,
+        });
+        match found {
+            Some(p) => {
+                if !plan.questions.iter().any(|(id, _)| id == &p.frontend_question_id) {
+                    plan.questions.push((p.frontend_question_id.clone(), p.title.clone()));
+                }
+            }
+            None => plan.unresolved.push(entry.label().to_string()),
+        }
+    }
+    plan
+}
+
+/// Outcome of an import, for reporting partial success.
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    pub name: String,
+    pub added: usize,
+    pub failed: Vec<(String, String)>, // (title, error)
+    pub unresolved: Vec<String>,
+}
+
+impl ImportReport {
+    pub fn summary(&self) -> String {
+        let mut msg = format!("Imported {} problems into \"{}\"", self.added, self.name);
+        if !self.unresolved.is_empty() {
+            msg.push_str(&format!("\n\nNot found: {}", self.unresolved.join(", ")));
+        }
+        if !self.failed.is_empty() {
+            let failures: Vec<String> =
+                self.failed.iter().map(|(t, e)| format!("{t}: {e}")).collect();
+            msg.push_str(&format!("\n\nFailed:\n{}", failures.join("\n")));
+        }
+        msg
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.failed.is_empty() && self.unresolved.is_empty()
+    }
+}
+
+/// The list an import adds its problems to.
+#[derive(Debug, Clone)]
+pub enum ImportTarget {
+    /// Create a list with this name first
+    New(String),
+    Existing { id_hash: String, name: String },
+}
+
+/// Add each planned problem to `target`, spacing the writes like the
+/// mutation queue does. `progress` is called with (done, total) after each
+/// problem.
+pub async fn run_import(
+    client: &LeetCodeClient,
+    target: ImportTarget,
+    plan: ImportPlan,
+    spacing: Duration,
+    progress: impl Fn(usize, usize),
+) -> Result<ImportReport> {
+    let (id_hash, name) = match target {
+        ImportTarget::Existing { id_hash, name } => (id_hash, name),
+        ImportTarget::New(name) => {
+            let id_hash = client.create_favorite_list(&name).await?.with_context(|| {
+                format!("Created list \"{name}\" but LeetCode didn't return its id")
+            })?;
+            (id_hash, name)
+        }
+    };
+
+    let total = plan.questions.len();
+    let mut report = ImportReport {
+        name,
+        unresolved: plan.unresolved,
+        ..Default::default()
+    };
+    for (i, (question_id, title)) in plan.questions.into_iter().enumerate() {
+        if i > 0 {
+            tokio::time::sleep(spacing).await;
+        }
+        match client.add_to_favorite(&id_hash, &question_id).await {
+            Ok(()) => report.added += 1,
+            Err(e) => report.failed.push((title, format!("{e}"))),
+        }
+        progress(i + 1, total);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::types::FavoriteQuestion;
+
+    fn list(public: bool) -> FavoriteList {
+        FavoriteList {
+            id_hash: "abc123".to_string(),
+            name: "Blind 75".to_string(),
+            description: None,
+            view_count: 0,
+            creator: "me".to_string(),
+            is_watched: false,
+            is_public_favorite: public,
+            questions: vec![FavoriteQuestion {
+                question_id: "1".to_string(),
+                status: Some("ac".to_string()),
+                title: "Two Sum".to_string(),
+                title_slug: "two-sum".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn json_export_keeps_the_name_and_questions() {
+        let json = export_json(&list(false)).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "name": "Blind 75",
+                "questions": [
+                    { "question_id": "1", "title": "Two Sum", "title_slug": "two-sum" }
+                ],
+            })
+        );
+        // Round-trips through import
+        let (name, entries) = parse_import(&json).unwrap();
+        assert_eq!(name.as_deref(), Some("Blind 75"));
+        assert_eq!(entries, [ImportEntry::Slug("two-sum".to_string())]);
+        assert_eq!(file_stem("Blind 75!"), "blind-75");
+    }
+
+    #[test]
+    fn only_public_lists_get_a_share_url() {
+        assert_eq!(
+            share_url(&list(true)).as_deref(),
+            Some("https://leetcode.com/problem-list/abc123/")
+        );
+        assert_eq!(share_url(&list(false)), None);
+    }
+
+    fn summary(id: &str, slug: &str) -> ProblemSummary {
+        ProblemSummary {
+            frontend_question_id: id.to_string(),
+            title: format!("Problem {id}"),
+            title_slug: slug.to_string(),
+            difficulty: "Easy".to_string(),
+            status: None,
+            ac_rate: 50.0,
+            is_paid_only: false,
+            topic_tags: Vec::new(),
+        }
+    }
+
+    fn entries(text: &str) -> Vec<ImportEntry> {
+        parse_import(text).unwrap().1
+    }
+
+    #[test]
+    fn mixed_ids_and_slugs_resolve_to_add_operations() {
+        let id = |s: &str| ImportEntry::Id(s.to_string());
+        let slug = |s: &str| ImportEntry::Slug(s.to_string());
+
+        let plain = entries("1\nTwo-Sum\n\n146\nno-such-problem\n");
+        assert_eq!(
+            plain,
+            [id("1"), slug("two-sum"), id("146"), slug("no-such-problem")]
+        );
+        assert_eq!(
+            entries(r#"[1, "lru-cache", null]"#),
+            [id("1"), slug("lru-cache")]
+        );
+
+        let problems = [summary("1", "two-sum"), summary("146", "lru-cache")];
+        let plan = plan_import(&plain, &problems);
+        // Two Sum is listed twice but added once
+        assert_eq!(
+            plan.questions,
+            [
+                ("1".to_string(), "Problem 1".to_string()),
+                ("146".to_string(), "Problem 146".to_string()),
+            ]
+        );
+        assert_eq!(plan.unresolved, ["no-such-problem"]);
+    }
+
+    #[test]
+    fn csv_reads_only_the_slug_or_id_column() {
+        // Our own problem export: titles and tags are not entries
+        let csv = "id,title,slug,difficulty,status,ac_rate,tags\n\
+                   50,\"Pow(x, n)\",powx-n,Medium,,37.0,Math;Recursion\n\
+                   1,Two Sum,two-sum,Easy,solved,55.1,Array\n";
+        assert_eq!(
+            entries(csv),
+            [
+                ImportEntry::Slug("powx-n".to_string()),
+                ImportEntry::Slug("two-sum".to_string())
+            ]
+        );
+
+        // Without a known header, the first column
+        let csv = "1,Two Sum,Easy\nlru-cache,LRU Cache,Medium\n";
+        assert_eq!(
+            entries(csv),
+            [
+                ImportEntry::Id("1".to_string()),
+                ImportEntry::Slug("lru-cache".to_string())
+            ]
+        );
+        assert_eq!(
+            entries("Frontend_ID,Notes\n70,\"climb, stairs\"\n"),
+            [ImportEntry::Id("70".to_string())]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::api::types::QuestionDetail;
+use crate::config::Config;
+use crate::scaffold;
+
+/// A case that runs longer than this is killed and reported as timed out.
+pub const CASE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How to build and run a solution locally. Both run through `sh -c` in the
+/// problem directory; each test case is fed to `run` on stdin.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocalCommand {
+    #[serde(default)]
+    pub build: Option<String>,
+    pub run: String,
+}
+
+impl LocalCommand {
+    fn new(build: Option<&str>, run: &str) -> Self {
+        Self {
+            build: build.map(String::from),
+            run: run.to_string(),
+        }
+    }
+
+    /// Built-in commands for files laid out by the scaffolder. With a
+    /// `harness` (see [`scaffold::harness`]) that's what gets run. None for
+    /// Rust, whose scaffold has no `main` reading stdin.
+    pub fn default_for(lang_slug: &str, harness: bool) -> Option<Self> {
+        Some(match lang_slug {
+            "python3" if harness => Self::new(None, "python3 main.py"),
+            "cpp" if harness => Self::new(
+                Some("g++ -std=c++17 -O2 -o solution main.cpp"),
+                "./solution",
+            ),
+            "java" if harness => Self::new(Some("javac Main.java Solution.java"), "java Main"),
+            "golang" if harness => Self::new(
+                Some("go build -o solution main.go solution.go"),
+                "./solution",
+            ),
+            "python3" => Self::new(None, "python3 solution.py"),
+            "cpp" => Self::new(
+                Some("g++ -std=c++17 -O2 -o solution solution.cpp"),
+                "./solution",
+            ),
+            "java" => Self::new(Some("javac Solution.java"), "java Solution"),
+            "javascript" => Self::new(None, "node solution.js"),
+            "typescript" => Self::new(None, "npx --yes tsx solution.ts"),
+            "golang" => Self::new(Some("go build -o solution solution.go"), "./solution"),
+            _ => return None,
+        })
+    }
+
+    /// The configured command for the current language, falling back to the
+    /// built-in one for the project in `dir`.
+    pub fn for_config(config: &Config, dir: &Path) -> Option<Self> {
+        config.local_test.get(&config.language).cloned().or_else(|| {
+            let harness = scaffold::harness::file_name(&config.language)
+                .is_some_and(|name| dir.join(name).exists());
+            Self::default_for(scaffold::lang_slug(&config.language), harness)
+        })
+    }
+}
+
+/// One example: what goes on stdin and, when the statement shows it, the
+/// expected stdout.
+#[derive(Debug, Clone)]
+pub struct LocalCase {
+    pub input: String,
+    pub expected: Option<String>,
+}
+
+/// The problem's example testcases, paired with the outputs listed in the
+/// statement.
+pub fn cases(detail: &QuestionDetail) -> Vec<LocalCase> {
+    let outputs: Vec<String> = scaffold::example_io(detail)
+        .into_iter()
+        .map(|(_, output)| output)
+        .collect();
+    detail
+        .sample_cases()
+        .into_iter()
+        .enumerate()
+        .map(|(i, input)| LocalCase {
+            input: input.to_string(),
+            expected: outputs.get(i).cloned(),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub enum CaseOutcome {
+    Passed { output: String, took: Duration },
+    Failed { output: String, took: Duration },
+    /// Ran fine, but there's no expected output to compare against.
+    Unchecked { output: String, took: Duration },
+    Error(String),
+}
+
+impl CaseOutcome {
+    pub fn output(&self) -> Option<&str> {
+        match self {
+            Self::Passed { output, .. }
+            | Self::Failed { output, .. }
+            | Self::Unchecked { output, .. } => Some(output),
+            Self::Error(_) => None,
+        }
+    }
+
+    pub fn took(&self) -> Option<Duration> {
+        match self {
+            Self::Passed { took, .. } | Self::Failed { took, .. } | Self::Unchecked { took, .. } => {
+                Some(*took)
+            }
+            Self::Error(_) => None,
+        }
+    }
+}
+
+/// Run the build step, if any. The error carries the compiler output.
+pub fn build(dir: &Path, command: &LocalCommand) -> Result<()> {
+    let Some(ref build) = command.build else {
+        return Ok(());
+    };
+    let output = shell(build)
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .output()
+        .with_context(|| format!("Failed to run `{build}`"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        bail!("`{build}` failed:\n{}{}", stderr.trim_end(), stdout.trim_end());
+    }
+    Ok(())
+}
+
+/// Run one case and compare its stdout with the expected output.
+pub fn run_case(dir: &Path, command: &LocalCommand, case: &LocalCase) -> CaseOutcome {
+    match run_with_input(dir, &command.run, &case.input) {
+        Ok((output, took)) => match case.expected {
+            Some(ref expected) if outputs_match(&output, expected) => {
+                CaseOutcome::Passed { output, took }
+            }
+            Some(_) => CaseOutcome::Failed { output, took },
+            None => CaseOutcome::Unchecked { output, took },
+        },
+        Err(e) => CaseOutcome::Error(format!("{e:#}")),
+    }
+}
+
+fn shell(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(command);
+    cmd
+}
+
+fn run_with_input(dir: &Path, run: &str, input: &str) -> Result<(String, Duration)> {
+    let start = Instant::now();
+    let mut child = shell(run)
+        .current_dir(dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run `{run}`"))?;
+
+    // Drain the output and feed stdin on their own threads, so a program
+    // that fills its stdout before reading all of the input, or never reads
+    // it at all, still gets killed at the timeout
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+    if let Some(mut stdin) = child.stdin.take() {
+        let input = format!("{input}\n");
+        std::thread::spawn(move || {
+            // A program that ignores stdin may exit before reading it
+            let _ = stdin.write_all(input.as_bytes());
+        });
+    }
+
+    let status = wait_timeout(&mut child, CASE_TIMEOUT)?;
+    let took = start.elapsed();
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    match status {
+        None => bail!("Timed out after {}s", CASE_TIMEOUT.as_secs()),
+        Some(status) if !status.success() => {
+            bail!("Exited with {status}\n{}", stderr.trim_end())
+        }
+        Some(_) => Ok((stdout.trim_end().to_string(), took)),
+    }
+}
+
+/// Read a pipe to the end on a separate thread so a chatty program can't
+/// block on a full pipe while we wait for it.
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> std::thread::JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        String::from_utf8_lossy(&buf).into_owned()
+    })
+}
+
+fn wait_timeout(child: &mut Child, timeout: Duration) -> Result<Option<std::process::ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(None);
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Compare outputs ignoring whitespace outside string literals, so
+/// `[0, 1]` matches LeetCode's `[0,1]`.
+pub fn outputs_match(actual: &str, expected: &str) -> bool {
+    normalize(actual) == normalize(expected)
+}
+
+fn normalize(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in s.chars() {
+        if in_string {
+            out.push(c);
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+            out.push(c);
+        } else if !c.is_whitespace() {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn whitespace_outside_strings_is_ignored() {
+        assert!(outputs_match("[0, 1]", "[0,1]"));
+        assert!(outputs_match("[0,1]\n", " [ 0 , 1 ] "));
+        assert!(!outputs_match("[1, 0]", "[0,1]"));
+    }
+
+    #[test]
+    fn whitespace_inside_strings_counts() {
+        assert!(outputs_match(r#"["a b", "c"]"#, r#"["a b","c"]"#));
+        assert!(!outputs_match(r#"["a b"]"#, r#"["ab"]"#));
+        assert!(!outputs_match(r#"["a  b"]"#, r#"["a b"]"#));
+    }
+
+    #[test]
+    fn escaped_quote_does_not_end_the_string() {
+        // Were the string closed at \", the spaces after it would be dropped
+        assert!(outputs_match(r#"["say \" hi"]"#, r#"["say \" hi"]"#));
+        assert!(!outputs_match(r#"["say \" hi"]"#, r#"["say \"hi"]"#));
+    }
+
+    #[test]
+    fn large_input_and_output_cannot_block_the_run() {
+        let dir = std::env::temp_dir();
+        let input = "x".repeat(256 * 1024);
+        // Fills the stdout pipe before reading stdin, then reads all of it
+        let run = "head -c 200000 /dev/zero | tr '\\0' y; wc -c";
+        let (output, took) = run_with_input(&dir, run, &input).unwrap();
+        assert!(output.ends_with(&(input.len() + 1).to_string()));
+        assert!(took < CASE_TIMEOUT);
+        // Never reads its input at all
+        let (output, _) = run_with_input(&dir, "echo done", &input).unwrap();
+        assert_eq!(output, "done");
+    }
+}
@@ -1,27 +1,60 @@
+mod anki;
 mod api;
 mod app;
+mod archive;
+mod attempts;
+mod browser_cookies;
+mod cli;
+mod clipboard;
 mod config;
+mod contest;
+mod credentials;
+mod editor;
 mod event;
+mod gist;
+mod headless;
+mod list_io;
+mod local_test;
+mod notes;
+mod practice;
+mod prefetch;
 mod scaffold;
+mod testcases;
 mod ui;
 
 use anyhow::Result;
+use clap::Parser;
 use std::time::Duration;
 
 use app::App;
+use cli::Cli;
 use config::Config;
 use event::EventHandler;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let cli = Cli::parse();
     let config = Config::load()?;
+    if let Some(ref c) = config {
+        api::site::set_site(c.site());
+    }
+
+    if let Some(command) = cli.command {
+        if let Some(ref c) = config {
+            ui::theme::set_theme(c.resolve_theme().0);
+        }
+        let ok = headless::run(command, config).await?;
+        std::process::exit(if ok { 0 } else { 1 });
+    }
 
     let mut terminal = ratatui::init();
+    crossterm::execute!(std::io::stdout(), crossterm::event::EnableFocusChange).ok();
     let mut events = EventHandler::new(Duration::from_millis(100));
-    let mut app = App::new(config)?;
+    let mut app = App::new(config, cli.start_screen())?;
 
     let result = app.run(&mut terminal, &mut events).await;
 
+    crossterm::execute!(std::io::stdout(), crossterm::event::DisableFocusChange).ok();
     ratatui::restore();
 
     // Print last opened directory so a shell wrapper can cd into it
@@ -0,0 +1,45 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Markdown notes for a problem live in `<workspace>/notes/<slug>.md`.
+pub fn notes_path(workspace: &Path, slug: &str) -> PathBuf {
+    workspace.join("notes").join(format!("{slug}.md"))
+}
+
+/// The saved notes for `slug`, if there are any worth showing.
+pub fn load(workspace: &Path, slug: &str) -> Option<String> {
+    std::fs::read_to_string(notes_path(workspace, slug))
+        .ok()
+        .filter(|text| !text.trim().is_empty())
+}
+
+/// Create `slug`'s notes file with a heading if it doesn't exist yet, so the
+/// editor opens on something. Returns its path.
+pub fn ensure(workspace: &Path, slug: &str, title: &str) -> std::io::Result<PathBuf> {
+    let path = notes_path(workspace, slug);
+    if !path.exists() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, format!("# {title}\n\n"))?;
+    }
+    Ok(path)
+}
+
+/// Slugs of every problem with notes worth showing.
+pub fn noted_slugs(workspace: &Path) -> HashSet<String> {
+    let Ok(entries) = std::fs::read_dir(workspace.join("notes")) else {
+        return HashSet::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()? != "md" {
+                return None;
+            }
+            let slug = path.file_stem()?.to_str()?.to_string();
+            load(workspace, &slug).map(|_| slug)
+        })
+        .collect()
+}
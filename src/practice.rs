@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::config::{self, Config};
+
+/// Stopwatch for the problem currently being worked on. Time only accrues
+/// while running, so pausing on focus loss doesn't count time away.
+#[derive(Debug, Clone)]
+pub struct PracticeTimer {
+    pub slug: String,
+    accumulated: Duration,
+    running_since: Option<Instant>,
+    /// Paused by hand; regaining focus doesn't resume it.
+    held: bool,
+}
+
+impl PracticeTimer {
+    pub fn start(slug: &str) -> Self {
+        Self {
+            slug: slug.to_string(),
+            accumulated: Duration::ZERO,
+            running_since: Some(Instant::now()),
+            held: false,
+        }
+    }
+
+    /// Pause or resume by hand. Returns whether the timer is now running.
+    pub fn toggle_hold(&mut self) -> bool {
+        self.held = !self.held;
+        if self.held {
+            self.pause();
+        } else {
+            self.resume();
+        }
+        !self.held
+    }
+
+    pub fn pause(&mut self) {
+        if let Some(since) = self.running_since.take() {
+            self.accumulated += since.elapsed();
+        }
+    }
+
+    pub fn resume(&mut self) {
+        if self.running_since.is_none() && !self.held {
+            self.running_since = Some(Instant::now());
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.accumulated + self.running_since.map_or(Duration::ZERO, |s| s.elapsed())
+    }
+}
+
+/// How long each problem took to get accepted, keyed by slug (seconds).
+#[derive(Debug, Clone, Default)]
+pub struct SolveTimes(HashMap<String, u64>);
+
+impl SolveTimes {
+    pub fn load() -> Self {
+        std::fs::read_to_string(Config::solve_times_path())
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .map(Self)
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        if let Ok(data) = serde_json::to_string_pretty(&self.0) {
+            let _ = config::write_atomic(&Config::solve_times_path(), &data);
+        }
+    }
+
+    pub fn get(&self, slug: &str) -> Option<Duration> {
+        self.0.get(slug).map(|&secs| Duration::from_secs(secs))
+    }
+
+    /// Record a solve, keeping the fastest time seen for the problem.
+    pub fn record(&mut self, slug: &str, took: Duration) {
+        let secs = took.as_secs();
+        let entry = self.0.entry(slug.to_string()).or_insert(secs);
+        *entry = (*entry).min(secs);
+    }
+}
+
+/// Short human duration: "45s", "23m", "1h 05m".
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs < 60 {
+        format!("{secs}s")
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+    }
+}
+
+/// Running clock: "04:07", "1:02:03".
+pub fn format_clock(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs < 3600 {
+        format!("{:02}:{:02}", secs / 60, secs % 60)
+    } else {
+        format!("{}:{:02}:{:02}", secs / 3600, secs % 3600 / 60, secs % 60)
+    }
+}
+
+/// Alternating work and break intervals.
+#[derive(Debug, Clone)]
+pub struct Pomodoro {
+    work: Duration,
+    rest: Duration,
+    pub on_break: bool,
+    phase_started: Instant,
+}
+
+impl Pomodoro {
+    /// Starts with a work interval. Zero-length intervals count as a minute.
+    pub fn start(work_mins: u64, break_mins: u64) -> Self {
+        Self {
+            work: Duration::from_secs(work_mins.max(1) * 60),
+            rest: Duration::from_secs(break_mins.max(1) * 60),
+            on_break: false,
+            phase_started: Instant::now(),
+        }
+    }
+
+    fn phase_length(&self) -> Duration {
+        if self.on_break { self.rest } else { self.work }
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.phase_length().saturating_sub(self.phase_started.elapsed())
+    }
+
+    /// Move to the next interval once the current one is over. Returns true
+    /// on a transition.
+    pub fn tick(&mut self) -> bool {
+        if self.phase_started.elapsed() < self.phase_length() {
+            return false;
+        }
+        self.on_break = !self.on_break;
+        self.phase_started = Instant::now();
+        true
+    }
+}
+
+/// Ring the terminal bell and raise a desktop notification, best effort.
+pub fn notify(summary: &str) {
+    use std::io::Write;
+
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+
+    let mut command = if cfg!(target_os = "macos") {
+        let mut c = Command::new("osascript");
+        c.arg("-e")
+            .arg(format!("display notification \"{summary}\" with title \"leetui\""));
+        c
+    } else {
+        let mut c = Command::new("notify-send");
+        c.arg("leetui").arg(summary);
+        c
+    };
+    let _ = command
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pretend the timer has been running for `secs`.
+    fn run_for(timer: &mut PracticeTimer, secs: u64) {
+        assert!(timer.is_running());
+        timer.running_since = Some(Instant::now() - Duration::from_secs(secs));
+    }
+
+    #[test]
+    fn timer_accumulates_only_while_running() {
+        let mut timer = PracticeTimer::start("two-sum");
+        run_for(&mut timer, 60);
+        timer.pause();
+        assert!(!timer.is_running());
+        let paused = timer.elapsed();
+        assert_eq!(paused.as_secs(), 60);
+        // Time away doesn't count
+        assert_eq!(timer.elapsed(), paused);
+
+        timer.resume();
+        run_for(&mut timer, 30);
+        assert_eq!(timer.elapsed().as_secs(), 90);
+        timer.pause();
+        assert_eq!(timer.elapsed().as_secs(), 90);
+    }
+
+    #[test]
+    fn held_timer_ignores_resume() {
+        let mut timer = PracticeTimer::start("two-sum");
+        run_for(&mut timer, 45);
+        assert!(!timer.toggle_hold());
+        // e.g. regaining focus
+        timer.resume();
+        assert!(!timer.is_running());
+        assert_eq!(timer.elapsed().as_secs(), 45);
+
+        assert!(timer.toggle_hold());
+        run_for(&mut timer, 15);
+        assert_eq!(timer.elapsed().as_secs(), 60);
+    }
+
+    #[test]
+    fn solve_times_keep_the_fastest() {
+        let mut times = SolveTimes::default();
+        times.record("two-sum", Duration::from_secs(1380));
+        times.record("two-sum", Duration::from_secs(1500));
+        assert_eq!(times.get("two-sum"), Some(Duration::from_secs(1380)));
+        assert_eq!(format_duration(Duration::from_secs(1380)), "23m");
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::VecDeque;
+
+use crate::api::types::QuestionDetail;
+
+/// Problem details kept in memory.
+const CAPACITY: usize = 32;
+
+/// Ticks (100ms) the home selection has to rest on a row before its detail
+/// is fetched, so scrolling through the table doesn't fire a request per row.
+const IDLE_TICKS: u32 = 4;
+
+/// Fetches the detail of the problem selected on the home screen in the
+/// background, so opening it doesn't wait on the network.
+#[derive(Default)]
+pub struct Prefetcher {
+    // Most recently used last
+    cache: VecDeque<QuestionDetail>,
+    selected: Option<String>,
+    idle_ticks: u32,
+    /// Slug being fetched, and whether it should open once it arrives.
+    in_flight: Option<(String, bool)>,
+}
+
+impl Prefetcher {
+    /// Called every tick with the home selection. Returns a slug to fetch
+    /// once the selection has settled on an uncached problem.
+    pub fn tick(&mut self, selected: Option<&str>) -> Option<String> {
+        if self.selected.as_deref() != selected {
+            self.selected = selected.map(str::to_string);
+            self.idle_ticks = 0;
+            return None;
+        }
+        self.idle_ticks = self.idle_ticks.saturating_add(1);
+        if self.idle_ticks != IDLE_TICKS || self.in_flight.is_some() {
+            return None;
+        }
+        let slug = self.selected.clone()?;
+        if self.cache.iter().any(|d| d.title_slug == slug) {
+            return None;
+        }
+        self.in_flight = Some((slug.clone(), false));
+        Some(slug)
+    }
+
+    /// The cached detail for `slug`, marked as most recently used.
+    pub fn get(&mut self, slug: &str) -> Option<QuestionDetail> {
+        let pos = self.cache.iter().position(|d| d.title_slug == slug)?;
+        let detail = self.cache.remove(pos)?;
+        self.cache.push_back(detail.clone());
+        Some(detail)
+    }
+
+    /// If `slug` is being prefetched, open it when it arrives instead of
+    /// fetching it again. Returns false when it isn't in flight.
+    pub fn open_when_ready(&mut self, slug: &str) -> bool {
+        match self.in_flight {
+            Some((ref s, ref mut open)) if s == slug => {
+                *open = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Store a fetched detail. Returns true if it was asked to be opened.
+    pub fn finish(&mut self, slug: &str, detail: Option<QuestionDetail>) -> bool {
+        let open = match self.in_flight.take() {
+            Some((s, open)) if s == slug => open,
+            other => {
+                self.in_flight = other;
+                false
+            }
+        };
+        if let Some(detail) = detail {
+            self.invalidate(slug);
+            if self.cache.len() >= CAPACITY {
+                self.cache.pop_front();
+            }
+            self.cache.push_back(detail);
+        }
+        open
+    }
+
+    /// Drop `slug`'s cached detail, e.g. once its status changed.
+    pub fn invalidate(&mut self, slug: &str) {
+        self.cache.retain(|d| d.title_slug != slug);
+    }
+}
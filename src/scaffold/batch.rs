@@ -0,0 +1,117 @@
+use anyhow::Result;
+use std::future::Future;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::api::types::QuestionDetail;
+
+/// Outcome of scaffolding a set of problems in one go.
+#[derive(Debug, Clone, Default)]
+pub struct BatchReport {
+    pub total: usize,
+    pub done: usize,
+    pub written: Vec<PathBuf>,
+    pub failed: Vec<(String, String)>, // (slug, error)
+}
+
+impl BatchReport {
+    pub fn summary(&self) -> String {
+        format!(
+            "Downloaded {}/{} problems",
+            self.written.len(),
+            self.total
+        )
+    }
+}
+
+/// Fetch and scaffold each slug in turn, sleeping `spacing` between fetches
+/// so a long list doesn't hammer the API. A failure on one problem is
+/// recorded and the batch carries on. `progress` is called after every item.
+pub async fn scaffold_batch<F, Fut, W, P>(
+    slugs: &[String],
+    spacing: Duration,
+    mut fetch: F,
+    mut write: W,
+    mut progress: P,
+) -> BatchReport
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<QuestionDetail>>,
+    W: FnMut(&QuestionDetail) -> Result<PathBuf>,
+    P: FnMut(&BatchReport),
+{
+    let mut report = BatchReport {
+        total: slugs.len(),
+        ..Default::default()
+    };
+
+    for (i, slug) in slugs.iter().enumerate() {
+        if i > 0 && !spacing.is_zero() {
+            tokio::time::sleep(spacing).await;
+        }
+        match fetch(slug.clone()).await.and_then(|detail| write(&detail)) {
+            Ok(path) => report.written.push(path),
+            Err(e) => report.failed.push((slug.clone(), format!("{e}"))),
+        }
+        report.done += 1;
+        progress(&report);
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail(slug: &str) -> QuestionDetail {
+        serde_json::from_value(serde_json::json!({
+            "questionId": "1",
+            "frontendQuestionId": "1",
+            "title": slug,
+            "titleSlug": slug,
+            "difficulty": "Easy",
+            "isPaidOnly": false,
+            "topicTags": [],
+            "hints": [],
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn every_slug_is_fetched_and_written_and_failures_recorded() {
+        let slugs = ["two-sum", "premium", "add-two-numbers"].map(String::from);
+        let mut fetched = Vec::new();
+        let mut progress = Vec::new();
+
+        let report = scaffold_batch(
+            &slugs,
+            Duration::ZERO,
+            |slug| {
+                fetched.push(slug.clone());
+                async move {
+                    if slug == "premium" {
+                        anyhow::bail!("Subscribe to unlock");
+                    }
+                    Ok(detail(&slug))
+                }
+            },
+            |detail| Ok(PathBuf::from(&detail.title_slug)),
+            |report| progress.push(report.done),
+        )
+        .await;
+
+        assert_eq!(fetched, slugs);
+        assert_eq!(progress, [1, 2, 3]);
+        assert_eq!(report.total, 3);
+        assert_eq!(
+            report.written,
+            [PathBuf::from("two-sum"), PathBuf::from("add-two-numbers")]
+        );
+        assert_eq!(
+            report.failed,
+            [("premium".to_string(), "Subscribe to unlock".to_string())]
+        );
+        assert_eq!(report.summary(), "Downloaded 2/3 problems");
+    }
+}
@@ -0,0 +1,606 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+/// A parameter of the solution method, as declared in the snippet.
+struct Param {
+    name: String,
+    ty: String,
+}
+
+/// The solution method LeetCode's snippet declares.
+struct Signature {
+    name: String,
+    params: Vec<Param>,
+    /// Return type; `void`, or empty in Go, for none
+    ret: String,
+}
+
+/// File the harness is written to, next to the solution, for `language`.
+pub fn file_name(language: &str) -> Option<&'static str> {
+    match super::lang_slug(language) {
+        "python3" => Some("main.py"),
+        "cpp" => Some("main.cpp"),
+        "java" => Some("Main.java"),
+        "golang" => Some("main.go"),
+        _ => None,
+    }
+}
+
+/// Write a runner next to the solution in `dir`: it reads one argument per
+/// line of stdin, the way LeetCode's testcases are written, calls the
+/// solution and prints the result the way LeetCode shows it. None when the
+/// language has no harness, or the snippet isn't a `Solution` method over
+/// types it can read (design problems, linked lists, trees).
+pub fn write(dir: &Path, language: &str, snippet: &str) -> Result<Option<PathBuf>> {
+    let Some(name) = file_name(language) else {
+        return Ok(None);
+    };
+    let path = dir.join(name);
+    // Idempotent: never overwrite an existing harness
+    if path.exists() {
+        return Ok(Some(path));
+    }
+    let source = match super::lang_slug(language) {
+        "python3" => python(snippet),
+        "cpp" => cpp(snippet),
+        "java" => java(snippet),
+        "golang" => go(snippet),
+        _ => None,
+    };
+    let Some(source) = source else {
+        return Ok(None);
+    };
+    std::fs::write(&path, source).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(Some(path))
+}
+
+/// Split a parameter list at the commas outside `<>` and `[]`.
+pub(super) fn split_params(list: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match c {
+            '<' | '[' => depth += 1,
+            '>' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(list[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(list[start..].trim());
+    parts.retain(|p| !p.is_empty());
+    parts
+}
+
+/// The line declaring the first method of `class Solution`, split into what
+/// comes before the `(`, the parameter list, and what follows the `)`.
+fn method_line(snippet: &str, is_method: impl Fn(&str) -> bool) -> Option<(&str, &str, &str)> {
+    let mut lines = snippet.lines();
+    lines.find(|l| l.contains("class Solution"))?;
+    let line = lines.map(str::trim).find(|l| is_method(l))?;
+    let open = line.find('(')?;
+    let close = line.rfind(')')?;
+    Some((&line[..open], &line[open + 1..close], &line[close + 1..]))
+}
+
+/// Split a C-style `type name` declaration at its last word.
+fn type_and_name(decl: &str) -> Option<(String, String)> {
+    let decl = decl.trim();
+    let at = decl.rfind(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+    let name = decl[at + 1..].to_string();
+    let ty = decl[..=at].replace('&', " ").replace("const ", " ");
+    let ty = ty.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!name.is_empty() && !ty.is_empty()).then_some((ty, name))
+}
+
+// Python
+
+fn python(snippet: &str) -> Option<String> {
+    let (before, params, _) = method_line(snippet, |l| l.starts_with("def "))?;
+    if params.contains("Node") {
+        return None;
+    }
+    let name = before.strip_prefix("def ")?.trim();
+    Some(PYTHON.replace("{{name}}", name))
+}
+
+const PYTHON: &str = r#"# Runs solution.py on a testcase: one argument per line of stdin, as
+# LeetCode writes them.
+import builtins
+import json
+import sys
+import typing
+
+# LeetCode's snippets use List, Optional, ... without importing them
+for name in typing.__all__:
+    setattr(builtins, name, getattr(typing, name))
+
+from solution import Solution
+
+
+def show(value):
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    if isinstance(value, float):
+        return f"{value:.5f}"
+    if isinstance(value, str):
+        return json.dumps(value)
+    if isinstance(value, (list, tuple)):
+        return "[" + ",".join(show(v) for v in value) + "]"
+    if value is None:
+        return "null"
+    return str(value)
+
+
+args = [json.loads(line) for line in sys.stdin.read().splitlines() if line.strip()]
+result = Solution().{{name}}(*args)
+# Methods that work in place return None; LeetCode shows the first argument
+print(show(args[0] if result is None and args else result))
+"#;
+
+// C++
+
+fn cpp_signature(snippet: &str) -> Option<Signature> {
+    let (before, params, _) =
+        method_line(snippet, |l| l.contains('(') && l.ends_with('{') && !l.starts_with("class "))?;
+    let (ret, name) = type_and_name(before)?;
+    let params = split_params(params)
+        .into_iter()
+        .map(|p| type_and_name(p).map(|(ty, name)| Param { name, ty }))
+        .collect::<Option<Vec<_>>>()?;
+    Some(Signature { name, params, ret })
+}
+
+/// Types the C++ harness can read and print.
+fn cpp_supported(ty: &str) -> bool {
+    match ty {
+        "int" | "long" | "long long" | "double" | "bool" | "char" | "string" => true,
+        _ => ty
+            .strip_prefix("vector<")
+            .and_then(|t| t.strip_suffix('>'))
+            .is_some_and(cpp_supported),
+    }
+}
+
+fn cpp(snippet: &str) -> Option<String> {
+    let sig = cpp_signature(snippet)?;
+    if !sig.params.iter().all(|p| cpp_supported(&p.ty))
+        || !(sig.ret == "void" || cpp_supported(&sig.ret))
+        || (sig.ret == "void" && sig.params.is_empty())
+    {
+        return None;
+    }
+    let mut body = String::new();
+    let count = sig.params.len();
+    body.push_str(&format!(
+        "    if (lines.size() < {count}) {{\n        cerr << \"expected {count} arguments, got \" << lines.size() << endl;\n        return 1;\n    }}\n"
+    ));
+    body.push_str("    size_t i;\n");
+    for (n, param) in sig.params.iter().enumerate() {
+        body.push_str(&format!("    {} a{n}{{}}; // {}\n", param.ty, param.name));
+        body.push_str(&format!("    i = 0;\n    read(lines[{n}], i, a{n});\n"));
+    }
+    let args: Vec<String> = (0..count).map(|n| format!("a{n}")).collect();
+    let call = format!("Solution().{}({})", sig.name, args.join(", "));
+    if sig.ret == "void" {
+        body.push_str(&format!("    {call};\n    write(cout, a0);\n"));
+    } else {
+        body.push_str(&format!("    auto result = {call};\n    write(cout, result);\n"));
+    }
+    Some(CPP.replace("{{body}}", &body))
+}
+
+const CPP: &str = r#"// Runs solution.cpp on a testcase: one argument per line of stdin, as
+// LeetCode writes them.
+#include <algorithm>
+#include <climits>
+#include <cmath>
+#include <cstring>
+#include <deque>
+#include <functional>
+#include <iomanip>
+#include <iostream>
+#include <map>
+#include <numeric>
+#include <queue>
+#include <set>
+#include <sstream>
+#include <stack>
+#include <string>
+#include <unordered_map>
+#include <unordered_set>
+#include <utility>
+#include <vector>
+using namespace std;
+
+#include "solution.cpp"
+
+static void skip(const string& s, size_t& i) {
+    while (i < s.size() && isspace((unsigned char)s[i])) i++;
+}
+
+static void read(const string& s, size_t& i, long long& v) {
+    skip(s, i);
+    size_t n = 0;
+    v = stoll(s.substr(i), &n);
+    i += n;
+}
+
+static void read(const string& s, size_t& i, long& v) {
+    long long x;
+    read(s, i, x);
+    v = (long)x;
+}
+
+static void read(const string& s, size_t& i, int& v) {
+    long long x;
+    read(s, i, x);
+    v = (int)x;
+}
+
+static void read(const string& s, size_t& i, double& v) {
+    skip(s, i);
+    size_t n = 0;
+    v = stod(s.substr(i), &n);
+    i += n;
+}
+
+static void read(const string& s, size_t& i, bool& v) {
+    skip(s, i);
+    v = s.compare(i, 4, "true") == 0;
+    i += v ? 4 : 5;
+}
+
+static void read(const string& s, size_t& i, string& v) {
+    skip(s, i);
+    i++; // opening quote
+    while (i < s.size() && s[i] != '"') {
+        if (s[i] == '\\' && i + 1 < s.size()) i++;
+        v += s[i++];
+    }
+    i++;
+}
+
+static void read(const string& s, size_t& i, char& v) {
+    string t;
+    read(s, i, t);
+    v = t.empty() ? '\0' : t[0];
+}
+
+template <class T>
+static void read(const string& s, size_t& i, vector<T>& v) {
+    skip(s, i);
+    i++; // [
+    skip(s, i);
+    if (i < s.size() && s[i] == ']') {
+        i++;
+        return;
+    }
+    while (i < s.size()) {
+        T x{};
+        read(s, i, x);
+        v.push_back(x);
+        skip(s, i);
+        if (s[i++] == ']') break;
+    }
+}
+
+static void write(ostream& o, int v) { o << v; }
+static void write(ostream& o, long v) { o << v; }
+static void write(ostream& o, long long v) { o << v; }
+static void write(ostream& o, double v) { o << fixed << setprecision(5) << v; }
+static void write(ostream& o, bool v) { o << (v ? "true" : "false"); }
+static void write(ostream& o, char v) { o << '"' << v << '"'; }
+static void write(ostream& o, const string& v) { o << '"' << v << '"'; }
+
+template <class T>
+static void write(ostream& o, const vector<T>& v) {
+    o << '[';
+    for (size_t k = 0; k < v.size(); k++) {
+        if (k) o << ',';
+        write(o, static_cast<T>(v[k]));
+    }
+    o << ']';
+}
+
+int main() {
+    vector<string> lines;
+    string line;
+    while (getline(cin, line)) {
+        if (line.find_first_not_of(" \t\r") != string::npos) lines.push_back(line);
+    }
+{{body}}    cout << endl;
+}
+"#;
+
+// Java
+
+fn java_signature(snippet: &str) -> Option<Signature> {
+    let (before, params, _) = method_line(snippet, |l| {
+        l.starts_with("public ") && l.contains('(') && !l.starts_with("public class")
+    })?;
+    let (ret, name) = type_and_name(before.trim_start_matches("public "))?;
+    let params = split_params(params)
+        .into_iter()
+        .map(|p| {
+            type_and_name(p.trim_start_matches("final ")).map(|(ty, name)| Param { name, ty })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(Signature { name, params, ret })
+}
+
+/// Java expression turning the parsed value `x` into `ty`. Lambda
+/// parameters are numbered by `depth`, as Java doesn't let them shadow.
+fn java_convert(ty: &str, x: &str, depth: usize) -> Option<String> {
+    let e = format!("e{depth}");
+    Some(match ty {
+        "int" | "Integer" => format!("((Number) {x}).intValue()"),
+        "long" | "Long" => format!("((Number) {x}).longValue()"),
+        "double" | "Double" => format!("((Number) {x}).doubleValue()"),
+        "boolean" | "Boolean" => format!("(Boolean) {x}"),
+        "String" => format!("(String) {x}"),
+        "char" | "Character" => format!("((String) {x}).charAt(0)"),
+        "int[]" => {
+            format!("((List<?>) {x}).stream().mapToInt({e} -> ((Number) {e}).intValue()).toArray()")
+        }
+        "long[]" => format!(
+            "((List<?>) {x}).stream().mapToLong({e} -> ((Number) {e}).longValue()).toArray()"
+        ),
+        "double[]" => format!(
+            "((List<?>) {x}).stream().mapToDouble({e} -> ((Number) {e}).doubleValue()).toArray()"
+        ),
+        "char[]" => format!(
+            "((List<?>) {x}).stream().map({e} -> (String) {e}).collect(Collectors.joining()).toCharArray()"
+        ),
+        _ => {
+            if let Some(inner) = ty.strip_suffix("[]") {
+                // Other primitive arrays have no stream to collect into
+                if matches!(inner, "boolean" | "byte" | "short" | "float") {
+                    return None;
+                }
+                let item = java_convert(inner, &e, depth + 1)?;
+                format!("((List<?>) {x}).stream().map({e} -> {item}).toArray({ty}::new)")
+            } else {
+                let inner = ty.strip_prefix("List<")?.strip_suffix('>')?;
+                let item = java_convert(inner, &e, depth + 1)?;
+                format!("((List<?>) {x}).stream().map({e} -> {item}).collect(Collectors.toList())")
+            }
+        }
+    })
+}
+
+fn java(snippet: &str) -> Option<String> {
+    let sig = java_signature(snippet)?;
+    if sig.ret == "void" && sig.params.is_empty() {
+        return None;
+    }
+    let mut body = String::new();
+    let count = sig.params.len();
+    body.push_str(&format!(
+        "        if (args.size() < {count}) {{\n            System.err.println(\"expected {count} arguments, got \" + args.size());\n            System.exit(1);\n        }}\n"
+    ));
+    for (n, param) in sig.params.iter().enumerate() {
+        let value = java_convert(&param.ty, &format!("args.get({n})"), 0)?;
+        body.push_str(&format!("        {} a{n} = {value}; // {}\n", param.ty, param.name));
+    }
+    let args: Vec<String> = (0..count).map(|n| format!("a{n}")).collect();
+    let call = format!("new Solution().{}({})", sig.name, args.join(", "));
+    if sig.ret == "void" {
+        body.push_str(&format!("        {call};\n        System.out.println(show(a0));\n"));
+    } else {
+        body.push_str(&format!(
+            "        Object result = {call};\n        System.out.println(show(result));\n"
+        ));
+    }
+    Some(JAVA.replace("{{body}}", &body))
+}
+
+const JAVA: &str = r#"// Runs Solution.java on a testcase: one argument per line of stdin, as
+// LeetCode writes them.
+import java.io.*;
+import java.util.*;
+import java.util.stream.*;
+
+public class Main {
+    public static void main(String[] argv) throws IOException {
+        BufferedReader in = new BufferedReader(new InputStreamReader(System.in));
+        List<Object> args = new ArrayList<>();
+        for (String line = in.readLine(); line != null; line = in.readLine()) {
+            if (!line.isBlank()) args.add(new Parser(line).value());
+        }
+{{body}}    }
+
+    /** LeetCode's testcase values: numbers, strings, booleans and arrays. */
+    static class Parser {
+        private final String s;
+        private int i;
+
+        Parser(String s) {
+            this.s = s;
+        }
+
+        Object value() {
+            skip();
+            char c = s.charAt(i);
+            if (c == '[') {
+                List<Object> list = new ArrayList<>();
+                i++;
+                skip();
+                if (s.charAt(i) == ']') {
+                    i++;
+                    return list;
+                }
+                while (true) {
+                    list.add(value());
+                    skip();
+                    if (s.charAt(i++) == ']') return list;
+                }
+            }
+            if (c == '"') {
+                StringBuilder b = new StringBuilder();
+                i++;
+                while (s.charAt(i) != '"') {
+                    if (s.charAt(i) == '\\') i++;
+                    b.append(s.charAt(i++));
+                }
+                i++;
+                return b.toString();
+            }
+            int start = i;
+            while (i < s.length() && ",]".indexOf(s.charAt(i)) < 0 && !Character.isWhitespace(s.charAt(i))) i++;
+            String word = s.substring(start, i);
+            switch (word) {
+                case "true": return true;
+                case "false": return false;
+                case "null": return null;
+            }
+            if (word.contains(".") || word.contains("e") || word.contains("E")) return Double.parseDouble(word);
+            return Long.parseLong(word);
+        }
+
+        private void skip() {
+            while (i < s.length() && Character.isWhitespace(s.charAt(i))) i++;
+        }
+    }
+
+    static String show(Object o) {
+        if (o == null) return "null";
+        if (o instanceof String || o instanceof Character) return "\"" + o + "\"";
+        if (o instanceof Double || o instanceof Float) {
+            return String.format(Locale.ROOT, "%.5f", ((Number) o).doubleValue());
+        }
+        StringJoiner joined = new StringJoiner(",", "[", "]");
+        if (o instanceof Iterable) {
+            for (Object x : (Iterable<?>) o) joined.add(show(x));
+            return joined.toString();
+        }
+        if (o.getClass().isArray()) {
+            for (int k = 0; k < java.lang.reflect.Array.getLength(o); k++) {
+                joined.add(show(java.lang.reflect.Array.get(o, k)));
+            }
+            return joined.toString();
+        }
+        return o.toString();
+    }
+}
+"#;
+
+// Go
+
+fn go_signature(snippet: &str) -> Option<Signature> {
+    // Design problems declare methods, `func (this *Foo) ...`
+    let line = snippet
+        .lines()
+        .map(str::trim)
+        .find(|l| l.starts_with("func ") && !l.starts_with("func ("))?;
+    let rest = line.strip_prefix("func ")?;
+    let open = rest.find('(')?;
+    let close = rest.find(')')?;
+    let name = rest[..open].trim().to_string();
+    let ret = rest[close + 1..].trim_end_matches('{').trim().to_string();
+    // `a, b int` declares both with the type that follows
+    let mut params: Vec<Param> = Vec::new();
+    let mut untyped = Vec::new();
+    for part in split_params(&rest[open + 1..close]) {
+        match part.split_once(' ') {
+            Some((name, ty)) => {
+                let ty = ty.trim().to_string();
+                for name in untyped.drain(..) {
+                    params.push(Param { name, ty: ty.clone() });
+                }
+                params.push(Param {
+                    name: name.to_string(),
+                    ty,
+                });
+            }
+            None => untyped.push(part.to_string()),
+        }
+    }
+    untyped.is_empty().then_some(Signature { name, params, ret })
+}
+
+fn go(snippet: &str) -> Option<String> {
+    let sig = go_signature(snippet)?;
+    // Pointers are linked lists and trees; bytes and runes aren't JSON
+    let readable = |ty: &str| !(ty.contains('*') || ty.contains("byte") || ty.contains("rune"));
+    if !sig.params.iter().all(|p| readable(&p.ty))
+        || !readable(&sig.ret)
+        || (sig.ret.is_empty() && sig.params.is_empty())
+    {
+        return None;
+    }
+    let mut body = String::new();
+    for (n, param) in sig.params.iter().enumerate() {
+        body.push_str(&format!("\tvar a{n} {} // {}\n", param.ty, param.name));
+        body.push_str(&format!("\tparse(lines, {n}, &a{n})\n"));
+    }
+    let args: Vec<String> = (0..sig.params.len()).map(|n| format!("a{n}")).collect();
+    let call = format!("{}({})", sig.name, args.join(", "));
+    if sig.ret.is_empty() {
+        body.push_str(&format!("\t{call}\n\tfmt.Println(show(a0))\n"));
+    } else {
+        body.push_str(&format!("\tfmt.Println(show({call}))\n"));
+    }
+    Some(GO.replace("{{body}}", &body))
+}
+
+const GO: &str = r#"// Runs solution.go on a testcase: one argument per line of stdin, as
+// LeetCode writes them.
+package main
+
+import (
+	"bufio"
+	"encoding/json"
+	"fmt"
+	"os"
+	"reflect"
+	"strconv"
+	"strings"
+)
+
+func main() {
+	var lines []string
+	scanner := bufio.NewScanner(os.Stdin)
+	scanner.Buffer(make([]byte, 1<<20), 1<<26)
+	for scanner.Scan() {
+		if line := strings.TrimSpace(scanner.Text()); line != "" {
+			lines = append(lines, line)
+		}
+	}
+{{body}}}
+
+func parse(lines []string, i int, v any) {
+	if i >= len(lines) {
+		fmt.Fprintf(os.Stderr, "missing argument %d\n", i+1)
+		os.Exit(1)
+	}
+	if err := json.Unmarshal([]byte(lines[i]), v); err != nil {
+		fmt.Fprintf(os.Stderr, "argument %d: %v\n", i+1, err)
+		os.Exit(1)
+	}
+}
+
+func show(v any) string {
+	r := reflect.ValueOf(v)
+	switch r.Kind() {
+	case reflect.Invalid:
+		return "null"
+	case reflect.Slice, reflect.Array:
+		parts := make([]string, r.Len())
+		for i := range parts {
+			parts[i] = show(r.Index(i).Interface())
+		}
+		return "[" + strings.Join(parts, ",") + "]"
+	case reflect.String:
+		return strconv.Quote(r.String())
+	case reflect.Float32, reflect.Float64:
+		return strconv.FormatFloat(r.Float(), 'f', 5, 64)
+	case reflect.Bool:
+		return strconv.FormatBool(r.Bool())
+	}
+	return fmt.Sprint(v)
+}
+"#;
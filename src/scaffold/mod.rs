@@ -1,17 +1,426 @@
+pub mod batch;
+pub mod harness;
 pub mod rust;
+pub mod template;
 
 use anyhow::{Result, bail};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::api::types::QuestionDetail;
+use crate::api::types::{CodeSnippet, QuestionDetail};
+use crate::config::RustLayout;
+use crate::ui::rich_text::{RenderOptions, html_to_lines};
+
+/// Pick the template to seed a solution with: the requested language's
+/// snippet, then any snippet, then none (e.g. premium problems).
+pub fn pick_snippet<'a>(detail: &'a QuestionDetail, lang_slug: &str) -> Option<&'a CodeSnippet> {
+    let snippets = detail.code_snippets.as_deref()?;
+    snippets
+        .iter()
+        .find(|s| s.lang_slug == lang_slug)
+        .or_else(|| snippets.first())
+}
+
+/// LeetCode's slug for a configured `language`, e.g. `go` -> `golang`.
+pub fn lang_slug(language: &str) -> &'static str {
+    match language {
+        "python3" | "python" => "python3",
+        "cpp" | "c++" => "cpp",
+        "java" => "java",
+        "javascript" => "javascript",
+        "typescript" => "typescript",
+        "go" | "golang" => "golang",
+        "c" => "c",
+        "csharp" | "c#" => "csharp",
+        "kotlin" => "kotlin",
+        "swift" => "swift",
+        "ruby" => "ruby",
+        "scala" => "scala",
+        "php" => "php",
+        "dart" => "dart",
+        "racket" => "racket",
+        "erlang" => "erlang",
+        "elixir" => "elixir",
+        _ => "rust",
+    }
+}
+
+/// The configured `language` for a LeetCode slug, the inverse of
+/// [`lang_slug`]. None for slugs that don't map back, like Python 2's.
+pub fn language_for_slug(slug: &str) -> Option<&str> {
+    let language = match slug {
+        "golang" => "go",
+        other => other,
+    };
+    (lang_slug(language) == slug).then_some(language)
+}
+
+/// The part of a solution file that gets sent to LeetCode. Rust files have
+/// the scaffolding (`main`, tests, the `Solution` shim) stripped.
+pub fn submittable_code(content: &str, language: &str) -> Result<String> {
+    if language.eq_ignore_ascii_case("rust") {
+        return rust::extract_solution(content);
+    }
+    if lang_slug(language) == "golang" {
+        // `package main` is there for the local harness; LeetCode adds its own
+        let code: Vec<&str> = content
+            .lines()
+            .filter(|l| !l.trim_start().starts_with("package "))
+            .collect();
+        return Ok(code.join("\n"));
+    }
+    Ok(content.to_string())
+}
+
+/// Line (1-based) just inside the solution function of a scaffolded file,
+/// for opening the editor there. None when no function is found.
+pub fn solution_line(content: &str, language: &str) -> Option<usize> {
+    let lines: Vec<&str> = content.lines().collect();
+    // Skip helper types (ListNode, TreeNode) defined above the solution
+    let start = lines
+        .iter()
+        .position(|l| l.contains("impl Solution") || l.contains("class Solution"))
+        .unwrap_or(0);
+    let is_function = |line: &str| {
+        let line = line.trim();
+        match language {
+            "rust" => line.starts_with("pub fn ") || line.starts_with("fn "),
+            "python3" | "python" => line.starts_with("def "),
+            "go" | "golang" => line.starts_with("func "),
+            "javascript" | "typescript" => {
+                line.starts_with("function ") || line.contains("= function")
+            }
+            _ => line.contains('(') && line.ends_with('{') && !line.starts_with("class "),
+        }
+    };
+    let signature = (start..lines.len()).find(|&i| is_function(lines[i]))?;
+    Some(signature + 2)
+}
+
+pub fn has_snippets(detail: &QuestionDetail) -> bool {
+    detail.code_snippets.as_ref().is_some_and(|s| !s.is_empty())
+}
+
+/// Whether there's code to run for `detail`: a solution file, or a template
+/// to scaffold one from. Premium problems can have neither.
+pub fn has_code(workspace: &Path, detail: &QuestionDetail, language: &str) -> bool {
+    has_snippets(detail) || solution_path(workspace, detail, language).exists()
+}
+
+/// Directory a problem is scaffolded into, `<workspace>/<id>-<slug>`.
+pub fn problem_dir(workspace: &Path, detail: &QuestionDetail) -> PathBuf {
+    workspace.join(format!("{}-{}", detail.frontend_question_id, detail.title_slug))
+}
+
+/// Where the solution file for `detail` lives (or will live) in `workspace`.
+pub fn solution_path(workspace: &Path, detail: &QuestionDetail, language: &str) -> PathBuf {
+    let dir = problem_dir(workspace, detail);
+    let path = solution_file(&dir, language);
+    // Scaffolded as one of the extra languages
+    let nested = solution_file(&dir.join(language), language);
+    if !path.exists() && nested.exists() {
+        nested
+    } else {
+        path
+    }
+}
+
+/// Directory the solution for `language` is built and run in: the problem
+/// directory, or its subdirectory when it was scaffolded as an extra
+/// language.
+pub fn project_dir(workspace: &Path, detail: &QuestionDetail, language: &str) -> PathBuf {
+    let dir = problem_dir(workspace, detail);
+    let nested = dir.join(language);
+    if solution_path(workspace, detail, language).starts_with(&nested) {
+        nested
+    } else {
+        dir
+    }
+}
+
+/// The solution file for `language` in a problem directory.
+fn solution_file(dir: &Path, language: &str) -> PathBuf {
+    match language {
+        // Workspace members are libraries
+        "rust" if dir.join("src").join("lib.rs").exists() => dir.join("src").join("lib.rs"),
+        "rust" => dir.join("src").join("main.rs"),
+        "python3" | "python" => dir.join("solution.py"),
+        "cpp" | "c++" => dir.join("solution.cpp"),
+        "java" => dir.join("Solution.java"),
+        "javascript" => dir.join("solution.js"),
+        "typescript" => dir.join("solution.ts"),
+        "go" | "golang" => dir.join("solution.go"),
+        "c" => dir.join("solution.c"),
+        "csharp" | "c#" => dir.join("Solution.cs"),
+        "kotlin" => dir.join("Solution.kt"),
+        "swift" => dir.join("solution.swift"),
+        "ruby" => dir.join("solution.rb"),
+        "scala" => dir.join("Solution.scala"),
+        "php" => dir.join("solution.php"),
+        "dart" => dir.join("solution.dart"),
+        "racket" => dir.join("solution.rkt"),
+        "erlang" => dir.join("solution.erl"),
+        "elixir" => dir.join("solution.ex"),
+        _ => dir.join("src").join("main.rs"),
+    }
+}
 
 pub fn scaffold_problem(
     workspace: &PathBuf,
     detail: &QuestionDetail,
     language: &str,
+    rust_layout: RustLayout,
 ) -> Result<PathBuf> {
-    match language {
-        "rust" => rust::scaffold_rust(workspace, detail),
-        _ => bail!("Unsupported language for scaffolding: {}", language),
+    match (language, load_template(language)?) {
+        ("rust", template) => match rust_layout {
+            RustLayout::Project => rust::scaffold_rust(workspace, detail, template.as_deref()),
+            RustLayout::Workspace => {
+                rust::scaffold_rust_member(workspace, detail, template.as_deref())
+            }
+        },
+        (_, template) => {
+            let path = solution_path(workspace, detail, language);
+            scaffold_file(&path, detail, language, template.as_deref().unwrap_or_default())
+        }
+    }
+}
+
+/// Write a non-Rust solution file and, for the languages that have one, the
+/// local harness next to it.
+fn scaffold_file(
+    path: &Path,
+    detail: &QuestionDetail,
+    language: &str,
+    template: &str,
+) -> Result<PathBuf> {
+    let path = template::scaffold_file(path, detail, language, template)?;
+    let slug = lang_slug(language);
+    if let Some(dir) = path.parent()
+        && let Some(snippet) = pick_snippet(detail, slug).filter(|s| s.lang_slug == slug)
+    {
+        harness::write(dir, language, &snippet.code)?;
+    }
+    Ok(path)
+}
+
+/// Scaffold `detail` in `language`, then in each of `extra`, for practicing
+/// one problem in several languages. The extra languages go into a
+/// subdirectory named after them, e.g. `1-two-sum/python3/solution.py`
+/// (Rust as a standalone project). Returns the main solution file and the
+/// extra ones.
+pub fn scaffold_languages(
+    workspace: &PathBuf,
+    detail: &QuestionDetail,
+    language: &str,
+    extra: &[String],
+    rust_layout: RustLayout,
+) -> Result<(PathBuf, Vec<PathBuf>)> {
+    let main = scaffold_problem(workspace, detail, language, rust_layout)?;
+    let dir = problem_dir(workspace, detail);
+    let mut others = Vec::new();
+    for other in extra.iter().filter(|l| lang_slug(l) != lang_slug(language)) {
+        let template = load_template(other)?;
+        let path = if other == "rust" {
+            rust::exclude_from_workspace(workspace, &dir.join(other))?;
+            rust::scaffold_rust_at(&dir.join(other), detail, template.as_deref())?
+        } else {
+            let path = solution_file(&dir.join(other), other);
+            scaffold_file(&path, detail, other, template.as_deref().unwrap_or_default())?
+        };
+        others.push(path);
+    }
+    Ok((main, others))
+}
+
+/// Outcome of [`pull`].
+pub enum Pull {
+    /// The problem wasn't scaffolded in the language; it now is, around the
+    /// pulled code
+    Written(PathBuf),
+    /// The solution file already holds the pulled code
+    Unchanged(PathBuf),
+    /// The solution file holds other code; `content` would replace it
+    Differs { path: PathBuf, content: String },
+}
+
+/// Put `code`, a submission in `language`, into the problem's solution file.
+/// A problem not yet scaffolded in that language is scaffolded around it
+/// (as an extra language when it isn't `main_language`); an existing file is
+/// left alone so the caller can confirm replacing it.
+pub fn pull(
+    workspace: &PathBuf,
+    detail: &QuestionDetail,
+    language: &str,
+    main_language: &str,
+    code: &str,
+    rust_layout: RustLayout,
+) -> Result<Pull> {
+    let path = solution_path(workspace, detail, language);
+    if !path.exists() {
+        let detail = with_snippet(detail, language, code);
+        let path = if lang_slug(language) == lang_slug(main_language) {
+            scaffold_problem(workspace, &detail, language, rust_layout)?
+        } else {
+            let extra = [language.to_string()];
+            let (_, others) =
+                scaffold_languages(workspace, &detail, main_language, &extra, rust_layout)?;
+            others.into_iter().next().unwrap_or(path)
+        };
+        return Ok(Pull::Written(path));
+    }
+    let current = std::fs::read_to_string(&path)?;
+    // Other languages keep the comment header in what gets submitted
+    if submittable_code(&current, language)?.contains(code.trim()) {
+        return Ok(Pull::Unchanged(path));
+    }
+    let lib = path.ends_with("src/lib.rs");
+    let content = source_with_code(detail, language, code, lib)?;
+    Ok(Pull::Differs { path, content })
+}
+
+/// What scaffolding writes to the solution file for `language`, with `code`
+/// in place of LeetCode's snippet. `lib` picks the Rust library layout.
+pub fn source_with_code(
+    detail: &QuestionDetail,
+    language: &str,
+    code: &str,
+    lib: bool,
+) -> Result<String> {
+    let detail = with_snippet(detail, language, code);
+    let template = load_template(language)?;
+    if language == "rust" {
+        return Ok(rust::source(&detail, template.as_deref(), lib));
+    }
+    Ok(template::render(template.as_deref().unwrap_or_default(), &detail, code))
+}
+
+/// `detail` with its snippet for `language` replaced by `code`.
+fn with_snippet(detail: &QuestionDetail, language: &str, code: &str) -> QuestionDetail {
+    let slug = lang_slug(language);
+    let mut detail = detail.clone();
+    let snippets = detail.code_snippets.get_or_insert_with(Vec::new);
+    match snippets.iter_mut().find(|s| s.lang_slug == slug) {
+        Some(snippet) => snippet.code = code.to_string(),
+        None => snippets.insert(
+            0,
+            CodeSnippet {
+                lang: language.to_string(),
+                lang_slug: slug.to_string(),
+                code: code.to_string(),
+            },
+        ),
+    }
+    detail
+}
+
+/// The user's template for `language`, else the built-in one. Rust has its
+/// own scaffold, so None means the default there.
+fn load_template(language: &str) -> Result<Option<String>> {
+    if let Some(template) = template::load(language) {
+        return Ok(Some(template));
+    }
+    if language == "rust" {
+        return Ok(None);
+    }
+    match template::builtin(language) {
+        Some(template) => Ok(Some(template)),
+        None => bail!(
+            "Unsupported language for scaffolding: {}\nAdd a template for it to {}",
+            language,
+            crate::config::Config::templates_dir().display()
+        ),
+    }
+}
+
+/// The `Input:`/`Output:` pair of each example in the problem statement,
+/// in order. Examples without both lines are skipped.
+pub fn example_io(detail: &QuestionDetail) -> Vec<(String, String)> {
+    let Some(html) = detail.content.as_deref() else {
+        return Vec::new();
+    };
+    let mut pairs = Vec::new();
+    let mut input = None;
+    for line in html_to_lines(html, RenderOptions::default()) {
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        // Examples in <pre> come back inside a box
+        let text = text.trim_matches(|c: char| c.is_whitespace() || c == '\u{2502}');
+        if let Some(rest) = text.strip_prefix("Input:") {
+            input = Some(rest.trim().to_string());
+        } else if let Some(rest) = text.strip_prefix("Output:")
+            && let Some(input) = input.take()
+        {
+            pairs.push((input, rest.trim().to_string()));
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail(snippets: serde_json::Value) -> QuestionDetail {
+        serde_json::from_value(serde_json::json!({
+            "questionId": "1",
+            "frontendQuestionId": "1",
+            "title": "Two Sum",
+            "titleSlug": "two-sum",
+            "difficulty": "Easy",
+            "isPaidOnly": false,
+            "topicTags": [],
+            "hints": [],
+            "codeSnippets": snippets,
+        }))
+        .unwrap()
+    }
+
+    fn snippet(lang_slug: &str) -> serde_json::Value {
+        serde_json::json!({ "lang": lang_slug, "langSlug": lang_slug, "code": "" })
+    }
+
+    #[test]
+    fn pick_snippet_prefers_the_language_then_any_then_none() {
+        let both = detail(serde_json::json!([snippet("cpp"), snippet("python3")]));
+        assert_eq!(pick_snippet(&both, "python3").unwrap().lang_slug, "python3");
+        assert_eq!(pick_snippet(&both, "rust").unwrap().lang_slug, "cpp");
+
+        assert!(pick_snippet(&detail(serde_json::json!([])), "rust").is_none());
+        assert!(pick_snippet(&detail(serde_json::Value::Null), "rust").is_none());
+    }
+
+    #[test]
+    fn without_snippets_only_a_solution_file_gives_code_to_run() {
+        let workspace =
+            std::env::temp_dir().join(format!("leetui-has-code-{}", std::process::id()));
+        let premium = detail(serde_json::Value::Null);
+        assert!(!has_snippets(&premium));
+        assert!(!has_code(&workspace, &premium, "python3"));
+
+        let path = solution_path(&workspace, &premium, "python3");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "print(1)\n").unwrap();
+        assert!(has_code(&workspace, &premium, "python3"));
+        std::fs::remove_dir_all(&workspace).unwrap();
+
+        assert!(has_code(&workspace, &detail(serde_json::json!([snippet("cpp")])), "python3"));
+    }
+
+    #[test]
+    fn solution_path_follows_the_language_and_layout() {
+        let workspace = std::env::temp_dir().join(format!("leetui-paths-{}", std::process::id()));
+        let two_sum = detail(serde_json::Value::Null);
+        let dir = workspace.join("1-two-sum");
+        assert_eq!(problem_dir(&workspace, &two_sum), dir);
+        assert_eq!(solution_path(&workspace, &two_sum, "python3"), dir.join("solution.py"));
+        assert_eq!(solution_path(&workspace, &two_sum, "java"), dir.join("Solution.java"));
+        assert_eq!(solution_path(&workspace, &two_sum, "rust"), dir.join("src/main.rs"));
+
+        // Workspace members are libraries, extra languages sit in a subdirectory
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("src/lib.rs"), "").unwrap();
+        assert_eq!(solution_path(&workspace, &two_sum, "rust"), dir.join("src/lib.rs"));
+        std::fs::create_dir_all(dir.join("cpp")).unwrap();
+        std::fs::write(dir.join("cpp/solution.cpp"), "").unwrap();
+        assert_eq!(solution_path(&workspace, &two_sum, "cpp"), dir.join("cpp/solution.cpp"));
+        std::fs::remove_dir_all(&workspace).unwrap();
     }
 }
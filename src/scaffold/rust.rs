@@ -1,18 +1,28 @@
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use crate::api::types::QuestionDetail;
+use crate::api::site::site;
+use crate::api::types::{MetaData, QuestionDetail};
 
-pub fn scaffold_rust(workspace: &PathBuf, detail: &QuestionDetail) -> Result<PathBuf> {
-    let dir_name = format!(
-        "{}-{}",
-        detail.frontend_question_id,
-        detail.title_slug
-    );
+/// Create a cargo project for `detail`. `template`, if given, replaces the
+/// generated `main.rs` (see [`super::template`]).
+pub fn scaffold_rust(
+    workspace: &Path,
+    detail: &QuestionDetail,
+    template: Option<&str>,
+) -> Result<PathBuf> {
+    scaffold_rust_at(&super::problem_dir(workspace, detail), detail, template)
+}
+
+/// Create the cargo project for `detail` in `project_dir`.
+pub fn scaffold_rust_at(
+    project_dir: &Path,
+    detail: &QuestionDetail,
+    template: Option<&str>,
+) -> Result<PathBuf> {
     // Cargo package names can't start with a digit, so prefix with "p"
-    let pkg_name = format!("p{dir_name}");
-    let project_dir = workspace.join(&dir_name);
+    let pkg_name = format!("p{}-{}", detail.frontend_question_id, detail.title_slug);
 
     // Idempotent: skip if already exists
     if project_dir.join("Cargo.toml").exists() {
@@ -20,12 +30,12 @@ pub fn scaffold_rust(workspace: &PathBuf, detail: &QuestionDetail) -> Result<Pat
     }
 
     // Create project with cargo init
-    std::fs::create_dir_all(&project_dir)
+    std::fs::create_dir_all(project_dir)
         .with_context(|| format!("Failed to create dir {}", project_dir.display()))?;
 
     let output = Command::new("cargo")
         .args(["init", "--name", &pkg_name])
-        .current_dir(&project_dir)
+        .current_dir(project_dir)
         .output()
         .context("Failed to run cargo init")?;
 
@@ -34,16 +44,342 @@ pub fn scaffold_rust(workspace: &PathBuf, detail: &QuestionDetail) -> Result<Pat
         anyhow::bail!("cargo init failed: {}", stderr);
     }
 
-    // Build the source file content
+    let main_rs = project_dir.join("src/main.rs");
+    std::fs::write(&main_rs, source(detail, template, false))
+        .with_context(|| format!("Failed to write {}", main_rs.display()))?;
+
+    Ok(main_rs)
+}
+
+/// Manifest written to the workspace root on first use. Each Rust problem
+/// is added to `members` as it's scaffolded; a glob would also pick up the
+/// problem directories of other languages, which have no `Cargo.toml`.
+const WORKSPACE_MANIFEST: &str = "[workspace]\nresolver = \"3\"\nmembers = []\n";
+
+/// The `members` glob of manifests written before members were listed.
+const MEMBERS_GLOB: &str = "[0-9]*";
+
+/// Create `detail` as a library crate inside a cargo workspace rooted at
+/// `workspace`, so one rust-analyzer instance covers every problem.
+pub fn scaffold_rust_member(
+    workspace: &Path,
+    detail: &QuestionDetail,
+    template: Option<&str>,
+) -> Result<PathBuf> {
+    let root_manifest = workspace.join("Cargo.toml");
+    if !root_manifest.exists() {
+        std::fs::create_dir_all(workspace)
+            .with_context(|| format!("Failed to create dir {}", workspace.display()))?;
+        std::fs::write(&root_manifest, WORKSPACE_MANIFEST)
+            .with_context(|| format!("Failed to write {}", root_manifest.display()))?;
+    }
+
+    let project_dir = super::problem_dir(workspace, detail);
+    let member = dir_name(&project_dir);
+    let lib_rs = project_dir.join("src/lib.rs");
+    // Idempotent: skip if already exists
+    if project_dir.join("Cargo.toml").exists() {
+        add_to_workspace(&root_manifest, "members", &member)?;
+        return Ok(super::solution_path(workspace, detail, "rust"));
+    }
+
+    std::fs::create_dir_all(project_dir.join("src"))
+        .with_context(|| format!("Failed to create dir {}", project_dir.display()))?;
+
+    // Cargo package names can't start with a digit, so prefix with "p"
+    let manifest = format!(
+        "[package]\nname = \"p{}-{}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n[dependencies]\n",
+        detail.frontend_question_id, detail.title_slug
+    );
+    let manifest_path = project_dir.join("Cargo.toml");
+    std::fs::write(&manifest_path, manifest)
+        .with_context(|| format!("Failed to write {}", manifest_path.display()))?;
+
+    let src = source(detail, template, true);
+    std::fs::write(&lib_rs, src).with_context(|| format!("Failed to write {}", lib_rs.display()))?;
+
+    add_to_workspace(&root_manifest, "members", &member)?;
+    Ok(lib_rs)
+}
+
+/// Keep the standalone crate in `project_dir` (an extra language's
+/// `<id>-<slug>/rust`) out of the cargo workspace rooted at `workspace`, if
+/// there is one; cargo refuses to build a crate nested in a workspace that
+/// doesn't list it.
+pub fn exclude_from_workspace(workspace: &Path, project_dir: &Path) -> Result<()> {
+    let root_manifest = workspace.join("Cargo.toml");
+    let Ok(relative) = project_dir.strip_prefix(workspace) else {
+        return Ok(());
+    };
+    if !root_manifest.exists() {
+        return Ok(());
+    }
+    let entry = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    add_to_workspace(&root_manifest, "exclude", &entry)
+}
+
+fn dir_name(dir: &Path) -> String {
+    dir.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Add `entry` to the `[workspace]` `list` ("members" or "exclude") of the
+/// manifest at `path`, unless it's already there.
+fn add_to_workspace(path: &Path, list: &str, entry: &str) -> Result<()> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let mut manifest: toml::Table =
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))?;
+    let Some(workspace) = manifest.get_mut("workspace").and_then(|w| w.as_table_mut()) else {
+        anyhow::bail!("{} has no [workspace] table", path.display());
+    };
+    let Some(entries) = workspace
+        .entry(list)
+        .or_insert_with(|| toml::Value::Array(Vec::new()))
+        .as_array_mut()
+    else {
+        anyhow::bail!("workspace.{list} in {} isn't a list", path.display());
+    };
+
+    let mut changed = false;
+    // Replace the old glob with the members it matched
+    if let Some(i) = entries.iter().position(|e| e.as_str() == Some(MEMBERS_GLOB)) {
+        entries.remove(i);
+        let root = path.parent().unwrap_or(Path::new("."));
+        let mut matched: Vec<String> = std::fs::read_dir(root)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|e| e.path())
+            .filter(|dir| dir.join("Cargo.toml").exists())
+            .map(|dir| dir_name(&dir))
+            .filter(|name| name.starts_with(|c: char| c.is_ascii_digit()))
+            .collect();
+        matched.sort();
+        for name in matched {
+            if !entries.iter().any(|e| e.as_str() == Some(name.as_str())) {
+                entries.push(name.into());
+            }
+        }
+        changed = true;
+    }
+    if !entries.iter().any(|e| e.as_str() == Some(entry)) {
+        entries.push(entry.into());
+        changed = true;
+    }
+    if !changed {
+        return Ok(());
+    }
+
+    let contents = toml::to_string_pretty(&manifest)
+        .with_context(|| "Failed to serialize workspace manifest")?;
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// The solution file's contents: `template` rendered, else the default
+/// `main.rs`, or `lib.rs` for a workspace member when `lib` is set.
+pub fn source(detail: &QuestionDetail, template: Option<&str>, lib: bool) -> String {
+    match (template, lib) {
+        (Some(template), _) => super::template::render(template, detail, &snippet(detail, lib)),
+        (None, true) => lib_source(detail),
+        (None, false) => default_source(detail),
+    }
+}
+
+/// `lib.rs` for a workspace member: the snippet plus one test per example,
+/// with the example's input and output to fill in.
+fn lib_source(detail: &QuestionDetail) -> String {
+    let mut src = header(detail);
+    src.push('\n');
+    src.push_str(&snippet(detail, true));
+    src.push('\n');
+
+    src.push_str(&tests(detail));
+    src
+}
+
+/// The test module: one test per example asserting its output when the
+/// signature and values can be written as Rust, a stub to fill in
+/// otherwise.
+fn tests(detail: &QuestionDetail) -> String {
+    let mut src = String::from("\n#[cfg(test)]\nmod tests {\n    use super::*;\n");
+    let examples = super::example_io(detail);
+    if examples.is_empty() {
+        src.push_str("\n    #[test]\n    fn test_solution() {\n");
+        src.push_str("        // TODO: add test cases\n");
+        src.push_str("    }\n");
+    }
+    let signature = signature(detail);
+    let inputs = detail.example_testcase_list.as_deref().unwrap_or_default();
+    for (i, (input, output)) in examples.iter().enumerate() {
+        src.push_str(&format!("\n    // Input: {input}\n    // Output: {output}\n"));
+        src.push_str(&format!("    #[test]\n    fn example_{}() {{\n", i + 1));
+        let body = signature
+            .as_ref()
+            .zip(inputs.get(i))
+            .and_then(|((sig, meta), input)| example_body(sig, meta, input, output));
+        src.push_str(&body.unwrap_or_else(|| "        // TODO: assert_eq!(Solution::..., ...);\n".to_string()));
+        src.push_str("    }\n");
+    }
+    src.push_str("}\n");
+    src
+}
+
+/// A parameter of the Rust snippet's method.
+struct Param {
+    name: String,
+    ty: String,
+}
+
+/// Name and parameters of the method in the Rust snippet.
+struct Signature {
+    name: String,
+    params: Vec<Param>,
+}
+
+/// The Rust snippet's method, with LeetCode's types for it. None for design
+/// problems and problems without a Rust snippet.
+fn signature(detail: &QuestionDetail) -> Option<(Signature, MetaData)> {
+    let meta = detail.meta()?;
+    let snippet = super::pick_snippet(detail, "rust").filter(|s| s.lang_slug == "rust")?;
+    let line = snippet.code.lines().map(str::trim).find(|l| l.starts_with("pub fn "))?;
+    let rest = line.strip_prefix("pub fn ")?;
+    let open = rest.find('(')?;
+    let close = rest.rfind(')')?;
+    let params = super::harness::split_params(&rest[open + 1..close])
+        .into_iter()
+        .map(|p| {
+            let (name, ty) = p.split_once(':')?;
+            Some(Param {
+                name: name.trim().trim_start_matches("mut ").to_string(),
+                ty: ty.trim().to_string(),
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let signature = Signature {
+        name: rest[..open].trim().to_string(),
+        params,
+    };
+    // The snippet's `two_sum` is the metadata's `twoSum`
+    let same_method = signature.name.replace('_', "") == meta.name.to_lowercase();
+    (same_method && signature.params.len() == meta.params.len()).then_some((signature, meta))
+}
+
+/// Statements asserting one example: a `let` per argument, the call, and
+/// the comparison with `output`. Methods returning nothing are checked on
+/// the argument they change.
+fn example_body(sig: &Signature, meta: &MetaData, input: &str, output: &str) -> Option<String> {
+    let values: Vec<&str> = input.lines().filter(|l| !l.trim().is_empty()).collect();
+    if values.len() != sig.params.len() {
+        return None;
+    }
+    let mut body = String::new();
+    let mut args = Vec::new();
+    for ((param, meta), value) in sig.params.iter().zip(&meta.params).zip(values) {
+        let value = literal(&serde_json::from_str(value).ok()?, &meta.ty)?;
+        let name = &param.name;
+        if param.ty.starts_with("&mut ") {
+            body.push_str(&format!("        let mut {name} = {value};\n"));
+            args.push(format!("&mut {name}"));
+        } else if param.ty.starts_with('&') {
+            body.push_str(&format!("        let {name} = {value};\n"));
+            args.push(format!("&{name}"));
+        } else {
+            body.push_str(&format!("        let {name} = {value};\n"));
+            args.push(name.clone());
+        }
+    }
+    let call = format!("Solution::{}({})", sig.name, args.join(", "));
+    let output = serde_json::from_str(output).ok()?;
+    match meta.ret.ty.as_str() {
+        "void" => {
+            // The changed argument is the first one taken by `&mut`
+            let (param, meta) = sig
+                .params
+                .iter()
+                .zip(&meta.params)
+                .find(|(p, _)| p.ty.starts_with("&mut "))?;
+            let expected = literal(&output, &meta.ty)?;
+            body.push_str(&format!("        {call};\n"));
+            body.push_str(&format!("        assert_eq!({}, {expected});\n", param.name));
+        }
+        "double" | "float" => {
+            let expected = literal(&output, &meta.ret.ty)?;
+            body.push_str(&format!("        let result = {call};\n"));
+            body.push_str(&format!(
+                "        assert!((result - {expected}).abs() < 1e-5, \"got {{result}}\");\n"
+            ));
+        }
+        ty => {
+            let expected = literal(&output, ty)?;
+            body.push_str(&format!("        assert_eq!({call}, {expected});\n"));
+        }
+    }
+    Some(body)
+}
+
+/// `value` as a Rust expression of LeetCode's type `ty`. None for types
+/// without a literal form here, like `ListNode` and `TreeNode`.
+fn literal(value: &serde_json::Value, ty: &str) -> Option<String> {
+    Some(match ty {
+        "integer" | "long" => value.as_i64()?.to_string(),
+        "double" | "float" => {
+            let text = value.as_f64()?.to_string();
+            if text.contains(['.', 'e', 'i', 'N']) {
+                text
+            } else {
+                format!("{text}.0")
+            }
+        }
+        "boolean" => value.as_bool()?.to_string(),
+        "string" => format!("{:?}.to_string()", value.as_str()?),
+        "character" => {
+            let mut chars = value.as_str()?.chars();
+            let c = chars.next()?;
+            chars.next().is_none().then_some(())?;
+            format!("{c:?}")
+        }
+        _ => {
+            let inner = ty
+                .strip_suffix("[]")
+                .or_else(|| ty.strip_prefix("list<")?.strip_suffix('>'))?;
+            let items = value
+                .as_array()?
+                .iter()
+                .map(|item| literal(item, inner))
+                .collect::<Option<Vec<_>>>()?;
+            format!("vec![{}]", items.join(", "))
+        }
+    })
+}
+
+fn default_source(detail: &QuestionDetail) -> String {
+    let mut src = header(detail);
+    src.push('\n');
+    src.push_str(&snippet(detail, false));
+    src.push('\n');
+
+    // Main function, and a test per example
+    src.push_str("\nfn main() {\n");
+    src.push_str("    println!(\"Run with: cargo test\");\n");
+    src.push_str("}\n");
+    src.push_str(&tests(detail));
+    src
+}
+
+/// Problem title, link and description as comments.
+fn header(detail: &QuestionDetail) -> String {
     let mut src = String::new();
 
     // Problem description as comments
     src.push_str(&format!("// {}: {}\n", detail.frontend_question_id, detail.title));
     src.push_str(&format!("// Difficulty: {}\n", detail.difficulty));
-    src.push_str(&format!(
-        "// https://leetcode.com/problems/{}/\n",
-        detail.title_slug
-    ));
+    src.push_str(&format!("// {}\n", site().problem_url(&detail.title_slug)));
     src.push_str("//\n");
 
     // Add description as comments
@@ -54,37 +390,203 @@ pub fn scaffold_rust(workspace: &PathBuf, detail: &QuestionDetail) -> Result<Pat
             src.push_str(&format!("// {}\n", line));
         }
     }
+    src
+}
 
-    src.push('\n');
-
-    // Code snippet
-    let snippet = detail
-        .code_snippets
-        .as_ref()
-        .and_then(|snippets| snippets.iter().find(|s| s.lang_slug == "rust"))
-        .map(|s| s.code.as_str())
-        .unwrap_or("// No Rust snippet available for this problem\n");
+/// Code snippet: Rust if available, otherwise another language's template
+/// commented out for reference, otherwise just a note. In a library the
+/// `Solution` shim is `pub` so it doesn't trip dead-code warnings.
+fn snippet(detail: &QuestionDetail, lib: bool) -> String {
+    let snippet = match super::pick_snippet(detail, "rust") {
+        Some(s) if s.lang_slug == "rust" => s.code.clone(),
+        Some(s) => format!(
+            "// No Rust snippet available for this problem.\n// {} template for reference:\n{}",
+            s.lang,
+            s.code
+                .lines()
+                .map(|l| format!("// {l}\n"))
+                .collect::<String>()
+        ),
+        None => "// No code template available for this problem (it may be premium-only)\n".to_string(),
+    };
 
     // Add `struct Solution;` for LSP if snippet uses `impl Solution` but doesn't define the struct
     if snippet.contains("impl Solution") && !snippet.contains("struct Solution") {
-        src.push_str("struct Solution;\n\n");
+        let vis = if lib { "pub " } else { "" };
+        format!("{vis}struct Solution;\n\n{snippet}")
+    } else {
+        snippet
     }
+}
 
-    src.push_str(snippet);
-    src.push('\n');
+/// Extract the solution portion of a Rust file using tree-sitter.
+///
+/// Walks top-level AST nodes and keeps everything except:
+/// - Leading line comments (problem description)
+/// - `struct Solution;` (LSP shim we added)
+/// - `fn main() { ... }`
+/// - `#[cfg(test)] mod tests { ... }`
+pub fn extract_solution(content: &str) -> Result<String> {
+    let mut parser = tree_sitter::Parser::new();
+    let language = tree_sitter_rust::LANGUAGE;
+    parser
+        .set_language(&language.into())
+        .map_err(|e| anyhow::anyhow!("Failed to set tree-sitter language: {e}"))?;
 
-    // Main function with test stub
-    src.push_str("\nfn main() {\n");
-    src.push_str("    println!(\"Run with: cargo test\");\n");
-    src.push_str("}\n");
-    src.push_str("\n#[cfg(test)]\nmod tests {\n    use super::*;\n\n");
-    src.push_str("    #[test]\n    fn test_solution() {\n");
-    src.push_str("        // TODO: add test cases\n");
-    src.push_str("    }\n}\n");
+    let tree = parser
+        .parse(content, None)
+        .ok_or_else(|| anyhow::anyhow!("Failed to parse Rust file"))?;
 
-    let main_rs = project_dir.join("src/main.rs");
-    std::fs::write(&main_rs, src)
-        .with_context(|| format!("Failed to write {}", main_rs.display()))?;
+    let root = tree.root_node();
+    let mut parts: Vec<&str> = Vec::new();
+    let mut in_leading_comments = true;
+    let mut skip_next = false;
 
-    Ok(main_rs)
+    let mut cursor = root.walk();
+    for child in root.children(&mut cursor) {
+        // If the previous node was #[cfg(test)], skip this node (the mod item)
+        if skip_next {
+            skip_next = false;
+            continue;
+        }
+
+        let kind = child.kind();
+        let text = &content[child.byte_range()];
+
+        // Skip leading line comments (problem description block)
+        if in_leading_comments && kind == "line_comment" {
+            continue;
+        }
+        if kind != "line_comment" {
+            in_leading_comments = false;
+        }
+
+        // Skip empty `struct Solution` in any form: `struct Solution;`, `struct Solution {}`, etc.
+        // These are LSP shims — LeetCode provides its own.
+        if kind == "struct_item" {
+            if let Some(name_node) = child.child_by_field_name("name") {
+                let name = &content[name_node.byte_range()];
+                if name == "Solution" {
+                    let has_fields = child.child_by_field_name("body").is_some_and(|body| {
+                        let mut bc = body.walk();
+                        body.children(&mut bc)
+                            .any(|c| c.kind() == "field_declaration")
+                    });
+                    if !has_fields {
+                        continue;
+                    }
+                }
+            }
+        }
+
+        // Skip `fn main() { ... }`
+        if kind == "function_item" {
+            if let Some(name_node) = child.child_by_field_name("name") {
+                if &content[name_node.byte_range()] == "main" {
+                    continue;
+                }
+            }
+        }
+
+        // Skip `#[cfg(test)]` attribute and mark to skip the next item (mod tests)
+        if kind == "attribute_item" && text.contains("cfg") && text.contains("test") {
+            skip_next = true;
+            continue;
+        }
+
+        parts.push(text);
+    }
+
+    let result = parts.join("\n").trim().to_string();
+    if result.is_empty() {
+        // Fallback: return original content if parsing produced nothing
+        Ok(content.to_string())
+    } else {
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail(snippets: serde_json::Value) -> QuestionDetail {
+        serde_json::from_value(serde_json::json!({
+            "questionId": "1",
+            "frontendQuestionId": "1",
+            "title": "Two Sum",
+            "titleSlug": "two-sum",
+            "difficulty": "Easy",
+            "isPaidOnly": false,
+            "topicTags": [],
+            "hints": [],
+            "codeSnippets": snippets,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn snippet_falls_back_to_another_language_then_a_note() {
+        let cpp = detail(serde_json::json!([
+            { "lang": "C++", "langSlug": "cpp", "code": "class Solution {\n};" }
+        ]));
+        assert_eq!(
+            snippet(&cpp, false),
+            "// No Rust snippet available for this problem.\n\
+             // C++ template for reference:\n\
+             // class Solution {\n\
+             // };\n"
+        );
+
+        let premium = snippet(&detail(serde_json::Value::Null), false);
+        assert!(premium.starts_with("// No code template available"));
+        assert!(!premium.contains("struct Solution"));
+    }
+
+    fn members(workspace: &Path, list: &str) -> Vec<String> {
+        let manifest = std::fs::read_to_string(workspace.join("Cargo.toml")).unwrap();
+        let manifest: toml::Table = toml::from_str(&manifest).unwrap();
+        manifest["workspace"]
+            .get(list)
+            .and_then(|l| l.as_array())
+            .map(|l| l.iter().map(|e| e.as_str().unwrap().to_string()).collect())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn workspace_lists_only_rust_members() {
+        let workspace =
+            std::env::temp_dir().join(format!("leetui-rust-members-{}", std::process::id()));
+        let mut two_sum = detail(serde_json::Value::Null);
+        scaffold_rust_member(&workspace, &two_sum, None).unwrap();
+        // Another language's problem directory isn't a crate
+        std::fs::create_dir_all(workspace.join("3-longest-substring")).unwrap();
+        two_sum.frontend_question_id = "2".to_string();
+        two_sum.title_slug = "add-two-numbers".to_string();
+        scaffold_rust_member(&workspace, &two_sum, None).unwrap();
+        scaffold_rust_member(&workspace, &two_sum, None).unwrap();
+        assert_eq!(members(&workspace, "members"), ["1-two-sum", "2-add-two-numbers"]);
+
+        exclude_from_workspace(&workspace, &workspace.join("3-longest-substring/rust")).unwrap();
+        assert_eq!(members(&workspace, "exclude"), ["3-longest-substring/rust"]);
+        std::fs::remove_dir_all(&workspace).unwrap();
+    }
+
+    #[test]
+    fn members_glob_is_replaced_by_the_crates_it_matched() {
+        let workspace =
+            std::env::temp_dir().join(format!("leetui-rust-glob-{}", std::process::id()));
+        std::fs::create_dir_all(workspace.join("2-add-two-numbers")).unwrap();
+        std::fs::write(workspace.join("2-add-two-numbers/Cargo.toml"), "").unwrap();
+        std::fs::create_dir_all(workspace.join("3-longest-substring")).unwrap();
+        std::fs::write(
+            workspace.join("Cargo.toml"),
+            "[workspace]\nresolver = \"3\"\nmembers = [\"[0-9]*\"]\n",
+        )
+        .unwrap();
+
+        scaffold_rust_member(&workspace, &detail(serde_json::Value::Null), None).unwrap();
+        assert_eq!(members(&workspace, "members"), ["1-two-sum", "2-add-two-numbers"]);
+        std::fs::remove_dir_all(&workspace).unwrap();
+    }
 }
@@ -0,0 +1,118 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::api::site::site;
+use crate::api::types::QuestionDetail;
+use crate::config::Config;
+
+/// The user's template for `language`: any file in the templates directory
+/// whose name without extension is the language, e.g. `rust.rs` or
+/// `python3.py`.
+pub fn load(language: &str) -> Option<String> {
+    find(&Config::templates_dir(), language).and_then(|path| std::fs::read_to_string(path).ok())
+}
+
+fn find(dir: &Path, language: &str) -> Option<PathBuf> {
+    let slug = super::lang_slug(language);
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file())
+        .find(|path| {
+            path.file_stem()
+                .and_then(|s| s.to_str())
+                .is_some_and(|stem| stem == language || stem == slug)
+        })
+}
+
+/// Template for a language without a user template: the problem's title,
+/// difficulty and link as comments over LeetCode's snippet, plus the
+/// imports or package clause it needs to build locally. None for
+/// languages there's no solution file for.
+pub fn builtin(language: &str) -> Option<String> {
+    let comment = match language {
+        "python3" | "python" | "ruby" | "elixir" => "#",
+        "erlang" => "%",
+        "racket" => ";",
+        "cpp" | "c++" | "c" | "csharp" | "c#" | "java" | "kotlin" | "scala" | "swift" | "php"
+        | "dart" | "javascript" | "typescript" | "go" | "golang" => "//",
+        _ => return None,
+    };
+    // What the snippet needs to compile on its own, next to the harness
+    let prelude = match language {
+        "java" => "import java.util.*;\n\n",
+        "go" | "golang" => "package main\n\n",
+        _ => "",
+    };
+    Some(format!(
+        "{comment} {{{{id}}}}: {{{{title}}}}\n{comment} Difficulty: {{{{difficulty}}}}\n{comment} {{{{url}}}}\n\n{prelude}{{{{snippet}}}}\n"
+    ))
+}
+
+/// Substitute `{{placeholder}}`s. Unknown placeholders are left as-is.
+pub fn render(template: &str, detail: &QuestionDetail, snippet: &str) -> String {
+    let mut out = String::with_capacity(template.len() + snippet.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+        match value(after[..end].trim(), detail, snippet) {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&rest[start..start + 2 + end + 2]),
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn value(name: &str, detail: &QuestionDetail, snippet: &str) -> Option<String> {
+    Some(match name {
+        "id" => detail.frontend_question_id.clone(),
+        "title" => detail.title.clone(),
+        "slug" => detail.title_slug.clone(),
+        "difficulty" => detail.difficulty.clone(),
+        "url" => site().problem_url(&detail.title_slug),
+        "snippet" => snippet.trim_end().to_string(),
+        "description" => detail
+            .content
+            .as_deref()
+            .and_then(|html| html2text::from_read(html.as_bytes(), 80).ok())
+            .unwrap_or_default()
+            .trim_end()
+            .to_string(),
+        "date" => time::OffsetDateTime::now_utc().date().to_string(),
+        _ => return None,
+    })
+}
+
+/// Scaffold a language other than Rust by writing the rendered template
+/// to its solution file at `path`.
+pub fn scaffold_file(
+    path: &Path,
+    detail: &QuestionDetail,
+    language: &str,
+    template: &str,
+) -> Result<PathBuf> {
+    let path = path.to_path_buf();
+    // Idempotent: never overwrite an existing solution
+    if path.exists() {
+        return Ok(path);
+    }
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create dir {}", dir.display()))?;
+    }
+    let slug = super::lang_slug(language);
+    let snippet = super::pick_snippet(detail, slug)
+        .filter(|s| s.lang_slug == slug)
+        .map(|s| s.code.as_str())
+        .unwrap_or_default();
+    std::fs::write(&path, render(template, detail, snippet))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
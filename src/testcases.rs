@@ -0,0 +1,54 @@
+use std::path::{Path, PathBuf};
+
+use crate::api::types::QuestionDetail;
+use crate::scaffold;
+
+/// Custom testcases for a problem live in `testcases.txt` next to its
+/// solution.
+pub fn testcases_path(workspace: &Path, detail: &QuestionDetail, language: &str) -> PathBuf {
+    scaffold::project_dir(workspace, detail, language).join("testcases.txt")
+}
+
+/// The custom cases with comment lines dropped, if there are any.
+pub fn load(workspace: &Path, detail: &QuestionDetail, language: &str) -> Option<String> {
+    let text = std::fs::read_to_string(testcases_path(workspace, detail, language)).ok()?;
+    let cases: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .collect();
+    (!cases.is_empty()).then(|| cases.join("\n"))
+}
+
+/// Create the testcases file with a comment on its format if it doesn't
+/// exist yet. Returns its path.
+pub fn ensure(workspace: &Path, detail: &QuestionDetail, language: &str) -> std::io::Result<PathBuf> {
+    let path = testcases_path(workspace, detail, language);
+    if !path.exists() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut text = String::from(
+            "# Custom testcases, run before the examples by `r` in leetui.\n\
+             # One argument per line, as on LeetCode; lines starting with # are ignored.\n",
+        );
+        if let Some(example) = detail.sample_cases().first() {
+            text.push_str("#\n# The first example:\n");
+            for line in example.lines() {
+                text.push_str(&format!("# {line}\n"));
+            }
+        }
+        text.push('\n');
+        std::fs::write(&path, text)?;
+    }
+    Ok(path)
+}
+
+/// Input for a run: the custom cases followed by the problem's sample.
+pub fn run_input(workspace: &Path, detail: &QuestionDetail, language: &str) -> String {
+    let sample = detail.sample_input();
+    match load(workspace, detail, language) {
+        Some(custom) if sample.is_empty() => custom,
+        Some(custom) => format!("{custom}\n{sample}"),
+        None => sample,
+    }
+}
@@ -0,0 +1,317 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph},
+};
+use time::{Date, OffsetDateTime};
+
+use crate::attempts::{Attempt, AttemptKind};
+
+use super::status_bar::render_status_bar;
+use super::theme::theme;
+
+const DAY: i64 = 86_400;
+const DIFFICULTIES: [&str; 3] = ["Easy", "Medium", "Hard"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    Week,
+    Month,
+}
+
+impl Period {
+    fn label(self) -> &'static str {
+        match self {
+            Period::Week => "week",
+            Period::Month => "month",
+        }
+    }
+}
+
+/// Progress over time: problems solved per week or month by difficulty,
+/// from the local history, and submissions from LeetCode's calendar.
+pub struct AnalyticsState {
+    /// Day (since the epoch) and difficulty of each problem's first
+    /// accepted submission, oldest first.
+    pub solves: Vec<(i64, String)>,
+    /// Submissions per day, from the user's LeetCode calendar.
+    pub calendar: BTreeMap<i64, u32>,
+    pub period: Period,
+    pub loading: bool,
+}
+
+impl AnalyticsState {
+    /// `attempts` oldest first.
+    pub fn new(attempts: &[Attempt], calendar: BTreeMap<i64, u32>) -> Self {
+        let mut seen = HashSet::new();
+        let solves = attempts
+            .iter()
+            .filter(|a| a.kind == AttemptKind::Submit && a.accepted())
+            .filter(|a| seen.insert(a.slug.clone()))
+            .map(|a| (a.timestamp as i64 / DAY, a.difficulty.clone()))
+            .collect();
+        Self {
+            solves,
+            calendar,
+            period: Period::Week,
+            loading: false,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> AnalyticsAction {
+        match key.code {
+            KeyCode::Char('b') | KeyCode::Esc => AnalyticsAction::Back,
+            KeyCode::Char('q') => AnalyticsAction::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                AnalyticsAction::Quit
+            }
+            KeyCode::Char('w') => {
+                self.period = Period::Week;
+                AnalyticsAction::None
+            }
+            KeyCode::Char('m') => {
+                self.period = Period::Month;
+                AnalyticsAction::None
+            }
+            KeyCode::Tab => {
+                self.period = match self.period {
+                    Period::Week => Period::Month,
+                    Period::Month => Period::Week,
+                };
+                AnalyticsAction::None
+            }
+            KeyCode::Char('r') if !self.loading => {
+                self.loading = true;
+                AnalyticsAction::Refresh
+            }
+            _ => AnalyticsAction::None,
+        }
+    }
+}
+
+pub enum AnalyticsAction {
+    None,
+    Back,
+    Quit,
+    Refresh,
+}
+
+fn date_of(day: i64) -> Date {
+    OffsetDateTime::from_unix_timestamp(day * DAY)
+        .map(|at| at.date())
+        .unwrap_or(Date::MIN)
+}
+
+fn day_of(date: Date) -> i64 {
+    date.midnight().assume_utc().unix_timestamp() / DAY
+}
+
+/// First day of the week (Monday) or month holding `day`.
+fn period_start(day: i64, period: Period) -> i64 {
+    let date = date_of(day);
+    match period {
+        Period::Week => day - date.weekday().number_days_from_monday() as i64,
+        Period::Month => day_of(date.replace_day(1).unwrap_or(date)),
+    }
+}
+
+/// Start of the `count` periods up to and including the one holding
+/// `today`, oldest first.
+fn period_starts(today: i64, period: Period, count: usize) -> Vec<i64> {
+    let mut starts = vec![period_start(today, period)];
+    while starts.len() < count {
+        let last = starts[starts.len() - 1];
+        starts.push(match period {
+            Period::Week => last - 7,
+            Period::Month => period_start(last - 1, period),
+        });
+    }
+    starts.reverse();
+    starts
+}
+
+/// Bucket index of `day` in `starts`, None before the first.
+fn bucket(starts: &[i64], day: i64) -> Option<usize> {
+    starts.iter().rposition(|&start| start <= day)
+}
+
+/// "10/5" for a week, "Oct" for a month; January has the year.
+fn period_label(start: i64, period: Period) -> String {
+    let date = date_of(start);
+    match period {
+        Period::Week => format!("{}/{}", date.month() as u8, date.day()),
+        Period::Month if date.month() == time::Month::January => {
+            format!("Jan'{:02}", date.year() % 100)
+        }
+        Period::Month => {
+            let name = date.month().to_string();
+            name[..3].to_string()
+        }
+    }
+}
+
+fn bar(value: u64, style: Style) -> Bar<'static> {
+    let bar = Bar::default().value(value).style(style);
+    // Empty periods stay blank rather than printing a 0
+    if value == 0 {
+        bar.text_value(String::new())
+    } else {
+        bar
+    }
+}
+
+pub fn render_analytics(frame: &mut Frame, area: Rect, state: &mut AnalyticsState) {
+    let layout = Layout::vertical([
+        Constraint::Length(1),      // title bar
+        Constraint::Percentage(50), // solved chart
+        Constraint::Min(5),         // submissions chart
+        Constraint::Length(1),      // status bar
+    ])
+    .split(area);
+
+    let today = OffsetDateTime::now_utc().unix_timestamp() / DAY;
+    // Three bars of two columns and a gap per period in the solved chart
+    let count = ((area.width.saturating_sub(2)) / 8).clamp(1, 52) as usize;
+    let starts = period_starts(today, state.period, count);
+    let labels: Vec<String> = starts
+        .iter()
+        .map(|&start| period_label(start, state.period))
+        .collect();
+
+    let mut solved = vec![[0u64; 3]; starts.len()];
+    for (day, difficulty) in &state.solves {
+        if let (Some(i), Some(d)) = (
+            bucket(&starts, *day),
+            DIFFICULTIES.iter().position(|x| x == difficulty),
+        ) {
+            solved[i][d] += 1;
+        }
+    }
+    let mut submitted = vec![0u64; starts.len()];
+    for (&day, &n) in &state.calendar {
+        if let Some(i) = bucket(&starts, day) {
+            submitted[i] += n as u64;
+        }
+    }
+
+    let muted = Style::default().fg(theme().muted);
+    let total_solved: u64 = solved.iter().flatten().sum();
+    let total_submitted: u64 = submitted.iter().sum();
+    let title = Line::from(vec![
+        Span::styled(
+            " Analytics ",
+            Style::default()
+                .fg(theme().badge_fg)
+                .bg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!(
+                "per {} \u{b7} last {} {}s: {total_solved} solved, {total_submitted} submissions",
+                state.period.label(),
+                starts.len(),
+                state.period.label()
+            ),
+            muted,
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(title), layout[0]);
+
+    // Solved per period, a bar per difficulty
+    let groups: Vec<BarGroup> = solved
+        .iter()
+        .zip(&labels)
+        .map(|(counts, label)| {
+            let bars: Vec<Bar> = counts
+                .iter()
+                .zip(DIFFICULTIES)
+                .map(|(&n, d)| bar(n, Style::default().fg(theme().difficulty(d))))
+                .collect();
+            BarGroup::new(bars).label(Line::from(label.clone()))
+        })
+        .collect();
+    let legend = Line::from(
+        DIFFICULTIES
+            .iter()
+            .flat_map(|d| {
+                [
+                    Span::styled(" \u{25a0} ", Style::default().fg(theme().difficulty(d))),
+                    Span::styled(*d, muted),
+                ]
+            })
+            .collect::<Vec<_>>(),
+    );
+    let block = Block::default()
+        .title(format!(
+            " Solved per {} (first accepted, from your history) ",
+            state.period.label()
+        ))
+        .title_bottom(legend)
+        .borders(Borders::ALL)
+        .border_style(muted);
+    if state.solves.is_empty() {
+        let empty = Paragraph::new(" Nothing accepted from leetui yet.")
+            .style(muted)
+            .block(block);
+        frame.render_widget(empty, layout[1]);
+    } else {
+        let chart = BarChart::grouped(groups)
+            .block(block)
+            .bar_width(2)
+            .bar_gap(0)
+            .group_gap(2)
+            .value_style(Style::default().fg(theme().badge_fg))
+            .label_style(muted);
+        frame.render_widget(chart, layout[1]);
+    }
+
+    // Submissions per period from the calendar
+    let block = Block::default()
+        .title(format!(
+            " Submissions per {} (LeetCode calendar) ",
+            state.period.label()
+        ))
+        .borders(Borders::ALL)
+        .border_style(muted);
+    if state.calendar.is_empty() {
+        let message = if state.loading {
+            " Loading your submission calendar..."
+        } else {
+            " No submission calendar (sign in; not available on leetcode.cn)."
+        };
+        frame.render_widget(Paragraph::new(message).style(muted).block(block), layout[2]);
+    } else {
+        let bars: Vec<Bar> = submitted
+            .iter()
+            .zip(&labels)
+            .map(|(&n, label)| {
+                bar(n, Style::default().fg(theme().accent)).label(Line::from(label.clone()))
+            })
+            .collect();
+        let chart = BarChart::vertical(bars)
+            .block(block)
+            .bar_width(6)
+            .bar_gap(2)
+            .value_style(Style::default().fg(theme().badge_fg))
+            .label_style(muted);
+        frame.render_widget(chart, layout[2]);
+    }
+
+    render_status_bar(
+        frame,
+        layout[3],
+        &[
+            ("w/m", "Per week/month"),
+            ("r", "Refresh"),
+            ("b/Esc", "Back"),
+            ("q", "Quit"),
+            ("?", "Help"),
+        ],
+    );
+}
@@ -0,0 +1,222 @@
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+/// Split text containing ANSI escape sequences into styled spans. SGR
+/// (color/bold/...) codes are layered on top of `base`; any other escape
+/// sequence is dropped so no raw escape bytes reach the terminal. With
+/// `NO_COLOR` set the codes are stripped and everything keeps `base`.
+pub fn ansi_to_spans(text: &str, base: Style) -> Vec<Span<'static>> {
+    let color = std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty());
+    parse_ansi(text, base, color)
+}
+
+fn parse_ansi(text: &str, base: Style, color: bool) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut style = base;
+    let mut buf = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            buf.push(c);
+            continue;
+        }
+        if chars.peek() != Some(&'[') {
+            // Lone ESC or a non-CSI sequence: skip the next char too
+            chars.next();
+            continue;
+        }
+        chars.next();
+        let mut params = String::new();
+        let mut finale = None;
+        for c in chars.by_ref() {
+            if ('\u{40}'..='\u{7e}').contains(&c) {
+                finale = Some(c);
+                break;
+            }
+            params.push(c);
+        }
+        if finale == Some('m') && color {
+            if !buf.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut buf), style));
+            }
+            style = apply_sgr(style, base, &params);
+        }
+    }
+    if !buf.is_empty() || spans.is_empty() {
+        spans.push(Span::styled(buf, style));
+    }
+    spans
+}
+
+/// Text with every escape sequence removed, e.g. for the clipboard.
+pub fn strip_ansi(text: &str) -> String {
+    ansi_to_spans(text, Style::default())
+        .into_iter()
+        .map(|s| s.content.into_owned())
+        .collect()
+}
+
+/// Inverse of [`ansi_to_spans`]: a rendered line as text with SGR codes,
+/// for printing outside the TUI. Plain text when `color` is false.
+pub fn line_to_ansi(line: &Line, color: bool) -> String {
+    let mut out = String::new();
+    for span in &line.spans {
+        let style = line.style.patch(span.style);
+        let codes = if color { sgr_codes(style) } else { Vec::new() };
+        if codes.is_empty() {
+            out.push_str(&span.content);
+        } else {
+            out.push_str(&format!("\u{1b}[{}m{}\u{1b}[0m", codes.join(";"), span.content));
+        }
+    }
+    out
+}
+
+fn sgr_codes(style: Style) -> Vec<String> {
+    let mut codes = Vec::new();
+    for (modifier, code) in [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+    ] {
+        if style.add_modifier.contains(modifier) {
+            codes.push(code.to_string());
+        }
+    }
+    if let Some(fg) = style.fg.and_then(|c| color_code(c, false)) {
+        codes.push(fg);
+    }
+    if let Some(bg) = style.bg.and_then(|c| color_code(c, true)) {
+        codes.push(bg);
+    }
+    codes
+}
+
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let offset = if background { 10 } else { 0 };
+    let basic = |n: u16| Some((n + offset).to_string());
+    match color {
+        Color::Reset => None,
+        Color::Black => basic(30),
+        Color::Red => basic(31),
+        Color::Green => basic(32),
+        Color::Yellow => basic(33),
+        Color::Blue => basic(34),
+        Color::Magenta => basic(35),
+        Color::Cyan => basic(36),
+        Color::Gray => basic(37),
+        Color::DarkGray => basic(90),
+        Color::LightRed => basic(91),
+        Color::LightGreen => basic(92),
+        Color::LightYellow => basic(93),
+        Color::LightBlue => basic(94),
+        Color::LightMagenta => basic(95),
+        Color::LightCyan => basic(96),
+        Color::White => basic(97),
+        Color::Indexed(i) => Some(format!("{};5;{i}", 38 + offset)),
+        Color::Rgb(r, g, b) => Some(format!("{};2;{r};{g};{b}", 38 + offset)),
+    }
+}
+
+fn apply_sgr(mut style: Style, base: Style, params: &str) -> Style {
+    let codes: Vec<u16> = params
+        .split(';')
+        .map(|p| p.parse().unwrap_or(0))
+        .collect();
+    let mut i = 0;
+    while i < codes.len() {
+        match codes[i] {
+            0 => style = base,
+            1 => style = style.add_modifier(Modifier::BOLD),
+            2 => style = style.add_modifier(Modifier::DIM),
+            3 => style = style.add_modifier(Modifier::ITALIC),
+            4 => style = style.add_modifier(Modifier::UNDERLINED),
+            22 => style = style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style = style.remove_modifier(Modifier::ITALIC),
+            24 => style = style.remove_modifier(Modifier::UNDERLINED),
+            n @ 30..=37 => style.fg = Some(basic_color(n - 30, false)),
+            n @ 90..=97 => style.fg = Some(basic_color(n - 90, true)),
+            39 => style.fg = base.fg,
+            n @ 40..=47 => style.bg = Some(basic_color(n - 40, false)),
+            n @ 100..=107 => style.bg = Some(basic_color(n - 100, true)),
+            49 => style.bg = base.bg,
+            n @ (38 | 48) => {
+                let color = match codes.get(i + 1) {
+                    Some(5) => {
+                        let idx = codes.get(i + 2).copied().unwrap_or(0);
+                        i += 2;
+                        Some(Color::Indexed(idx as u8))
+                    }
+                    Some(2) => {
+                        let rgb = |k: usize| codes.get(i + k).copied().unwrap_or(0) as u8;
+                        let color = Color::Rgb(rgb(2), rgb(3), rgb(4));
+                        i += 4;
+                        Some(color)
+                    }
+                    _ => None,
+                };
+                if n == 38 {
+                    style.fg = color.or(style.fg);
+                } else {
+                    style.bg = color.or(style.bg);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    style
+}
+
+fn basic_color(n: u16, bright: bool) -> Color {
+    match (n, bright) {
+        (0, false) => Color::Black,
+        (1, false) => Color::Red,
+        (2, false) => Color::Green,
+        (3, false) => Color::Yellow,
+        (4, false) => Color::Blue,
+        (5, false) => Color::Magenta,
+        (6, false) => Color::Cyan,
+        (7, false) => Color::Gray,
+        (0, true) => Color::DarkGray,
+        (1, true) => Color::LightRed,
+        (2, true) => Color::LightGreen,
+        (3, true) => Color::LightYellow,
+        (4, true) => Color::LightBlue,
+        (5, true) => Color::LightMagenta,
+        (6, true) => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPILE_ERROR: &str =
+        "\u{1b}[1m\u{1b}[31merror[E0308]\u{1b}[0m: mismatched types\u{1b}[K";
+
+    #[test]
+    fn red_error_becomes_a_red_span_without_escapes() {
+        let spans = parse_ansi(COMPILE_ERROR, Style::default(), true);
+        let error = spans.iter().find(|s| s.content == "error[E0308]").unwrap();
+        assert_eq!(error.style.fg, Some(Color::Red));
+        assert!(error.style.add_modifier.contains(Modifier::BOLD));
+        let rest = spans.last().unwrap();
+        assert_eq!(rest.content, ": mismatched types");
+        assert_eq!(rest.style, Style::default());
+        assert!(spans.iter().all(|s| !s.content.contains('\u{1b}')));
+    }
+
+    #[test]
+    fn without_color_the_codes_are_stripped() {
+        let spans = parse_ansi(COMPILE_ERROR, Style::default(), false);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].content, "error[E0308]: mismatched types");
+        assert_eq!(spans[0].style, Style::default());
+    }
+}
@@ -0,0 +1,364 @@
+use std::sync::OnceLock;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use syntect::parsing::{ParseState, ScopeStack, SyntaxSet};
+
+use crate::api::types::QuestionDetail;
+
+use super::status_bar::render_status_bar;
+
+/// Read-only, highlighted view of a source file.
+pub struct CodeViewState {
+    pub detail: QuestionDetail,
+    /// Badge in front of the title
+    pub label: &'static str,
+    pub title: String,
+    pub lines: Vec<Line<'static>>,
+    pub scroll_offset: u16,
+    pub content_height: u16,
+}
+
+impl CodeViewState {
+    /// `lang` is a LeetCode language slug, used to pick the highlighter.
+    pub fn new(detail: QuestionDetail, title: String, code: &str, lang: &str) -> Self {
+        Self {
+            detail,
+            label: "Solution",
+            title,
+            lines: highlight(code, lang),
+            scroll_offset: 0,
+            content_height: 0,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> CodeViewAction {
+        match key.code {
+            KeyCode::Char('b') | KeyCode::Esc => CodeViewAction::Back,
+            KeyCode::Char('q') => CodeViewAction::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                CodeViewAction::Quit
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.scroll(1);
+                CodeViewAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.scroll(-1);
+                CodeViewAction::None
+            }
+            KeyCode::Char('d') => {
+                self.scroll(self.content_height as i32 / 2);
+                CodeViewAction::None
+            }
+            KeyCode::Char('u') => {
+                self.scroll(-(self.content_height as i32 / 2));
+                CodeViewAction::None
+            }
+            KeyCode::Char('g') => {
+                self.scroll_offset = 0;
+                CodeViewAction::None
+            }
+            KeyCode::Char('G') => {
+                self.scroll_offset = u16::MAX;
+                CodeViewAction::None
+            }
+            _ => CodeViewAction::None,
+        }
+    }
+
+    fn scroll(&mut self, delta: i32) {
+        let new_offset = self.scroll_offset as i32 + delta;
+        self.scroll_offset = new_offset.clamp(0, u16::MAX as i32) as u16;
+    }
+}
+
+pub enum CodeViewAction {
+    None,
+    Back,
+    Quit,
+}
+
+/// Split `code` into styled lines. `lang` is a LeetCode language slug or a
+/// Markdown fence name. Rust is highlighted with tree-sitter, other
+/// languages with syntect's grammars; anything else is shown plain.
+pub fn highlight(code: &str, lang: &str) -> Vec<Line<'static>> {
+    let code = code.replace("\r\n", "\n").replace('\t', "    ");
+    let spans = match lang {
+        "rust" | "rs" => rust_spans(&code),
+        _ => syntect_spans(&code, lang),
+    }
+    .unwrap_or_default();
+
+    let default = Style::default().fg(Color::White);
+    let mut lines = vec![Line::default()];
+    let mut push = |text: &str, style: Style| {
+        for (i, part) in text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(Line::default());
+            }
+            if !part.is_empty() {
+                let line = lines.last_mut().expect("lines is never empty");
+                line.spans.push(Span::styled(part.to_string(), style));
+            }
+        }
+    };
+
+    let mut pos = 0;
+    for (start, end, style) in spans {
+        if start > pos {
+            push(&code[pos..start], default);
+        }
+        push(&code[start..end], style);
+        pos = end;
+    }
+    push(&code[pos..], default);
+
+    if code.ends_with('\n') {
+        lines.pop();
+    }
+    lines
+}
+
+/// Styled byte ranges for Rust source, in order and non-overlapping.
+fn rust_spans(code: &str) -> Option<Vec<(usize, usize, Style)>> {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_rust::LANGUAGE.into())
+        .ok()?;
+    let tree = parser.parse(code, None)?;
+
+    let mut spans = Vec::new();
+    let mut cursor = tree.walk();
+    loop {
+        let node = cursor.node();
+        let style = rust_style(&node);
+        // Comments, strings and attributes are colored as a whole
+        let atomic = style.is_some() && node.child_count() > 0;
+        if let Some(style) = style.filter(|_| atomic || node.child_count() == 0) {
+            spans.push((node.start_byte(), node.end_byte(), style));
+        }
+
+        if !atomic && cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                return Some(spans);
+            }
+        }
+    }
+}
+
+/// Styled byte ranges from syntect's grammar for `lang`, in order and
+/// non-overlapping. None when there's no grammar for it.
+fn syntect_spans(code: &str, lang: &str) -> Option<Vec<(usize, usize, Style)>> {
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    let syntaxes = SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines);
+    let syntax = syntaxes.find_syntax_by_token(syntax_token(lang))?;
+
+    let mut state = ParseState::new(syntax);
+    let mut stack = ScopeStack::new();
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    for line in code.split_inclusive('\n') {
+        let mut pos = 0;
+        for (i, op) in state.parse_line(line, syntaxes).ok()? {
+            if i > pos {
+                if let Some(style) = scope_style(&stack) {
+                    spans.push((offset + pos, offset + i, style));
+                }
+                pos = i;
+            }
+            stack.apply(&op).ok()?;
+        }
+        if line.len() > pos
+            && let Some(style) = scope_style(&stack)
+        {
+            spans.push((offset + pos, offset + line.len(), style));
+        }
+        offset += line.len();
+    }
+    Some(spans)
+}
+
+/// syntect's name for a LeetCode language slug.
+fn syntax_token(lang: &str) -> &str {
+    match lang {
+        "python" | "python3" => "py",
+        "golang" => "go",
+        "csharp" => "cs",
+        "javascript" => "js",
+        "typescript" => "ts",
+        "ruby" => "rb",
+        "mysql" | "mssql" | "oraclesql" | "postgresql" => "sql",
+        "bash" | "shell" => "sh",
+        "c++" => "cpp",
+        other => other,
+    }
+}
+
+/// Color for the innermost scope we have a color for, in the same palette
+/// as the Rust highlighter.
+fn scope_style(stack: &ScopeStack) -> Option<Style> {
+    for scope in stack.as_slice().iter().rev() {
+        let name = scope.build_string();
+        let color = match name.as_str() {
+            s if s.starts_with("comment") => {
+                return Some(
+                    Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::ITALIC),
+                );
+            }
+            s if s.starts_with("string") || s.starts_with("constant.character") => Color::Green,
+            s if s.starts_with("constant.numeric") || s.starts_with("constant.language") => {
+                Color::Magenta
+            }
+            s if s.starts_with("entity.name.function")
+                || s.starts_with("support.function")
+                || s.starts_with("variable.function") =>
+            {
+                Color::Blue
+            }
+            // `def`, `class`, `fn`: keywords, whatever the grammar calls them
+            s if s.starts_with("storage.type.function") || s.starts_with("storage.type.class") => {
+                Color::Yellow
+            }
+            s if s.starts_with("entity.name.type")
+                || s.starts_with("entity.name.class")
+                || s.starts_with("support.type")
+                || s.starts_with("support.class")
+                || s.starts_with("storage.type") =>
+            {
+                Color::Cyan
+            }
+            s if s.starts_with("keyword.operator.word") => Color::Yellow,
+            // Operators and quotes take the color of what they're in
+            s if s.starts_with("keyword.operator") || s.starts_with("punctuation") => continue,
+            s if s.starts_with("keyword") || s.starts_with("storage") => Color::Yellow,
+            s if s.starts_with("variable.language") => Color::Red,
+            _ => continue,
+        };
+        return Some(Style::default().fg(color));
+    }
+    None
+}
+
+fn rust_style(node: &tree_sitter::Node) -> Option<Style> {
+    let color = match node.kind() {
+        "line_comment" | "block_comment" => {
+            return Some(
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
+            );
+        }
+        "string_literal" | "raw_string_literal" | "char_literal" => Color::Green,
+        "integer_literal" | "float_literal" | "boolean_literal" => Color::Magenta,
+        "type_identifier" | "primitive_type" => Color::Cyan,
+        "attribute_item" | "inner_attribute_item" => Color::DarkGray,
+        "lifetime" => Color::LightRed,
+        "self" => Color::Red,
+        "identifier" | "field_identifier" if is_function_name(node) => Color::Blue,
+        "identifier" | "field_identifier" => return None,
+        "!" if node.parent().is_some_and(|p| p.kind() == "macro_invocation") => Color::Blue,
+        kind if !node.is_named() && kind.chars().all(|c| c.is_ascii_lowercase() || c == '_') => {
+            Color::Yellow
+        }
+        _ => return None,
+    };
+    Some(Style::default().fg(color))
+}
+
+/// Name of a function definition, or the callee in a call/macro.
+fn is_function_name(node: &tree_sitter::Node) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        "function_item" | "function_signature_item" => {
+            parent.child_by_field_name("name") == Some(*node)
+        }
+        "call_expression" | "macro_invocation" => parent.child(0) == Some(*node),
+        "field_expression" => parent
+            .parent()
+            .is_some_and(|gp| gp.kind() == "call_expression")
+            && parent.child_by_field_name("field") == Some(*node),
+        _ => false,
+    }
+}
+
+pub fn render_code_view(frame: &mut Frame, area: Rect, state: &mut CodeViewState) {
+    let layout = Layout::vertical([
+        Constraint::Length(2), // title bar
+        Constraint::Min(3),   // code
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    let title = Paragraph::new(Line::from(vec![
+        Span::styled(
+            format!(" {} ", state.label),
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            state.title.clone(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::BOTTOM)
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    frame.render_widget(title, layout[0]);
+
+    state.content_height = layout[1].height;
+    let max_scroll = (state.lines.len() as u16).saturating_sub(state.content_height);
+    state.scroll_offset = state.scroll_offset.min(max_scroll);
+
+    // Line-number gutter
+    let width = state.lines.len().to_string().len();
+    let numbered: Vec<Line> = state
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut spans = vec![Span::styled(
+                format!(" {:>width$} ", i + 1),
+                Style::default().fg(Color::DarkGray),
+            )];
+            spans.extend(line.spans.iter().cloned());
+            Line::from(spans)
+        })
+        .collect();
+
+    let code = Paragraph::new(numbered).scroll((state.scroll_offset, 0));
+    frame.render_widget(code, layout[1]);
+
+    render_status_bar(
+        frame,
+        layout[2],
+        &[
+            ("j/k", "Scroll"),
+            ("d/u", "Half page"),
+            ("g/G", "Top/Bottom"),
+            ("b/Esc", "Back"),
+            ("q", "Quit"),
+            ("?", "Help"),
+        ],
+    );
+}
@@ -0,0 +1,473 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::api::types::{CompanyPeriod, CompanyProblem, CompanyTag};
+
+use super::home::CompanyFilter;
+use super::status_bar::render_status_bar;
+use super::theme::theme;
+
+pub struct CompaniesState {
+    pub companies: Vec<CompanyTag>,
+    /// Indices into `companies` matching the search.
+    pub filtered: Vec<usize>,
+    pub search_query: String,
+    pub search_mode: bool,
+    /// None until the account has been checked.
+    pub premium: Option<bool>,
+    pub loading: bool,
+    pub error_message: Option<String>,
+    pub spinner_frame: usize,
+    pub table_state: TableState,
+    pub period: CompanyPeriod,
+    // The opened company
+    pub viewing: Option<CompanyView>,
+}
+
+pub struct CompanyView {
+    pub company: CompanyTag,
+    pub period: CompanyPeriod,
+    /// None while loading.
+    pub problems: Option<Vec<CompanyProblem>>,
+    pub error_message: Option<String>,
+    pub table_state: TableState,
+}
+
+impl CompanyView {
+    fn move_selection(&mut self, delta: i32) {
+        let len = self.problems.as_ref().map_or(0, Vec::len);
+        if len == 0 {
+            return;
+        }
+        let current = self.table_state.selected().unwrap_or(0) as i32;
+        let next = (current + delta).clamp(0, len as i32 - 1) as usize;
+        self.table_state.select(Some(next));
+    }
+}
+
+impl CompaniesState {
+    pub fn new() -> Self {
+        Self {
+            companies: Vec::new(),
+            filtered: Vec::new(),
+            search_query: String::new(),
+            search_mode: false,
+            premium: None,
+            loading: true,
+            error_message: None,
+            spinner_frame: 0,
+            table_state: TableState::default(),
+            period: CompanyPeriod::default(),
+            viewing: None,
+        }
+    }
+
+    pub fn set_companies(&mut self, companies: Vec<CompanyTag>, premium: bool) {
+        self.companies = companies;
+        self.premium = Some(premium);
+        self.loading = false;
+        self.rebuild_filter();
+    }
+
+    pub fn set_problems(
+        &mut self,
+        slug: &str,
+        period: CompanyPeriod,
+        result: Result<Vec<CompanyProblem>, String>,
+    ) {
+        let Some(ref mut view) = self.viewing else {
+            return;
+        };
+        if view.company.slug != slug || view.period != period {
+            return;
+        }
+        match result {
+            Ok(problems) => {
+                view.table_state
+                    .select(if problems.is_empty() { None } else { Some(0) });
+                view.problems = Some(problems);
+            }
+            Err(e) => view.error_message = Some(e),
+        }
+    }
+
+    fn rebuild_filter(&mut self) {
+        let query = self.search_query.trim().to_lowercase();
+        self.filtered = self
+            .companies
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| query.is_empty() || c.name.to_lowercase().contains(&query))
+            .map(|(i, _)| i)
+            .collect();
+        self.table_state.select(if self.filtered.is_empty() {
+            None
+        } else {
+            Some(0)
+        });
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> CompaniesAction {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return CompaniesAction::Quit;
+        }
+        if self.search_mode {
+            return self.handle_search_key(key);
+        }
+        if let Some(ref mut view) = self.viewing {
+            return match key.code {
+                KeyCode::Esc | KeyCode::Char('b') => {
+                    self.viewing = None;
+                    CompaniesAction::None
+                }
+                KeyCode::Char('q') => CompaniesAction::Quit,
+                KeyCode::Char('j') | KeyCode::Down => {
+                    view.move_selection(1);
+                    CompaniesAction::None
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    view.move_selection(-1);
+                    CompaniesAction::None
+                }
+                KeyCode::Char('p') | KeyCode::Tab => {
+                    view.period = view.period.next();
+                    view.problems = None;
+                    view.error_message = None;
+                    self.period = view.period;
+                    CompaniesAction::OpenCompany(view.company.slug.clone(), view.period)
+                }
+                KeyCode::Char('F') => match view.problems {
+                    Some(ref problems) => CompaniesAction::FilterHome(CompanyFilter {
+                        name: view.company.name.clone(),
+                        period: view.period,
+                        slugs: problems.iter().map(|p| p.title_slug.clone()).collect(),
+                    }),
+                    None => CompaniesAction::None,
+                },
+                KeyCode::Enter => {
+                    let slug = view
+                        .table_state
+                        .selected()
+                        .and_then(|i| view.problems.as_ref()?.get(i))
+                        .map(|p| p.title_slug.clone());
+                    match slug {
+                        Some(slug) => CompaniesAction::OpenDetail(slug),
+                        None => CompaniesAction::None,
+                    }
+                }
+                _ => CompaniesAction::None,
+            };
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') => CompaniesAction::Back,
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.move_selection(1);
+                CompaniesAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.move_selection(-1);
+                CompaniesAction::None
+            }
+            KeyCode::Char('/') => {
+                self.search_mode = true;
+                CompaniesAction::None
+            }
+            KeyCode::Enter => {
+                let Some(company) = self
+                    .table_state
+                    .selected()
+                    .and_then(|i| self.filtered.get(i))
+                    .map(|&i| self.companies[i].clone())
+                else {
+                    return CompaniesAction::None;
+                };
+                let slug = company.slug.clone();
+                let premium = self.premium == Some(true);
+                self.viewing = Some(CompanyView {
+                    company,
+                    period: self.period,
+                    problems: None,
+                    error_message: (!premium)
+                        .then(|| "Company problem lists need LeetCode Premium.".to_string()),
+                    table_state: TableState::default(),
+                });
+                if premium {
+                    CompaniesAction::OpenCompany(slug, self.period)
+                } else {
+                    CompaniesAction::None
+                }
+            }
+            _ => CompaniesAction::None,
+        }
+    }
+
+    fn handle_search_key(&mut self, key: KeyEvent) -> CompaniesAction {
+        match key.code {
+            KeyCode::Esc => {
+                self.search_mode = false;
+                self.search_query.clear();
+                self.rebuild_filter();
+            }
+            KeyCode::Enter => self.search_mode = false,
+            KeyCode::Backspace => {
+                self.search_query.pop();
+                self.rebuild_filter();
+            }
+            KeyCode::Char(c) => {
+                self.search_query.push(c);
+                self.rebuild_filter();
+            }
+            _ => {}
+        }
+        CompaniesAction::None
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        if self.filtered.is_empty() {
+            return;
+        }
+        let current = self.table_state.selected().unwrap_or(0) as i32;
+        let max = self.filtered.len() as i32 - 1;
+        let next = (current + delta).clamp(0, max) as usize;
+        self.table_state.select(Some(next));
+    }
+}
+
+pub enum CompaniesAction {
+    None,
+    Back,
+    Quit,
+    OpenCompany(String, CompanyPeriod),
+    OpenDetail(String),
+    FilterHome(CompanyFilter),
+}
+
+pub fn render_companies(frame: &mut Frame, area: Rect, state: &mut CompaniesState) {
+    let layout = Layout::vertical([
+        Constraint::Length(1), // title bar
+        Constraint::Min(3),    // content
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    let mut spans = vec![
+        Span::styled(
+            " Companies ",
+            Style::default()
+                .fg(theme().badge_fg)
+                .bg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+    ];
+    match state.viewing {
+        Some(ref view) => {
+            spans.push(Span::styled(
+                format!("{} ", view.company.name),
+                Style::default()
+                    .fg(theme().text)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::styled(
+                format!("[{}]", view.period.label()),
+                Style::default().fg(theme().special),
+            ));
+            if let Some(ref problems) = view.problems {
+                spans.push(Span::styled(
+                    format!(" {} problems", problems.len()),
+                    Style::default().fg(theme().muted),
+                ));
+            }
+        }
+        None if state.search_mode || !state.search_query.is_empty() => {
+            let cursor = if state.search_mode { "\u{2588}" } else { "" };
+            spans.push(Span::styled(
+                format!("/{}{cursor}", state.search_query),
+                Style::default().fg(theme().warning),
+            ));
+        }
+        None => {
+            spans.push(Span::styled(
+                format!("{} companies", state.companies.len()),
+                Style::default().fg(theme().muted),
+            ));
+            if state.premium == Some(false) {
+                spans.push(Span::styled(
+                    "  problem lists need Premium",
+                    Style::default().fg(theme().warning),
+                ));
+            }
+        }
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(spans)).style(Style::default().bg(theme().bar_bg)),
+        layout[0],
+    );
+
+    let spinner = [
+        "\u{280b}", "\u{2819}", "\u{2839}", "\u{2838}", "\u{283c}", "\u{2834}", "\u{2826}",
+        "\u{2827}", "\u{2807}", "\u{280f}",
+    ];
+    let s = spinner[state.spinner_frame % spinner.len()];
+    match state.viewing {
+        Some(ref mut view) => match (&view.problems, &view.error_message) {
+            (_, Some(err)) => {
+                let error = Paragraph::new(format!(" Error: {err}"))
+                    .style(Style::default().fg(theme().error));
+                frame.render_widget(error, layout[1]);
+            }
+            (None, None) => {
+                let loading = Paragraph::new(format!(" {s} Loading problems..."))
+                    .style(Style::default().fg(theme().warning));
+                frame.render_widget(loading, layout[1]);
+            }
+            (Some(_), None) => render_problem_table(frame, layout[1], view),
+        },
+        None if state.loading => {
+            let loading = Paragraph::new(format!(" {s} Loading companies..."))
+                .style(Style::default().fg(theme().warning));
+            frame.render_widget(loading, layout[1]);
+        }
+        None => match state.error_message {
+            Some(ref err) => {
+                let error = Paragraph::new(format!(" Error: {err}"))
+                    .style(Style::default().fg(theme().error));
+                frame.render_widget(error, layout[1]);
+            }
+            None => render_company_table(frame, layout[1], state),
+        },
+    }
+
+    let hints = if state.search_mode {
+        vec![("Enter", "Done"), ("Esc", "Clear")]
+    } else if state.viewing.is_some() {
+        vec![
+            ("j/k", "Navigate"),
+            ("Enter", "View"),
+            ("p", "Period"),
+            ("F", "Filter Home"),
+            ("b/Esc", "Back"),
+            ("?", "Help"),
+        ]
+    } else {
+        vec![
+            ("j/k", "Navigate"),
+            ("Enter", "Open"),
+            ("/", "Search"),
+            ("Esc", "Back"),
+            ("?", "Help"),
+        ]
+    };
+    render_status_bar(frame, layout[2], &hints);
+}
+
+fn render_company_table(frame: &mut Frame, area: Rect, state: &mut CompaniesState) {
+    let header = Row::new([Cell::from("Company"), Cell::from("Problems")]).style(
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let rows: Vec<Row> = state
+        .filtered
+        .iter()
+        .map(|&i| {
+            let c = &state.companies[i];
+            Row::new([
+                Cell::from(format!(" {}", c.name)),
+                Cell::from(Span::styled(
+                    c.question_count.to_string(),
+                    Style::default().fg(theme().subtle),
+                )),
+            ])
+        })
+        .collect();
+
+    let widths = [Constraint::Min(24), Constraint::Length(10)];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::NONE))
+        .row_highlight_style(
+            Style::default()
+                .bg(theme().selection)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{25b8} ");
+
+    frame.render_stateful_widget(table, area, &mut state.table_state);
+}
+
+fn render_problem_table(frame: &mut Frame, area: Rect, view: &mut CompanyView) {
+    let Some(ref problems) = view.problems else {
+        return;
+    };
+
+    let header = Row::new([
+        Cell::from(""),
+        Cell::from("#"),
+        Cell::from("Title"),
+        Cell::from("Difficulty"),
+        Cell::from("Frequency"),
+    ])
+    .style(
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let rows: Vec<Row> = problems
+        .iter()
+        .map(|p| {
+            let status = match p.status.as_deref() {
+                Some("ac") => Span::styled(" \u{2714}", Style::default().fg(theme().success)),
+                Some("notac") => Span::styled(" \u{25cf}", Style::default().fg(theme().warning)),
+                _ => Span::raw("  "),
+            };
+            let mut title = vec![Span::raw(p.title.clone())];
+            if p.paid_only {
+                title.push(Span::styled(
+                    " \u{1f512}",
+                    Style::default().fg(theme().warning),
+                ));
+            }
+            let frequency = p.frequency.map(|f| format!("{f:.0}%")).unwrap_or_default();
+            Row::new([
+                Cell::from(status),
+                Cell::from(p.frontend_question_id.clone()),
+                Cell::from(Line::from(title)),
+                Cell::from(Span::styled(
+                    p.difficulty.clone(),
+                    Style::default().fg(theme().difficulty(&p.difficulty)),
+                )),
+                Cell::from(Span::styled(frequency, Style::default().fg(theme().subtle))),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(3),
+        Constraint::Length(6),
+        Constraint::Min(20),
+        Constraint::Length(10),
+        Constraint::Length(10),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::NONE))
+        .row_highlight_style(
+            Style::default()
+                .bg(theme().selection)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{25b8} ");
+
+    frame.render_stateful_widget(table, area, &mut view.table_state);
+}
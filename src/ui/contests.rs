@@ -0,0 +1,513 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+use std::collections::HashMap;
+
+use crate::api::types::{Contest, ContestQuestion, ContestResult};
+use crate::contest::VirtualContest;
+use crate::practice::{format_clock, format_duration};
+
+use super::status_bar::render_status_bar;
+use super::theme::theme;
+
+pub struct ContestsState {
+    pub upcoming: Vec<Contest>,
+    pub past: Vec<Contest>,
+    /// The user's results, keyed by contest title.
+    pub history: HashMap<String, ContestResult>,
+    pub loading: bool,
+    pub error_message: Option<String>,
+    pub spinner_frame: usize,
+    pub table_state: TableState,
+    // Problems of the opened contest
+    pub viewing: Option<ContestView>,
+}
+
+pub struct ContestView {
+    pub contest: Contest,
+    pub upcoming: bool,
+    /// None while loading.
+    pub problems: Option<Vec<ContestQuestion>>,
+    pub error_message: Option<String>,
+    pub table_state: TableState,
+}
+
+impl ContestsState {
+    pub fn new() -> Self {
+        Self {
+            upcoming: Vec::new(),
+            past: Vec::new(),
+            history: HashMap::new(),
+            loading: true,
+            error_message: None,
+            spinner_frame: 0,
+            table_state: TableState::default(),
+            viewing: None,
+        }
+    }
+
+    pub fn set_contests(&mut self, upcoming: Vec<Contest>, past: Vec<Contest>) {
+        self.upcoming = upcoming;
+        self.past = past;
+        self.loading = false;
+        self.error_message = None;
+        let count = self.upcoming.len() + self.past.len();
+        match self.table_state.selected() {
+            _ if count == 0 => self.table_state.select(None),
+            Some(i) if i < count => {}
+            // Start on the most recent past contest, the usual pick
+            _ => self.table_state.select(Some(self.upcoming.len().min(count - 1))),
+        }
+    }
+
+    pub fn set_history(&mut self, history: Vec<ContestResult>) {
+        self.history = history
+            .into_iter()
+            .map(|r| (r.contest.title.clone(), r))
+            .collect();
+    }
+
+    pub fn set_problems(&mut self, slug: &str, result: Result<Vec<ContestQuestion>, String>) {
+        let Some(ref mut view) = self.viewing else {
+            return;
+        };
+        if view.contest.title_slug != slug {
+            return;
+        }
+        match result {
+            Ok(problems) => {
+                view.table_state = TableState::default();
+                if !problems.is_empty() {
+                    view.table_state.select(Some(0));
+                }
+                view.problems = Some(problems);
+            }
+            Err(e) => {
+                view.problems = Some(Vec::new());
+                view.error_message = Some(e);
+            }
+        }
+    }
+
+    fn contest_count(&self) -> usize {
+        self.upcoming.len() + self.past.len()
+    }
+
+    /// The contest at table row `i`: upcoming ones first, then past.
+    fn contest_at(&self, i: usize) -> Option<(&Contest, bool)> {
+        match self.upcoming.get(i) {
+            Some(c) => Some((c, true)),
+            None => self.past.get(i - self.upcoming.len()).map(|c| (c, false)),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> ContestsAction {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return ContestsAction::Quit;
+        }
+        if self.viewing.is_some() {
+            return self.handle_contest_key(key);
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') => ContestsAction::Back,
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.move_selection(1);
+                ContestsAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.move_selection(-1);
+                ContestsAction::None
+            }
+            KeyCode::Char('r') if !self.loading => {
+                self.loading = true;
+                ContestsAction::Refresh
+            }
+            KeyCode::Enter => {
+                let Some((contest, upcoming)) =
+                    self.table_state.selected().and_then(|i| self.contest_at(i))
+                else {
+                    return ContestsAction::None;
+                };
+                let slug = contest.title_slug.clone();
+                self.viewing = Some(ContestView {
+                    contest: contest.clone(),
+                    upcoming,
+                    problems: None,
+                    error_message: None,
+                    table_state: TableState::default(),
+                });
+                ContestsAction::OpenContest(slug)
+            }
+            _ => ContestsAction::None,
+        }
+    }
+
+    fn handle_contest_key(&mut self, key: KeyEvent) -> ContestsAction {
+        let Some(ref mut view) = self.viewing else {
+            return ContestsAction::None;
+        };
+        let count = view.problems.as_ref().map_or(0, Vec::len);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('b') => {
+                self.viewing = None;
+                ContestsAction::None
+            }
+            KeyCode::Char('q') => ContestsAction::Quit,
+            KeyCode::Char('j') | KeyCode::Down if count > 0 => {
+                let i = view.table_state.selected().map_or(0, |i| (i + 1).min(count - 1));
+                view.table_state.select(Some(i));
+                ContestsAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up if count > 0 => {
+                let i = view.table_state.selected().map_or(0, |i| i.saturating_sub(1));
+                view.table_state.select(Some(i));
+                ContestsAction::None
+            }
+            KeyCode::Enter => view
+                .table_state
+                .selected()
+                .and_then(|i| view.problems.as_ref()?.get(i))
+                .map(|q| ContestsAction::OpenDetail(q.title_slug.clone()))
+                .unwrap_or(ContestsAction::None),
+            KeyCode::Char('v') if !view.upcoming && count > 0 => ContestsAction::StartVirtual(
+                view.contest.clone(),
+                view.problems.clone().unwrap_or_default(),
+            ),
+            KeyCode::Char('x') => ContestsAction::EndVirtual,
+            _ => ContestsAction::None,
+        }
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        let count = self.contest_count();
+        if count == 0 {
+            return;
+        }
+        let current = self.table_state.selected().unwrap_or(0) as i32;
+        let next = (current + delta).clamp(0, count as i32 - 1) as usize;
+        self.table_state.select(Some(next));
+    }
+}
+
+pub enum ContestsAction {
+    None,
+    Back,
+    Quit,
+    Refresh,
+    OpenContest(String),
+    OpenDetail(String),
+    StartVirtual(Contest, Vec<ContestQuestion>),
+    EndVirtual,
+}
+
+fn now_unix() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// "2025-03-02 02:30 UTC".
+fn format_start(start: i64) -> String {
+    match time::OffsetDateTime::from_unix_timestamp(start) {
+        Ok(at) => format!("{} {:02}:{:02} UTC", at.date(), at.hour(), at.minute()),
+        Err(_) => String::new(),
+    }
+}
+
+/// "in 2d 03h", "in 45m", "live".
+fn format_countdown(contest: &Contest) -> String {
+    let until = contest.start_time - now_unix();
+    if until <= 0 {
+        return "live".to_string();
+    }
+    let until = until as u64;
+    if until >= 86_400 {
+        format!("in {}d {:02}h", until / 86_400, until % 86_400 / 3600)
+    } else {
+        format!("in {}", format_duration(std::time::Duration::from_secs(until)))
+    }
+}
+
+pub fn render_contests(
+    frame: &mut Frame,
+    area: Rect,
+    state: &mut ContestsState,
+    virtual_contest: Option<&VirtualContest>,
+) {
+    let layout = Layout::vertical([
+        Constraint::Length(1), // title bar
+        Constraint::Min(3),   // content
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    render_title_bar(frame, layout[0], state, virtual_contest);
+
+    if state.loading && state.contest_count() == 0 {
+        let spinner = ["\u{280b}", "\u{2819}", "\u{2839}", "\u{2838}", "\u{283c}", "\u{2834}", "\u{2826}", "\u{2827}", "\u{2807}", "\u{280f}"];
+        let s = spinner[state.spinner_frame % spinner.len()];
+        let loading = Paragraph::new(format!(" {s} Loading contests..."))
+            .style(Style::default().fg(theme().warning));
+        frame.render_widget(loading, layout[1]);
+    } else if let Some(ref err) = state.error_message {
+        let error = Paragraph::new(format!(" Error: {err}"))
+            .style(Style::default().fg(theme().error));
+        frame.render_widget(error, layout[1]);
+    } else if state.viewing.is_some() {
+        render_problem_table(frame, layout[1], state, virtual_contest);
+    } else {
+        render_contest_table(frame, layout[1], state);
+    }
+
+    let hints = match state.viewing {
+        Some(ref view) => {
+            let mut hints = vec![("j/k", "Navigate"), ("Enter", "View")];
+            let running = virtual_contest.is_some_and(|v| !v.is_over());
+            if !view.upcoming && !running {
+                hints.push(("v", "Virtual Contest"));
+            }
+            if virtual_contest.is_some() {
+                hints.push(("x", "End Virtual"));
+            }
+            hints.extend([("b/Esc", "Back"), ("?", "Help")]);
+            hints
+        }
+        None => vec![
+            ("j/k", "Navigate"),
+            ("Enter", "Open"),
+            ("r", "Refresh"),
+            ("Esc", "Back"),
+            ("?", "Help"),
+        ],
+    };
+    render_status_bar(frame, layout[2], &hints);
+}
+
+fn render_title_bar(
+    frame: &mut Frame,
+    area: Rect,
+    state: &ContestsState,
+    virtual_contest: Option<&VirtualContest>,
+) {
+    let mut spans = vec![
+        Span::styled(
+            " Contests ",
+            Style::default()
+                .fg(theme().badge_fg)
+                .bg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+    ];
+
+    match state.viewing {
+        Some(ref view) => spans.push(Span::styled(
+            format!("{} ", view.contest.title),
+            Style::default()
+                .fg(theme().text)
+                .add_modifier(Modifier::BOLD),
+        )),
+        None => spans.push(Span::styled(
+            format!("{} upcoming, {} past ", state.upcoming.len(), state.past.len()),
+            Style::default().fg(theme().muted),
+        )),
+    }
+
+    if let Some(v) = virtual_contest {
+        let clock = if v.is_over() {
+            "finished".to_string()
+        } else {
+            format!("{} left", format_clock(v.remaining()))
+        };
+        spans.push(Span::styled(
+            format!(
+                " Virtual: {}  {clock}  score {}  {}/{} solved",
+                v.contest.title,
+                v.score(),
+                v.solved_count(),
+                v.problems.len()
+            ),
+            Style::default().fg(theme().warning),
+        ));
+    }
+
+    let title = Paragraph::new(Line::from(spans)).style(Style::default().bg(theme().bar_bg));
+    frame.render_widget(title, area);
+}
+
+fn render_contest_table(frame: &mut Frame, area: Rect, state: &mut ContestsState) {
+    let header = Row::new([
+        Cell::from("Contest"),
+        Cell::from("Start"),
+        Cell::from("Length"),
+        Cell::from("Your Result"),
+    ])
+    .style(
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let upcoming = state.upcoming.iter().map(|c| {
+        Row::new([
+            Cell::from(Span::styled(
+                format!(" {}", c.title),
+                Style::default().fg(theme().success),
+            )),
+            Cell::from(format_start(c.start_time)),
+            Cell::from(format_duration(std::time::Duration::from_secs(
+                c.duration.max(0) as u64,
+            ))),
+            Cell::from(Span::styled(
+                format_countdown(c),
+                Style::default().fg(theme().success),
+            )),
+        ])
+    });
+    let past = state.past.iter().map(|c| {
+        let result = match state.history.get(&c.title) {
+            Some(r) => Span::styled(
+                format!("#{}  {}/{}", r.ranking, r.problems_solved, r.total_problems),
+                Style::default().fg(theme().special),
+            ),
+            None => Span::styled("-", Style::default().fg(theme().muted)),
+        };
+        Row::new([
+            Cell::from(format!(" {}", c.title)),
+            Cell::from(Span::styled(
+                format_start(c.start_time),
+                Style::default().fg(theme().subtle),
+            )),
+            Cell::from(format_duration(std::time::Duration::from_secs(
+                c.duration.max(0) as u64,
+            ))),
+            Cell::from(result),
+        ])
+    });
+    let rows: Vec<Row> = upcoming.chain(past).collect();
+
+    let widths = [
+        Constraint::Min(24),
+        Constraint::Length(22),
+        Constraint::Length(8),
+        Constraint::Length(16),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::NONE))
+        .row_highlight_style(
+            Style::default()
+                .bg(theme().selection)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{25b8} ");
+
+    frame.render_stateful_widget(table, area, &mut state.table_state);
+}
+
+fn render_problem_table(
+    frame: &mut Frame,
+    area: Rect,
+    state: &mut ContestsState,
+    virtual_contest: Option<&VirtualContest>,
+) {
+    let Some(ref mut view) = state.viewing else {
+        return;
+    };
+
+    let Some(ref problems) = view.problems else {
+        let spinner = ["\u{280b}", "\u{2819}", "\u{2839}", "\u{2838}", "\u{283c}", "\u{2834}", "\u{2826}", "\u{2827}", "\u{2807}", "\u{280f}"];
+        let s = spinner[state.spinner_frame % spinner.len()];
+        let loading = Paragraph::new(format!(" {s} Loading problems..."))
+            .style(Style::default().fg(theme().warning));
+        frame.render_widget(loading, area);
+        return;
+    };
+    if let Some(ref err) = view.error_message {
+        let error = Paragraph::new(format!(" Error: {err}"))
+            .style(Style::default().fg(theme().error));
+        frame.render_widget(error, area);
+        return;
+    }
+    if problems.is_empty() {
+        let msg = if view.upcoming {
+            " Problems are revealed when the contest starts."
+        } else {
+            " No problems found for this contest."
+        };
+        let empty = Paragraph::new(msg).style(Style::default().fg(theme().muted));
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    // Only the contest being run virtually has verdicts to show
+    let virtual_contest =
+        virtual_contest.filter(|v| v.contest.title_slug == view.contest.title_slug);
+
+    let header = Row::new([
+        Cell::from(" #"),
+        Cell::from("Credit"),
+        Cell::from("Title"),
+        Cell::from("Virtual"),
+    ])
+    .style(
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let rows: Vec<Row> = problems
+        .iter()
+        .enumerate()
+        .map(|(i, q)| {
+            let status = match virtual_contest {
+                Some(v) => match v.solved_at(&q.title_slug) {
+                    Some(at) => Span::styled(
+                        format!("\u{2714} {}", format_clock(at)),
+                        Style::default().fg(theme().success),
+                    ),
+                    None => match v.wrong_attempts(&q.title_slug) {
+                        0 => Span::raw(""),
+                        n => Span::styled(
+                            format!("\u{2718} {n}"),
+                            Style::default().fg(theme().error),
+                        ),
+                    },
+                },
+                None => Span::raw(""),
+            };
+            Row::new([
+                Cell::from(format!(" {}", i + 1)),
+                Cell::from(format!("{}", q.credit)),
+                Cell::from(q.title.clone()),
+                Cell::from(status),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(4),
+        Constraint::Length(7),
+        Constraint::Min(20),
+        Constraint::Length(12),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::NONE))
+        .row_highlight_style(
+            Style::default()
+                .bg(theme().selection)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{25b8} ");
+
+    frame.render_stateful_widget(table, area, &mut view.table_state);
+}
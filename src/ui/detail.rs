@@ -1,50 +1,381 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
+    buffer::Buffer,
     layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, Paragraph, Tabs, Wrap},
     Frame,
 };
+use std::ops::Range;
 
-use crate::api::types::QuestionDetail;
+use crate::api::site::site;
+use crate::api::types::{CompanyStat, QuestionDetail, QuestionStats, SimilarQuestion};
+use crate::attempts::{Attempt, AttemptKind};
+use crate::practice;
 
-use super::rich_text::html_to_lines;
+use super::graphics::Protocol;
+use super::hyperlink::{self, Link};
+use super::result::{ResultAction, ResultState, render_inline_result};
+use super::rich_text::{
+    ConstraintsBlock, RenderOptions, Section, example_starts, extract_constraints,
+    html_to_lines, image_urls, looks_truncated, section_starts,
+};
+use super::search::{SearchInput, TextSearch};
 use super::status_bar::render_status_bar;
+use super::submissions::format_age;
+use super::theme::theme;
+
+/// The problem's place among the same-difficulty problems of the filtered
+/// home set, used to step to the previous/next one without leaving detail.
+#[derive(Debug, Clone)]
+pub struct DifficultyNav {
+    pub slugs: Vec<String>,
+    pub position: usize,
+}
+
+impl DifficultyNav {
+    /// Slug of the neighbor in the given direction, wrapping at the ends.
+    pub fn neighbor(&self, forward: bool) -> Option<&str> {
+        let len = self.slugs.len();
+        if len < 2 {
+            return None;
+        }
+        let idx = if forward {
+            (self.position + 1) % len
+        } else {
+            (self.position + len - 1) % len
+        };
+        self.slugs.get(idx).map(String::as_str)
+    }
+}
+
+/// The statement's images, shown one at a time over the detail view on
+/// terminals with a graphics protocol.
+pub struct ImageView {
+    pub protocol: Protocol,
+    pub index: usize,
+    /// The image once fetched, or why it couldn't be.
+    pub image: Option<Result<Vec<u8>, String>>,
+    /// Where the image goes, set on render.
+    pub area: Rect,
+    /// Whether the image has been drawn over the current screen.
+    pub drawn: bool,
+}
 
 pub struct DetailState {
     pub detail: QuestionDetail,
     pub content_lines: Vec<Line<'static>>,
     pub scroll_offset: u16,
     pub content_height: u16,
+    pub search: TextSearch,
+    // Where the title bar and statement were drawn last frame
+    pub title_area: Rect,
+    pub content_area: Rect,
+    pub nav: Option<DifficultyNav>,
+    pub constraints: Option<ConstraintsBlock>,
+    pub constraints_panel: bool,
+    /// Start line of each example, and the one last jumped to.
+    pub examples: Vec<usize>,
+    pub example_idx: usize,
+    /// Where Description, Examples, Constraints and Follow-up start.
+    pub sections: Vec<(Section, usize)>,
+    // Lines moved into the constraints panel on the last frame
+    hidden_lines: Range<usize>,
+    // The view not currently shown (notes while reading the problem, or the
+    // problem while reading notes) and its scroll position
+    other_lines: Option<Vec<Line<'static>>>,
+    other_scroll: u16,
+    pub showing_notes: bool,
+    pub acceptance: Option<QuestionStats>,
+    /// Companies that asked it lately (premium only).
+    pub companies: Vec<CompanyStat>,
+    pub similar: Vec<SimilarQuestion>,
+    /// Selected row of the similar-questions popup, while it's open.
+    pub similar_picker: Option<usize>,
+    /// Each hint, rendered; only the first `hints_shown` are visible.
+    pub hints: Vec<Vec<Line<'static>>>,
+    pub hints_shown: usize,
+    /// Run/submit verdict shown under the statement in `inline` result mode.
+    pub inline_result: Option<Box<ResultState>>,
+    /// Image URLs in the statement, numbered like their placeholders.
+    pub images: Vec<String>,
+    pub image_view: Option<ImageView>,
+    /// Runs and submissions made from leetui, newest first.
+    pub attempts: Vec<Attempt>,
 }
 
+/// Minimum terminal width for showing constraints in a side panel.
+const CONSTRAINTS_PANEL_MIN_WIDTH: u16 = 110;
+
 impl DetailState {
-    pub fn new(detail: QuestionDetail) -> Self {
-        let content_lines = if detail.is_paid_only && detail.content.is_none() {
+    pub fn new(detail: QuestionDetail, options: RenderOptions) -> Self {
+        let hints = detail
+            .hints
+            .iter()
+            .map(|hint| html_to_lines(hint, options.clone()))
+            .collect();
+        let mut content_lines = if detail.is_paid_only && detail.content.is_none() {
             vec![Line::from(Span::styled(
                 " Premium content — not available without authentication.",
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme().warning),
             ))]
         } else if let Some(ref html) = detail.content {
-            html_to_lines(html)
+            html_to_lines(html, options)
         } else {
             vec![Line::from(Span::styled(
                 " No content available.",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme().muted),
             ))]
         };
 
+        let constraints = extract_constraints(&content_lines);
+        let examples = example_starts(&content_lines);
+        let sections = section_starts(&content_lines);
+
+        if detail.content.as_deref().is_some_and(looks_truncated) {
+            content_lines.push(Line::from(""));
+            content_lines.push(Line::from(Span::styled(
+                " Content may be incomplete.",
+                Style::default()
+                    .fg(theme().muted)
+                    .add_modifier(Modifier::ITALIC),
+            )));
+        }
+
+        let similar = detail.similar();
+        if !similar.is_empty() {
+            content_lines.push(Line::from(""));
+            content_lines.push(Line::from(Span::styled(
+                "Similar Questions",
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            for q in &similar {
+                content_lines.push(Line::from(vec![
+                    Span::styled("  \u{2022} ", Style::default().fg(theme().muted)),
+                    Span::raw(q.title.clone()),
+                    Span::styled(
+                        format!("  {}", q.difficulty),
+                        Style::default().fg(theme().difficulty(&q.difficulty)),
+                    ),
+                ]));
+            }
+        }
+
         Self {
+            acceptance: detail.acceptance(),
+            companies: detail.recent_companies(),
+            similar,
+            similar_picker: None,
+            images: detail.content.as_deref().map(image_urls).unwrap_or_default(),
+            image_view: None,
+            hints,
+            hints_shown: 0,
             detail,
             content_lines,
             scroll_offset: 0,
             content_height: 0,
+            search: TextSearch::default(),
+            title_area: Rect::default(),
+            content_area: Rect::default(),
+            nav: None,
+            constraints,
+            constraints_panel: true,
+            examples,
+            example_idx: 0,
+            sections,
+            hidden_lines: 0..0,
+            other_lines: None,
+            other_scroll: 0,
+            showing_notes: false,
+            inline_result: None,
+            attempts: Vec::new(),
+        }
+    }
+
+    pub fn set_notes(&mut self, notes: Option<String>) {
+        let lines = notes.map(|text| notes_to_lines(&text));
+        if !self.showing_notes {
+            self.other_lines = lines;
+            return;
+        }
+        match lines {
+            Some(lines) => self.content_lines = lines,
+            // Notes were emptied while shown: back to the problem
+            None => {
+                self.toggle_notes();
+                self.other_lines = None;
+            }
+        }
+    }
+
+    pub fn has_notes(&self) -> bool {
+        self.showing_notes || self.other_lines.is_some()
+    }
+
+    /// Flip between the problem statement and the notes. No-op without notes.
+    pub fn toggle_notes(&mut self) {
+        let Some(ref mut other) = self.other_lines else {
+            return;
+        };
+        std::mem::swap(&mut self.content_lines, other);
+        std::mem::swap(&mut self.scroll_offset, &mut self.other_scroll);
+        self.showing_notes = !self.showing_notes;
+    }
+
+    /// Example navigation needs at least two examples and the problem view.
+    pub fn has_example_tabs(&self) -> bool {
+        self.examples.len() >= 2 && !self.showing_notes
+    }
+
+    /// Step to the previous/next example and scroll to its heading.
+    pub fn jump_example(&mut self, forward: bool) {
+        if !self.has_example_tabs() {
+            return;
+        }
+        self.example_idx = if forward {
+            (self.example_idx + 1).min(self.examples.len() - 1)
+        } else {
+            self.example_idx.saturating_sub(1)
+        };
+        self.scroll_offset = self.examples[self.example_idx] as u16;
+    }
+
+    /// Statement line `line` as a scroll offset, skipping the lines shown in
+    /// the constraints panel.
+    fn display_line(&self, line: usize) -> usize {
+        if line >= self.hidden_lines.end {
+            line - self.hidden_lines.len()
+        } else {
+            line
+        }
+    }
+
+    /// Scroll to the start of the `n`th section. Constraints shown in the
+    /// side panel are already in view.
+    pub fn jump_section(&mut self, n: usize) {
+        if self.showing_notes {
+            return;
+        }
+        if let Some(&(_, line)) = self.sections.get(n)
+            && !self.hidden_lines.contains(&line)
+        {
+            self.scroll_offset = self.display_line(line) as u16;
+        }
+    }
+
+    /// Index of the section the top of the view is in.
+    pub fn current_section(&self) -> usize {
+        self.sections
+            .iter()
+            .rposition(|&(_, line)| self.display_line(line) <= self.scroll_offset as usize)
+            .unwrap_or(0)
+    }
+
+    /// Reveal the next hint; once all are shown, collapse them again.
+    pub fn next_hint(&mut self) {
+        self.hints_shown = if self.hints_shown < self.hints.len() {
+            self.hints_shown + 1
+        } else {
+            0
+        };
+    }
+
+    /// Open the image viewer on the first image, returning its URL to fetch.
+    pub fn open_images(&mut self, protocol: Protocol) -> Option<String> {
+        let url = self.images.first()?.clone();
+        self.image_view = Some(ImageView {
+            protocol,
+            index: 0,
+            image: None,
+            area: Rect::default(),
+            drawn: false,
+        });
+        Some(url)
+    }
+
+    /// Store a fetched image if the viewer is still showing it.
+    pub fn set_image(&mut self, url: &str, result: Result<Vec<u8>, String>) {
+        if let Some(ref mut view) = self.image_view
+            && self.images.get(view.index).is_some_and(|u| u == url)
+        {
+            view.image = Some(result);
+            view.drawn = false;
+        }
+    }
+
+    fn handle_image_key(&mut self, key: KeyEvent) -> DetailAction {
+        let Some(ref mut view) = self.image_view else {
+            return DetailAction::None;
+        };
+        let step = match key.code {
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('n') => 1,
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('p') => -1,
+            KeyCode::Char('o') => return DetailAction::OpenUrl(self.images[view.index].clone()),
+            KeyCode::Esc | KeyCode::Char('i') | KeyCode::Char('b') | KeyCode::Char('q') => {
+                self.image_view = None;
+                return DetailAction::CloseImages;
+            }
+            _ => return DetailAction::None,
+        };
+        let index = view.index as i32 + step;
+        if index < 0 || index as usize >= self.images.len() {
+            return DetailAction::None;
         }
+        view.index = index as usize;
+        view.image = None;
+        view.drawn = false;
+        DetailAction::LoadImage(self.images[view.index].clone())
+    }
+
+    fn handle_similar_key(&mut self, key: KeyEvent, selected: usize) -> DetailAction {
+        let len = self.similar.len();
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.similar_picker = Some((selected + 1).min(len - 1));
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.similar_picker = Some(selected.saturating_sub(1));
+            }
+            KeyCode::Enter => {
+                self.similar_picker = None;
+                return DetailAction::OpenDetail(self.similar[selected].title_slug.clone());
+            }
+            KeyCode::Esc | KeyCode::Char('S') | KeyCode::Char('q') => self.similar_picker = None,
+            _ => {}
+        }
+        DetailAction::None
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) -> DetailAction {
+        if let Some(selected) = self.similar_picker {
+            return self.handle_similar_key(key, selected);
+        }
+        if self.image_view.is_some() {
+            return self.handle_image_key(key);
+        }
+        if self.search.editing {
+            if let SearchInput::Changed = self.search.handle_input(key, &self.content_lines) {
+                self.scroll_to_match(self.search.current_line(&self.content_lines));
+            }
+            return DetailAction::None;
+        }
         match key.code {
+            KeyCode::Char('/') => {
+                self.search.start(self.scroll_offset as usize);
+                DetailAction::None
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') if self.search.is_active() => {
+                let line = self.search.step(&self.content_lines, key.code == KeyCode::Char('n'));
+                self.scroll_to_match(line);
+                DetailAction::None
+            }
+            KeyCode::Esc if self.search.is_active() => {
+                self.search.clear();
+                DetailAction::None
+            }
             KeyCode::Char('b') | KeyCode::Esc => DetailAction::Back,
             KeyCode::Char('j') | KeyCode::Down => {
                 self.scroll(1);
@@ -66,10 +397,76 @@ impl DetailState {
             KeyCode::Char('a') => DetailAction::AddToList(self.detail.question_id.clone()),
             KeyCode::Char('r') => DetailAction::RunCode,
             KeyCode::Char('s') => DetailAction::SubmitCode,
+            KeyCode::Char('p') => DetailAction::Scratchpad,
+            // While a result is shown inline, e/y/t act on it as on the
+            // result screen: expand, copy, debug the failing case
+            KeyCode::Char('e' | 'y' | 't') if self.inline_result.is_some() => {
+                match self.inline_result.as_mut().map(|result| result.handle_key(key)) {
+                    Some(ResultAction::Copy(text)) => DetailAction::CopyResult(text),
+                    Some(ResultAction::DebugInput(input)) => DetailAction::DebugInput(input),
+                    _ => DetailAction::None,
+                }
+            }
+            KeyCode::Char('y') => DetailAction::CopyPath,
+            KeyCode::Char('H') => DetailAction::Submissions,
+            KeyCode::Char('P') => DetailAction::PullAccepted,
+            KeyCode::Char('D') => DetailAction::DiffAccepted,
+            KeyCode::Char('v') => DetailAction::ViewSolution,
+            KeyCode::Char('e') => DetailAction::OpenEditorial,
+            KeyCode::Char('C') => DetailAction::CommunitySolutions,
+            KeyCode::Char('h') if !self.hints.is_empty() => {
+                self.next_hint();
+                DetailAction::None
+            }
+            KeyCode::Char('S') if !self.similar.is_empty() => {
+                self.similar_picker = Some(0);
+                DetailAction::None
+            }
+            KeyCode::Char('l') => DetailAction::LocalTest,
+            KeyCode::Char('R') => DetailAction::Reload(self.detail.title_slug.clone()),
+            KeyCode::Char('n') => {
+                self.toggle_notes();
+                DetailAction::None
+            }
+            KeyCode::Char('N') => DetailAction::EditNotes,
+            KeyCode::Char('t') => DetailAction::EditTestcases,
+            KeyCode::Char('E') => DetailAction::EditBuiltin,
+            KeyCode::Char('O') => DetailAction::OpenInBrowser(self.detail.title_slug.clone()),
+            KeyCode::Char('i') if !self.images.is_empty() => DetailAction::ViewImages,
+            KeyCode::Char('T') => DetailAction::ToggleTimer,
+            KeyCode::Char('J') | KeyCode::Char('K') => {
+                if let Some(ref mut result) = self.inline_result {
+                    result.scroll(if key.code == KeyCode::Char('J') { 1 } else { -1 });
+                }
+                DetailAction::None
+            }
+            KeyCode::Char('x') => {
+                self.inline_result = None;
+                DetailAction::None
+            }
+            KeyCode::Left | KeyCode::Right => {
+                self.jump_example(key.code == KeyCode::Right);
+                DetailAction::None
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                self.jump_section(c as usize - '1' as usize);
+                DetailAction::None
+            }
+            KeyCode::Char(']') | KeyCode::Char('[') => {
+                let forward = key.code == KeyCode::Char(']');
+                match self.nav.as_ref().and_then(|n| n.neighbor(forward)) {
+                    Some(slug) => DetailAction::OpenDetail(slug.to_string()),
+                    None => DetailAction::None,
+                }
+            }
             KeyCode::Char('q') => DetailAction::Quit,
             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 DetailAction::Quit
             }
+            KeyCode::Char('c') => {
+                self.constraints_panel = !self.constraints_panel;
+                DetailAction::None
+            }
             _ => DetailAction::None,
         }
     }
@@ -78,6 +475,13 @@ impl DetailState {
         let new_offset = self.scroll_offset as i32 + delta;
         self.scroll_offset = new_offset.max(0) as u16;
     }
+
+    /// Bring a search match into view, with a little context above it.
+    fn scroll_to_match(&mut self, line: Option<usize>) {
+        if let Some(line) = line {
+            self.scroll_offset = line.saturating_sub(2) as u16;
+        }
+    }
 }
 
 pub enum DetailAction {
@@ -88,11 +492,37 @@ pub enum DetailAction {
     AddToList(String),
     RunCode,
     SubmitCode,
+    Scratchpad,
+    OpenDetail(String),
+    CopyPath,
+    CopyResult(String),
+    DebugInput(String),
+    Reload(String),
+    Submissions,
+    PullAccepted,
+    DiffAccepted,
+    ViewSolution,
+    OpenEditorial,
+    CommunitySolutions,
+    LocalTest,
+    EditNotes,
+    EditTestcases,
+    EditBuiltin,
+    ToggleTimer,
+    OpenInBrowser(String),
+    ViewImages,
+    LoadImage(String),
+    CloseImages,
+    OpenUrl(String),
 }
 
 pub fn render_detail(frame: &mut Frame, area: Rect, state: &mut DetailState) {
+    let title_height = 3
+        + state.acceptance.is_some() as u16
+        + !state.companies.is_empty() as u16
+        + !state.attempts.is_empty() as u16;
     let layout = Layout::vertical([
-        Constraint::Length(3), // title bar
+        Constraint::Length(title_height), // title bar
         Constraint::Min(3),   // content
         Constraint::Length(1), // status bar
     ])
@@ -100,21 +530,71 @@ pub fn render_detail(frame: &mut Frame, area: Rect, state: &mut DetailState) {
 
     // Title bar
     render_detail_title(frame, layout[0], state);
+    state.title_area = layout[0];
+
+    // Inline run/submit result takes the lower part of the content area
+    let body = if let Some(ref mut result) = state.inline_result {
+        let rows = Layout::vertical([Constraint::Min(5), Constraint::Percentage(40)])
+            .split(layout[1]);
+        render_inline_result(frame, rows[1], result);
+        rows[0]
+    } else {
+        layout[1]
+    };
+
+    // Revealed hints under the statement
+    let body = if state.hints_shown > 0 {
+        let height = hints_height(state, body.width).min(body.height / 2);
+        let rows = Layout::vertical([Constraint::Min(3), Constraint::Length(height)]).split(body);
+        render_hints(frame, rows[1], state);
+        rows[0]
+    } else {
+        body
+    };
+
+    // Example strip above the statement
+    let body = if state.has_example_tabs() {
+        let rows = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).split(body);
+        render_example_tabs(frame, rows[0], state);
+        rows[1]
+    } else {
+        body
+    };
 
-    // Content area
-    state.content_height = layout[1].height;
+    // Content area, with constraints pinned to the right on wide terminals
+    let panel = state.constraints.as_ref().filter(|_| {
+        state.constraints_panel && !state.showing_notes && area.width >= CONSTRAINTS_PANEL_MIN_WIDTH
+    });
+    let (content_area, panel_area) = if panel.is_some() {
+        let cols = Layout::horizontal([Constraint::Min(40), Constraint::Percentage(35)])
+            .split(body);
+        (cols[0], Some(cols[1]))
+    } else {
+        (body, None)
+    };
+    let hidden = panel.map(|c| c.range.clone()).unwrap_or(0..0);
+    state.hidden_lines = hidden.clone();
+
+    state.content_height = content_area.height;
+    state.content_area = content_area;
 
-    let total_lines = state.content_lines.len() as u16;
+    let total_lines = (state.content_lines.len() - hidden.len()) as u16;
     let max_scroll = total_lines.saturating_sub(state.content_height);
     if state.scroll_offset > max_scroll {
         state.scroll_offset = max_scroll;
     }
 
     // Add left padding to each line
-    let padded_lines: Vec<Line> = state
-        .content_lines
+    let lines = if state.search.is_active() {
+        state.search.highlight(&state.content_lines)
+    } else {
+        state.content_lines.clone()
+    };
+    let padded_lines: Vec<Line> = lines
         .iter()
-        .map(|line| {
+        .enumerate()
+        .filter(|(i, _)| !hidden.contains(i))
+        .map(|(_, line)| {
             let mut spans = vec![Span::raw("  ")];
             spans.extend(line.spans.iter().cloned());
             Line::from(spans)
@@ -126,7 +606,11 @@ pub fn render_detail(frame: &mut Frame, area: Rect, state: &mut DetailState) {
         .wrap(Wrap { trim: false })
         .scroll((state.scroll_offset, 0));
 
-    frame.render_widget(content, layout[1]);
+    frame.render_widget(content, content_area);
+
+    if let (Some(block), Some(panel_area)) = (panel, panel_area) {
+        render_constraints_panel(frame, panel_area, block);
+    }
 
     // Scroll indicator
     if total_lines > state.content_height {
@@ -137,49 +621,369 @@ pub fn render_detail(frame: &mut Frame, area: Rect, state: &mut DetailState) {
         };
         let indicator = format!(" {}% ", pct);
         let ind_area = Rect::new(
-            layout[1].right().saturating_sub(indicator.len() as u16 + 1),
-            layout[1].y,
+            content_area.right().saturating_sub(indicator.len() as u16 + 1),
+            content_area.y,
             indicator.len() as u16,
             1,
         );
         frame.render_widget(
-            Paragraph::new(indicator).style(Style::default().fg(Color::DarkGray)),
+            Paragraph::new(indicator).style(Style::default().fg(theme().muted)),
             ind_area,
         );
     }
 
     // Status bar
-    render_status_bar(
-        frame,
-        layout[2],
-        &[
-            ("j/k", "Scroll"),
-            ("d/u", "Half page"),
-            ("o", "Open"),
-            ("a", "Add to List"),
-            ("r", "Run"),
-            ("s", "Submit"),
-            ("b/Esc", "Back"),
-            ("q", "Quit"),
-            ("?", "Help"),
-        ],
+    let mut hints = vec![
+        ("j/k", "Scroll"),
+        ("d/u", "Half page"),
+        ("o", "Open"),
+        ("a", "Add to List"),
+        ("r", "Run"),
+        ("s", "Submit"),
+        ("l", "Test locally against the examples"),
+        ("p", "Scratchpad"),
+        ("[/]", "Prev/Next"),
+        ("c", "Constraints"),
+    ];
+    if state.has_example_tabs() {
+        hints.push(("\u{2190}/\u{2192}", "Examples"));
+    }
+    if state.has_notes() {
+        hints.push(("n", if state.showing_notes { "Problem" } else { "Notes" }));
+    }
+    hints.push(("N", "Edit notes"));
+    if state.inline_result.is_some() {
+        hints.extend([
+            ("J/K", "Scroll result"),
+            ("e", "Expand"),
+            ("y", "Copy result"),
+            ("t", "Debug case"),
+            ("x", "Close result"),
+        ]);
+    }
+    if !state.hints.is_empty() {
+        hints.push(("h", "Hint"));
+    }
+    if !state.similar.is_empty() {
+        hints.push(("S", "Similar"));
+    }
+    if !state.images.is_empty() {
+        hints.push(("i", "Images"));
+    }
+    hints.extend([("/", "Search"), ("b/Esc", "Back"), ("q", "Quit"), ("?", "Help")]);
+    if state.search.is_active() {
+        state.search.render(frame, layout[2], &state.content_lines);
+    } else {
+        render_status_bar(frame, layout[2], &hints);
+    }
+
+    if let Some(selected) = state.similar_picker {
+        render_similar_popup(frame, area, &state.similar, selected);
+    }
+    if let Some(ref mut view) = state.image_view {
+        render_image_view(frame, area, view, &state.images);
+    }
+}
+
+/// Frame of the image viewer. The image itself is drawn over the blank
+/// inner area after the frame is flushed.
+fn render_image_view(frame: &mut Frame, area: Rect, view: &mut ImageView, images: &[String]) {
+    let popup_area = Rect::new(
+        area.x + 2,
+        area.y + 1,
+        area.width.saturating_sub(4),
+        area.height.saturating_sub(2),
+    );
+    frame.render_widget(Clear, popup_area);
+
+    let url = &images[view.index];
+    let block = Block::default()
+        .title(format!(" Image {}/{} ", view.index + 1, images.len()))
+        .title_bottom(" \u{2190}/\u{2192}: prev/next  o: open in browser  Esc: close ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().special));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let [caption, image_area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).areas(inner);
+    frame.render_widget(
+        Paragraph::new(Span::styled(url.as_str(), Style::default().fg(theme().muted))),
+        caption,
+    );
+    let message = match view.image {
+        None => Some(Span::styled("Loading\u{2026}", Style::default().fg(theme().warning))),
+        Some(Err(ref e)) => Some(Span::styled(
+            format!("Can't show this image: {e}"),
+            Style::default().fg(theme().error),
+        )),
+        Some(Ok(_)) => None,
+    };
+    if let Some(message) = message {
+        frame.render_widget(Paragraph::new(message), image_area);
+    }
+    if view.area != image_area {
+        view.area = image_area;
+        view.drawn = false;
+    }
+}
+
+/// Rows the revealed hints take at `width`, border included.
+fn hints_height(state: &DetailState, width: u16) -> u16 {
+    let width = width.saturating_sub(2).max(1) as usize;
+    let rows: usize = state.hints[..state.hints_shown]
+        .iter()
+        .map(|lines| {
+            lines
+                .iter()
+                .map(|l| l.width().div_ceil(width).max(1))
+                .sum::<usize>()
+                + 1
+        })
+        .sum();
+    rows as u16 + 1
+}
+
+fn render_hints(frame: &mut Frame, area: Rect, state: &DetailState) {
+    let label = Style::default()
+        .fg(theme().warning)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = Vec::new();
+    for (i, hint) in state.hints[..state.hints_shown].iter().enumerate() {
+        lines.push(Line::from(Span::styled(format!("Hint {}", i + 1), label)));
+        lines.extend(hint.iter().cloned());
+    }
+
+    let more = if state.hints_shown < state.hints.len() {
+        "h: next hint"
+    } else {
+        "h: hide hints"
+    };
+    let p = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(format!(
+                    " Hints {}/{} \u{b7} {more} ",
+                    state.hints_shown,
+                    state.hints.len()
+                ))
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(theme().muted)),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(p, area);
+}
+
+fn render_similar_popup(
+    frame: &mut Frame,
+    area: Rect,
+    similar: &[SimilarQuestion],
+    selected: usize,
+) {
+    let popup_width = 56u16.min(area.width.saturating_sub(4));
+    let popup_height = (similar.len() as u16 + 2).min(area.height.saturating_sub(4));
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    // Keep the selection visible when the list is taller than the popup
+    let visible = popup_height.saturating_sub(2) as usize;
+    let skip = (selected + 1).saturating_sub(visible);
+    let lines: Vec<Line> = similar
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(i, q)| {
+            let highlight = i == selected;
+            let style = if highlight {
+                Style::default()
+                    .fg(theme().text)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme().text)
+            };
+            Line::from(vec![
+                Span::styled(if highlight { "\u{25b8} " } else { "  " }, style),
+                Span::styled(q.title.clone(), style),
+                Span::styled(
+                    format!("  {}", q.difficulty),
+                    Style::default().fg(theme().difficulty(&q.difficulty)),
+                ),
+            ])
+        })
+        .collect();
+
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Similar Questions ")
+            .title_bottom(" Enter: open  Esc: close ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme().special)),
     );
+    frame.render_widget(popup, popup_area);
+}
+
+fn render_example_tabs(frame: &mut Frame, area: Rect, state: &DetailState) {
+    let tabs = Tabs::new((1..=state.examples.len()).map(|n| format!("Example {n}")))
+        .select(state.example_idx)
+        .style(Style::default().fg(theme().muted))
+        .highlight_style(
+            Style::default()
+                .fg(theme().warning)
+                .add_modifier(Modifier::BOLD),
+        );
+    frame.render_widget(tabs, area);
+}
+
+/// Plain rendering of markdown notes: headings highlighted, the rest as-is.
+fn notes_to_lines(text: &str) -> Vec<Line<'static>> {
+    text.lines()
+        .map(|line| {
+            if line.starts_with('#') {
+                Line::from(Span::styled(
+                    line.trim_start_matches('#').trim().to_string(),
+                    Style::default()
+                        .fg(theme().accent)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(line.to_string(), Style::default().fg(theme().text)))
+            }
+        })
+        .collect()
+}
+
+fn render_constraints_panel(frame: &mut Frame, area: Rect, block: &ConstraintsBlock) {
+    let lines: Vec<Line> = block
+        .items
+        .iter()
+        .map(|item| {
+            let mut spans = vec![Span::styled(" \u{2022} ", Style::default().fg(theme().accent))];
+            spans.extend(item.spans.iter().cloned());
+            Line::from(spans)
+        })
+        .collect();
+
+    let p = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Constraints ")
+                .borders(Borders::LEFT)
+                .border_style(Style::default().fg(theme().muted)),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(p, area);
+}
+
+fn acceptance_line(stats: &QuestionStats) -> Line<'static> {
+    let count = |display: &str, raw: u64| {
+        if display.is_empty() {
+            raw.to_string()
+        } else {
+            display.to_string()
+        }
+    };
+    let label = Style::default().fg(theme().muted);
+    let value = Style::default().fg(theme().accent);
+    Line::from(vec![
+        Span::styled(" Accepted ", label),
+        Span::styled(count(&stats.total_accepted, stats.total_accepted_raw), value),
+        Span::styled(" / Submitted ", label),
+        Span::styled(count(&stats.total_submission, stats.total_submission_raw), value),
+        Span::styled(" \u{b7} ", label),
+        Span::styled(format!("{:.1}%", stats.rate()), value),
+    ])
+}
+
+/// Most frequent companies first; the rest are counted, not named.
+const COMPANIES_SHOWN: usize = 6;
+
+fn companies_line(companies: &[CompanyStat]) -> Line<'static> {
+    let label = Style::default().fg(theme().muted);
+    let mut spans = vec![Span::styled(" Asked at (6 mo) ", label)];
+    for (i, c) in companies.iter().take(COMPANIES_SHOWN).enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" \u{b7} ", label));
+        }
+        spans.push(Span::styled(c.name.clone(), Style::default().fg(theme().special)));
+        spans.push(Span::styled(format!(" \u{d7}{}", c.times_encountered), label));
+    }
+    if companies.len() > COMPANIES_SHOWN {
+        spans.push(Span::styled(
+            format!("  +{} more", companies.len() - COMPANIES_SHOWN),
+            label,
+        ));
+    }
+    Line::from(spans)
+}
+
+/// The runs and submissions made at the problem, e.g. "4 runs · 2 submits
+/// · last: Wrong Answer, 3h ago · accepted 25m after scaffolding".
+/// `attempts` is newest first.
+fn attempts_line(attempts: &[Attempt], now: u64) -> Line<'static> {
+    let label = Style::default().fg(theme().muted);
+    let runs = attempts.iter().filter(|a| a.kind == AttemptKind::Run).count();
+    let submits = attempts.len() - runs;
+    let plural = |n: usize| if n == 1 { "" } else { "s" };
+    let mut spans = vec![
+        Span::styled(" Attempts ", label),
+        Span::styled(
+            format!("{runs} run{} \u{b7} {submits} submit{}", plural(runs), plural(submits)),
+            Style::default().fg(theme().text),
+        ),
+    ];
+    if let Some(last) = attempts.first() {
+        let verdict = if last.accepted() { theme().success } else { theme().error };
+        spans.push(Span::styled(" \u{b7} last: ", label));
+        spans.push(Span::styled(last.verdict.clone(), Style::default().fg(verdict)));
+        spans.push(Span::styled(format!(", {}", format_age(last.timestamp, now)), label));
+    }
+    // The first accepted submission is the one that solved it
+    let solved = attempts
+        .iter()
+        .rev()
+        .find(|a| a.kind == AttemptKind::Submit && a.accepted());
+    if let Some(secs) = solved.and_then(|a| a.since_scaffold) {
+        spans.push(Span::styled(
+            format!(
+                " \u{b7} accepted {} after scaffolding",
+                practice::format_duration(std::time::Duration::from_secs(secs))
+            ),
+            label,
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Links on the last frame: the title to the problem's page, and any URL
+/// printed in the statement.
+pub fn links(state: &DetailState, buffer: &Buffer) -> Vec<Link> {
+    if state.image_view.is_some() {
+        return Vec::new();
+    }
+    let d = &state.detail;
+    let title = format!("{}. {}", d.frontend_question_id, d.title);
+    let url = site().problem_url(&d.title_slug);
+    let area = state.title_area;
+    let mut links: Vec<Link> = (area.top()..area.bottom())
+        .find_map(|y| hyperlink::find(buffer, area, y, &title, &url))
+        .into_iter()
+        .collect();
+    links.extend(hyperlink::urls(buffer, state.content_area));
+    links
 }
 
 fn render_detail_title(frame: &mut Frame, area: Rect, state: &DetailState) {
     let d = &state.detail;
-    let diff_color = match d.difficulty.as_str() {
-        "Easy" => Color::Green,
-        "Medium" => Color::Yellow,
-        "Hard" => Color::Red,
-        _ => Color::White,
-    };
+    let diff_color = theme().difficulty(&d.difficulty);
 
     let mut title_spans = vec![
         Span::styled(
             format!(" {}. {} ", d.frontend_question_id, d.title),
             Style::default()
-                .fg(Color::White)
+                .fg(theme().text)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::styled(
@@ -193,15 +997,51 @@ fn render_detail_title(frame: &mut Frame, area: Rect, state: &DetailState) {
     match d.status.as_deref() {
         Some("ac") => title_spans.push(Span::styled(
             " \u{2714} Solved",
-            Style::default().fg(Color::Green),
+            Style::default().fg(theme().success),
         )),
         Some("notac") => title_spans.push(Span::styled(
             " \u{25cf} Attempted",
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme().warning),
         )),
         _ => {}
     }
 
+    if state.has_notes() {
+        let active = Style::default()
+            .fg(theme().special)
+            .add_modifier(Modifier::BOLD);
+        let inactive = Style::default().fg(theme().muted);
+        let (problem_style, notes_style) = if state.showing_notes {
+            (inactive, active)
+        } else {
+            (active, inactive)
+        };
+        title_spans.push(Span::raw("  "));
+        title_spans.push(Span::styled("Problem", problem_style));
+        title_spans.push(Span::styled(" | ", Style::default().fg(theme().muted)));
+        title_spans.push(Span::styled("Notes", notes_style));
+    }
+
+    // Sections of the statement, numbered by the keys that jump to them
+    if state.sections.len() > 1 && !state.showing_notes {
+        let current = state.current_section();
+        title_spans.push(Span::styled("  \u{2502}", Style::default().fg(theme().muted)));
+        for (i, (section, _)) in state.sections.iter().enumerate() {
+            let style = if i == current {
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme().subtle)
+            };
+            title_spans.push(Span::styled(
+                format!(" {}", i + 1),
+                Style::default().fg(theme().muted),
+            ));
+            title_spans.push(Span::styled(format!(" {}", section.label()), style));
+        }
+    }
+
     let title_line = Line::from(title_spans);
 
     let tags: Vec<Span> = d
@@ -212,8 +1052,8 @@ fn render_detail_title(frame: &mut Frame, area: Rect, state: &DetailState) {
             let mut spans = vec![Span::styled(
                 format!(" {} ", t.name),
                 Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::DarkGray),
+                    .fg(theme().badge_fg)
+                    .bg(theme().surface),
             )];
             if i < d.topic_tags.len() - 1 {
                 spans.push(Span::raw(" "));
@@ -225,12 +1065,182 @@ fn render_detail_title(frame: &mut Frame, area: Rect, state: &DetailState) {
     let mut tags_line_spans = vec![Span::styled(" ", Style::default())];
     tags_line_spans.extend(tags);
 
-    let title_block = Paragraph::new(vec![title_line, Line::from(tags_line_spans)])
+    let mut lines = vec![title_line, Line::from(tags_line_spans)];
+    if let Some(ref stats) = state.acceptance {
+        lines.push(acceptance_line(stats));
+    }
+    if !state.companies.is_empty() {
+        lines.push(companies_line(&state.companies));
+    }
+    if !state.attempts.is_empty() {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        lines.push(attempts_line(&state.attempts, now));
+    }
+
+    let title_block = Paragraph::new(lines)
         .block(
             Block::default()
                 .borders(Borders::BOTTOM)
-                .border_style(Style::default().fg(Color::DarkGray)),
+                .border_style(Style::default().fg(theme().muted)),
         );
 
     frame.render_widget(title_block, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail() -> QuestionDetail {
+        serde_json::from_value(serde_json::json!({
+            "questionId": "1",
+            "frontendQuestionId": "1",
+            "title": "Two Sum",
+            "titleSlug": "two-sum",
+            "difficulty": "Easy",
+            "isPaidOnly": false,
+            "topicTags": [],
+            "hints": [],
+            "content": "<p>Given an array of integers.</p>",
+        }))
+        .unwrap()
+    }
+
+    fn shown(state: &DetailState) -> String {
+        state
+            .content_lines
+            .iter()
+            .flat_map(|line| &line.spans)
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    fn nav(position: usize) -> DifficultyNav {
+        DifficultyNav {
+            slugs: ["a", "b", "c"].map(String::from).to_vec(),
+            position,
+        }
+    }
+
+    #[test]
+    fn neighbor_steps_and_wraps_at_the_ends() {
+        assert_eq!(nav(1).neighbor(true), Some("c"));
+        assert_eq!(nav(1).neighbor(false), Some("a"));
+        assert_eq!(nav(2).neighbor(true), Some("a"));
+        assert_eq!(nav(0).neighbor(false), Some("c"));
+    }
+
+    #[test]
+    fn neighbor_needs_another_problem() {
+        let alone = DifficultyNav {
+            slugs: vec!["a".to_string()],
+            position: 0,
+        };
+        assert_eq!(alone.neighbor(true), None);
+        assert_eq!(alone.neighbor(false), None);
+    }
+
+    #[test]
+    fn n_switches_between_the_statement_and_notes() {
+        let mut state = DetailState::new(detail(), RenderOptions::default());
+        let n = KeyEvent::from(KeyCode::Char('n'));
+        // Nothing to switch to without notes
+        state.handle_key(n);
+        assert!(!state.showing_notes);
+        assert!(shown(&state).contains("Given an array"));
+
+        state.set_notes(Some("Use a hash map of complements.".to_string()));
+        assert!(state.has_notes());
+        state.scroll_offset = 3;
+        state.handle_key(n);
+        assert!(state.showing_notes);
+        assert!(shown(&state).contains("hash map of complements"));
+        assert!(!shown(&state).contains("Given an array"));
+        assert_eq!(state.scroll_offset, 0);
+
+        state.handle_key(n);
+        assert!(!state.showing_notes);
+        assert!(shown(&state).contains("Given an array"));
+        assert_eq!(state.scroll_offset, 3);
+    }
+
+    #[test]
+    fn inline_result_keys_act_on_the_result_while_shown() {
+        use crate::ui::result::{ResultData, ResultKind};
+
+        let mut state = DetailState::new(detail(), RenderOptions::default());
+        let key = |c| KeyEvent::from(KeyCode::Char(c));
+        assert!(matches!(state.handle_key(key('y')), DetailAction::CopyPath));
+
+        let mut result = ResultState::new(ResultKind::Submit, "1. Two Sum".into(), detail());
+        result.set_result(ResultData {
+            status_msg: "Wrong Answer".to_string(),
+            status_code: 11,
+            total_correct: Some(3),
+            total_testcases: Some(10),
+            runtime: None,
+            memory: None,
+            code_output: Some(vec!["[1,0]".to_string()]),
+            expected_output: Some("[0,1]".to_string()),
+            last_testcase: Some("[2,7,11,15]\n9".to_string()),
+            compile_error: None,
+        });
+        state.inline_result = Some(Box::new(result));
+
+        let DetailAction::DebugInput(input) = state.handle_key(key('t')) else {
+            panic!("t should debug the failing case");
+        };
+        assert_eq!(input, "[2,7,11,15]\n9");
+        let DetailAction::CopyResult(text) = state.handle_key(key('y')) else {
+            panic!("y should copy the result");
+        };
+        assert!(text.starts_with("Wrong Answer"));
+        assert!(matches!(state.handle_key(key('e')), DetailAction::None));
+        assert!(state.inline_result.as_ref().unwrap().expanded);
+
+        // Closing the result gives the keys back
+        state.handle_key(key('x'));
+        assert!(matches!(state.handle_key(key('t')), DetailAction::EditTestcases));
+    }
+
+    #[test]
+    fn arrows_jump_to_each_example_heading() {
+        let mut two_sum = detail();
+        two_sum.content = Some(
+            "<p>Given an array of integers.</p>\
+             <p><strong>Example 1:</strong></p><pre>Input: nums = [2,7]\nOutput: [0,1]</pre>\
+             <p><strong>Example 2:</strong></p><pre>Input: nums = [3,3]\nOutput: [0,1]</pre>\
+             <p><strong>Example 3:</strong></p><pre>Input: nums = [3,2,4]\nOutput: [1,2]</pre>"
+                .to_string(),
+        );
+        let mut state = DetailState::new(two_sum, RenderOptions::default());
+        assert_eq!(state.examples.len(), 3);
+        for (n, &start) in state.examples.iter().enumerate() {
+            let heading: String = state.content_lines[start]
+                .spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect();
+            assert_eq!(heading.trim(), format!("Example {}:", n + 1));
+        }
+
+        let (left, right) = (KeyEvent::from(KeyCode::Left), KeyEvent::from(KeyCode::Right));
+        state.handle_key(right);
+        assert_eq!((state.example_idx, state.scroll_offset), (1, state.examples[1] as u16));
+        state.handle_key(right);
+        state.handle_key(right);
+        assert_eq!((state.example_idx, state.scroll_offset), (2, state.examples[2] as u16));
+        state.handle_key(left);
+        state.handle_key(left);
+        state.handle_key(left);
+        assert_eq!((state.example_idx, state.scroll_offset), (0, state.examples[0] as u16));
+
+        // Fewer than two examples get no tabs and the arrows do nothing
+        let mut state = DetailState::new(detail(), RenderOptions::default());
+        assert!(!state.has_example_tabs());
+        state.handle_key(right);
+        assert_eq!(state.scroll_offset, 0);
+    }
+}
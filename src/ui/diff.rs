@@ -0,0 +1,286 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::api::types::QuestionDetail;
+
+use super::status_bar::render_status_bar;
+use super::theme::theme;
+
+/// One line of a line-by-line diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Line diff from `old` to `new`, by longest common subsequence. Trailing
+/// whitespace is ignored when matching lines up.
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<Change<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let same = |i: usize, j: usize| old[i].trim_end() == new[j].trim_end();
+
+    // lcs[i][j]: length of the LCS of old[i..] and new[j..]
+    let mut lcs = vec![vec![0u32; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if same(i, j) {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if same(i, j) {
+            changes.push(Change::Same(new[j]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            changes.push(Change::Removed(old[i]));
+            i += 1;
+        } else {
+            changes.push(Change::Added(new[j]));
+            j += 1;
+        }
+    }
+    changes.extend(old[i..].iter().map(|l| Change::Removed(l)));
+    changes.extend(new[j..].iter().map(|l| Change::Added(l)));
+    changes
+}
+
+/// A colored diff between two versions of a solution.
+pub struct DiffState {
+    pub detail: QuestionDetail,
+    pub title: String,
+    pub lines: Vec<Line<'static>>,
+    /// First line of each run of changes, for `n`/`N`
+    pub hunks: Vec<usize>,
+    pub added: usize,
+    pub removed: usize,
+    pub scroll_offset: u16,
+    pub content_height: u16,
+}
+
+impl DiffState {
+    /// Diff from `old` to `new`, numbered by their own lines.
+    pub fn new(detail: QuestionDetail, title: String, old: &str, new: &str) -> Self {
+        let changes = diff_lines(old, new);
+        let old_width = old.lines().count().to_string().len();
+        let new_width = new.lines().count().to_string().len();
+        let muted = Style::default().fg(theme().muted);
+
+        let mut lines = Vec::with_capacity(changes.len());
+        let mut hunks = Vec::new();
+        let (mut added, mut removed) = (0, 0);
+        let (mut old_no, mut new_no) = (0, 0);
+        let mut in_hunk = false;
+        for change in &changes {
+            let (old_col, new_col, sign, text, style) = match *change {
+                Change::Same(text) => {
+                    old_no += 1;
+                    new_no += 1;
+                    (
+                        old_no.to_string(),
+                        new_no.to_string(),
+                        ' ',
+                        text,
+                        Style::default().fg(theme().text),
+                    )
+                }
+                Change::Removed(text) => {
+                    old_no += 1;
+                    removed += 1;
+                    (
+                        old_no.to_string(),
+                        String::new(),
+                        '-',
+                        text,
+                        Style::default().fg(theme().error),
+                    )
+                }
+                Change::Added(text) => {
+                    new_no += 1;
+                    added += 1;
+                    (
+                        String::new(),
+                        new_no.to_string(),
+                        '+',
+                        text,
+                        Style::default().fg(theme().success),
+                    )
+                }
+            };
+            let changed = !matches!(change, Change::Same(_));
+            if changed && !in_hunk {
+                hunks.push(lines.len());
+            }
+            in_hunk = changed;
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!(" {old_col:>old_width$} {new_col:>new_width$} "),
+                    muted,
+                ),
+                Span::styled(format!("{sign} "), style.add_modifier(Modifier::BOLD)),
+                Span::styled(text.replace('\t', "    "), style),
+            ]));
+        }
+
+        Self {
+            detail,
+            title,
+            lines,
+            hunks,
+            added,
+            removed,
+            scroll_offset: 0,
+            content_height: 0,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> DiffAction {
+        match key.code {
+            KeyCode::Char('b') | KeyCode::Esc => DiffAction::Back,
+            KeyCode::Char('q') => DiffAction::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => DiffAction::Quit,
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.scroll(1);
+                DiffAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.scroll(-1);
+                DiffAction::None
+            }
+            KeyCode::Char('d') => {
+                self.scroll(self.content_height as i32 / 2);
+                DiffAction::None
+            }
+            KeyCode::Char('u') => {
+                self.scroll(-(self.content_height as i32 / 2));
+                DiffAction::None
+            }
+            KeyCode::Char('g') => {
+                self.scroll_offset = 0;
+                DiffAction::None
+            }
+            KeyCode::Char('G') => {
+                self.scroll_offset = u16::MAX;
+                DiffAction::None
+            }
+            KeyCode::Char('n') => {
+                self.jump_to_hunk(true);
+                DiffAction::None
+            }
+            KeyCode::Char('N') => {
+                self.jump_to_hunk(false);
+                DiffAction::None
+            }
+            _ => DiffAction::None,
+        }
+    }
+
+    fn scroll(&mut self, delta: i32) {
+        let new_offset = self.scroll_offset as i32 + delta;
+        self.scroll_offset = new_offset.clamp(0, u16::MAX as i32) as u16;
+    }
+
+    /// Scroll the next (or previous) change to the top, with a couple of
+    /// lines of context above it.
+    fn jump_to_hunk(&mut self, forward: bool) {
+        const CONTEXT: usize = 2;
+        let top = self.scroll_offset as usize + CONTEXT;
+        let target = if forward {
+            self.hunks.iter().find(|&&h| h > top)
+        } else {
+            self.hunks.iter().rev().find(|&&h| h < top)
+        };
+        if let Some(&line) = target {
+            self.scroll_offset = line.saturating_sub(CONTEXT) as u16;
+        }
+    }
+}
+
+pub enum DiffAction {
+    None,
+    Back,
+    Quit,
+}
+
+pub fn render_diff(frame: &mut Frame, area: Rect, state: &mut DiffState) {
+    let layout = Layout::vertical([
+        Constraint::Length(2), // title bar
+        Constraint::Min(3),    // diff
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    let mut title = vec![
+        Span::styled(
+            " Diff ",
+            Style::default()
+                .fg(theme().badge_fg)
+                .bg(theme().warning)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            state.title.clone(),
+            Style::default()
+                .fg(theme().text)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw("  "),
+    ];
+    if state.hunks.is_empty() {
+        title.push(Span::styled(
+            "no changes",
+            Style::default().fg(theme().muted),
+        ));
+    } else {
+        title.push(Span::styled(
+            format!("+{}", state.added),
+            Style::default().fg(theme().success),
+        ));
+        title.push(Span::raw(" "));
+        title.push(Span::styled(
+            format!("-{}", state.removed),
+            Style::default().fg(theme().error),
+        ));
+    }
+    let title = Paragraph::new(Line::from(title)).block(
+        Block::default()
+            .borders(Borders::BOTTOM)
+            .border_style(Style::default().fg(theme().muted)),
+    );
+    frame.render_widget(title, layout[0]);
+
+    state.content_height = layout[1].height;
+    let max_scroll = (state.lines.len() as u16).saturating_sub(state.content_height);
+    state.scroll_offset = state.scroll_offset.min(max_scroll);
+
+    let diff = Paragraph::new(state.lines.clone()).scroll((state.scroll_offset, 0));
+    frame.render_widget(diff, layout[1]);
+
+    render_status_bar(
+        frame,
+        layout[2],
+        &[
+            ("j/k", "Scroll"),
+            ("n/N", "Next/prev change"),
+            ("g/G", "Top/Bottom"),
+            ("b/Esc", "Back"),
+            ("q", "Quit"),
+            ("?", "Help"),
+        ],
+    );
+}
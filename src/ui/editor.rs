@@ -0,0 +1,645 @@
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::api::types::QuestionDetail;
+
+use super::code_view::highlight;
+use super::status_bar::render_status_bar;
+use super::theme::theme;
+
+/// Undo steps kept per file.
+const UNDO_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Normal,
+    Insert,
+}
+
+/// The buffer and cursor as they were before an edit.
+struct Snapshot {
+    lines: Vec<String>,
+    cursor: (usize, usize),
+}
+
+/// A small modal editor for the solution file, for quick fixes between
+/// runs without suspending the TUI for the external editor.
+pub struct EditorState {
+    pub detail: QuestionDetail,
+    pub path: PathBuf,
+    lang: String,
+    lines: Vec<String>,
+    // Cursor line and char column
+    row: usize,
+    col: usize,
+    pub mode: Mode,
+    // First visible line and display column
+    top: usize,
+    left: usize,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    /// Changed since the last save.
+    pub dirty: bool,
+    // First key of a two-key command (dd, yy, gg)
+    pending: Option<char>,
+    // Line yanked or deleted with yy/dd
+    register: Option<String>,
+    // `:` command being typed
+    command: Option<String>,
+    pub message: Option<String>,
+    // Highlighted lines, dropped on every change
+    highlighted: Option<Vec<Line<'static>>>,
+    content_height: u16,
+}
+
+pub enum EditorAction {
+    None,
+    Back,
+    Save,
+    SaveAndBack,
+    /// Save, then run the sample cases
+    Run,
+    /// Save, then submit
+    Submit,
+}
+
+impl EditorState {
+    /// `lang` is a LeetCode language slug, used to pick the highlighter.
+    pub fn new(detail: QuestionDetail, path: PathBuf, content: &str, lang: &str) -> Self {
+        let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        Self {
+            detail,
+            path,
+            lang: lang.to_string(),
+            lines,
+            row: 0,
+            col: 0,
+            mode: Mode::Normal,
+            top: 0,
+            left: 0,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            dirty: false,
+            pending: None,
+            register: None,
+            command: None,
+            message: None,
+            highlighted: None,
+            content_height: 0,
+        }
+    }
+
+    /// The buffer as it's written to disk.
+    pub fn text(&self) -> String {
+        let mut text = self.lines.join("\n");
+        text.push('\n');
+        text
+    }
+
+    pub fn mark_saved(&mut self) {
+        self.dirty = false;
+        self.message = Some(format!("Wrote {}", self.path.display()));
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> EditorAction {
+        if self.command.is_some() {
+            return self.handle_command_key(key);
+        }
+        if key.code == KeyCode::Char('s') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return EditorAction::Save;
+        }
+        self.message = None;
+        match self.mode {
+            Mode::Normal => self.handle_normal_key(key),
+            Mode::Insert => {
+                self.handle_insert_key(key);
+                EditorAction::None
+            }
+        }
+    }
+
+    fn handle_command_key(&mut self, key: KeyEvent) -> EditorAction {
+        let Some(ref mut command) = self.command else {
+            return EditorAction::None;
+        };
+        match key.code {
+            KeyCode::Esc => self.command = None,
+            KeyCode::Backspace if command.is_empty() => self.command = None,
+            KeyCode::Backspace => {
+                command.pop();
+            }
+            KeyCode::Char(c) => command.push(c),
+            KeyCode::Enter => {
+                let command = self.command.take().unwrap_or_default();
+                return self.run_command(command.trim());
+            }
+            _ => {}
+        }
+        EditorAction::None
+    }
+
+    fn run_command(&mut self, command: &str) -> EditorAction {
+        match command {
+            "w" => EditorAction::Save,
+            "wq" | "x" => EditorAction::SaveAndBack,
+            "q" => self.back(),
+            "q!" => EditorAction::Back,
+            "run" | "r" => EditorAction::Run,
+            "submit" | "s" => EditorAction::Submit,
+            "" => EditorAction::None,
+            _ => {
+                if let Ok(line) = command.parse::<usize>() {
+                    self.row = line.saturating_sub(1).min(self.lines.len() - 1);
+                    self.col = 0;
+                } else {
+                    self.message = Some(format!("Unknown command: {command}"));
+                }
+                EditorAction::None
+            }
+        }
+    }
+
+    /// Leave, unless that would throw away changes.
+    fn back(&mut self) -> EditorAction {
+        if self.dirty {
+            self.message = Some("Unsaved changes (:w to save, :q! to discard)".to_string());
+            EditorAction::None
+        } else {
+            EditorAction::Back
+        }
+    }
+
+    fn handle_normal_key(&mut self, key: KeyEvent) -> EditorAction {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let pending = self.pending.take();
+        match key.code {
+            KeyCode::Esc => return self.back(),
+            KeyCode::Char(':') => self.command = Some(String::new()),
+            KeyCode::Char('r') if ctrl => self.redo(),
+            KeyCode::Char('d') if ctrl => self.move_rows(self.content_height as i32 / 2),
+            KeyCode::Char('u') if ctrl => self.move_rows(-(self.content_height as i32 / 2)),
+            KeyCode::Char('R') => return EditorAction::Run,
+            KeyCode::Char('S') => return EditorAction::Submit,
+
+            KeyCode::Char('h') | KeyCode::Left => self.col = self.col.saturating_sub(1),
+            KeyCode::Char('l') | KeyCode::Right => self.col += 1,
+            KeyCode::Char('j') | KeyCode::Down => self.move_rows(1),
+            KeyCode::Char('k') | KeyCode::Up => self.move_rows(-1),
+            KeyCode::PageDown => self.move_rows(self.content_height as i32),
+            KeyCode::PageUp => self.move_rows(-(self.content_height as i32)),
+            KeyCode::Char('0') | KeyCode::Home => self.col = 0,
+            KeyCode::Char('^') => self.col = self.first_non_blank(),
+            KeyCode::Char('$') | KeyCode::End => self.col = usize::MAX,
+            KeyCode::Char('w') => self.word_forward(),
+            KeyCode::Char('b') => self.word_backward(),
+            KeyCode::Char('g') if pending == Some('g') => (self.row, self.col) = (0, 0),
+            KeyCode::Char('G') => self.row = self.lines.len() - 1,
+
+            KeyCode::Char('i') => self.insert(),
+            KeyCode::Char('a') => {
+                self.col = (self.col + 1).min(self.line_len());
+                self.insert();
+            }
+            KeyCode::Char('I') => {
+                self.col = self.first_non_blank();
+                self.insert();
+            }
+            KeyCode::Char('A') => {
+                self.col = self.line_len();
+                self.insert();
+            }
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                self.checkpoint();
+                let indent = self.indent_of(self.row);
+                if key.code == KeyCode::Char('o') {
+                    self.row += 1;
+                }
+                self.lines.insert(self.row, indent.clone());
+                self.col = indent.chars().count();
+                self.changed();
+                self.mode = Mode::Insert;
+            }
+            KeyCode::Char('x') | KeyCode::Delete if self.line_len() > 0 => {
+                self.checkpoint();
+                let at = self.byte_at(self.col);
+                self.lines[self.row].remove(at);
+                self.changed();
+            }
+            KeyCode::Char('J') if self.row + 1 < self.lines.len() => {
+                self.checkpoint();
+                let next = self.lines.remove(self.row + 1);
+                let line = &mut self.lines[self.row];
+                self.col = line.chars().count();
+                line.push(' ');
+                line.push_str(next.trim_start());
+                self.changed();
+            }
+            KeyCode::Char('d') if pending == Some('d') => {
+                self.checkpoint();
+                self.register = Some(self.lines.remove(self.row));
+                if self.lines.is_empty() {
+                    self.lines.push(String::new());
+                }
+                self.row = self.row.min(self.lines.len() - 1);
+                self.col = self.first_non_blank();
+                self.changed();
+            }
+            KeyCode::Char('y') if pending == Some('y') => {
+                self.register = Some(self.lines[self.row].clone());
+                self.message = Some("Yanked 1 line".to_string());
+            }
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                if let Some(line) = self.register.clone() {
+                    self.checkpoint();
+                    if key.code == KeyCode::Char('p') {
+                        self.row += 1;
+                    }
+                    self.lines.insert(self.row, line);
+                    self.col = self.first_non_blank();
+                    self.changed();
+                }
+            }
+            KeyCode::Char('u') => self.undo(),
+            KeyCode::Char(c @ ('d' | 'y' | 'g')) => self.pending = Some(c),
+            _ => {}
+        }
+        self.clamp_cursor();
+        EditorAction::None
+    }
+
+    fn handle_insert_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.mode = Mode::Normal;
+                // An insert that changed nothing isn't worth an undo step
+                if self.undo.last().is_some_and(|s| s.lines == self.lines) {
+                    self.undo.pop();
+                }
+                self.col = self.col.saturating_sub(1);
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                let at = self.byte_at(self.col);
+                self.lines[self.row].insert(at, c);
+                self.col += 1;
+                self.changed();
+            }
+            KeyCode::Tab => {
+                let unit = self.indent_unit();
+                let at = self.byte_at(self.col);
+                self.lines[self.row].insert_str(at, unit);
+                self.col += unit.chars().count();
+                self.changed();
+            }
+            KeyCode::Enter => {
+                let at = self.byte_at(self.col);
+                let rest = self.lines[self.row].split_off(at);
+                let indent = self.indent_of(self.row);
+                self.col = indent.chars().count();
+                self.row += 1;
+                self.lines.insert(self.row, indent + rest.trim_start());
+                self.changed();
+            }
+            KeyCode::Backspace => {
+                if self.col > 0 {
+                    self.col -= 1;
+                    let at = self.byte_at(self.col);
+                    self.lines[self.row].remove(at);
+                    self.changed();
+                } else if self.row > 0 {
+                    let line = self.lines.remove(self.row);
+                    self.row -= 1;
+                    self.col = self.line_len();
+                    self.lines[self.row].push_str(&line);
+                    self.changed();
+                }
+            }
+            KeyCode::Delete => {
+                if self.col < self.line_len() {
+                    let at = self.byte_at(self.col);
+                    self.lines[self.row].remove(at);
+                    self.changed();
+                } else if self.row + 1 < self.lines.len() {
+                    let next = self.lines.remove(self.row + 1);
+                    self.lines[self.row].push_str(&next);
+                    self.changed();
+                }
+            }
+            KeyCode::Left => self.col = self.col.saturating_sub(1),
+            KeyCode::Right => self.col = (self.col + 1).min(self.line_len()),
+            KeyCode::Up => self.move_rows(-1),
+            KeyCode::Down => self.move_rows(1),
+            KeyCode::Home => self.col = 0,
+            KeyCode::End => self.col = self.line_len(),
+            _ => {}
+        }
+        self.clamp_cursor();
+    }
+
+    fn insert(&mut self) {
+        self.checkpoint();
+        self.mode = Mode::Insert;
+    }
+
+    /// Remember the buffer before an edit, for `u`.
+    fn checkpoint(&mut self) {
+        self.undo.push(Snapshot {
+            lines: self.lines.clone(),
+            cursor: (self.row, self.col),
+        });
+        if self.undo.len() > UNDO_LIMIT {
+            self.undo.remove(0);
+        }
+        self.redo.clear();
+    }
+
+    fn undo(&mut self) {
+        let Some(snapshot) = self.undo.pop() else {
+            self.message = Some("Already at oldest change".to_string());
+            return;
+        };
+        let current = self.restore(snapshot);
+        self.redo.push(current);
+    }
+
+    fn redo(&mut self) {
+        let Some(snapshot) = self.redo.pop() else {
+            self.message = Some("Already at newest change".to_string());
+            return;
+        };
+        let current = self.restore(snapshot);
+        self.undo.push(current);
+    }
+
+    /// Swap `snapshot` in, returning the state it replaced.
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        let current = Snapshot {
+            lines: std::mem::replace(&mut self.lines, snapshot.lines),
+            cursor: (self.row, self.col),
+        };
+        (self.row, self.col) = snapshot.cursor;
+        self.changed();
+        self.clamp_cursor();
+        current
+    }
+
+    fn changed(&mut self) {
+        self.dirty = true;
+        self.highlighted = None;
+    }
+
+    fn line_len(&self) -> usize {
+        self.lines[self.row].chars().count()
+    }
+
+    /// Byte offset of char column `col` on the cursor line.
+    fn byte_at(&self, col: usize) -> usize {
+        let line = &self.lines[self.row];
+        line.char_indices().nth(col).map_or(line.len(), |(i, _)| i)
+    }
+
+    fn first_non_blank(&self) -> usize {
+        self.lines[self.row]
+            .chars()
+            .position(|c| !c.is_whitespace())
+            .unwrap_or(0)
+    }
+
+    fn indent_of(&self, row: usize) -> String {
+        self.lines[row]
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .collect()
+    }
+
+    /// Tabs if the file is indented with them, otherwise four spaces.
+    fn indent_unit(&self) -> &'static str {
+        if self.lines.iter().any(|l| l.starts_with('\t')) {
+            "\t"
+        } else {
+            "    "
+        }
+    }
+
+    fn move_rows(&mut self, delta: i32) {
+        let row = self.row as i32 + delta;
+        self.row = row.clamp(0, self.lines.len() as i32 - 1) as usize;
+    }
+
+    /// Keep the cursor on the buffer: normal mode sits on a char, insert
+    /// mode may also sit past the last one.
+    fn clamp_cursor(&mut self) {
+        self.row = self.row.min(self.lines.len() - 1);
+        let len = self.line_len();
+        let max = match self.mode {
+            Mode::Normal => len.saturating_sub(1),
+            Mode::Insert => len,
+        };
+        self.col = self.col.min(max);
+    }
+
+    fn word_forward(&mut self) {
+        let chars: Vec<char> = self.lines[self.row].chars().collect();
+        let mut col = self.col;
+        if let Some(&c) = chars.get(col) {
+            let class = char_class(c);
+            while chars.get(col).is_some_and(|&c| char_class(c) == class) {
+                col += 1;
+            }
+        }
+        while chars.get(col).is_some_and(|c| c.is_whitespace()) {
+            col += 1;
+        }
+        if col >= chars.len() && self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = self.first_non_blank();
+        } else {
+            self.col = col;
+        }
+    }
+
+    fn word_backward(&mut self) {
+        if self.col == 0 || self.lines[self.row][..self.byte_at(self.col)].trim().is_empty() {
+            if self.row > 0 {
+                self.row -= 1;
+                self.col = self.line_len();
+            }
+            return;
+        }
+        let chars: Vec<char> = self.lines[self.row].chars().collect();
+        let mut col = self.col;
+        while col > 0 && chars[col - 1].is_whitespace() {
+            col -= 1;
+        }
+        if col > 0 {
+            let class = char_class(chars[col - 1]);
+            while col > 0 && char_class(chars[col - 1]) == class {
+                col -= 1;
+            }
+        }
+        self.col = col;
+    }
+
+    fn highlighted(&mut self) -> &[Line<'static>] {
+        self.highlighted.get_or_insert_with(|| {
+            let mut lines = highlight(&self.lines.join("\n"), &self.lang);
+            lines.resize(self.lines.len(), Line::default());
+            lines
+        })
+    }
+}
+
+/// Words are runs of identifier chars or of other punctuation.
+fn char_class(c: char) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if c.is_alphanumeric() || c == '_' {
+        1
+    } else {
+        2
+    }
+}
+
+/// Columns `text` takes on screen, with tabs as four (as highlighted).
+fn display_width(text: &str) -> usize {
+    Span::raw(text.replace('\t', "    ")).width()
+}
+
+pub fn render_editor(frame: &mut Frame, area: Rect, state: &mut EditorState) {
+    let layout = Layout::vertical([
+        Constraint::Length(2), // title bar
+        Constraint::Min(3),   // code
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    let mut title_spans = vec![
+        Span::styled(
+            " Edit ",
+            Style::default()
+                .fg(theme().badge_fg)
+                .bg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!("{}. {}", state.detail.frontend_question_id, state.detail.title),
+            Style::default()
+                .fg(theme().text)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  {}", state.path.display()),
+            Style::default().fg(theme().muted),
+        ),
+    ];
+    if state.dirty {
+        title_spans.push(Span::styled(" [+]", Style::default().fg(theme().warning)));
+    }
+    let title = Paragraph::new(Line::from(title_spans)).block(
+        Block::default()
+            .borders(Borders::BOTTOM)
+            .border_style(Style::default().fg(theme().muted)),
+    );
+    frame.render_widget(title, layout[0]);
+
+    // Line-number gutter beside the code
+    let gutter_width = state.lines.len().to_string().len() as u16 + 2;
+    let [gutter, code] =
+        Layout::horizontal([Constraint::Length(gutter_width), Constraint::Min(1)]).areas(layout[1]);
+    state.content_height = code.height;
+
+    // Scroll just enough to keep the cursor in view
+    let height = code.height.max(1) as usize;
+    if state.row < state.top {
+        state.top = state.row;
+    } else if state.row >= state.top + height {
+        state.top = state.row + 1 - height;
+    }
+    let line = &state.lines[state.row];
+    let cursor_x = display_width(&line[..state.byte_at(state.col)]);
+    let width = code.width.max(1) as usize;
+    if cursor_x < state.left {
+        state.left = cursor_x;
+    } else if cursor_x >= state.left + width {
+        state.left = cursor_x + 1 - width;
+    }
+
+    let (top, left, row) = (state.top, state.left, state.row);
+    let end = (top + height).min(state.lines.len());
+    let numbers: Vec<Line> = (top..end)
+        .map(|i| {
+            let style = if i == row {
+                Style::default().fg(theme().warning)
+            } else {
+                Style::default().fg(theme().muted)
+            };
+            Line::from(Span::styled(
+                format!(" {:>w$} ", i + 1, w = gutter_width as usize - 2),
+                style,
+            ))
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(numbers), gutter);
+
+    let lines = state.highlighted()[top..end].to_vec();
+    frame.render_widget(Paragraph::new(lines).scroll((0, left as u16)), code);
+
+    if state.command.is_none() {
+        frame.set_cursor_position((
+            code.x + (cursor_x - left) as u16,
+            code.y + (row - top) as u16,
+        ));
+    }
+
+    // Status bar: the command being typed, a message, or the keys
+    if let Some(ref command) = state.command {
+        let line = Line::from(vec![
+            Span::styled(format!(":{command}"), Style::default().fg(theme().text)),
+            Span::styled("\u{258e}", Style::default().fg(theme().accent)),
+        ]);
+        let bar = Paragraph::new(line).style(Style::default().bg(theme().bar_bg));
+        frame.render_widget(bar, layout[2]);
+        return;
+    }
+    let position = format!("{}:{}", row + 1, state.col + 1);
+    let mut hints = match state.mode {
+        Mode::Insert => vec![("INSERT", position.as_str()), ("Esc", "Normal"), ("Ctrl+S", "Save")],
+        Mode::Normal => vec![
+            ("NORMAL", position.as_str()),
+            ("i", "Insert"),
+            ("u/C-r", "Undo/Redo"),
+            (":w", "Save"),
+            ("R", "Run"),
+            ("S", "Submit"),
+            ("Esc", "Back"),
+        ],
+    };
+    if let Some(ref message) = state.message {
+        hints.truncate(1);
+        let bar = Line::from(vec![
+            Span::styled(
+                format!(" {} ", hints[0].0),
+                Style::default()
+                    .fg(theme().badge_fg)
+                    .bg(theme().muted)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!(" {message}"), Style::default().fg(theme().warning)),
+        ]);
+        let bar = Paragraph::new(bar).style(Style::default().bg(theme().bar_bg));
+        frame.render_widget(bar, layout[2]);
+    } else {
+        render_status_bar(frame, layout[2], &hints);
+    }
+}
@@ -0,0 +1,199 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::api::types::{OfficialSolution, QuestionDetail};
+
+use super::rich_text::{RenderOptions, markdown_to_lines};
+use super::status_bar::render_status_bar;
+use super::theme::theme;
+
+/// The problem's official solution article.
+pub struct EditorialState {
+    pub detail: QuestionDetail,
+    pub title: Option<String>,
+    /// None while loading.
+    pub lines: Option<Vec<Line<'static>>>,
+    pub error_message: Option<String>,
+    pub spinner_frame: usize,
+    pub scroll_offset: u16,
+    pub content_height: u16,
+}
+
+impl EditorialState {
+    pub fn new(detail: QuestionDetail) -> Self {
+        Self {
+            detail,
+            title: None,
+            lines: None,
+            error_message: None,
+            spinner_frame: 0,
+            scroll_offset: 0,
+            content_height: 0,
+        }
+    }
+
+    pub fn set_editorial(
+        &mut self,
+        result: Result<Option<OfficialSolution>, String>,
+        options: RenderOptions,
+    ) {
+        let notice = |text: &str| {
+            vec![Line::from(Span::styled(
+                format!(" {text}"),
+                Style::default().fg(theme().warning),
+            ))]
+        };
+        match result {
+            Ok(Some(solution)) if solution.is_locked() => {
+                self.lines = Some(notice(
+                    "Premium editorial — not available without LeetCode Premium.",
+                ));
+            }
+            Ok(Some(solution)) => {
+                let content = solution.content.as_deref().unwrap_or_default();
+                self.lines = Some(markdown_to_lines(content, options));
+                self.title = Some(solution.title).filter(|t| !t.is_empty());
+            }
+            Ok(None) => self.lines = Some(notice("This problem has no editorial yet.")),
+            Err(e) => self.error_message = Some(e),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> EditorialAction {
+        match key.code {
+            KeyCode::Char('b') | KeyCode::Esc => EditorialAction::Back,
+            KeyCode::Char('q') => EditorialAction::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                EditorialAction::Quit
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.scroll(1);
+                EditorialAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.scroll(-1);
+                EditorialAction::None
+            }
+            KeyCode::Char('d') => {
+                self.scroll(self.content_height as i32 / 2);
+                EditorialAction::None
+            }
+            KeyCode::Char('u') => {
+                self.scroll(-(self.content_height as i32 / 2));
+                EditorialAction::None
+            }
+            KeyCode::Char('g') => {
+                self.scroll_offset = 0;
+                EditorialAction::None
+            }
+            KeyCode::Char('G') => {
+                self.scroll_offset = u16::MAX;
+                EditorialAction::None
+            }
+            _ => EditorialAction::None,
+        }
+    }
+
+    fn scroll(&mut self, delta: i32) {
+        let new_offset = self.scroll_offset as i32 + delta;
+        self.scroll_offset = new_offset.clamp(0, u16::MAX as i32) as u16;
+    }
+}
+
+pub enum EditorialAction {
+    None,
+    Back,
+    Quit,
+}
+
+pub fn render_editorial(frame: &mut Frame, area: Rect, state: &mut EditorialState) {
+    let layout = Layout::vertical([
+        Constraint::Length(2), // title bar
+        Constraint::Min(3),    // article
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    let d = &state.detail;
+    let title = state
+        .title
+        .clone()
+        .unwrap_or_else(|| format!("{}. {}", d.frontend_question_id, d.title));
+    let title = Paragraph::new(Line::from(vec![
+        Span::styled(
+            " Editorial ",
+            Style::default()
+                .fg(theme().badge_fg)
+                .bg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            title,
+            Style::default()
+                .fg(theme().text)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::BOTTOM)
+            .border_style(Style::default().fg(theme().muted)),
+    );
+    frame.render_widget(title, layout[0]);
+
+    let body = layout[1];
+    state.content_height = body.height;
+    match (&state.lines, &state.error_message) {
+        (_, Some(err)) => {
+            let error = Paragraph::new(format!(" Error: {err}"))
+                .style(Style::default().fg(theme().error));
+            frame.render_widget(error, body);
+        }
+        (None, None) => {
+            let spinner = [
+                "\u{280b}", "\u{2819}", "\u{2839}", "\u{2838}", "\u{283c}", "\u{2834}", "\u{2826}",
+                "\u{2827}", "\u{2807}", "\u{280f}",
+            ];
+            let s = spinner[state.spinner_frame % spinner.len()];
+            let loading = Paragraph::new(format!(" {s} Loading editorial..."))
+                .style(Style::default().fg(theme().warning));
+            frame.render_widget(loading, body);
+        }
+        (Some(lines), None) => {
+            let max_scroll = (lines.len() as u16).saturating_sub(state.content_height);
+            state.scroll_offset = state.scroll_offset.min(max_scroll);
+
+            let padded: Vec<Line> = lines
+                .iter()
+                .map(|line| {
+                    let mut spans = vec![Span::raw("  ")];
+                    spans.extend(line.spans.iter().cloned());
+                    Line::from(spans)
+                })
+                .collect();
+            let article = Paragraph::new(padded)
+                .wrap(Wrap { trim: false })
+                .scroll((state.scroll_offset, 0));
+            frame.render_widget(article, body);
+        }
+    }
+
+    render_status_bar(
+        frame,
+        layout[2],
+        &[
+            ("j/k", "Scroll"),
+            ("d/u", "Half page"),
+            ("g/G", "Top/Bottom"),
+            ("b/Esc", "Back"),
+            ("?", "Help"),
+        ],
+    );
+}
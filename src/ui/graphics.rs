@@ -0,0 +1,116 @@
+use std::io::{self, Write};
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use crossterm::{cursor::MoveTo, queue};
+use ratatui::layout::Rect;
+
+/// Terminal graphics protocols images can be drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    /// kitty's graphics protocol (kitty, Ghostty); PNG only
+    Kitty,
+    /// iTerm2's inline images (iTerm2, WezTerm)
+    Iterm2,
+}
+
+/// Base64 bytes per kitty escape sequence, the protocol's maximum.
+const KITTY_CHUNK: usize = 4096;
+
+/// Guess the protocol from the environment. None when the terminal isn't
+/// known to show images, or a multiplexer would swallow the escapes.
+pub fn detect() -> Option<Protocol> {
+    let var = |name| std::env::var(name).unwrap_or_default();
+    let term = var("TERM");
+    if std::env::var_os("TMUX").is_some() || term.starts_with("screen") {
+        return None;
+    }
+    let program = var("TERM_PROGRAM");
+    if std::env::var_os("KITTY_WINDOW_ID").is_some()
+        || term.contains("kitty")
+        || program == "ghostty"
+    {
+        return Some(Protocol::Kitty);
+    }
+    match program.as_str() {
+        "iTerm.app" | "WezTerm" => Some(Protocol::Iterm2),
+        _ => None,
+    }
+}
+
+/// Draw `image` into `area`, keeping its aspect ratio.
+pub fn draw(out: &mut impl Write, protocol: Protocol, image: &[u8], area: Rect) -> io::Result<()> {
+    let data = STANDARD.encode(image);
+    queue!(out, MoveTo(area.x, area.y))?;
+    match protocol {
+        Protocol::Kitty => {
+            let Some((width, height)) = png_size(image) else {
+                return Err(io::Error::other("kitty can only show PNG images"));
+            };
+            let (cols, rows) = fit(width, height, area);
+            // q=2 silences the terminal's replies, which would otherwise
+            // arrive as key presses; C=1 leaves the cursor where it is
+            let chunks: Vec<&str> = data
+                .as_bytes()
+                .chunks(KITTY_CHUNK)
+                .map(|c| std::str::from_utf8(c).unwrap_or_default())
+                .collect();
+            for (i, chunk) in chunks.iter().enumerate() {
+                let more = u8::from(i + 1 < chunks.len());
+                if i == 0 {
+                    write!(
+                        out,
+                        "\u{1b}_Ga=T,f=100,q=2,C=1,c={cols},r={rows},m={more};{chunk}\u{1b}\\"
+                    )?;
+                } else {
+                    write!(out, "\u{1b}_Gm={more};{chunk}\u{1b}\\")?;
+                }
+            }
+        }
+        Protocol::Iterm2 => write!(
+            out,
+            "\u{1b}]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=1:{data}\u{7}",
+            image.len(),
+            area.width,
+            area.height
+        )?,
+    }
+    out.flush()
+}
+
+/// Remove drawn images. iTerm2 images are plain cells, gone once the
+/// screen is redrawn.
+pub fn clear(out: &mut impl Write, protocol: Protocol) -> io::Result<()> {
+    if protocol == Protocol::Kitty {
+        write!(out, "\u{1b}_Ga=d,q=2\u{1b}\\")?;
+        out.flush()?;
+    }
+    Ok(())
+}
+
+/// Pixel size from a PNG's header.
+fn png_size(image: &[u8]) -> Option<(u32, u32)> {
+    if !image.starts_with(b"\x89PNG\r\n\x1a\n") || image.len() < 24 {
+        return None;
+    }
+    let width = u32::from_be_bytes(image[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(image[20..24].try_into().ok()?);
+    Some((width, height))
+}
+
+/// Columns and rows that fit a `width`x`height` image in `area`, assuming
+/// cells twice as tall as they're wide.
+fn fit(width: u32, height: u32, area: Rect) -> (u16, u16) {
+    if width == 0 || height == 0 {
+        return (area.width, area.height);
+    }
+    let aspect = height as f64 / width as f64 / 2.0;
+    let cols = area.width as f64;
+    let rows = cols * aspect;
+    if rows <= area.height as f64 {
+        (area.width, (rows.round() as u16).max(1))
+    } else {
+        let cols = area.height as f64 / aspect;
+        ((cols.round() as u16).max(1), area.height)
+    }
+}
@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+
+use crate::attempts::{Attempt, AttemptKind};
+use crate::practice;
+
+use super::status_bar::render_status_bar;
+use super::submissions::format_age;
+use super::theme::theme;
+
+/// Every run and submission recorded locally, newest first.
+pub struct HistoryState {
+    pub attempts: Vec<Attempt>,
+    pub table_state: TableState,
+}
+
+impl HistoryState {
+    pub fn new(attempts: Vec<Attempt>) -> Self {
+        let mut table_state = TableState::default();
+        if !attempts.is_empty() {
+            table_state.select(Some(0));
+        }
+        Self {
+            attempts,
+            table_state,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> HistoryAction {
+        match key.code {
+            KeyCode::Char('b') | KeyCode::Esc => HistoryAction::Back,
+            KeyCode::Char('q') => HistoryAction::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                HistoryAction::Quit
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.move_selection(1);
+                HistoryAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.move_selection(-1);
+                HistoryAction::None
+            }
+            KeyCode::Char('g') | KeyCode::Home => {
+                self.move_selection(-(self.attempts.len() as i32));
+                HistoryAction::None
+            }
+            KeyCode::Char('G') | KeyCode::End => {
+                self.move_selection(self.attempts.len() as i32);
+                HistoryAction::None
+            }
+            KeyCode::Enter => match self.selected() {
+                Some(a) => HistoryAction::OpenDetail(a.slug.clone()),
+                None => HistoryAction::None,
+            },
+            _ => HistoryAction::None,
+        }
+    }
+
+    fn selected(&self) -> Option<&Attempt> {
+        self.table_state
+            .selected()
+            .and_then(|i| self.attempts.get(i))
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        if self.attempts.is_empty() {
+            return;
+        }
+        let current = self.table_state.selected().unwrap_or(0) as i32;
+        let max = self.attempts.len() as i32 - 1;
+        let next = (current + delta).clamp(0, max) as usize;
+        self.table_state.select(Some(next));
+    }
+}
+
+pub enum HistoryAction {
+    None,
+    Back,
+    Quit,
+    OpenDetail(String),
+}
+
+fn verdict_style(attempt: &Attempt) -> Style {
+    if attempt.accepted() {
+        Style::default().fg(theme().success)
+    } else {
+        Style::default().fg(theme().error)
+    }
+}
+
+pub fn render_history(frame: &mut Frame, area: Rect, state: &mut HistoryState) {
+    let layout = Layout::vertical([
+        Constraint::Length(1), // title bar
+        Constraint::Min(3),    // content
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    let accepted = state
+        .attempts
+        .iter()
+        .filter(|a| a.kind == AttemptKind::Submit && a.accepted())
+        .count();
+    let title = Line::from(vec![
+        Span::styled(
+            " History ",
+            Style::default()
+                .fg(theme().badge_fg)
+                .bg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!(
+                "{} runs and submissions, {accepted} accepted",
+                state.attempts.len()
+            ),
+            Style::default().fg(theme().muted),
+        ),
+    ]);
+    frame.render_widget(
+        Paragraph::new(title).style(Style::default().bg(theme().bar_bg)),
+        layout[0],
+    );
+
+    if state.attempts.is_empty() {
+        let empty = Paragraph::new(" Nothing run or submitted yet.")
+            .style(Style::default().fg(theme().muted));
+        frame.render_widget(empty, layout[1]);
+    } else {
+        render_history_table(frame, layout[1], state);
+    }
+
+    render_status_bar(
+        frame,
+        layout[2],
+        &[
+            ("j/k", "Navigate"),
+            ("Enter", "Open problem"),
+            ("g/G", "Top/Bottom"),
+            ("b/Esc", "Back"),
+            ("q", "Quit"),
+            ("?", "Help"),
+        ],
+    );
+}
+
+fn render_history_table(frame: &mut Frame, area: Rect, state: &mut HistoryState) {
+    let header = Row::new([
+        Cell::from(" When"),
+        Cell::from("Problem"),
+        Cell::from("Kind"),
+        Cell::from("Verdict"),
+        Cell::from("Runtime"),
+        Cell::from("Language"),
+        Cell::from("Since scaffold"),
+    ])
+    .style(
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let rows: Vec<Row> = state
+        .attempts
+        .iter()
+        .map(|a| {
+            let kind = match a.kind {
+                AttemptKind::Run => "run",
+                AttemptKind::Submit => "submit",
+            };
+            let since = a
+                .since_scaffold
+                .map(|secs| practice::format_duration(Duration::from_secs(secs)))
+                .unwrap_or_default();
+            Row::new([
+                Cell::from(Span::styled(
+                    format!(" {}", format_age(a.timestamp, now)),
+                    Style::default().fg(theme().muted),
+                )),
+                Cell::from(a.title.clone()),
+                Cell::from(kind),
+                Cell::from(Span::styled(a.verdict.clone(), verdict_style(a))),
+                Cell::from(a.runtime.clone().unwrap_or_default()),
+                Cell::from(a.language.clone()),
+                Cell::from(since),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Min(24),
+        Constraint::Length(7),
+        Constraint::Length(22),
+        Constraint::Length(9),
+        Constraint::Length(11),
+        Constraint::Length(14),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::NONE))
+        .row_highlight_style(
+            Style::default()
+                .bg(theme().selection)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{25b8} ");
+
+    frame.render_stateful_widget(table, area, &mut state.table_state);
+}
@@ -1,23 +1,95 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
+    buffer::Buffer,
     layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+    widgets::{
+        Block, Borders, Cell, Clear, Gauge, LineGauge, Paragraph, Row, Table, TableState, Tabs,
+    },
     Frame,
 };
 
-use crate::api::types::{ProblemSummary, UserStats};
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
+use crate::api::site::site;
+use crate::api::types::{Category, CompanyPeriod, FavoriteList, ProblemSummary, UserStats};
+
+use super::detail::DifficultyNav;
+use super::hyperlink::{self, Link};
 use super::status_bar::render_status_bar;
+use super::theme::theme;
 
 pub struct FilterState {
     pub easy: bool,
     pub medium: bool,
     pub hard: bool,
     pub hide_solved: bool,
+    /// Hide problems that need LeetCode Premium.
+    pub hide_premium: bool,
+    /// Topic tags (by name) a problem must all have.
+    pub tags: BTreeSet<String>,
+    /// Only problems a company asked, set from the company browser.
+    pub company: Option<CompanyFilter>,
     pub active_item: usize,
     pub open: bool,
+    /// Tag list opened from the filter popup
+    pub tag_picker: Option<TagPicker>,
+}
+
+/// Searchable list of every topic tag, for picking several to filter by.
+pub struct TagPicker {
+    /// Tag names with how many loaded problems have them, most common first.
+    pub tags: Vec<(String, usize)>,
+    pub query: String,
+    /// Indices into `tags` matching the query.
+    pub filtered: Vec<usize>,
+    pub selected: usize,
+}
+
+impl TagPicker {
+    /// Tags of `problems`, plus any already `chosen` that none of them have.
+    fn new(problems: &[ProblemSummary], chosen: &BTreeSet<String>) -> Self {
+        let mut counts: HashMap<&str, usize> = chosen.iter().map(|t| (t.as_str(), 0)).collect();
+        for tag in problems.iter().flat_map(|p| &p.topic_tags) {
+            *counts.entry(tag.name.as_str()).or_default() += 1;
+        }
+        let mut tags: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(name, count)| (name.to_string(), count))
+            .collect();
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let mut picker = Self {
+            tags,
+            query: String::new(),
+            filtered: Vec::new(),
+            selected: 0,
+        };
+        picker.rebuild_filter();
+        picker
+    }
+
+    fn rebuild_filter(&mut self) {
+        let query = self.query.trim().to_lowercase();
+        self.filtered = (0..self.tags.len())
+            .filter(|&i| self.tags[i].0.to_lowercase().contains(&query))
+            .collect();
+        self.selected = self.selected.min(self.filtered.len().saturating_sub(1));
+    }
+
+    fn selected_tag(&self) -> Option<&str> {
+        let i = *self.filtered.get(self.selected)?;
+        Some(&self.tags[i].0)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CompanyFilter {
+    pub name: String,
+    pub period: CompanyPeriod,
+    pub slugs: HashSet<String>,
 }
 
 impl FilterState {
@@ -27,18 +99,24 @@ impl FilterState {
             medium: true,
             hard: true,
             hide_solved: false,
+            hide_premium: false,
+            tags: BTreeSet::new(),
+            company: None,
             active_item: 0,
             open: false,
+            tag_picker: None,
         }
     }
 
     fn item_count(&self) -> usize {
-        4 // Easy, Medium, Hard, Hide Solved
+        // Easy, Medium, Hard, Hide Solved, Hide Premium, Tags, and the
+        // company when set
+        6 + self.company.is_some() as usize
     }
 
     pub fn summary(&self) -> Option<String> {
-        let all = self.easy && self.medium && self.hard && !self.hide_solved;
-        if all {
+        let all = self.easy && self.medium && self.hard && !self.hide_solved && !self.hide_premium;
+        if all && self.tags.is_empty() && self.company.is_none() {
             return None;
         }
         let mut parts = Vec::new();
@@ -49,23 +127,124 @@ impl FilterState {
         if self.hide_solved {
             s.push_str(" -Solved");
         }
+        if self.hide_premium {
+            s.push_str(" -Premium");
+        }
+        if !self.tags.is_empty() {
+            let tags: Vec<&str> = self.tags.iter().map(String::as_str).collect();
+            s.push_str(&format!(" #{}", tags.join("+")));
+        }
+        if let Some(ref c) = self.company {
+            s.push_str(&format!(" @{} ({})", c.name, c.period.label()));
+        }
         Some(format!("[{s}]"))
     }
 }
 
+/// Column the home table is ordered by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortField {
+    #[default]
+    Id,
+    Title,
+    Difficulty,
+    AcRate,
+}
+
+impl SortField {
+    fn next(self) -> Self {
+        match self {
+            SortField::Id => SortField::Title,
+            SortField::Title => SortField::Difficulty,
+            SortField::Difficulty => SortField::AcRate,
+            SortField::AcRate => SortField::Id,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SortField::Id => "number",
+            SortField::Title => "title",
+            SortField::Difficulty => "difficulty",
+            SortField::AcRate => "AC rate",
+        }
+    }
+
+    fn compare(self, a: &ProblemSummary, b: &ProblemSummary) -> std::cmp::Ordering {
+        match self {
+            // leetcode.cn has a few non-numeric ids; those sort after the rest
+            SortField::Id => {
+                let key = |p: &ProblemSummary| {
+                    (p.frontend_question_id.parse::<u32>().unwrap_or(u32::MAX), p.frontend_question_id.clone())
+                };
+                key(a).cmp(&key(b))
+            }
+            SortField::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            SortField::Difficulty => {
+                difficulty_rank(&a.difficulty).cmp(&difficulty_rank(&b.difficulty))
+            }
+            SortField::AcRate => a.ac_rate.total_cmp(&b.ac_rate),
+        }
+    }
+}
+
+fn difficulty_rank(difficulty: &str) -> u8 {
+    match difficulty {
+        "Easy" => 0,
+        "Medium" => 1,
+        "Hard" => 2,
+        _ => 3,
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SortState {
+    pub field: SortField,
+    pub descending: bool,
+}
+
+impl SortState {
+    fn is_default(self) -> bool {
+        self == SortState::default()
+    }
+
+    fn arrow(self) -> &'static str {
+        if self.descending { "\u{25bc}" } else { "\u{25b2}" }
+    }
+}
+
 pub struct HomeState {
     pub table_state: TableState,
     pub problems: Vec<ProblemSummary>,
     pub filtered_indices: Vec<usize>,
+    // Title chars matched by the search, parallel to `filtered_indices`
+    pub match_positions: Vec<Vec<usize>>,
     pub search_query: String,
     pub search_mode: bool,
     pub filter: FilterState,
+    pub sort: SortState,
     pub loading: bool,
     pub loading_buffer: Vec<ProblemSummary>,
     pub total_problems: i32,
     pub error_message: Option<String>,
     pub spinner_frame: usize,
     pub user_stats: Option<UserStats>,
+    pub show_stats: bool,
+    // Full breakdown panel instead of the compact gauges
+    pub stats_expanded: bool,
+    // Another user's stats shown alongside ours
+    pub compare_stats: Option<UserStats>,
+    pub compare_input: Option<String>,
+    pub max_title_width: Option<usize>,
+    // Slugs of problems saved in at least one favorite list
+    pub listed_slugs: HashSet<String>,
+    // Slugs of problems with local notes
+    pub noted_slugs: HashSet<String>,
+    pub category: Category,
+    // Last refresh failed; showing the cached list
+    pub offline: bool,
+    // Screen row, shown title and slug of each row drawn last frame
+    pub title_rows: Vec<(u16, String, String)>,
 }
 
 impl HomeState {
@@ -74,25 +253,45 @@ impl HomeState {
             table_state: TableState::default(),
             problems: Vec::new(),
             filtered_indices: Vec::new(),
+            match_positions: Vec::new(),
             search_query: String::new(),
             search_mode: false,
             filter: FilterState::new(),
+            sort: SortState::default(),
             loading: true,
             loading_buffer: Vec::new(),
             total_problems: 0,
             error_message: None,
             spinner_frame: 0,
             user_stats: None,
+            show_stats: true,
+            stats_expanded: false,
+            compare_stats: None,
+            compare_input: None,
+            max_title_width: None,
+            listed_slugs: HashSet::new(),
+            noted_slugs: HashSet::new(),
+            category: Category::default(),
+            offline: false,
+            title_rows: Vec::new(),
         }
     }
 
+    /// Narrow the table to a company's problems, from the top.
+    pub fn set_company_filter(&mut self, company: CompanyFilter) {
+        self.filter.company = Some(company);
+        self.table_state.select(Some(0));
+        self.rebuild_filter();
+    }
+
     pub fn rebuild_filter(&mut self) {
-        let query = self.search_query.to_lowercase();
-        self.filtered_indices = self
+        let query = self.search_query.trim().to_lowercase();
+        let matcher = SkimMatcherV2::default().ignore_case();
+        let mut scored: Vec<(usize, i64, Vec<usize>)> = self
             .problems
             .iter()
             .enumerate()
-            .filter(|(_, p)| {
+            .filter_map(|(i, p)| {
                 // Difficulty filter
                 let diff_ok = match p.difficulty.as_str() {
                     "Easy" => self.filter.easy,
@@ -101,24 +300,70 @@ impl HomeState {
                     _ => true,
                 };
                 if !diff_ok {
-                    return false;
+                    return None;
                 }
 
                 // Hide solved filter
                 if self.filter.hide_solved && p.status.as_deref() == Some("ac") {
-                    return false;
+                    return None;
+                }
+
+                // Hide premium filter
+                if self.filter.hide_premium && p.is_paid_only {
+                    return None;
+                }
+
+                // Tag filter
+                if !self
+                    .filter
+                    .tags
+                    .iter()
+                    .all(|t| p.topic_tags.iter().any(|pt| &pt.name == t))
+                {
+                    return None;
+                }
+
+                // Company filter
+                if let Some(ref c) = self.filter.company
+                    && !c.slugs.contains(&p.title_slug)
+                {
+                    return None;
                 }
 
                 // Search filter
                 if query.is_empty() {
-                    return true;
+                    return Some((i, 0, Vec::new()));
+                }
+                if p.frontend_question_id == query {
+                    return Some((i, i64::MAX, Vec::new()));
+                }
+                let title = matcher.fuzzy_indices(&p.title, &query);
+                let slug = matcher.fuzzy_match(&p.title_slug, &query);
+                // Rank by the better of the two, highlight whatever matched the title
+                match (title, slug) {
+                    (Some((score, positions)), slug) => {
+                        Some((i, score.max(slug.unwrap_or(score)), positions))
+                    }
+                    (None, Some(score)) => Some((i, score, Vec::new())),
+                    (None, None) => None,
                 }
-                p.title.to_lowercase().contains(&query)
-                    || p.frontend_question_id == query
             })
-            .map(|(i, _)| i)
             .collect();
 
+        // An explicit sort wins; otherwise best match first, ties keeping
+        // problem-number order
+        if !self.sort.is_default() {
+            let sort = self.sort;
+            scored.sort_by(|a, b| {
+                let ord = sort.field.compare(&self.problems[a.0], &self.problems[b.0]);
+                if sort.descending { ord.reverse() } else { ord }
+            });
+        } else if !query.is_empty() {
+            scored.sort_by_key(|s| std::cmp::Reverse(s.1));
+        }
+        self.filtered_indices = scored.iter().map(|(i, _, _)| *i).collect();
+        self.match_positions = scored.into_iter().map(|(_, _, m)| m).collect();
+
         // Keep selection in bounds
         if self.filtered_indices.is_empty() {
             self.table_state.select(None);
@@ -131,12 +376,74 @@ impl HomeState {
         }
     }
 
+    /// Apply `sort`, keeping the selected problem selected.
+    fn set_sort(&mut self, sort: SortState) {
+        let selected = self.selected_problem().map(|p| p.title_slug.clone());
+        self.sort = sort;
+        self.rebuild_filter();
+        if let Some(slug) = selected
+            && let Some(pos) = self
+                .filtered_indices
+                .iter()
+                .position(|&i| self.problems[i].title_slug == slug)
+        {
+            self.table_state.select(Some(pos));
+        }
+    }
+
+    /// Mark `slug` as accepted. Returns false if it isn't in the list or
+    /// was already solved.
+    pub fn mark_solved(&mut self, slug: &str) -> bool {
+        let Some(problem) = self.problems.iter_mut().find(|p| p.title_slug == slug) else {
+            return false;
+        };
+        if problem.status.as_deref() == Some("ac") {
+            return false;
+        }
+        problem.status = Some("ac".to_string());
+        self.rebuild_filter();
+        true
+    }
+
+    pub fn set_favorites(&mut self, lists: &[FavoriteList]) {
+        self.listed_slugs = favorite_slugs(lists);
+    }
+
     pub fn selected_problem(&self) -> Option<&ProblemSummary> {
         let selected = self.table_state.selected()?;
         let idx = *self.filtered_indices.get(selected)?;
         self.problems.get(idx)
     }
 
+    /// The problems currently shown, in table order.
+    pub fn filtered_problems(&self) -> Vec<&ProblemSummary> {
+        self.filtered_indices
+            .iter()
+            .filter_map(|&i| self.problems.get(i))
+            .collect()
+    }
+
+    /// Build the same-difficulty navigation for `slug` from the filtered set,
+    /// selecting it in the table so returning home lands on it.
+    pub fn difficulty_nav(&mut self, slug: &str) -> Option<DifficultyNav> {
+        let pos = self
+            .filtered_indices
+            .iter()
+            .position(|&i| self.problems[i].title_slug == slug)?;
+        self.table_state.select(Some(pos));
+
+        let difficulty = &self.problems[self.filtered_indices[pos]].difficulty;
+        let slugs: Vec<String> = self
+            .filtered_indices
+            .iter()
+            .map(|&i| &self.problems[i])
+            .filter(|p| &p.difficulty == difficulty)
+            .map(|p| p.title_slug.clone())
+            .collect();
+        let position = slugs.iter().position(|s| s == slug)?;
+        Some(DifficultyNav { slugs, position })
+    }
+
     pub fn handle_key(&mut self, key: KeyEvent) -> HomeAction {
         if self.filter.open {
             return self.handle_filter_key(key);
@@ -146,6 +453,10 @@ impl HomeState {
             return self.handle_search_key(key);
         }
 
+        if self.compare_input.is_some() {
+            return self.handle_compare_key(key);
+        }
+
         match key.code {
             KeyCode::Char('q') => HomeAction::Quit,
             KeyCode::Char('j') | KeyCode::Down => {
@@ -192,6 +503,13 @@ impl HomeState {
                     HomeAction::None
                 }
             }
+            KeyCode::Char('O') => {
+                if let Some(problem) = self.selected_problem() {
+                    HomeAction::OpenInBrowser(problem.title_slug.clone())
+                } else {
+                    HomeAction::None
+                }
+            }
             KeyCode::Char('a') => {
                 if let Some(problem) = self.selected_problem() {
                     HomeAction::AddToList(problem.frontend_question_id.clone())
@@ -199,16 +517,67 @@ impl HomeState {
                     HomeAction::None
                 }
             }
+            KeyCode::Char('s') => {
+                self.set_sort(SortState {
+                    field: self.sort.field.next(),
+                    descending: false,
+                });
+                HomeAction::None
+            }
+            KeyCode::Char('r') => {
+                self.set_sort(SortState {
+                    descending: !self.sort.descending,
+                    ..self.sort
+                });
+                HomeAction::None
+            }
+            KeyCode::Char('t') => {
+                // Compact gauges, then the full breakdown, then hidden
+                (self.show_stats, self.stats_expanded) =
+                    match (self.show_stats, self.stats_expanded) {
+                        (false, _) => (true, false),
+                        (true, false) => (true, true),
+                        (true, true) => (false, false),
+                    };
+                HomeAction::ToggleStats {
+                    show: self.show_stats,
+                    expanded: self.stats_expanded,
+                }
+            }
+            KeyCode::Char(c @ '1'..='6') => {
+                let idx = c as usize - '1' as usize;
+                self.switch_category(Category::ALL[idx])
+            }
+            KeyCode::Left | KeyCode::Right => {
+                let n = Category::ALL.len();
+                let step = if key.code == KeyCode::Right { 1 } else { n - 1 };
+                self.switch_category(Category::ALL[(self.category.index() + step) % n])
+            }
+            KeyCode::Char('C') => {
+                self.compare_input = Some(String::new());
+                HomeAction::None
+            }
+            KeyCode::Char('D') => HomeAction::DailyChallenge,
             KeyCode::Char('L') => HomeAction::Lists,
+            KeyCode::Char('P') => HomeAction::StudyPlans,
+            KeyCode::Char('K') => HomeAction::Skills,
+            KeyCode::Char('H') => HomeAction::History,
+            KeyCode::Char('A') => HomeAction::Analytics,
+            KeyCode::Char('E') => HomeAction::ExportProblems,
+            KeyCode::Char('T') => HomeAction::Companies,
             KeyCode::Char('S') => HomeAction::Settings,
             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 HomeAction::Quit
             }
+            KeyCode::Char('c') => HomeAction::Contests,
             _ => HomeAction::None,
         }
     }
 
     fn handle_filter_key(&mut self, key: KeyEvent) -> HomeAction {
+        if self.filter.tag_picker.is_some() {
+            return self.handle_tag_picker_key(key);
+        }
         match key.code {
             KeyCode::Char('j') | KeyCode::Down => {
                 self.filter.active_item = (self.filter.active_item + 1) % self.filter.item_count();
@@ -225,6 +594,16 @@ impl HomeState {
                     1 => self.filter.medium = !self.filter.medium,
                     2 => self.filter.hard = !self.filter.hard,
                     3 => self.filter.hide_solved = !self.filter.hide_solved,
+                    4 => self.filter.hide_premium = !self.filter.hide_premium,
+                    5 => {
+                        self.filter.tag_picker =
+                            Some(TagPicker::new(&self.problems, &self.filter.tags));
+                        return HomeAction::None;
+                    }
+                    6 => {
+                        self.filter.company = None;
+                        self.filter.active_item = 0;
+                    }
                     _ => {}
                 }
                 self.rebuild_filter();
@@ -238,6 +617,42 @@ impl HomeState {
         }
     }
 
+    fn handle_tag_picker_key(&mut self, key: KeyEvent) -> HomeAction {
+        let Some(picker) = self.filter.tag_picker.as_mut() else {
+            return HomeAction::None;
+        };
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => self.filter.tag_picker = None,
+            KeyCode::Down if picker.selected + 1 < picker.filtered.len() => picker.selected += 1,
+            KeyCode::Up => picker.selected = picker.selected.saturating_sub(1),
+            KeyCode::Enter | KeyCode::Tab => {
+                if let Some(tag) = picker.selected_tag() {
+                    let tag = tag.to_string();
+                    if !self.filter.tags.remove(&tag) {
+                        self.filter.tags.insert(tag);
+                    }
+                    self.rebuild_filter();
+                }
+            }
+            KeyCode::Char('x') if ctrl => {
+                self.filter.tags.clear();
+                self.rebuild_filter();
+            }
+            KeyCode::Backspace => {
+                picker.query.pop();
+                picker.rebuild_filter();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                picker.query.push(c);
+                picker.selected = 0;
+                picker.rebuild_filter();
+            }
+            _ => {}
+        }
+        HomeAction::None
+    }
+
     fn handle_search_key(&mut self, key: KeyEvent) -> HomeAction {
         match key.code {
             KeyCode::Esc => {
@@ -287,6 +702,50 @@ impl HomeState {
         }
     }
 
+    fn switch_category(&mut self, category: Category) -> HomeAction {
+        if category == self.category {
+            return HomeAction::None;
+        }
+        self.category = category;
+        self.loading = true;
+        self.loading_buffer.clear();
+        self.error_message = None;
+        HomeAction::SwitchCategory(category)
+    }
+
+    fn handle_compare_key(&mut self, key: KeyEvent) -> HomeAction {
+        let Some(input) = self.compare_input.as_mut() else {
+            return HomeAction::None;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.compare_input = None;
+                HomeAction::None
+            }
+            KeyCode::Enter => {
+                let name = input.trim().to_string();
+                self.compare_input = None;
+                if name.is_empty() {
+                    // Submitting an empty name clears the comparison
+                    self.compare_stats = None;
+                    HomeAction::None
+                } else {
+                    self.show_stats = true;
+                    HomeAction::CompareUser(name)
+                }
+            }
+            KeyCode::Char(c) => {
+                input.push(c);
+                HomeAction::None
+            }
+            KeyCode::Backspace => {
+                input.pop();
+                HomeAction::None
+            }
+            _ => HomeAction::None,
+        }
+    }
+
     fn move_selection(&mut self, delta: i32) {
         if self.filtered_indices.is_empty() {
             return;
@@ -303,18 +762,50 @@ pub enum HomeAction {
     Quit,
     OpenDetail(String),
     Scaffold(String),
+    OpenInBrowser(String),
     SearchFetch(String),
     AddToList(String),
     Settings,
     Lists,
+    Contests,
+    StudyPlans,
+    Companies,
+    Skills,
+    History,
+    Analytics,
+    ExportProblems,
+    ToggleStats { show: bool, expanded: bool },
+    CompareUser(String),
+    SwitchCategory(Category),
+    DailyChallenge,
+}
+
+/// Every problem slug that appears in any of `lists`.
+pub fn favorite_slugs(lists: &[FavoriteList]) -> HashSet<String> {
+    lists
+        .iter()
+        .flat_map(|l| l.questions.iter())
+        .map(|q| q.title_slug.clone())
+        .collect()
+}
+
+/// Height of the stats header: two rows when stats are loaded and shown,
+/// five for the full breakdown.
+pub fn stats_header_height(state: &HomeState) -> u16 {
+    let any_stats = state.user_stats.is_some() || state.compare_stats.is_some();
+    match (any_stats && state.show_stats, state.stats_expanded) {
+        (false, _) => 0,
+        (true, false) => 2,
+        (true, true) => 5,
+    }
 }
 
 pub fn render_home(frame: &mut Frame, area: Rect, state: &mut HomeState) {
-    let has_stats = state.user_stats.is_some();
-    let stats_height: u16 = if has_stats { 2 } else { 0 };
+    let stats_height = stats_header_height(state);
 
     let layout = Layout::vertical([
         Constraint::Length(1),            // title bar
+        Constraint::Length(1),            // category tabs
         Constraint::Length(stats_height), // stats header
         Constraint::Min(3),              // table
         Constraint::Length(1),           // status bar
@@ -324,9 +815,39 @@ pub fn render_home(frame: &mut Frame, area: Rect, state: &mut HomeState) {
     // Title bar
     render_title_bar(frame, layout[0], state);
 
-    // Stats header
-    if let Some(ref stats) = state.user_stats {
-        render_stats_header(frame, layout[1], stats);
+    // Category tabs
+    let tabs = Tabs::new(
+        Category::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, c)| format!("{} {}", i + 1, c.label())),
+    )
+    .select(state.category.index())
+    .style(Style::default().fg(theme().muted))
+    .highlight_style(
+        Style::default()
+            .fg(theme().warning)
+            .add_modifier(Modifier::BOLD),
+    );
+    frame.render_widget(tabs, layout[1]);
+
+    // Stats header, split in two when comparing with another user
+    if stats_height > 0 {
+        match (&state.user_stats, &state.compare_stats) {
+            (Some(ours), Some(theirs)) => {
+                let cols = Layout::horizontal([
+                    Constraint::Percentage(50),
+                    Constraint::Percentage(50),
+                ])
+                .split(layout[2]);
+                render_stats_header(frame, cols[0], ours, state.stats_expanded);
+                render_stats_header(frame, cols[1], theirs, state.stats_expanded);
+            }
+            (Some(stats), None) | (None, Some(stats)) => {
+                render_stats_header(frame, layout[2], stats, state.stats_expanded);
+            }
+            (None, None) => {}
+        }
     }
 
     // Problem table
@@ -334,18 +855,20 @@ pub fn render_home(frame: &mut Frame, area: Rect, state: &mut HomeState) {
         let spinner = ["\u{280b}", "\u{2819}", "\u{2839}", "\u{2838}", "\u{283c}", "\u{2834}", "\u{2826}", "\u{2827}", "\u{2807}", "\u{280f}"];
         let s = spinner[state.spinner_frame % spinner.len()];
         let loading = Paragraph::new(format!(" {s} Loading problems..."))
-            .style(Style::default().fg(Color::Yellow));
-        frame.render_widget(loading, layout[2]);
+            .style(Style::default().fg(theme().warning));
+        frame.render_widget(loading, layout[3]);
     } else if let Some(ref err) = state.error_message {
         let error = Paragraph::new(format!(" Error: {err}"))
-            .style(Style::default().fg(Color::Red));
-        frame.render_widget(error, layout[2]);
+            .style(Style::default().fg(theme().error));
+        frame.render_widget(error, layout[3]);
     } else {
-        render_table(frame, layout[2], state);
+        render_table(frame, layout[3], state);
     }
 
     // Status bar
-    let hints = if state.search_mode {
+    let hints = if state.compare_input.is_some() {
+        vec![("Enter", "Compare"), ("Esc", "Cancel")]
+    } else if state.search_mode {
         vec![
             ("Enter", "Apply"),
             ("Esc", "Cancel"),
@@ -359,63 +882,203 @@ pub fn render_home(frame: &mut Frame, area: Rect, state: &mut HomeState) {
             ("a", "Add to List"),
             ("/", "Search"),
             ("f", "Filter"),
+            ("s/r", "Sort"),
+            ("t", "Stats"),
+            ("K", "Profile"),
+            ("H", "History"),
+            ("A", "Analytics"),
+            ("C", "Compare"),
+            ("D", "Daily"),
             ("L", "Lists"),
+            ("c", "Contests"),
+            ("P", "Plans"),
+            ("T", "Companies"),
             ("S", "Settings"),
             ("q", "Quit"),
             ("?", "Help"),
         ]
     };
-    render_status_bar(frame, layout[3], &hints);
+    render_status_bar(frame, layout[4], &hints);
 
     // Filter popup overlay
     if state.filter.open {
         render_filter_popup(frame, area, &state.filter);
+        if let Some(ref picker) = state.filter.tag_picker {
+            render_tag_picker(frame, area, picker, &state.filter.tags);
+        }
+    }
+
+    if let Some(ref input) = state.compare_input {
+        render_compare_prompt(frame, area, input);
     }
 }
 
-fn render_stats_header(frame: &mut Frame, area: Rect, stats: &UserStats) {
-    let rows = Layout::vertical([
-        Constraint::Length(1),
-        Constraint::Length(1),
-    ]).split(area);
+fn render_compare_prompt(frame: &mut Frame, area: Rect, input: &str) {
+    let w = 40u16.min(area.width.saturating_sub(4));
+    let h = 5u16;
+    let x = area.x + (area.width.saturating_sub(w)) / 2;
+    let y = area.y + (area.height.saturating_sub(h)) / 2;
+    let overlay = Rect::new(x, y, w, h);
+
+    frame.render_widget(Clear, overlay);
+    let text = format!("\n {input}\u{258e}");
+    let p = Paragraph::new(text)
+        .block(
+            Block::default()
+                .title(" Compare with user ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme().accent)),
+        )
+        .style(Style::default().fg(theme().text));
+    frame.render_widget(p, overlay);
+}
+
+fn render_stats_header(frame: &mut Frame, area: Rect, stats: &UserStats, expanded: bool) {
+    let rows = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).split(area);
 
     let total_solved = stats.easy_solved + stats.medium_solved + stats.hard_solved;
     let total_all = stats.easy_total + stats.medium_total + stats.hard_total;
 
-    // Row 0: username + total
-    let line0 = Line::from(vec![
+    // Row 0: username + total + streak + contest rating
+    let mut line0 = Line::from(vec![
         Span::styled(
             format!("  {} ", stats.username),
-            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            Style::default().fg(theme().accent).add_modifier(Modifier::BOLD),
         ),
         Span::styled(
             format!("{total_solved}/{total_all} solved"),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme().muted),
         ),
     ]);
+    if let Some(streak) = stats.streak {
+        let color = if streak.current > 0 { theme().warning } else { theme().muted };
+        line0.spans.extend([
+            Span::raw("  "),
+            Span::styled(
+                format!("{}d streak", streak.current),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" \u{b7} best {}d", streak.longest),
+                Style::default().fg(theme().muted),
+            ),
+        ]);
+    }
+    if let Some(ref contest) = stats.contest {
+        line0.spans.extend([
+            Span::raw("  "),
+            Span::styled("Rating ", Style::default().fg(theme().special)),
+            Span::styled(
+                format!("{:.0}", contest.rating),
+                Style::default().fg(theme().text).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" \u{b7} #{}", group_digits(contest.global_ranking)),
+                Style::default().fg(theme().muted),
+            ),
+        ]);
+    }
     frame.render_widget(Paragraph::new(line0), rows[0]);
 
-    // Row 1: Easy x/y  Med x/y  Hard x/y
-    let line1 = Line::from(vec![
-        Span::styled("  Easy ", Style::default().fg(Color::Green)),
-        Span::styled(
-            format!("{}/{}", stats.easy_solved, stats.easy_total),
-            Style::default().fg(Color::White),
-        ),
-        Span::raw("  "),
-        Span::styled("Med ", Style::default().fg(Color::Yellow)),
-        Span::styled(
-            format!("{}/{}", stats.medium_solved, stats.medium_total),
-            Style::default().fg(Color::White),
-        ),
-        Span::raw("  "),
-        Span::styled("Hard ", Style::default().fg(Color::Red)),
-        Span::styled(
-            format!("{}/{}", stats.hard_solved, stats.hard_total),
-            Style::default().fg(Color::White),
-        ),
-    ]);
-    frame.render_widget(Paragraph::new(line1), rows[1]);
+    let difficulties = [
+        ("Easy", stats.easy_solved, stats.easy_total, theme().easy),
+        ("Med", stats.medium_solved, stats.medium_total, theme().medium),
+        ("Hard", stats.hard_solved, stats.hard_total, theme().hard),
+    ];
+    if expanded {
+        // Rows 1-4: a full-width gauge per difficulty and one for the total
+        let all = ("All", total_solved, total_all, theme().accent);
+        let rows = Layout::vertical([Constraint::Length(1); 4]).split(rows[1]);
+        for (row, (name, solved, total, color)) in
+            rows.iter().zip(difficulties.into_iter().chain([all]))
+        {
+            let cols = Layout::horizontal([
+                Constraint::Length(8),
+                Constraint::Min(10),
+                Constraint::Length(13),
+            ])
+            .split(*row);
+            frame.render_widget(
+                Paragraph::new(Span::styled(
+                    format!("  {name}"),
+                    Style::default().fg(color),
+                )),
+                cols[0],
+            );
+            let ratio = solved_ratio(solved, total);
+            let gauge = Gauge::default()
+                .ratio(ratio)
+                .label(Span::styled(
+                    format!("{:.1}%", ratio * 100.0),
+                    Style::default().fg(theme().text).add_modifier(Modifier::BOLD),
+                ))
+                .gauge_style(Style::default().fg(color).bg(theme().surface))
+                .use_unicode(true);
+            frame.render_widget(gauge, cols[1]);
+            frame.render_widget(
+                Paragraph::new(
+                    Line::styled(
+                        format!("{solved}/{total}"),
+                        Style::default().fg(theme().text),
+                    )
+                    .right_aligned(),
+                ),
+                cols[2],
+            );
+        }
+    } else {
+        // Row 1: a line gauge per difficulty, labelled x/y
+        let cols = Layout::horizontal([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Length(2),
+            Constraint::Fill(1),
+        ])
+        .split(rows[1]);
+        for (col, (name, solved, total, color)) in
+            [cols[1], cols[3], cols[5]].into_iter().zip(difficulties)
+        {
+            let gauge = LineGauge::default()
+                .ratio(solved_ratio(solved, total))
+                .label(Line::from(vec![
+                    Span::styled(format!("{name} "), Style::default().fg(color)),
+                    Span::styled(
+                        format!("{solved}/{total}"),
+                        Style::default().fg(theme().text),
+                    ),
+                ]))
+                .filled_style(Style::default().fg(color))
+                .unfilled_style(Style::default().fg(theme().muted));
+            frame.render_widget(gauge, col);
+        }
+    }
+}
+
+/// Share of `total` solved, for a gauge; 0 when there's nothing to solve.
+fn solved_ratio(solved: i32, total: i32) -> f64 {
+    if total > 0 {
+        (solved as f64 / total as f64).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// `1234567` as `1,234,567`.
+pub fn group_digits(n: i64) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    if n < 0 {
+        out.insert(0, '-');
+    }
+    out
 }
 
 fn render_title_bar(frame: &mut Frame, area: Rect, state: &HomeState) {
@@ -423,8 +1086,8 @@ fn render_title_bar(frame: &mut Frame, area: Rect, state: &HomeState) {
         Span::styled(
             " LeetCode ",
             Style::default()
-                .fg(Color::Black)
-                .bg(Color::Yellow)
+                .fg(theme().badge_fg)
+                .bg(theme().warning)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" "),
@@ -435,17 +1098,23 @@ fn render_title_bar(frame: &mut Frame, area: Rect, state: &HomeState) {
         let s = spinner[state.spinner_frame % spinner.len()];
         spans.push(Span::styled(
             format!("{s} Loading... {}/{} ", state.loading_buffer.len(), state.total_problems),
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme().warning),
         ));
     } else {
         if let Some(summary) = state.filter.summary() {
             spans.push(Span::styled(
                 format!("{summary} "),
                 Style::default()
-                    .fg(Color::Magenta)
+                    .fg(theme().special)
                     .add_modifier(Modifier::BOLD),
             ));
         }
+        if !state.sort.is_default() {
+            spans.push(Span::styled(
+                format!("by {} {} ", state.sort.field.label(), state.sort.arrow()),
+                Style::default().fg(theme().accent),
+            ));
+        }
 
         spans.push(Span::styled(
             format!(
@@ -453,7 +1122,14 @@ fn render_title_bar(frame: &mut Frame, area: Rect, state: &HomeState) {
                 state.filtered_indices.len(),
                 state.total_problems
             ),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme().muted),
+        ));
+    }
+
+    if state.offline {
+        spans.push(Span::styled(
+            "  offline \u{b7} cached list",
+            Style::default().fg(theme().error),
         ));
     }
 
@@ -461,53 +1137,132 @@ fn render_title_bar(frame: &mut Frame, area: Rect, state: &HomeState) {
         spans.push(Span::raw("  "));
         spans.push(Span::styled(
             format!("/{}", state.search_query),
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(theme().accent),
         ));
         if state.search_mode {
-            spans.push(Span::styled("\u{258e}", Style::default().fg(Color::Cyan)));
+            spans.push(Span::styled("\u{258e}", Style::default().fg(theme().accent)));
         }
     }
 
-    let title = Paragraph::new(Line::from(spans)).style(Style::default().bg(Color::Black));
+    let title = Paragraph::new(Line::from(spans)).style(Style::default().bg(theme().bar_bg));
     frame.render_widget(title, area);
 }
 
+/// Shorten `title` to at most `max` characters, ending in an ellipsis.
+pub fn truncate_title(title: &str, max: usize) -> String {
+    if title.chars().count() <= max {
+        return title.to_string();
+    }
+    let mut out: String = title.chars().take(max.saturating_sub(1)).collect();
+    out.push('\u{2026}');
+    out
+}
+
+/// Split `title` into spans, emphasizing the chars at `positions`.
+fn highlight_matches(title: &str, positions: &[usize]) -> Vec<Span<'static>> {
+    if positions.is_empty() {
+        return vec![Span::raw(title.to_string())];
+    }
+    let hit = Style::default()
+        .fg(theme().warning)
+        .add_modifier(Modifier::BOLD);
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut run = String::new();
+    let mut run_hit = false;
+    for (i, c) in title.chars().enumerate() {
+        let is_hit = positions.contains(&i);
+        if is_hit != run_hit && !run.is_empty() {
+            let text = std::mem::take(&mut run);
+            spans.push(if run_hit { Span::styled(text, hit) } else { Span::raw(text) });
+        }
+        run_hit = is_hit;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        spans.push(if run_hit { Span::styled(run, hit) } else { Span::raw(run) });
+    }
+    spans
+}
+
 fn render_table(frame: &mut Frame, area: Rect, state: &mut HomeState) {
+    // Room left for the title once the fixed columns, highlight symbol and
+    // column spacing are accounted for
+    let available = (area.width.saturating_sub(3 + 6 + 12 + 9 + 2 + 4) as usize).max(20);
+    let title_width = state
+        .max_title_width
+        .map_or(available, |max| max.min(available));
+
+    // The sorted column gets an arrow; the default order goes unmarked
+    let column = |label: &str, field: SortField| {
+        if field == state.sort.field && !state.sort.is_default() {
+            Cell::from(format!("{label} {}", state.sort.arrow()))
+        } else {
+            Cell::from(label.to_string())
+        }
+    };
     let header = Row::new([
         Cell::from(" "),
-        Cell::from(" # "),
-        Cell::from("Title"),
-        Cell::from("Difficulty"),
-        Cell::from("AC Rate"),
+        column(" #", SortField::Id),
+        column("Title", SortField::Title),
+        column("Difficulty", SortField::Difficulty),
+        column("AC Rate", SortField::AcRate),
     ])
     .style(
         Style::default()
-            .fg(Color::Cyan)
+            .fg(theme().accent)
             .add_modifier(Modifier::BOLD),
     )
     .bottom_margin(0);
 
-    let rows: Vec<Row> = state
-        .filtered_indices
+    // Only build rows for the visible window: scroll the window just enough
+    // to keep the selection in view, as the table widget itself would
+    let visible = area.height.saturating_sub(1).max(1) as usize;
+    let len = state.filtered_indices.len();
+    let mut offset = state.table_state.offset().min(len.saturating_sub(1));
+    if let Some(selected) = state.table_state.selected() {
+        if selected < offset {
+            offset = selected;
+        } else if selected >= offset + visible {
+            offset = selected + 1 - visible;
+        }
+    }
+    *state.table_state.offset_mut() = offset;
+    let end = (offset + visible).min(len);
+
+    let mut title_rows = Vec::new();
+    let rows: Vec<Row> = state.filtered_indices[offset..end]
         .iter()
-        .map(|&idx| {
+        .enumerate()
+        .map(|(row, &idx)| {
+            let row = offset + row;
             let p = &state.problems[idx];
-            let diff_color = match p.difficulty.as_str() {
-                "Easy" => Color::Green,
-                "Medium" => Color::Yellow,
-                "Hard" => Color::Red,
-                _ => Color::White,
-            };
+            let matched = state.match_positions.get(row).map_or(&[][..], Vec::as_slice);
+            let diff_color = theme().difficulty(&p.difficulty);
             let paid = if p.is_paid_only { " \u{1f512}" } else { "" };
             let status_cell = match p.status.as_deref() {
-                Some("ac") => Cell::from(Span::styled(" \u{2714}", Style::default().fg(Color::Green))),
-                Some("notac") => Cell::from(Span::styled(" \u{25cf}", Style::default().fg(Color::Yellow))),
+                Some("ac") => Cell::from(Span::styled(" \u{2714}", Style::default().fg(theme().success))),
+                Some("notac") => Cell::from(Span::styled(" \u{25cf}", Style::default().fg(theme().warning))),
                 _ => Cell::from("  "),
             };
+            let mut marks = Vec::new();
+            if state.listed_slugs.contains(&p.title_slug) {
+                marks.push(Span::styled(" \u{2605}", Style::default().fg(theme().special)));
+            }
+            if state.noted_slugs.contains(&p.title_slug) {
+                marks.push(Span::styled(" \u{270e}", Style::default().fg(theme().subtle)));
+            }
+            let title = truncate_title(
+                &format!("{}{}", p.title, paid),
+                title_width.saturating_sub(2 * marks.len()),
+            );
+            let mut spans = highlight_matches(&title, matched);
+            title_rows.push((area.y + 1 + (row - offset) as u16, title, p.title_slug.clone()));
+            spans.extend(marks);
+            let title_cell = Cell::from(Line::from(spans));
             Row::new([
                 status_cell,
                 Cell::from(format!(" {}", p.frontend_question_id)),
-                Cell::from(format!("{}{}", p.title, paid)),
+                title_cell,
                 Cell::from(Span::styled(
                     p.difficulty.clone(),
                     Style::default().fg(diff_color),
@@ -521,8 +1276,8 @@ fn render_table(frame: &mut Frame, area: Rect, state: &mut HomeState) {
         Constraint::Length(3),
         Constraint::Length(6),
         Constraint::Min(20),
-        Constraint::Length(10),
-        Constraint::Length(8),
+        Constraint::Length(12),
+        Constraint::Length(9),
     ];
 
     let table = Table::new(rows, widths)
@@ -530,17 +1285,35 @@ fn render_table(frame: &mut Frame, area: Rect, state: &mut HomeState) {
         .block(Block::default().borders(Borders::NONE))
         .row_highlight_style(
             Style::default()
-                .bg(Color::DarkGray)
+                .bg(theme().selection)
                 .add_modifier(Modifier::BOLD),
         )
         .highlight_symbol("\u{25b8} ");
 
-    frame.render_stateful_widget(table, area, &mut state.table_state);
+    let mut window = TableState::default()
+        .with_selected(state.table_state.selected().map(|s| s - offset));
+    frame.render_stateful_widget(table, area, &mut window);
+    state.title_rows = title_rows;
+}
+
+/// Problem titles on the last frame, linked to their pages.
+pub fn links(state: &HomeState, buffer: &Buffer) -> Vec<Link> {
+    state
+        .title_rows
+        .iter()
+        .filter_map(|(y, title, slug)| {
+            hyperlink::find(buffer, buffer.area, *y, title, &site().problem_url(slug))
+        })
+        .collect()
 }
 
 fn render_filter_popup(frame: &mut Frame, area: Rect, filter: &FilterState) {
-    let popup_width = 30u16.min(area.width.saturating_sub(4));
-    let popup_height = 9u16;
+    let company = filter
+        .company
+        .as_ref()
+        .map(|c| format!("{} ({})", c.name, c.period.label()));
+    let popup_width = 36u16.min(area.width.saturating_sub(4));
+    let popup_height = 11 + company.is_some() as u16;
     let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
     let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
     let popup_area = Rect::new(x, y, popup_width, popup_height);
@@ -550,16 +1323,26 @@ fn render_filter_popup(frame: &mut Frame, area: Rect, filter: &FilterState) {
     let block = Block::default()
         .title(" Filter ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Magenta));
+        .border_style(Style::default().fg(theme().special));
     frame.render_widget(block, popup_area);
 
     let inner = Rect::new(popup_area.x + 2, popup_area.y + 1, popup_area.width.saturating_sub(4), popup_area.height.saturating_sub(2));
-    let items = [
-        ("Easy", filter.easy, Color::Green),
-        ("Medium", filter.medium, Color::Yellow),
-        ("Hard", filter.hard, Color::Red),
-        ("Hide Solved", filter.hide_solved, Color::Cyan),
+    let tags = match filter.tags.len() {
+        0 => "Tags\u{2026}".to_string(),
+        1 => format!("Tag: {}", filter.tags.iter().next().unwrap()),
+        n => format!("Tags: {n} selected"),
+    };
+    let mut items = vec![
+        ("Easy", filter.easy, theme().easy),
+        ("Medium", filter.medium, theme().medium),
+        ("Hard", filter.hard, theme().hard),
+        ("Hide Solved", filter.hide_solved, theme().accent),
+        ("Hide Premium", filter.hide_premium, theme().warning),
+        (tags.as_str(), !filter.tags.is_empty(), theme().special),
     ];
+    if let Some(ref label) = company {
+        items.push((label.as_str(), true, theme().special));
+    }
 
     let mut constraints: Vec<Constraint> = items.iter().map(|_| Constraint::Length(1)).collect();
     constraints.push(Constraint::Length(1)); // blank
@@ -586,7 +1369,286 @@ fn render_filter_popup(frame: &mut Frame, area: Rect, filter: &FilterState) {
     // Hint at bottom
     let hint = Paragraph::new(Line::from(Span::styled(
         "  Space: toggle  Esc: close",
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme().muted),
     )));
     frame.render_widget(hint, rows[items.len() + 1]);
 }
+
+fn render_tag_picker(frame: &mut Frame, area: Rect, picker: &TagPicker, chosen: &BTreeSet<String>) {
+    let popup_width = 44u16.min(area.width.saturating_sub(4));
+    let popup_height = 24u16.min(area.height.saturating_sub(4));
+    let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+    let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let title = if chosen.is_empty() {
+        " Tags ".to_string()
+    } else {
+        format!(" Tags ({} selected) ", chosen.len())
+    };
+    let block = Block::default()
+        .title(title)
+        .title_bottom(" Enter: toggle  ^X: clear  Esc: done ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().special));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let rows = Layout::vertical([
+        Constraint::Length(1), // search
+        Constraint::Length(1), // blank
+        Constraint::Min(1),    // tags
+    ])
+    .split(inner);
+
+    let search = Line::from(vec![
+        Span::styled(" / ", Style::default().fg(theme().warning)),
+        Span::styled(picker.query.clone(), Style::default().fg(theme().text)),
+        Span::styled("\u{2588}", Style::default().fg(theme().muted)),
+    ]);
+    frame.render_widget(Paragraph::new(search), rows[0]);
+
+    if picker.filtered.is_empty() {
+        let empty = Paragraph::new(" No matching tags").style(Style::default().fg(theme().muted));
+        frame.render_widget(empty, rows[2]);
+        return;
+    }
+
+    let lines: Vec<Line> = picker
+        .filtered
+        .iter()
+        .enumerate()
+        .map(|(row, &i)| {
+            let (name, count) = &picker.tags[i];
+            let checked = chosen.contains(name);
+            let highlight = row == picker.selected;
+            let color = if checked { theme().special } else { theme().text };
+            let style = if highlight {
+                Style::default().fg(color).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(color)
+            };
+            let prefix = if highlight { "\u{25b8} " } else { "  " };
+            let marker = if checked { "[x]" } else { "[ ]" };
+            Line::from(vec![
+                Span::styled(format!("{prefix}{marker} {name}"), style),
+                Span::styled(format!(" {count}"), Style::default().fg(theme().muted)),
+            ])
+        })
+        .collect();
+    // Keep the selection visible when the list is taller than the popup
+    let visible = rows[2].height as usize;
+    let scroll = picker.selected.saturating_sub(visible.saturating_sub(1));
+    frame.render_widget(Paragraph::new(lines).scroll((scroll as u16, 0)), rows[2]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problem(id: &str, difficulty: &str) -> ProblemSummary {
+        ProblemSummary {
+            frontend_question_id: id.to_string(),
+            title: format!("Problem {id}"),
+            title_slug: format!("p{id}"),
+            difficulty: difficulty.to_string(),
+            status: None,
+            ac_rate: 50.0,
+            is_paid_only: false,
+            topic_tags: Vec::new(),
+        }
+    }
+
+    fn stats(username: &str) -> UserStats {
+        UserStats {
+            username: username.to_string(),
+            easy_solved: 1,
+            easy_total: 2,
+            medium_solved: 0,
+            medium_total: 2,
+            hard_solved: 0,
+            hard_total: 1,
+            streak: None,
+            calendar: Default::default(),
+            contest: None,
+        }
+    }
+
+    fn home(problems: Vec<ProblemSummary>) -> HomeState {
+        let mut state = HomeState::new();
+        state.problems = problems;
+        state.rebuild_filter();
+        state
+    }
+
+    #[test]
+    fn difficulty_nav_lists_the_filtered_problems_of_the_same_difficulty() {
+        let mut state = home(vec![
+            problem("1", "Easy"),
+            problem("2", "Medium"),
+            problem("3", "Easy"),
+            problem("4", "Easy"),
+        ]);
+        state.filter.hide_solved = true;
+        state.problems[3].status = Some("ac".to_string());
+        state.rebuild_filter();
+
+        let nav = state.difficulty_nav("p3").unwrap();
+        assert_eq!(nav.slugs, ["p1", "p3"]);
+        assert_eq!(nav.position, 1);
+        assert_eq!(nav.neighbor(true), Some("p1"));
+        // The table follows along
+        assert_eq!(state.table_state.selected(), Some(2));
+
+        assert!(state.difficulty_nav("p4").is_none());
+    }
+
+    #[test]
+    fn stats_header_height_follows_the_toggle() {
+        let mut state = home(Vec::new());
+        // Nothing to show until stats are loaded
+        assert_eq!(stats_header_height(&state), 0);
+        state.user_stats = Some(stats("me"));
+        assert_eq!(stats_header_height(&state), 2);
+
+        let t = KeyEvent::from(KeyCode::Char('t'));
+        let mut heights = Vec::new();
+        for _ in 0..3 {
+            let HomeAction::ToggleStats { show, expanded } = state.handle_key(t) else {
+                panic!("t should toggle the stats header");
+            };
+            assert_eq!((show, expanded), (state.show_stats, state.stats_expanded));
+            heights.push(stats_header_height(&state));
+        }
+        assert_eq!(heights, [5, 0, 2]);
+    }
+
+    #[test]
+    fn entering_a_username_fetches_and_shows_their_stats() {
+        let mut state = home(vec![problem("1", "Easy")]);
+        state.user_stats = Some(stats("me"));
+        state.show_stats = false;
+
+        state.handle_key(KeyEvent::from(KeyCode::Char('C')));
+        for c in " bob ".chars() {
+            state.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+        let enter = KeyEvent::from(KeyCode::Enter);
+        let HomeAction::CompareUser(name) = state.handle_key(enter) else {
+            panic!("Enter should fetch the user's stats");
+        };
+        assert_eq!(name, "bob");
+        assert!(state.compare_input.is_none());
+        assert!(state.show_stats);
+
+        // The fetched stats sit beside our own
+        state.compare_stats = Some(stats(&name));
+        let backend = ratatui::backend::TestBackend::new(120, 12);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_home(frame, frame.area(), &mut state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = (0..buffer.area.width)
+            .map(|x| buffer[(x, 2)].symbol())
+            .collect::<String>();
+        let (ours, theirs) = row.split_at(row.find("bob").unwrap());
+        assert!(ours.contains(" me "));
+        assert!(theirs.starts_with("bob"));
+    }
+
+    #[test]
+    fn long_titles_are_truncated_to_the_max_width() {
+        let title = "Longest Substring Without Repeating Characters";
+        assert_eq!(truncate_title(title, 12), "Longest Sub\u{2026}");
+        assert_eq!(truncate_title(title, 12).chars().count(), 12);
+        assert_eq!(truncate_title("Two Sum", 12), "Two Sum");
+
+        // The table keeps the difficulty column in view
+        let mut state = home(vec![problem("3", "Medium")]);
+        state.problems[0].title = title.to_string();
+        state.max_title_width = Some(12);
+        state.rebuild_filter();
+        let backend = ratatui::backend::TestBackend::new(80, 10);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_home(frame, frame.area(), &mut state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .find(|row| row.contains("Longest"))
+            .unwrap();
+        assert!(row.contains("Longest Sub\u{2026}"));
+        assert!(!row.contains("Repeating"));
+        assert!(row.contains("Medium"));
+    }
+
+    fn list(name: &str, slugs: &[&str]) -> FavoriteList {
+        FavoriteList {
+            id_hash: name.to_string(),
+            name: name.to_string(),
+            description: None,
+            view_count: 0,
+            creator: "me".to_string(),
+            is_watched: false,
+            is_public_favorite: false,
+            questions: slugs
+                .iter()
+                .map(|slug| crate::api::types::FavoriteQuestion {
+                    question_id: String::new(),
+                    status: None,
+                    title: String::new(),
+                    title_slug: slug.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn listed_slugs_cover_every_favorite_list() {
+        let lists = [list("dp", &["p1", "p2"]), list("graphs", &["p2", "p3"]), list("empty", &[])];
+        let listed = favorite_slugs(&lists);
+        assert_eq!(listed.len(), 3);
+        assert!(["p1", "p2", "p3"].iter().all(|slug| listed.contains(*slug)));
+        assert!(!listed.contains("p4"));
+
+        // Replaced wholesale when the lists change
+        let mut state = home(Vec::new());
+        state.set_favorites(&lists);
+        state.set_favorites(&lists[1..2]);
+        assert!(!state.listed_slugs.contains("p1"));
+        assert!(state.listed_slugs.contains("p3"));
+    }
+
+    fn switched_to(state: &mut HomeState, code: KeyCode) -> Option<Category> {
+        match state.handle_key(KeyEvent::from(code)) {
+            HomeAction::SwitchCategory(category) => Some(category),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn category_tabs_fetch_the_selected_category() {
+        let mut state = home(vec![problem("1", "Easy")]);
+        state.loading = false;
+
+        assert_eq!(switched_to(&mut state, KeyCode::Char('3')), Some(Category::Database));
+        assert_eq!(state.category.slug(), "database");
+        assert!(state.loading);
+        // The current tab doesn't refetch
+        assert_eq!(switched_to(&mut state, KeyCode::Char('3')), None);
+
+        assert_eq!(switched_to(&mut state, KeyCode::Right), Some(Category::Shell));
+        assert_eq!(switched_to(&mut state, KeyCode::Char('6')), Some(Category::JavaScript));
+        assert_eq!(switched_to(&mut state, KeyCode::Right), Some(Category::All));
+        assert_eq!(switched_to(&mut state, KeyCode::Left), Some(Category::JavaScript));
+        assert_eq!(state.category.slug(), "javascript");
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crossterm::{cursor::MoveTo, queue};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+
+use crate::ui::ansi::line_to_ansi;
+
+/// A run of cells on one row that should open `url` when clicked.
+#[derive(Debug, Clone)]
+pub struct Link {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub url: String,
+}
+
+/// Whether the terminal is known to understand OSC 8 hyperlinks. Others
+/// may print the escapes as garbage, so they're left alone.
+pub fn supported() -> bool {
+    let var = |name| std::env::var(name).unwrap_or_default();
+    let term = var("TERM");
+    if std::env::var_os("TMUX").is_some() || term.starts_with("screen") || term == "linux" || term == "dumb" {
+        return false;
+    }
+    if matches!(
+        var("TERM_PROGRAM").as_str(),
+        "iTerm.app" | "WezTerm" | "vscode" | "ghostty"
+    ) {
+        return true;
+    }
+    ["KITTY_WINDOW_ID", "WT_SESSION", "VTE_VERSION", "KONSOLE_VERSION"]
+        .iter()
+        .any(|name| std::env::var_os(name).is_some())
+        || ["kitty", "alacritty", "foot", "wezterm"]
+            .iter()
+            .any(|t| term.contains(t))
+}
+
+/// Links written over the last frame. ratatui only redraws cells that
+/// changed, so a link stays on screen until its cells do; remembering what
+/// was written keeps from rewriting it every frame.
+#[derive(Default)]
+pub struct Hyperlinks {
+    written: HashMap<(u16, u16), String>,
+}
+
+impl Hyperlinks {
+    /// Forget what was written, after the terminal was cleared or replaced.
+    pub fn reset(&mut self) {
+        self.written.clear();
+    }
+
+    /// Rewrite the cells under each of `links` wrapped in OSC 8 escapes.
+    pub fn emit(&mut self, out: &mut impl Write, buffer: &Buffer, links: &[Link]) -> io::Result<()> {
+        let mut written = HashMap::with_capacity(links.len());
+        let mut dirty = false;
+        for link in links {
+            let area = buffer.area;
+            let end = (link.x + link.width).min(area.right());
+            if link.y >= area.bottom() || link.x >= end {
+                continue;
+            }
+            let spans: Vec<Span> = (link.x..end)
+                .filter_map(|x| buffer.cell((x, link.y)))
+                .map(|cell| Span::styled(cell.symbol().to_string(), cell.style()))
+                .collect();
+            let text = line_to_ansi(&Line::from(spans), true);
+            // The same cells and URL as last frame are still on screen
+            let signature = format!("{}\0{text}", link.url);
+            let key = (link.x, link.y);
+            if self.written.get(&key) != Some(&signature) {
+                queue!(out, MoveTo(link.x, link.y))?;
+                write!(out, "\u{1b}]8;;{}\u{1b}\\{text}\u{1b}]8;;\u{1b}\\", link.url)?;
+                dirty = true;
+            }
+            written.insert(key, signature);
+        }
+        self.written = written;
+        if dirty {
+            out.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Where `text` is drawn on row `y` of `area`, as a link to `url`.
+pub fn find(buffer: &Buffer, area: Rect, y: u16, text: &str, url: &str) -> Option<Link> {
+    if text.is_empty() || y < area.top() || y >= area.bottom() {
+        return None;
+    }
+    let width = Span::raw(text).width() as u16;
+    (area.left()..area.right())
+        .find(|&x| matches_at(buffer, x, y, text))
+        .map(|x| Link {
+            x,
+            y,
+            width,
+            url: url.to_string(),
+        })
+}
+
+fn matches_at(buffer: &Buffer, mut x: u16, y: u16, text: &str) -> bool {
+    for c in text.chars() {
+        let Some(cell) = buffer.cell((x, y)) else {
+            return false;
+        };
+        let mut utf8 = [0; 4];
+        let symbol = c.encode_utf8(&mut utf8);
+        if cell.symbol() != symbol {
+            return false;
+        }
+        x += (Span::raw(&*symbol).width() as u16).max(1);
+    }
+    true
+}
+
+/// Every `http(s)://` URL printed in `area`. A URL running up to the right
+/// edge is assumed to be wrapped, and continues at the start of the next
+/// row.
+pub fn urls(buffer: &Buffer, area: Rect) -> Vec<Link> {
+    let row = |y: u16| -> Vec<&str> {
+        (area.left()..area.right())
+            .map(|x| buffer.cell((x, y)).map_or(" ", |c| c.symbol()))
+            .collect()
+    };
+    let mut links = Vec::new();
+    let mut y = area.top();
+    while y < area.bottom() {
+        let cells = row(y);
+        let mut next_y = y + 1;
+        let mut from = 0;
+        while let Some(start) = find_scheme(&cells, from) {
+            let end = cells[start..]
+                .iter()
+                .position(|s| s.trim().is_empty())
+                .map_or(cells.len(), |n| start + n);
+            let mut url: String = cells[start..end].concat();
+            let mut parts = vec![(area.left() + start as u16, y, (end - start) as u16)];
+            // Follow the URL onto the rows it wrapped to
+            let mut wrapped = end == cells.len();
+            while wrapped && next_y < area.bottom() {
+                let more = row(next_y);
+                let len = more.iter().position(|s| s.trim().is_empty()).unwrap_or(more.len());
+                if len == 0 {
+                    break;
+                }
+                url.push_str(&more[..len].concat());
+                parts.push((area.left(), next_y, len as u16));
+                wrapped = len == more.len();
+                next_y += 1;
+            }
+            let url = url.trim_end_matches(['.', ',', ')', ';']).to_string();
+            for (x, y, width) in parts {
+                links.push(Link {
+                    x,
+                    y,
+                    width,
+                    url: url.clone(),
+                });
+            }
+            from = end;
+        }
+        // Rows a URL wrapped onto hold nothing else worth linking
+        y = next_y;
+    }
+    links
+}
+
+/// Cell index of the next `http://` or `https://` at or after `from`.
+fn find_scheme(cells: &[&str], from: usize) -> Option<usize> {
+    (from..cells.len()).find(|&i| {
+        ["https://", "http://"].iter().any(|scheme| {
+            scheme
+                .chars()
+                .enumerate()
+                .all(|(n, c)| cells.get(i + n).is_some_and(|s| s.len() == 1 && s.starts_with(c)))
+        })
+    })
+}
@@ -1,7 +1,7 @@
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap},
     Frame,
@@ -10,6 +10,7 @@ use ratatui::{
 use crate::api::types::FavoriteList;
 
 use super::status_bar::render_status_bar;
+use super::theme::theme;
 
 pub struct ListsState {
     pub lists: Vec<FavoriteList>,
@@ -24,8 +25,16 @@ pub struct ListsState {
     // Create mode
     pub create_mode: bool,
     pub create_input: String,
+    // The create prompt is reused for the import file path
+    pub import_mode: bool,
+    // List the import adds to; None creates a new one
+    pub import_into: Option<usize>,
     // Confirm delete
     pub confirm_delete: bool,
+    // Bulk download progress (done, total)
+    pub download: Option<(usize, usize)>,
+    // Import progress (done, total)
+    pub import: Option<(usize, usize)>,
 }
 
 impl ListsState {
@@ -40,7 +49,11 @@ impl ListsState {
             problem_table_state: TableState::default(),
             create_mode: false,
             create_input: String::new(),
+            import_mode: false,
+            import_into: None,
             confirm_delete: false,
+            download: None,
+            import: None,
         }
     }
 
@@ -103,6 +116,21 @@ impl ListsState {
             }
             KeyCode::Char('n') => {
                 self.create_mode = true;
+                self.import_mode = false;
+                self.create_input.clear();
+                ListsAction::None
+            }
+            KeyCode::Char('i') | KeyCode::Char('I') if self.import.is_none() => {
+                self.import_into = if key.code == KeyCode::Char('I') {
+                    match self.selected_list_idx() {
+                        Some(idx) if idx < self.lists.len() => Some(idx),
+                        _ => return ListsAction::None,
+                    }
+                } else {
+                    None
+                };
+                self.create_mode = true;
+                self.import_mode = true;
                 self.create_input.clear();
                 ListsAction::None
             }
@@ -112,6 +140,14 @@ impl ListsState {
                 }
                 ListsAction::None
             }
+            KeyCode::Char('e') => match self.selected_list_idx() {
+                Some(idx) if idx < self.lists.len() => ListsAction::ExportJson(idx),
+                _ => ListsAction::None,
+            },
+            KeyCode::Char('u') => match self.selected_list_idx() {
+                Some(idx) if idx < self.lists.len() => ListsAction::CopyShareUrl(idx),
+                _ => ListsAction::None,
+            },
             _ => ListsAction::None,
         }
     }
@@ -153,6 +189,17 @@ impl ListsState {
                 }
                 ListsAction::None
             }
+            KeyCode::Char('D') => {
+                if self.download.is_some() {
+                    return ListsAction::None;
+                }
+                match self.viewing_list_ref() {
+                    Some(list) if !list.questions.is_empty() => ListsAction::DownloadAll(
+                        list.questions.iter().map(|q| q.title_slug.clone()).collect(),
+                    ),
+                    _ => ListsAction::None,
+                }
+            }
             _ => ListsAction::None,
         }
     }
@@ -169,7 +216,14 @@ impl ListsState {
                     let name = self.create_input.trim().to_string();
                     self.create_mode = false;
                     self.create_input.clear();
-                    ListsAction::CreateList(name)
+                    if self.import_mode {
+                        ListsAction::ImportFile {
+                            path: name,
+                            into: self.import_into,
+                        }
+                    } else {
+                        ListsAction::CreateList(name)
+                    }
                 } else {
                     self.create_mode = false;
                     self.create_input.clear();
@@ -236,6 +290,10 @@ pub enum ListsAction {
     CreateList(String),
     DeleteList(String),
     RemoveProblem { id_hash: String, question_id: String },
+    DownloadAll(Vec<String>),
+    ExportJson(usize),
+    CopyShareUrl(usize),
+    ImportFile { path: String, into: Option<usize> },
 }
 
 pub fn render_lists(frame: &mut Frame, area: Rect, state: &mut ListsState) {
@@ -254,11 +312,11 @@ pub fn render_lists(frame: &mut Frame, area: Rect, state: &mut ListsState) {
         let spinner = ["\u{280b}", "\u{2819}", "\u{2839}", "\u{2838}", "\u{283c}", "\u{2834}", "\u{2826}", "\u{2827}", "\u{2807}", "\u{280f}"];
         let s = spinner[state.spinner_frame % spinner.len()];
         let loading = Paragraph::new(format!(" {s} Loading lists..."))
-            .style(Style::default().fg(Color::Yellow));
+            .style(Style::default().fg(theme().warning));
         frame.render_widget(loading, layout[1]);
     } else if let Some(ref err) = state.error_message {
         let error = Paragraph::new(format!(" Error: {err}"))
-            .style(Style::default().fg(Color::Red));
+            .style(Style::default().fg(theme().error));
         frame.render_widget(error, layout[1]);
     } else if state.viewing_list.is_some() {
         render_problem_table(frame, layout[1], state);
@@ -268,7 +326,8 @@ pub fn render_lists(frame: &mut Frame, area: Rect, state: &mut ListsState) {
 
     // Status bar
     let hints = if state.create_mode {
-        vec![("Enter", "Create"), ("Esc", "Cancel")]
+        let verb = if state.import_mode { "Import" } else { "Create" };
+        vec![("Enter", verb), ("Esc", "Cancel")]
     } else if state.confirm_delete {
         vec![("y", "Confirm"), ("any", "Cancel")]
     } else if state.viewing_list.is_some() {
@@ -276,6 +335,7 @@ pub fn render_lists(frame: &mut Frame, area: Rect, state: &mut ListsState) {
             ("j/k", "Navigate"),
             ("Enter", "View"),
             ("d", "Remove"),
+            ("D", "Download All"),
             ("b/Esc", "Back"),
             ("?", "Help"),
         ]
@@ -285,6 +345,9 @@ pub fn render_lists(frame: &mut Frame, area: Rect, state: &mut ListsState) {
             ("Enter", "Open"),
             ("n", "New List"),
             ("d", "Delete"),
+            ("e", "Export"),
+            ("i/I", "Import"),
+            ("u", "Share URL"),
             ("Esc", "Back"),
             ("?", "Help"),
         ]
@@ -293,7 +356,12 @@ pub fn render_lists(frame: &mut Frame, area: Rect, state: &mut ListsState) {
 
     // Create overlay
     if state.create_mode {
-        render_create_overlay(frame, area, &state.create_input);
+        let title = match state.import_into.and_then(|i| state.lists.get(i)) {
+            _ if !state.import_mode => " New List ".to_string(),
+            Some(list) => format!(" Import into {} (path to .json / .csv) ", list.name),
+            None => " Import List (path to .json / .csv) ".to_string(),
+        };
+        render_create_overlay(frame, area, &title, &state.create_input);
     }
 
     // Confirm delete overlay
@@ -309,8 +377,8 @@ fn render_title_bar(frame: &mut Frame, area: Rect, state: &ListsState) {
         Span::styled(
             " Lists ",
             Style::default()
-                .fg(Color::Black)
-                .bg(Color::Magenta)
+                .fg(theme().badge_fg)
+                .bg(theme().special)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" "),
@@ -320,21 +388,34 @@ fn render_title_bar(frame: &mut Frame, area: Rect, state: &ListsState) {
         spans.push(Span::styled(
             format!("{} ", list.name),
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         ));
         spans.push(Span::styled(
             format!("{} problems", list.questions.len()),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme().muted),
         ));
     } else {
         spans.push(Span::styled(
             format!("{} lists", state.lists.len()),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme().muted),
+        ));
+    }
+
+    if let Some((done, total)) = state.download {
+        spans.push(Span::styled(
+            format!("  Downloading {done}/{total}"),
+            Style::default().fg(theme().warning),
+        ));
+    }
+    if let Some((done, total)) = state.import {
+        spans.push(Span::styled(
+            format!("  Importing {done}/{total}"),
+            Style::default().fg(theme().warning),
         ));
     }
 
-    let title = Paragraph::new(Line::from(spans)).style(Style::default().bg(Color::Black));
+    let title = Paragraph::new(Line::from(spans)).style(Style::default().bg(theme().bar_bg));
     frame.render_widget(title, area);
 }
 
@@ -346,7 +427,7 @@ fn render_list_table(frame: &mut Frame, area: Rect, state: &mut ListsState) {
     ])
     .style(
         Style::default()
-            .fg(Color::Cyan)
+            .fg(theme().accent)
             .add_modifier(Modifier::BOLD),
     );
 
@@ -355,9 +436,9 @@ fn render_list_table(frame: &mut Frame, area: Rect, state: &mut ListsState) {
         .iter()
         .map(|list| {
             let vis = if list.is_public_favorite {
-                Span::styled("Public", Style::default().fg(Color::Green))
+                Span::styled("Public", Style::default().fg(theme().success))
             } else {
-                Span::styled("Private", Style::default().fg(Color::DarkGray))
+                Span::styled("Private", Style::default().fg(theme().muted))
             };
             Row::new([
                 Cell::from(format!(" {}", list.name)),
@@ -378,7 +459,7 @@ fn render_list_table(frame: &mut Frame, area: Rect, state: &mut ListsState) {
         .block(Block::default().borders(Borders::NONE))
         .row_highlight_style(
             Style::default()
-                .bg(Color::DarkGray)
+                .bg(theme().selection)
                 .add_modifier(Modifier::BOLD),
         )
         .highlight_symbol("\u{25b8} ");
@@ -398,7 +479,7 @@ fn render_problem_table(frame: &mut Frame, area: Rect, state: &mut ListsState) {
     ])
     .style(
         Style::default()
-            .fg(Color::Cyan)
+            .fg(theme().accent)
             .add_modifier(Modifier::BOLD),
     );
 
@@ -409,11 +490,11 @@ fn render_problem_table(frame: &mut Frame, area: Rect, state: &mut ListsState) {
             let status_cell = match q.status.as_deref() {
                 Some("ac") => Cell::from(Span::styled(
                     " \u{2714}",
-                    Style::default().fg(Color::Green),
+                    Style::default().fg(theme().success),
                 )),
                 Some("notac") => Cell::from(Span::styled(
                     " \u{25cf}",
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme().warning),
                 )),
                 _ => Cell::from("  "),
             };
@@ -431,7 +512,7 @@ fn render_problem_table(frame: &mut Frame, area: Rect, state: &mut ListsState) {
         .block(Block::default().borders(Borders::NONE))
         .row_highlight_style(
             Style::default()
-                .bg(Color::DarkGray)
+                .bg(theme().selection)
                 .add_modifier(Modifier::BOLD),
         )
         .highlight_symbol("\u{25b8} ");
@@ -439,8 +520,8 @@ fn render_problem_table(frame: &mut Frame, area: Rect, state: &mut ListsState) {
     frame.render_stateful_widget(table, area, &mut state.problem_table_state);
 }
 
-fn render_create_overlay(frame: &mut Frame, area: Rect, input: &str) {
-    let w = 40u16.min(area.width.saturating_sub(4));
+fn render_create_overlay(frame: &mut Frame, area: Rect, title: &str, input: &str) {
+    let w = 50u16.min(area.width.saturating_sub(4));
     let h = 5u16;
     let x = area.x + (area.width.saturating_sub(w)) / 2;
     let y = area.y + (area.height.saturating_sub(h)) / 2;
@@ -451,11 +532,11 @@ fn render_create_overlay(frame: &mut Frame, area: Rect, input: &str) {
     let p = Paragraph::new(text)
         .block(
             Block::default()
-                .title(" New List ")
+                .title(title)
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(Style::default().fg(theme().accent)),
         )
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme().text))
         .wrap(Wrap { trim: false });
     frame.render_widget(p, overlay);
 }
@@ -479,9 +560,9 @@ fn render_confirm_delete(frame: &mut Frame, area: Rect, name: &str, problem_coun
             Block::default()
                 .title(" Confirm Delete ")
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Red)),
+                .border_style(Style::default().fg(theme().error)),
         )
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme().text))
         .wrap(Wrap { trim: true });
     frame.render_widget(p, overlay);
 }
@@ -0,0 +1,313 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
+    Frame,
+};
+
+use crate::api::types::QuestionDetail;
+use crate::local_test::{CaseOutcome, LocalCase};
+
+use super::status_bar::render_status_bar;
+use super::theme::theme;
+
+/// Example cases run against the local solution, filled in as they finish.
+pub struct LocalTestState {
+    pub detail: QuestionDetail,
+    pub command: String,
+    pub cases: Vec<LocalCase>,
+    pub outcomes: Vec<Option<CaseOutcome>>,
+    pub build_error: Option<String>,
+    pub table_state: TableState,
+    pub spinner_frame: usize,
+}
+
+impl LocalTestState {
+    pub fn new(detail: QuestionDetail, command: String, cases: Vec<LocalCase>) -> Self {
+        let outcomes = vec![None; cases.len()];
+        let mut table_state = TableState::default();
+        if !cases.is_empty() {
+            table_state.select(Some(0));
+        }
+        Self {
+            detail,
+            command,
+            cases,
+            outcomes,
+            build_error: None,
+            table_state,
+            spinner_frame: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.outcomes = vec![None; self.cases.len()];
+        self.build_error = None;
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.build_error.is_none() && self.outcomes.iter().any(Option::is_none)
+    }
+
+    pub fn set_outcome(&mut self, index: usize, outcome: CaseOutcome) {
+        if let Some(slot) = self.outcomes.get_mut(index) {
+            *slot = Some(outcome);
+        }
+    }
+
+    /// (passed, checked) over cases that have an expected output.
+    pub fn score(&self) -> (usize, usize) {
+        let passed = self
+            .outcomes
+            .iter()
+            .filter(|o| matches!(o, Some(CaseOutcome::Passed { .. })))
+            .count();
+        let checked = self.cases.iter().filter(|c| c.expected.is_some()).count();
+        (passed, checked)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> LocalTestAction {
+        match key.code {
+            KeyCode::Char('b') | KeyCode::Esc => LocalTestAction::Back,
+            KeyCode::Char('q') => LocalTestAction::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                LocalTestAction::Quit
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.move_selection(1);
+                LocalTestAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.move_selection(-1);
+                LocalTestAction::None
+            }
+            KeyCode::Char('r') if !self.is_running() => LocalTestAction::Rerun,
+            _ => LocalTestAction::None,
+        }
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        if self.cases.is_empty() {
+            return;
+        }
+        let current = self.table_state.selected().unwrap_or(0) as i32;
+        let max = self.cases.len() as i32 - 1;
+        let next = (current + delta).clamp(0, max) as usize;
+        self.table_state.select(Some(next));
+    }
+}
+
+pub enum LocalTestAction {
+    None,
+    Back,
+    Quit,
+    Rerun,
+}
+
+/// Several lines squeezed into one table cell.
+fn one_line(s: &str) -> String {
+    s.lines().collect::<Vec<_>>().join(" | ")
+}
+
+pub fn render_local_test(frame: &mut Frame, area: Rect, state: &mut LocalTestState) {
+    let layout = Layout::vertical([
+        Constraint::Length(1), // title bar
+        Constraint::Min(3),   // cases
+        Constraint::Length(10), // selected case
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    // Title bar
+    let d = &state.detail;
+    let (passed, checked) = state.score();
+    let mut spans = vec![
+        Span::styled(
+            " Local Test ",
+            Style::default()
+                .fg(theme().badge_fg)
+                .bg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!("{}. {} ", d.frontend_question_id, d.title),
+            Style::default()
+                .fg(theme().text)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if state.is_running() {
+        let spinner = ["\u{280b}", "\u{2819}", "\u{2839}", "\u{2838}", "\u{283c}", "\u{2834}", "\u{2826}", "\u{2827}", "\u{2807}", "\u{280f}"];
+        spans.push(Span::styled(
+            format!("{} running", spinner[state.spinner_frame % spinner.len()]),
+            Style::default().fg(theme().warning),
+        ));
+    } else if state.build_error.is_none() && checked > 0 {
+        let color = if passed == checked { theme().success } else { theme().error };
+        spans.push(Span::styled(
+            format!("{passed}/{checked} passed"),
+            Style::default().fg(color),
+        ));
+    }
+    spans.push(Span::styled(
+        format!("  $ {}", state.command),
+        Style::default().fg(theme().muted),
+    ));
+    frame.render_widget(
+        Paragraph::new(Line::from(spans)).style(Style::default().bg(theme().bar_bg)),
+        layout[0],
+    );
+
+    // Build failures replace the table
+    if let Some(ref err) = state.build_error {
+        let error = Paragraph::new(err.clone())
+            .style(Style::default().fg(theme().error))
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::NONE));
+        let area = layout[1].union(layout[2]);
+        frame.render_widget(error, area);
+    } else if state.cases.is_empty() {
+        let empty = Paragraph::new(" This problem has no example testcases.")
+            .style(Style::default().fg(theme().muted));
+        frame.render_widget(empty, layout[1]);
+    } else {
+        render_case_table(frame, layout[1], state);
+        render_case_detail(frame, layout[2], state);
+    }
+
+    let mut hints = vec![("j/k", "Navigate")];
+    if !state.is_running() {
+        hints.push(("r", "Rerun"));
+    }
+    hints.extend([("b/Esc", "Back"), ("q", "Quit"), ("?", "Help")]);
+    render_status_bar(frame, layout[3], &hints);
+}
+
+fn render_case_table(frame: &mut Frame, area: Rect, state: &mut LocalTestState) {
+    let header = Row::new([
+        Cell::from(" #"),
+        Cell::from("Status"),
+        Cell::from("Time"),
+        Cell::from("Input"),
+        Cell::from("Expected"),
+        Cell::from("Output"),
+    ])
+    .style(
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let rows: Vec<Row> = state
+        .cases
+        .iter()
+        .zip(&state.outcomes)
+        .enumerate()
+        .map(|(i, (case, outcome))| {
+            let (status, color) = match outcome {
+                None => ("\u{2026}", theme().muted),
+                Some(CaseOutcome::Passed { .. }) => ("\u{2714} pass", theme().success),
+                Some(CaseOutcome::Failed { .. }) => ("\u{2718} fail", theme().error),
+                Some(CaseOutcome::Unchecked { .. }) => ("? ran", theme().warning),
+                Some(CaseOutcome::Error(_)) => ("! error", theme().error),
+            };
+            let took = outcome
+                .as_ref()
+                .and_then(CaseOutcome::took)
+                .map(|d| format!("{}ms", d.as_millis()))
+                .unwrap_or_default();
+            let output = match outcome {
+                Some(CaseOutcome::Error(e)) => e.lines().next().unwrap_or_default().to_string(),
+                Some(o) => one_line(o.output().unwrap_or_default()),
+                None => String::new(),
+            };
+            Row::new([
+                Cell::from(format!(" {}", i + 1)),
+                Cell::from(Span::styled(status, Style::default().fg(color))),
+                Cell::from(Span::styled(took, Style::default().fg(theme().muted))),
+                Cell::from(one_line(&case.input)),
+                Cell::from(case.expected.clone().unwrap_or_else(|| "-".to_string())),
+                Cell::from(Span::styled(output, Style::default().fg(color))),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(4),
+        Constraint::Length(9),
+        Constraint::Length(8),
+        Constraint::Percentage(40),
+        Constraint::Percentage(25),
+        Constraint::Percentage(25),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::NONE))
+        .row_highlight_style(
+            Style::default()
+                .bg(theme().selection)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{25b8} ");
+
+    frame.render_stateful_widget(table, area, &mut state.table_state);
+}
+
+/// Full input/expected/output of the selected case, untruncated.
+fn render_case_detail(frame: &mut Frame, area: Rect, state: &LocalTestState) {
+    let Some(i) = state.table_state.selected() else {
+        return;
+    };
+    let (Some(case), Some(outcome)) = (state.cases.get(i), state.outcomes.get(i)) else {
+        return;
+    };
+
+    let label = |s: &'static str| {
+        Line::from(Span::styled(
+            s,
+            Style::default()
+                .fg(theme().text)
+                .add_modifier(Modifier::BOLD),
+        ))
+    };
+    let body = |s: &str, color| {
+        s.lines()
+            .map(|l| Line::from(Span::styled(format!("  {l}"), Style::default().fg(color))))
+            .collect::<Vec<_>>()
+    };
+
+    let mut lines = vec![label("Input:")];
+    lines.extend(body(&case.input, theme().subtle));
+    if let Some(ref expected) = case.expected {
+        lines.push(label("Expected:"));
+        lines.extend(body(expected, theme().success));
+    }
+    match outcome {
+        Some(CaseOutcome::Error(e)) => {
+            lines.push(label("Error:"));
+            lines.extend(body(e, theme().error));
+        }
+        Some(o) => {
+            lines.push(label("Output:"));
+            let color = match o {
+                CaseOutcome::Passed { .. } => theme().success,
+                CaseOutcome::Failed { .. } => theme().error,
+                _ => theme().text,
+            };
+            lines.extend(body(o.output().unwrap_or_default(), color));
+        }
+        None => {}
+    }
+
+    let detail = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::TOP)
+            .title(format!(" Case {} ", i + 1))
+            .border_style(Style::default().fg(theme().muted)),
+    );
+    frame.render_widget(detail, area);
+}
@@ -1,7 +1,26 @@
+pub mod analytics;
+pub mod ansi;
+pub mod code_view;
+pub mod companies;
+pub mod contests;
 pub mod home;
 pub mod detail;
+pub mod diff;
+pub mod editor;
+pub mod editorial;
+pub mod graphics;
+pub mod history;
+pub mod hyperlink;
 pub mod lists;
+pub mod local_test;
 pub mod result;
 pub mod rich_text;
+pub mod scratchpad;
+pub mod search;
 pub mod setup;
+pub mod skills;
+pub mod solutions;
+pub mod study_plans;
+pub mod submissions;
 pub mod status_bar;
+pub mod theme;
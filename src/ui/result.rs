@@ -1,7 +1,7 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
@@ -9,9 +9,15 @@ use ratatui::{
 
 use crate::api::types::CheckResponse;
 
+use super::ansi::{ansi_to_spans, strip_ansi};
+use super::search::{SearchInput, TextSearch};
 use super::status_bar::render_status_bar;
+use super::theme::theme;
 
-#[derive(Debug, Clone, Copy)]
+/// Max lines shown per output section before it is collapsed behind a marker.
+pub const MAX_OUTPUT_LINES: usize = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ResultKind {
     Run,
     Submit,
@@ -48,6 +54,24 @@ impl ResultData {
             compile_error: resp.full_compile_error.clone().or(resp.compile_error.clone()),
         }
     }
+
+    /// Untruncated plain-text rendering of the result, for the clipboard.
+    pub fn to_plain_text(&self) -> String {
+        let mut out = format!("{}\n", self.status_msg);
+        if let Some(ref err) = self.compile_error {
+            out.push_str(&format!("\nCompile Error:\n{err}\n"));
+        }
+        if let Some(ref input) = self.last_testcase {
+            out.push_str(&format!("\nLast Testcase:\n{input}\n"));
+        }
+        if let Some(ref expected) = self.expected_output {
+            out.push_str(&format!("\nExpected:\n{expected}\n"));
+        }
+        if let Some(ref output) = self.code_output {
+            out.push_str(&format!("\nOutput:\n{}\n", output.join("\n")));
+        }
+        strip_ansi(&out)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -66,6 +90,8 @@ pub struct ResultState {
     pub content_lines: Vec<Line<'static>>,
     pub content_height: u16,
     pub detail: crate::api::types::QuestionDetail,
+    pub expanded: bool,
+    pub search: TextSearch,
 }
 
 impl ResultState {
@@ -79,11 +105,13 @@ impl ResultState {
             content_lines: Vec::new(),
             content_height: 0,
             detail,
+            expanded: false,
+            search: TextSearch::default(),
         }
     }
 
     pub fn set_result(&mut self, data: ResultData) {
-        self.content_lines = build_result_lines(&data, self.kind);
+        self.content_lines = build_result_lines(&data, self.kind, self.expanded);
         self.status = ResultStatus::Success(data);
     }
 
@@ -92,14 +120,43 @@ impl ResultState {
             Line::from(""),
             Line::from(Span::styled(
                 format!("  Error: {msg}"),
-                Style::default().fg(Color::Red),
+                Style::default().fg(theme().error),
             )),
         ];
         self.status = ResultStatus::Error(msg);
     }
 
+    /// Input of the case that failed, if the verdict came with one.
+    pub fn failing_input(&self) -> Option<&str> {
+        match self.status {
+            ResultStatus::Success(ref data) if data.status_code != 10 => {
+                data.last_testcase.as_deref().filter(|s| !s.trim().is_empty())
+            }
+            _ => None,
+        }
+    }
+
     pub fn handle_key(&mut self, key: KeyEvent) -> ResultAction {
+        if self.search.editing {
+            if let SearchInput::Changed = self.search.handle_input(key, &self.content_lines) {
+                self.scroll_to_match(self.search.current_line(&self.content_lines));
+            }
+            return ResultAction::None;
+        }
         match key.code {
+            KeyCode::Char('/') => {
+                self.search.start(self.scroll_offset as usize);
+                ResultAction::None
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') if self.search.is_active() => {
+                let line = self.search.step(&self.content_lines, key.code == KeyCode::Char('n'));
+                self.scroll_to_match(line);
+                ResultAction::None
+            }
+            KeyCode::Esc if self.search.is_active() => {
+                self.search.clear();
+                ResultAction::None
+            }
             KeyCode::Char('b') | KeyCode::Esc => ResultAction::Back,
             KeyCode::Char('q') => ResultAction::Quit,
             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
@@ -113,20 +170,52 @@ impl ResultState {
                 self.scroll(-1);
                 ResultAction::None
             }
+            KeyCode::Char('e') => {
+                if let ResultStatus::Success(ref data) = self.status {
+                    self.expanded = !self.expanded;
+                    self.content_lines = build_result_lines(data, self.kind, self.expanded);
+                }
+                ResultAction::None
+            }
+            KeyCode::Char('y') => match self.status {
+                ResultStatus::Success(ref data) => ResultAction::Copy(data.to_plain_text()),
+                _ => ResultAction::None,
+            },
+            KeyCode::Char('t') => match self.failing_input() {
+                Some(input) => ResultAction::DebugInput(input.to_string()),
+                None => ResultAction::None,
+            },
+            KeyCode::Char('G') if self.is_accepted() => ResultAction::UploadGist,
             _ => ResultAction::None,
         }
     }
 
-    fn scroll(&mut self, delta: i32) {
+    pub fn scroll(&mut self, delta: i32) {
         let new_offset = self.scroll_offset as i32 + delta;
         self.scroll_offset = new_offset.max(0) as u16;
     }
+
+    /// Bring a search match into view, with a little context above it.
+    fn scroll_to_match(&mut self, line: Option<usize>) {
+        if let Some(line) = line {
+            self.scroll_offset = line.saturating_sub(2) as u16;
+        }
+    }
+
+    /// An accepted submission (not just passing sample cases).
+    pub fn is_accepted(&self) -> bool {
+        matches!(self.status, ResultStatus::Success(ref data)
+            if self.kind == ResultKind::Submit && data.status_code == 10)
+    }
 }
 
 pub enum ResultAction {
     None,
     Back,
     Quit,
+    Copy(String),
+    DebugInput(String),
+    UploadGist,
 }
 
 pub fn render_result(frame: &mut Frame, area: Rect, state: &mut ResultState) {
@@ -146,15 +235,15 @@ pub fn render_result(frame: &mut Frame, area: Rect, state: &mut ResultState) {
         Span::styled(
             format!(" {kind_label} Result "),
             Style::default()
-                .fg(Color::Black)
-                .bg(Color::Yellow)
+                .fg(theme().badge_fg)
+                .bg(theme().warning)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" "),
         Span::styled(
             &state.problem_title,
             Style::default()
-                .fg(Color::White)
+                .fg(theme().text)
                 .add_modifier(Modifier::BOLD),
         ),
     ]);
@@ -163,12 +252,51 @@ pub fn render_result(frame: &mut Frame, area: Rect, state: &mut ResultState) {
         .block(
             Block::default()
                 .borders(Borders::BOTTOM)
-                .border_style(Style::default().fg(Color::DarkGray)),
+                .border_style(Style::default().fg(theme().muted)),
         );
     frame.render_widget(title_block, layout[0]);
 
-    // Content area
-    state.content_height = layout[1].height;
+    render_result_body(frame, layout[1], state);
+
+    // Status bar
+    let mut hints = vec![("j/k", "Scroll"), ("e", "Expand"), ("y", "Copy")];
+    if state.failing_input().is_some() {
+        hints.push(("t", "Debug case"));
+    }
+    if state.is_accepted() {
+        hints.push(("G", "Gist"));
+    }
+    hints.extend([("/", "Search"), ("b/Esc", "Back"), ("q", "Quit"), ("?", "Help")]);
+    if state.search.is_active() {
+        state.search.render(frame, layout[2], &state.content_lines);
+    } else {
+        render_status_bar(frame, layout[2], &hints);
+    }
+}
+
+/// Verdict pane shown under the problem statement in `inline` result mode.
+pub fn render_inline_result(frame: &mut Frame, area: Rect, state: &mut ResultState) {
+    let kind_label = match state.kind {
+        ResultKind::Run => " Run Result ",
+        ResultKind::Submit => " Submit Result ",
+    };
+    let block = Block::default()
+        .title(Span::styled(
+            kind_label,
+            Style::default()
+                .fg(theme().warning)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(theme().muted));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    render_result_body(frame, inner, state);
+}
+
+/// Spinner while pending, otherwise the scrollable verdict lines.
+fn render_result_body(frame: &mut Frame, area: Rect, state: &mut ResultState) {
+    state.content_height = area.height;
 
     if matches!(state.status, ResultStatus::Pending) {
         let spinner = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
@@ -179,8 +307,8 @@ pub fn render_result(frame: &mut Frame, area: Rect, state: &mut ResultState) {
             ResultKind::Submit => "Submitting",
         };
         let loading = Paragraph::new(format!("\n  {s} {kind_verb}... ({elapsed}s)"))
-            .style(Style::default().fg(Color::Yellow));
-        frame.render_widget(loading, layout[1]);
+            .style(Style::default().fg(theme().warning));
+        frame.render_widget(loading, area);
     } else {
         let total_lines = state.content_lines.len() as u16;
         let max_scroll = total_lines.saturating_sub(state.content_height);
@@ -188,39 +316,56 @@ pub fn render_result(frame: &mut Frame, area: Rect, state: &mut ResultState) {
             state.scroll_offset = max_scroll;
         }
 
-        let content = Paragraph::new(state.content_lines.clone())
+        let lines = if state.search.is_active() {
+            state.search.highlight(&state.content_lines)
+        } else {
+            state.content_lines.clone()
+        };
+        let content = Paragraph::new(lines)
             .block(Block::default().borders(Borders::NONE))
             .wrap(Wrap { trim: false })
             .scroll((state.scroll_offset, 0));
 
-        frame.render_widget(content, layout[1]);
+        frame.render_widget(content, area);
     }
+}
 
-    // Status bar
-    render_status_bar(
-        frame,
-        layout[2],
-        &[
-            ("j/k", "Scroll"),
-            ("b/Esc", "Back"),
-            ("q", "Quit"),
-            ("?", "Help"),
-        ],
-    );
+/// Push `items` as indented lines, eliding everything past `MAX_OUTPUT_LINES`
+/// behind a marker unless `expanded` is set.
+fn push_capped<S: AsRef<str>>(
+    lines: &mut Vec<Line<'static>>,
+    items: &[S],
+    indent: &str,
+    style: Style,
+    expanded: bool,
+) {
+    let total = items.len();
+    let shown = if expanded { total } else { total.min(MAX_OUTPUT_LINES) };
+    for line in &items[..shown] {
+        let mut spans = vec![Span::styled(indent.to_string(), style)];
+        spans.extend(ansi_to_spans(line.as_ref(), style));
+        lines.push(Line::from(spans));
+    }
+    if shown < total {
+        lines.push(Line::from(Span::styled(
+            format!("{indent}\u{2026} ({} more lines, press e to expand)", total - shown),
+            Style::default().fg(theme().muted).add_modifier(Modifier::ITALIC),
+        )));
+    }
 }
 
-fn build_result_lines(data: &ResultData, kind: ResultKind) -> Vec<Line<'static>> {
+pub fn build_result_lines(data: &ResultData, kind: ResultKind, expanded: bool) -> Vec<Line<'static>> {
     let mut lines: Vec<Line<'static>> = Vec::new();
     lines.push(Line::from(""));
 
     // Status code 10 = Accepted, 11 = Wrong Answer, 12 = MLE, 13 = Output Limit,
     // 14 = TLE, 15 = Runtime Error, 20 = Compile Error
     let (icon, color) = match data.status_code {
-        10 => ("✔", Color::Green),
-        20 => ("✘", Color::Red),
-        14 => ("⏱", Color::Yellow),
-        15 => ("!", Color::Red),
-        _ => ("✘", Color::Red),
+        10 => ("✔", theme().success),
+        20 => ("✘", theme().error),
+        14 => ("⏱", theme().warning),
+        15 => ("!", theme().error),
+        _ => ("✘", theme().error),
     };
 
     lines.push(Line::from(Span::styled(
@@ -232,10 +377,10 @@ fn build_result_lines(data: &ResultData, kind: ResultKind) -> Vec<Line<'static>>
     // Passed count
     if let (Some(correct), Some(total)) = (data.total_correct, data.total_testcases) {
         lines.push(Line::from(vec![
-            Span::styled("  Passed: ", Style::default().fg(Color::White)),
+            Span::styled("  Passed: ", Style::default().fg(theme().text)),
             Span::styled(
                 format!("{correct} / {total}"),
-                Style::default().fg(if correct == total { Color::Green } else { Color::Yellow }),
+                Style::default().fg(if correct == total { theme().success } else { theme().warning }),
             ),
         ]));
     }
@@ -243,14 +388,14 @@ fn build_result_lines(data: &ResultData, kind: ResultKind) -> Vec<Line<'static>>
     // Runtime & memory (for accepted/submit)
     if let Some(ref rt) = data.runtime {
         lines.push(Line::from(vec![
-            Span::styled("  Runtime: ", Style::default().fg(Color::White)),
-            Span::styled(rt.clone(), Style::default().fg(Color::Cyan)),
+            Span::styled("  Runtime: ", Style::default().fg(theme().text)),
+            Span::styled(rt.clone(), Style::default().fg(theme().accent)),
         ]));
     }
     if let Some(ref mem) = data.memory {
         lines.push(Line::from(vec![
-            Span::styled("  Memory: ", Style::default().fg(Color::White)),
-            Span::styled(mem.clone(), Style::default().fg(Color::Cyan)),
+            Span::styled("  Memory: ", Style::default().fg(theme().text)),
+            Span::styled(mem.clone(), Style::default().fg(theme().accent)),
         ]));
     }
 
@@ -259,14 +404,15 @@ fn build_result_lines(data: &ResultData, kind: ResultKind) -> Vec<Line<'static>>
         lines.push(Line::from(""));
         lines.push(Line::from(Span::styled(
             "  Compile Error:",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            Style::default().fg(theme().error).add_modifier(Modifier::BOLD),
         )));
-        for line in err.lines() {
-            lines.push(Line::from(Span::styled(
-                format!    ("  {line}"),
-                Style::default().fg(Color::Red),
-            )));
-        }
+        push_capped(
+            &mut lines,
+            &err.lines().collect::<Vec<_>>(),
+            "  ",
+            Style::default().fg(theme().error),
+            expanded,
+        );
     }
 
     // Wrong answer diff
@@ -275,42 +421,45 @@ fn build_result_lines(data: &ResultData, kind: ResultKind) -> Vec<Line<'static>>
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "  Last Testcase:",
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().text).add_modifier(Modifier::BOLD),
             )));
-            for line in input.lines() {
-                lines.push(Line::from(Span::styled(
-                    format!("    {line}"),
-                    Style::default().fg(Color::Gray),
-                )));
-            }
+            push_capped(
+                &mut lines,
+                &input.lines().collect::<Vec<_>>(),
+                "    ",
+                Style::default().fg(theme().subtle),
+                expanded,
+            );
         }
 
         if let Some(ref expected) = data.expected_output {
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "  Expected:",
-                Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().success).add_modifier(Modifier::BOLD),
             )));
-            for line in expected.lines() {
-                lines.push(Line::from(Span::styled(
-                    format!("    {line}"),
-                    Style::default().fg(Color::Green),
-                )));
-            }
+            push_capped(
+                &mut lines,
+                &expected.lines().collect::<Vec<_>>(),
+                "    ",
+                Style::default().fg(theme().success),
+                expanded,
+            );
         }
 
         if let Some(ref output) = data.code_output {
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "  Output:",
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().error).add_modifier(Modifier::BOLD),
             )));
-            for line in output {
-                lines.push(Line::from(Span::styled(
-                    format!("    {line}"),
-                    Style::default().fg(Color::Red),
-                )));
-            }
+            push_capped(
+                &mut lines,
+                output,
+                "    ",
+                Style::default().fg(theme().error),
+                expanded,
+            );
         }
     }
 
@@ -321,30 +470,95 @@ fn build_result_lines(data: &ResultData, kind: ResultKind) -> Vec<Line<'static>>
                 lines.push(Line::from(""));
                 lines.push(Line::from(Span::styled(
                     "  Output:",
-                    Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme().text).add_modifier(Modifier::BOLD),
                 )));
-                for line in output {
-                    lines.push(Line::from(Span::styled(
-                        format!("    {line}"),
-                        Style::default().fg(Color::White),
-                    )));
-                }
+                push_capped(
+                    &mut lines,
+                    output,
+                    "    ",
+                    Style::default().fg(theme().text),
+                    expanded,
+                );
             }
         }
         if let Some(ref expected) = data.expected_output {
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
                 "  Expected:",
-                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                Style::default().fg(theme().text).add_modifier(Modifier::BOLD),
             )));
-            for line in expected.lines() {
-                lines.push(Line::from(Span::styled(
-                    format!("    {line}"),
-                    Style::default().fg(Color::Green),
-                )));
-            }
+            push_capped(
+                &mut lines,
+                &expected.lines().collect::<Vec<_>>(),
+                "    ",
+                Style::default().fg(theme().success),
+                expanded,
+            );
         }
     }
 
     lines
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    fn wrong_answer(output_lines: usize) -> ResultData {
+        ResultData {
+            status_msg: "Wrong Answer".to_string(),
+            status_code: 11,
+            total_correct: Some(3),
+            total_testcases: Some(10),
+            runtime: None,
+            memory: None,
+            code_output: Some((0..output_lines).map(|i| i.to_string()).collect()),
+            expected_output: Some("42".to_string()),
+            last_testcase: Some("[1,2,3]".to_string()),
+            compile_error: None,
+        }
+    }
+
+    #[test]
+    fn output_past_the_cap_is_elided_with_its_count() {
+        let data = wrong_answer(MAX_OUTPUT_LINES + 25);
+        let lines: Vec<String> = build_result_lines(&data, ResultKind::Submit, false)
+            .iter()
+            .map(text)
+            .collect();
+
+        let markers: Vec<&String> = lines.iter().filter(|l| l.contains("more lines")).collect();
+        assert_eq!(markers.len(), 1);
+        assert!(markers[0].contains("(25 more lines, press e to expand)"));
+        let last_shown = (MAX_OUTPUT_LINES - 1).to_string();
+        assert!(lines.iter().any(|l| l.trim() == last_shown));
+        assert!(!lines.iter().any(|l| l.trim() == MAX_OUTPUT_LINES.to_string()));
+    }
+
+    #[test]
+    fn expanded_or_short_output_is_shown_whole() {
+        let data = wrong_answer(MAX_OUTPUT_LINES + 25);
+        let lines = build_result_lines(&data, ResultKind::Submit, true);
+        assert!(!lines.iter().any(|l| text(l).contains("more lines")));
+        let last = (MAX_OUTPUT_LINES + 24).to_string();
+        assert!(lines.iter().any(|l| text(l).trim() == last));
+
+        let data = wrong_answer(MAX_OUTPUT_LINES);
+        let lines = build_result_lines(&data, ResultKind::Submit, false);
+        assert!(!lines.iter().any(|l| text(l).contains("more lines")));
+    }
+
+    #[test]
+    fn plain_text_keeps_everything_for_the_clipboard() {
+        let data = wrong_answer(MAX_OUTPUT_LINES + 25);
+        let plain = data.to_plain_text();
+        // Every output line, plus the expected "42"
+        let numbers = plain.lines().filter(|l| l.parse::<usize>().is_ok()).count();
+        assert_eq!(numbers, MAX_OUTPUT_LINES + 25 + 1);
+        assert!(plain.starts_with("Wrong Answer\n"));
+    }
+}
@@ -2,46 +2,107 @@ use ratatui::{
     style::{Color, Modifier, Style},
     text::{Line, Span},
 };
+use std::ops::Range;
+
+use crate::api::site::site;
+
+use super::code_view::highlight;
 
 const BOX_STYLE: Color = Color::DarkGray;
 const CODE_BG: Color = Color::Rgb(40, 40, 55);
 
+/// Example labels highlighted at the start of `<pre>` lines.
+const EXAMPLE_LABELS: [(&str, Color); 3] = [
+    ("Input:", Color::Cyan),
+    ("Output:", Color::Green),
+    ("Explanation:", Color::Gray),
+];
+
+/// Rendering preferences for problem statements.
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+    /// Color the "Input:"/"Output:"/"Explanation:" labels in examples.
+    pub example_labels: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            example_labels: true,
+        }
+    }
+}
+
+/// A `<table>` being collected: each row's cells, with whether the cell is
+/// a header.
+#[derive(Default)]
+struct Table {
+    rows: Vec<Vec<(Vec<Span<'static>>, bool)>>,
+    in_cell: bool,
+}
+
 struct Parser {
     lines: Vec<Line<'static>>,
     current_spans: Vec<Span<'static>>,
-    bold: bool,
-    italic: bool,
-    code: bool,
+    // Nesting depth of each inline style, so repeated or nested tags
+    // unwind correctly and stray closing tags are harmless
+    bold: u8,
+    italic: u8,
+    code: u8,
+    var: u8,
     pre: bool,
     list_depth: usize,
     buf: String,
     last_was_blank: bool,
     pre_lines: Vec<Line<'static>>,
+    images: usize,
+    table: Option<Table>,
+    // Language of a <pre><code class="language-..."> block
+    pre_lang: Option<String>,
+    // Inside <sup> (true) or <sub> (false)
+    script: Option<bool>,
+    // Link targets, listed as footnotes at the end
+    links: Vec<String>,
+    // Footnote index of the open <a>
+    link: Option<usize>,
+    options: RenderOptions,
 }
 
 impl Parser {
-    fn new() -> Self {
+    fn new(options: RenderOptions) -> Self {
         Self {
             lines: Vec::new(),
             current_spans: Vec::new(),
-            bold: false,
-            italic: false,
-            code: false,
+            bold: 0,
+            italic: 0,
+            code: 0,
+            var: 0,
             pre: false,
             list_depth: 0,
             buf: String::new(),
             last_was_blank: false,
             pre_lines: Vec::new(),
+            images: 0,
+            table: None,
+            pre_lang: None,
+            script: None,
+            links: Vec::new(),
+            link: None,
+            options,
         }
     }
 
     fn style(&self) -> Style {
         let mut s = Style::default();
+        let bold = self.bold > 0;
+        let italic = self.italic > 0;
+        let code = self.code > 0;
+        let var = self.var > 0;
 
-        if self.code && !self.pre {
+        if code && !self.pre {
             s = s.fg(Color::Yellow).bg(CODE_BG);
         } else if self.pre {
-            if self.bold {
+            if bold {
                 s = s.fg(Color::Cyan).add_modifier(Modifier::BOLD);
             } else {
                 s = s.fg(Color::White);
@@ -50,23 +111,34 @@ impl Parser {
             s = s.fg(Color::White);
         }
 
-        if self.bold && !self.pre {
+        if bold && !self.pre {
             s = s.add_modifier(Modifier::BOLD).fg(Color::Cyan);
         }
 
-        if self.italic && !self.pre {
+        if italic && !self.pre {
             s = s.add_modifier(Modifier::ITALIC);
-            if !self.bold && !self.code {
+            if !bold && !code {
                 s = s.fg(Color::Gray);
             }
         }
 
+        if var && !self.pre {
+            s = s.add_modifier(Modifier::ITALIC).fg(Color::Cyan);
+        }
+
+        if self.link.is_some() && !self.pre {
+            s = s.add_modifier(Modifier::UNDERLINED);
+        }
+
         s
     }
 
     fn flush_buf(&mut self) {
         if !self.buf.is_empty() {
-            let text = std::mem::take(&mut self.buf);
+            let mut text = std::mem::take(&mut self.buf);
+            if !self.pre {
+                text = superscript_powers(&text);
+            }
             let style = self.style();
             self.current_spans.push(Span::styled(text, style));
         }
@@ -99,6 +171,16 @@ impl Parser {
     }
 
     fn emit_pre_block(&mut self) {
+        // Code blocks get language-aware colors instead of example labels
+        let code_block = match self.pre_lang.take() {
+            Some(lang) => {
+                let code: Vec<String> = self.pre_lines.iter().map(line_text).collect();
+                self.pre_lines = highlight(&code.join("\n"), &lang);
+                true
+            }
+            None => false,
+        };
+
         // Find the max content width across pre_lines
         let max_w = self
             .pre_lines
@@ -120,7 +202,13 @@ impl Parser {
         ]));
 
         // Content lines
+        let example_labels = self.options.example_labels && !code_block;
         for line in self.pre_lines.drain(..) {
+            let line = if example_labels {
+                style_example_label(line)
+            } else {
+                line
+            };
             let content_len: usize = line.spans.iter().map(|s| s.content.len()).sum();
             let pad = box_w.saturating_sub(content_len + 1);
             let mut spans = vec![
@@ -146,8 +234,382 @@ impl Parser {
     }
 }
 
-pub fn html_to_lines(html: &str) -> Vec<Line<'static>> {
-    let mut p = Parser::new();
+/// Value of attribute `name` in the inside of a tag, e.g. `img src="..."`.
+fn tag_attr(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let start = lower.find(&format!(" {name}="))? + name.len() + 2;
+    let rest = &tag[start..];
+    let value = match rest.chars().next()? {
+        quote @ ('"' | '\'') => rest[1..].split(quote).next()?,
+        _ => rest.split_whitespace().next()?.trim_end_matches('/'),
+    };
+    Some(value.to_string())
+}
+
+/// Where an `<a>` tag points, made absolute. In-page anchors and scripts
+/// aren't worth a footnote.
+fn link_href(tag: &str) -> Option<String> {
+    let href = tag_attr(tag, "href")?;
+    let href = href.trim();
+    if href.is_empty() || href.starts_with('#') || href.starts_with("javascript:") {
+        None
+    } else if href.starts_with("//") {
+        Some(format!("https:{href}"))
+    } else if href.starts_with('/') {
+        Some(site().url(href))
+    } else {
+        Some(href.to_string())
+    }
+}
+
+/// Where an `<img>` tag points, made absolute.
+fn image_src(tag: &str) -> Option<String> {
+    let src = tag_attr(tag, "src")?;
+    let src = src.trim();
+    if src.is_empty() {
+        None
+    } else if src.starts_with("//") {
+        Some(format!("https:{src}"))
+    } else {
+        Some(src.to_string())
+    }
+}
+
+/// URLs of the images in `html`, in the order `html_to_lines` numbers their
+/// placeholders.
+pub fn image_urls(html: &str) -> Vec<String> {
+    html.split('<')
+        .skip(1)
+        .filter(|tag| {
+            tag.get(..3).is_some_and(|t| t.eq_ignore_ascii_case("img"))
+                && tag[3..].starts_with(char::is_whitespace)
+        })
+        .filter_map(|tag| image_src(tag.split('>').next().unwrap_or(tag)))
+        .collect()
+}
+
+/// Drop the whitespace around a cell's content.
+fn trim_spans(mut spans: Vec<Span<'static>>) -> Vec<Span<'static>> {
+    spans.retain(|s| !s.content.trim().is_empty());
+    if let Some(first) = spans.first_mut() {
+        first.content = first.content.trim_start().to_string().into();
+    }
+    if let Some(last) = spans.last_mut() {
+        last.content = last.content.trim_end().to_string().into();
+    }
+    spans
+}
+
+/// Box-drawn lines for a table, columns padded to their widest cell and
+/// header rows set off by a rule.
+fn table_lines(table: Table) -> Vec<Line<'static>> {
+    let rows: Vec<_> = table.rows.into_iter().filter(|r| !r.is_empty()).collect();
+    let cols = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if cols == 0 {
+        return Vec::new();
+    }
+    let cell_width = |spans: &[Span]| spans.iter().map(|s| s.width()).sum::<usize>();
+    let mut widths = vec![1; cols];
+    for row in &rows {
+        for (i, (spans, _)) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell_width(spans));
+        }
+    }
+
+    let border = Style::default().fg(BOX_STYLE);
+    let rule = |left: &str, mid: &str, right: &str| {
+        let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+        Line::from(Span::styled(
+            format!("  {left}{}{right}", segments.join(mid)),
+            border,
+        ))
+    };
+
+    let mut lines = vec![rule("┌", "┬", "┐")];
+    let last = rows.len() - 1;
+    for (r, row) in rows.into_iter().enumerate() {
+        let header = row.iter().all(|(_, th)| *th);
+        let mut spans = vec![Span::styled("  │", border)];
+        for (i, width) in widths.iter().enumerate() {
+            let cell = row.get(i).map(|(s, _)| s.clone()).unwrap_or_default();
+            let pad = width - cell_width(&cell);
+            spans.push(Span::raw(" "));
+            spans.extend(cell.into_iter().map(|s| {
+                if header {
+                    s.patch_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                } else {
+                    s
+                }
+            }));
+            spans.push(Span::raw(" ".repeat(pad + 1)));
+            spans.push(Span::styled("│", border));
+        }
+        lines.push(Line::from(spans));
+        if header && r < last {
+            lines.push(rule("├", "┼", "┤"));
+        }
+    }
+    lines.push(rule("└", "┴", "┘"));
+    lines
+}
+
+/// Open or close one level of an inline style.
+fn nest(depth: &mut u8, closing: bool) {
+    if closing {
+        *depth = depth.saturating_sub(1);
+    } else {
+        *depth = depth.saturating_add(1);
+    }
+}
+
+/// Color a leading "Input:"/"Output:"/"Explanation:" label in a pre line,
+/// leaving the value after it plain.
+fn style_example_label(line: Line<'static>) -> Line<'static> {
+    let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+    let trimmed = text.trim_start();
+    let Some((label, color)) = EXAMPLE_LABELS
+        .iter()
+        .find(|(label, _)| trimmed.starts_with(label))
+    else {
+        return line;
+    };
+
+    let label_end = text.len() - trimmed.len() + label.len();
+    let (head, value) = text.split_at(label_end);
+    Line::from(vec![
+        Span::styled(
+            head.to_string(),
+            Style::default().fg(*color).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(value.to_string(), Style::default().fg(Color::White)),
+    ])
+}
+
+/// Render a Markdown article (editorials, community posts) through the same
+/// pipeline as problem statements.
+pub fn markdown_to_lines(md: &str, options: RenderOptions) -> Vec<Line<'static>> {
+    let md = md.replace("[TOC]", "");
+    let parser = pulldown_cmark::Parser::new_ext(
+        &md,
+        pulldown_cmark::Options::ENABLE_TABLES | pulldown_cmark::Options::ENABLE_STRIKETHROUGH,
+    );
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, parser);
+
+    // Articles write math as $$...$$, which strip_inline_math knows as \(...\)
+    let mut out = String::with_capacity(html.len());
+    for (i, part) in html.split("$$").enumerate() {
+        if i > 0 {
+            out.push_str(if i % 2 == 1 { "\\(" } else { "\\)" });
+        }
+        out.push_str(part);
+    }
+    html_to_lines(&out, options)
+}
+
+fn superscript(c: char) -> Option<char> {
+    Some(match c {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4'..='9' => char::from_u32('⁴' as u32 + (c as u32 - '4' as u32))?,
+        '+' => '⁺',
+        '-' | '−' => '⁻',
+        '=' => '⁼',
+        '(' => '⁽',
+        ')' => '⁾',
+        'a' => 'ᵃ',
+        'b' => 'ᵇ',
+        'c' => 'ᶜ',
+        'd' => 'ᵈ',
+        'e' => 'ᵉ',
+        'f' => 'ᶠ',
+        'g' => 'ᵍ',
+        'h' => 'ʰ',
+        'i' => 'ⁱ',
+        'j' => 'ʲ',
+        'k' => 'ᵏ',
+        'l' => 'ˡ',
+        'm' => 'ᵐ',
+        'n' => 'ⁿ',
+        'o' => 'ᵒ',
+        'p' => 'ᵖ',
+        'r' => 'ʳ',
+        's' => 'ˢ',
+        't' => 'ᵗ',
+        'u' => 'ᵘ',
+        'v' => 'ᵛ',
+        'w' => 'ʷ',
+        'x' => 'ˣ',
+        'y' => 'ʸ',
+        'z' => 'ᶻ',
+        _ => return None,
+    })
+}
+
+fn subscript(c: char) -> Option<char> {
+    Some(match c {
+        '0'..='9' => char::from_u32('₀' as u32 + (c as u32 - '0' as u32))?,
+        '+' => '₊',
+        '-' | '−' => '₋',
+        '=' => '₌',
+        '(' => '₍',
+        ')' => '₎',
+        'a' => 'ₐ',
+        'e' => 'ₑ',
+        'h' => 'ₕ',
+        'i' => 'ᵢ',
+        'j' => 'ⱼ',
+        'k' => 'ₖ',
+        'l' => 'ₗ',
+        'm' => 'ₘ',
+        'n' => 'ₙ',
+        'o' => 'ₒ',
+        'p' => 'ₚ',
+        'r' => 'ᵣ',
+        's' => 'ₛ',
+        't' => 'ₜ',
+        'u' => 'ᵤ',
+        'v' => 'ᵥ',
+        'x' => 'ₓ',
+        _ => return None,
+    })
+}
+
+/// `text` raised (or lowered) with Unicode super/subscript characters, or
+/// `^(text)` / `_(text)` when some character has no such form.
+fn to_script(text: &str, sup: bool) -> String {
+    let text = text.trim();
+    let map = if sup { superscript } else { subscript };
+    if let Some(mapped) = text.chars().map(map).collect::<Option<String>>() {
+        return mapped;
+    }
+    let mark = if sup { '^' } else { '_' };
+    if text.chars().count() == 1 {
+        format!("{mark}{text}")
+    } else {
+        format!("{mark}({text})")
+    }
+}
+
+/// Powers written out as `10^5`, `2^-31` or `n^2` become `10⁵` / `2⁻³¹` /
+/// `n²`. Only a `^` squeezed between a word and a number counts, so XOR
+/// expressions like `a ^ b` are left alone.
+fn superscript_powers(text: &str) -> String {
+    if !text.contains('^') {
+        return text.to_string();
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let after_word = i > 0 && (chars[i - 1].is_alphanumeric() || chars[i - 1] == ')');
+        if c == '^' && after_word {
+            let sign = usize::from(chars.get(i + 1) == Some(&'-'));
+            let digits = chars[i + 1 + sign..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            if digits > 0 {
+                let exponent: String = chars[i + 1..i + 1 + sign + digits].iter().collect();
+                out.push_str(&to_script(&exponent, true));
+                i += 1 + sign + digits;
+                continue;
+            }
+        }
+        out.push(c);
+        i += 1;
+    }
+    out
+}
+
+/// Replace inline MathJax segments (`\(...\)`, `\[...\]`) with readable text.
+fn strip_inline_math(html: &str) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    loop {
+        let start = match (rest.find("\\("), rest.find("\\[")) {
+            (Some(a), Some(b)) => a.min(b),
+            (Some(a), None) | (None, Some(a)) => a,
+            (None, None) => break,
+        };
+        let close = if rest[start..].starts_with("\\(") { "\\)" } else { "\\]" };
+        let Some(len) = rest[start + 2..].find(close) else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(&simplify_latex(&rest[start + 2..start + 2 + len]));
+        rest = &rest[start + 2 + len + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Convert common LaTeX commands to Unicode and un-escape punctuation.
+/// Anything unrecognized is left as-is.
+fn simplify_latex(src: &str) -> String {
+    let mut out = String::with_capacity(src.len());
+    let mut chars = src.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == '^' || ch == '_' {
+            // A braced group or a single character
+            let arg: String = if chars.peek() == Some(&'{') {
+                chars.next();
+                chars.by_ref().take_while(|&c| c != '}').collect()
+            } else {
+                chars.next().map(String::from).unwrap_or_default()
+            };
+            out.push_str(&to_script(&simplify_latex(&arg), ch == '^'));
+            continue;
+        }
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        let mut cmd = String::new();
+        while let Some(&c) = chars.peek() {
+            if !c.is_ascii_alphabetic() {
+                break;
+            }
+            cmd.push(c);
+            chars.next();
+        }
+        if cmd.is_empty() {
+            // Escaped punctuation like `\_` or `\{`
+            if let Some(c) = chars.next() {
+                out.push(c);
+            }
+            continue;
+        }
+        let replacement = match cmd.as_str() {
+            "le" | "leq" => "≤",
+            "ge" | "geq" => "≥",
+            "ne" | "neq" => "≠",
+            "times" => "×",
+            "cdot" => "·",
+            "ldots" | "dots" | "cdots" => "…",
+            "infty" => "∞",
+            "pm" => "±",
+            "to" | "rightarrow" => "→",
+            "sum" => "∑",
+            "log" => "log",
+            _ => {
+                out.push('\\');
+                out.push_str(&cmd);
+                continue;
+            }
+        };
+        out.push_str(replacement);
+    }
+    out
+}
+
+pub fn html_to_lines(html: &str, options: RenderOptions) -> Vec<Line<'static>> {
+    // CRLF or lone CR would otherwise leave stray '\r' inside code boxes
+    let html = strip_inline_math(html).replace("\r\n", "\n").replace('\r', "\n");
+    let mut p = Parser::new(options);
     let mut chars = html.chars().peekable();
     let mut skip_next_newline = false;
 
@@ -172,21 +634,89 @@ pub fn html_to_lines(html: &str) -> Vec<Line<'static>> {
                 .trim_start_matches('/');
             let is_closing = tag_lower.starts_with('/');
 
+            // Block tags inside a table cell just separate words
+            if p.table.is_some() && matches!(tag_name, "p" | "br" | "div" | "li") {
+                if !p.buf.is_empty() && !p.buf.ends_with(' ') {
+                    p.buf.push(' ');
+                }
+                continue;
+            }
+
             match tag_name {
+                "table" => {
+                    if !is_closing {
+                        p.flush_buf();
+                        if !p.current_spans.is_empty() {
+                            p.push_line();
+                        }
+                        p.table = Some(Table::default());
+                    } else if let Some(table) = p.table.take() {
+                        p.buf.clear();
+                        p.current_spans.clear();
+                        let lines = table_lines(table);
+                        if !lines.is_empty() {
+                            p.ensure_blank_line();
+                            p.lines.extend(lines);
+                            p.last_was_blank = false;
+                        }
+                    }
+                }
+                "tr" => {
+                    if let Some(ref mut table) = p.table
+                        && !is_closing
+                    {
+                        table.rows.push(Vec::new());
+                    }
+                }
+                "td" | "th" if p.table.is_some() => {
+                    if !is_closing {
+                        // Whitespace between cells isn't content
+                        p.buf.clear();
+                        p.current_spans.clear();
+                        if let Some(ref mut table) = p.table {
+                            table.in_cell = true;
+                        }
+                    } else {
+                        p.flush_buf();
+                        let spans = trim_spans(std::mem::take(&mut p.current_spans));
+                        if let Some(ref mut table) = p.table
+                            && table.in_cell
+                        {
+                            table.in_cell = false;
+                            if table.rows.is_empty() {
+                                table.rows.push(Vec::new());
+                            }
+                            if let Some(row) = table.rows.last_mut() {
+                                row.push((spans, tag_name == "th"));
+                            }
+                        }
+                    }
+                }
                 "strong" | "b" => {
                     p.flush_buf();
-                    p.bold = !is_closing;
+                    nest(&mut p.bold, is_closing);
                 }
                 "em" | "i" => {
                     p.flush_buf();
-                    p.italic = !is_closing;
+                    nest(&mut p.italic, is_closing);
                 }
                 "code" => {
                     p.flush_buf();
                     if !p.pre {
-                        p.code = !is_closing;
+                        nest(&mut p.code, is_closing);
+                    } else if !is_closing {
+                        p.pre_lang = tag_attr(&tag, "class").and_then(|class| {
+                            class
+                                .split_whitespace()
+                                .find_map(|c| c.strip_prefix("language-"))
+                                .map(str::to_lowercase)
+                        });
                     }
                 }
+                "var" => {
+                    p.flush_buf();
+                    nest(&mut p.var, is_closing);
+                }
                 "pre" => {
                     p.flush_buf();
                     if !is_closing {
@@ -242,7 +772,78 @@ pub fn html_to_lines(html: &str) -> Vec<Line<'static>> {
                         p.push_line();
                     }
                 }
-                "sup" | "sub" | "div" | "span" => {}
+                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                    if is_closing {
+                        p.flush_buf();
+                        nest(&mut p.bold, true);
+                        p.push_line();
+                    } else {
+                        if !p.lines.is_empty() {
+                            p.ensure_blank_line();
+                        }
+                        p.flush_buf();
+                        nest(&mut p.bold, false);
+                    }
+                }
+                "img" => {
+                    if let Some(src) = image_src(&tag) {
+                        p.flush_buf();
+                        if !p.current_spans.is_empty() {
+                            p.push_line();
+                        }
+                        p.images += 1;
+                        let alt = tag_attr(&tag, "alt").filter(|a| !a.trim().is_empty());
+                        let label = match alt {
+                            Some(alt) => format!("  [Image {}: {}] ", p.images, alt.trim()),
+                            None => format!("  [Image {}] ", p.images),
+                        };
+                        p.lines.push(Line::from(vec![
+                            Span::styled(
+                                label,
+                                Style::default()
+                                    .fg(Color::Magenta)
+                                    .add_modifier(Modifier::BOLD),
+                            ),
+                            Span::styled(
+                                src,
+                                Style::default()
+                                    .fg(Color::DarkGray)
+                                    .add_modifier(Modifier::UNDERLINED),
+                            ),
+                        ]));
+                        p.last_was_blank = false;
+                    }
+                }
+                "sup" | "sub" => {
+                    if !is_closing {
+                        p.flush_buf();
+                        p.script = Some(tag_name == "sup");
+                    } else if let Some(sup) = p.script.take() {
+                        p.buf = to_script(&p.buf, sup);
+                    }
+                }
+                "a" => {
+                    if !is_closing {
+                        if let Some(href) = link_href(&tag) {
+                            p.flush_buf();
+                            let idx = match p.links.iter().position(|l| *l == href) {
+                                Some(idx) => idx,
+                                None => {
+                                    p.links.push(href);
+                                    p.links.len() - 1
+                                }
+                            };
+                            p.link = Some(idx);
+                        }
+                    } else if let Some(idx) = p.link.take() {
+                        p.flush_buf();
+                        p.current_spans.push(Span::styled(
+                            to_script(&(idx + 1).to_string(), true),
+                            Style::default().fg(Color::Cyan),
+                        ));
+                    }
+                }
+                "div" | "span" => {}
                 _ => {}
             }
         } else if ch == '&' {
@@ -282,7 +883,10 @@ pub fn html_to_lines(html: &str) -> Vec<Line<'static>> {
                             num_str.parse::<u32>().ok()
                         };
                         if let Some(c) = code.and_then(char::from_u32) {
-                            p.buf.push(c);
+                            // An encoded CR (&#13;) is dropped like a literal one
+                            if c != '\r' {
+                                p.buf.push(c);
+                            }
                             continue;
                         }
                     }
@@ -351,5 +955,330 @@ pub fn html_to_lines(html: &str) -> Vec<Line<'static>> {
         }
     }
 
+    // Footnotes for the numbered links
+    if !p.links.is_empty() {
+        if !result.is_empty() {
+            result.push(Line::from(""));
+        }
+        for (i, url) in p.links.into_iter().enumerate() {
+            result.push(Line::from(vec![
+                Span::styled(
+                    format!("  {} ", to_script(&(i + 1).to_string(), true)),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(url, Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+    }
+
     result
 }
+
+/// A "Constraints:" block found in rendered problem content.
+#[derive(Debug, Clone)]
+pub struct ConstraintsBlock {
+    /// Lines covered by the block (heading through last item).
+    pub range: Range<usize>,
+    /// One entry per list item, without the bullet prefix.
+    pub items: Vec<Line<'static>>,
+}
+
+fn line_text(line: &Line) -> String {
+    line.spans.iter().map(|s| s.content.as_ref()).collect()
+}
+
+/// Heuristic for statements LeetCode cut short: the HTML ends inside a tag
+/// or leaves a block element unclosed. Deliberately conservative, so
+/// problems that simply have no constraints section are not flagged.
+pub fn looks_truncated(html: &str) -> bool {
+    let html = html.trim_end();
+    if html.is_empty() {
+        return false;
+    }
+    if html.rfind('<') > html.rfind('>') {
+        return true;
+    }
+    ["pre", "ul", "ol"].iter().any(|tag| {
+        let opens = html.matches(&format!("<{tag}>")).count()
+            + html.matches(&format!("<{tag} ")).count();
+        opens > html.matches(&format!("</{tag}>")).count()
+    })
+}
+
+/// Line index of each "Example N:" heading in lines produced by
+/// `html_to_lines`, in order.
+pub fn example_starts(lines: &[Line<'static>]) -> Vec<usize> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| {
+            let text = line_text(l);
+            let text = text.trim().trim_end_matches(':');
+            text.strip_prefix("Example")
+                .is_some_and(|n| n.trim().parse::<u32>().is_ok())
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// The standard parts of a statement, for jumping between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    Description,
+    Examples,
+    Constraints,
+    FollowUp,
+}
+
+impl Section {
+    pub fn label(self) -> &'static str {
+        match self {
+            Section::Description => "Description",
+            Section::Examples => "Examples",
+            Section::Constraints => "Constraints",
+            Section::FollowUp => "Follow-up",
+        }
+    }
+}
+
+/// Line index where each section starts in lines produced by
+/// `html_to_lines`, in order. The description always opens the statement;
+/// the others are listed only when found.
+pub fn section_starts(lines: &[Line<'static>]) -> Vec<(Section, usize)> {
+    let follow_up = lines.iter().position(|l| {
+        let text = line_text(l).trim_start().to_lowercase();
+        text.starts_with("follow-up") || text.starts_with("follow up")
+    });
+    let mut sections = vec![(Section::Description, 0)];
+    for (section, start) in [
+        (Section::Examples, example_starts(lines).first().copied()),
+        (Section::Constraints, constraints_heading(lines)),
+        (Section::FollowUp, follow_up),
+    ] {
+        if let Some(start) = start.filter(|&s| s > 0) {
+            sections.push((section, start));
+        }
+    }
+    sections.sort_by_key(|&(_, start)| start);
+    sections
+}
+
+fn constraints_heading(lines: &[Line<'static>]) -> Option<usize> {
+    lines.iter().position(|l| {
+        let text = line_text(l);
+        let text = text.trim().trim_end_matches(':');
+        text.eq_ignore_ascii_case("constraints")
+    })
+}
+
+/// Find the "Constraints:" heading in lines produced by `html_to_lines` and
+/// collect the bulleted list that follows it.
+pub fn extract_constraints(lines: &[Line<'static>]) -> Option<ConstraintsBlock> {
+    let heading = constraints_heading(lines)?;
+
+    let mut items: Vec<Line<'static>> = Vec::new();
+    let mut end = heading + 1;
+    // Allow a single blank line between the heading and the list
+    if lines.get(end).is_some_and(|l| line_text(l).trim().is_empty()) {
+        end += 1;
+    }
+
+    while let Some(line) = lines.get(end) {
+        let is_item = line
+            .spans
+            .first()
+            .is_some_and(|s| s.content.trim_start().starts_with('\u{2022}'));
+        if is_item {
+            let mut item = line.clone();
+            item.spans.remove(0);
+            items.push(item);
+        } else if line_text(line).trim().is_empty() || items.is_empty() {
+            break;
+        } else if let Some(last) = items.last_mut() {
+            // Continuation of a multi-line item (e.g. a <br> inside <li>)
+            last.spans.push(Span::raw(" "));
+            last.spans.extend(line.spans.iter().cloned());
+        }
+        end += 1;
+    }
+
+    if items.is_empty() {
+        return None;
+    }
+
+    Some(ConstraintsBlock {
+        range: heading..end,
+        items,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(lines: &[Line]) -> Vec<String> {
+        lines.iter().map(line_text).collect()
+    }
+
+    const STATEMENT: &str = "<p>Given an array <code>nums</code>, return the indices.</p>\n\
+        <p><strong class=\"example\">Example 1:</strong></p>\n\
+        <pre><strong>Input:</strong> nums = [2,7,11,15], target = 9\n\
+        <strong>Output:</strong> [0,1]\n</pre>\n\
+        <p><strong>Constraints:</strong></p>\n\
+        <ul>\n\
+        \t<li><code>2 &lt;= nums.length &lt;= 10<sup>4</sup></code></li>\n\
+        \t<li><code>-10<sup>9</sup> &lt;= nums[i] &lt;= 10<sup>9</sup></code></li>\n\
+        \t<li><strong>Only one valid answer exists.</strong></li>\n\
+        </ul>\n\
+        <p>&nbsp;</p>\n\
+        <strong>Follow-up:</strong> Can you do better than O(n<sup>2</sup>)?";
+
+    #[test]
+    fn extracts_the_constraints_list() {
+        let lines = html_to_lines(STATEMENT, RenderOptions::default());
+        let block = extract_constraints(&lines).unwrap();
+
+        assert_eq!(
+            texts(&block.items)
+                .iter()
+                .map(|t| t.trim())
+                .collect::<Vec<_>>(),
+            [
+                "2 <= nums.length <= 10\u{2074}",
+                "-10\u{2079} <= nums[i] <= 10\u{2079}",
+                "Only one valid answer exists.",
+            ]
+        );
+        let covered = texts(&lines[block.range.clone()]);
+        assert_eq!(covered[0].trim(), "Constraints:");
+        assert!(covered.last().unwrap().contains("Only one valid answer"));
+    }
+
+    #[test]
+    fn no_constraints_without_the_heading_or_items() {
+        let lines = html_to_lines("<p>Just a statement.</p>", RenderOptions::default());
+        assert!(extract_constraints(&lines).is_none());
+
+        let lines = html_to_lines(
+            "<p><strong>Constraints:</strong></p>\n<p>None to speak of.</p>",
+            RenderOptions::default(),
+        );
+        assert!(extract_constraints(&lines).is_none());
+    }
+
+    #[test]
+    fn var_renders_italic() {
+        let lines = html_to_lines("<p>Given <var>n</var> stones.</p>", RenderOptions::default());
+        let span = lines
+            .iter()
+            .flat_map(|line| &line.spans)
+            .find(|span| span.content == "n")
+            .unwrap();
+        assert!(span.style.add_modifier.contains(Modifier::ITALIC));
+        assert_eq!(span.style.fg, Some(Color::Cyan));
+    }
+
+    #[test]
+    fn inline_math_becomes_plain_text() {
+        let lines = html_to_lines("<p>Return \\(x\\).</p>", RenderOptions::default());
+        assert_eq!(texts(&lines).concat().trim(), "Return x.");
+
+        assert_eq!(strip_inline_math("\\(1 \\le n \\le 10^5\\)"), "1 ≤ n ≤ 10⁵");
+        // Unknown commands are kept, only un-escaped
+        assert_eq!(strip_inline_math("\\(\\frac{a}{b}\\_\\)"), "\\frac{a}{b}_");
+    }
+
+    fn example_line(options: RenderOptions, label: &str) -> Line<'static> {
+        let html = "<pre><strong>Input:</strong> nums = [2,7], target = 9\n\
+                    <strong>Output:</strong> [0,1]\n</pre>";
+        html_to_lines(html, options)
+            .into_iter()
+            .find(|line| line_text(line).contains(label))
+            .unwrap()
+    }
+
+    #[test]
+    fn example_labels_color_only_the_label() {
+        let line = example_line(RenderOptions::default(), "Input:");
+        let label = line.spans.iter().find(|s| s.content.contains("Input:")).unwrap();
+        assert_eq!(label.content.trim(), "Input:");
+        assert_eq!(label.style.fg, Some(Color::Cyan));
+        let value = line.spans.iter().find(|s| s.content.contains("nums")).unwrap();
+        assert_eq!(value.content.as_ref(), " nums = [2,7], target = 9");
+        assert_eq!(value.style.fg, Some(Color::White));
+
+        let output = example_line(RenderOptions::default(), "Output:");
+        assert!(output.spans.iter().any(|s| s.style.fg == Some(Color::Green)));
+        let plain = RenderOptions {
+            example_labels: false,
+        };
+        let output = example_line(plain, "Output:");
+        assert!(output.spans.iter().all(|s| s.style.fg != Some(Color::Green)));
+    }
+
+    #[test]
+    fn truncation_heuristic_flags_cut_html_only() {
+        assert!(!looks_truncated(STATEMENT));
+        assert!(!looks_truncated("<p>No constraints section here.</p>"));
+        assert!(!looks_truncated(""));
+
+        // Cut inside a tag, and inside a list
+        let cut = &STATEMENT[..STATEMENT.find("<li><code>-10").unwrap() + 6];
+        assert!(looks_truncated(cut));
+        let cut = &STATEMENT[..STATEMENT.find("\t<li><strong>").unwrap()];
+        assert!(looks_truncated(cut));
+        assert!(looks_truncated("<pre>\n<strong>Input:</strong> n = 3"));
+    }
+
+    fn span_style(lines: &[Line<'static>], text: &str) -> Style {
+        lines
+            .iter()
+            .flat_map(|line| &line.spans)
+            .find(|span| span.content.contains(text))
+            .unwrap()
+            .style
+    }
+
+    #[test]
+    fn nested_bold_and_italic_compose_and_unwind() {
+        let lines = html_to_lines(
+            "<p><strong>bold <em>both</em> <strong>again</strong> still</strong> plain</p>",
+            RenderOptions::default(),
+        );
+        let both = span_style(&lines, "both").add_modifier;
+        assert!(both.contains(Modifier::BOLD) && both.contains(Modifier::ITALIC));
+        // Closing the inner <strong> leaves the outer one on
+        assert!(span_style(&lines, "still").add_modifier.contains(Modifier::BOLD));
+        assert!(!span_style(&lines, "still").add_modifier.contains(Modifier::ITALIC));
+        assert!(!span_style(&lines, "plain").add_modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn stray_closing_tag_does_not_unbalance_bold() {
+        let lines = html_to_lines(
+            "<p>before</strong> <strong>bold</strong> after</p>",
+            RenderOptions::default(),
+        );
+        assert!(!span_style(&lines, "before").add_modifier.contains(Modifier::BOLD));
+        assert!(span_style(&lines, "bold").add_modifier.contains(Modifier::BOLD));
+        assert!(!span_style(&lines, "after").add_modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn crlf_pre_content_leaves_no_carriage_returns() {
+        let html = "<p>Line one\r\nstill one.</p>\r\n\
+                    <pre>\r\n<strong>Input:</strong> s = \"ab\"\r\n\
+                    <strong>Output:</strong> 2\r<strong>Explanation:</strong> two\r\n</pre>";
+        let lines = html_to_lines(html, RenderOptions::default());
+        assert!(
+            lines
+                .iter()
+                .flat_map(|line| &line.spans)
+                .all(|span| !span.content.contains('\r'))
+        );
+        // A lone CR still breaks the line
+        let text = texts(&lines);
+        assert!(text.iter().any(|t| t.contains("Output: 2") && !t.contains("Explanation")));
+        assert!(text.iter().any(|t| t.contains("Explanation: two")));
+    }
+}
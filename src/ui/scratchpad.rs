@@ -0,0 +1,660 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::api::types::QuestionDetail;
+use crate::config::{self, Config};
+
+use super::code_view::highlight;
+use super::status_bar::render_status_bar;
+
+pub const LANGUAGES: [&str; 7] = [
+    "rust",
+    "python3",
+    "cpp",
+    "java",
+    "javascript",
+    "typescript",
+    "golang",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScratchpadField {
+    Language,
+    Input,
+    Code,
+}
+
+/// Last scratchpad content, persisted between sessions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SavedScratchpad {
+    pub title_slug: String,
+    pub lang: String,
+    pub data_input: String,
+    pub code: String,
+}
+
+impl SavedScratchpad {
+    pub fn load() -> Option<Self> {
+        let data = std::fs::read_to_string(Config::scratchpad_path()).ok()?;
+        serde_json::from_str(&data).ok()
+    }
+
+    pub fn save(&self) {
+        if let Ok(data) = serde_json::to_string_pretty(self) {
+            let _ = config::write_atomic(&Config::scratchpad_path(), &data);
+        }
+    }
+}
+
+/// Everything `LeetCodeClient::run_code` needs for a scratchpad run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunRequest {
+    pub slug: String,
+    pub question_id: String,
+    pub lang: String,
+    pub typed_code: String,
+    pub data_input: String,
+}
+
+pub struct ScratchpadState {
+    pub detail: QuestionDetail,
+    pub lang_idx: usize,
+    pub data_input: String,
+    pub code: String,
+    /// Byte offsets of the cursor in `data_input` and `code`
+    pub input_cursor: usize,
+    pub code_cursor: usize,
+    pub active_field: ScratchpadField,
+    /// Edited since the last save.
+    pub dirty: bool,
+}
+
+impl ScratchpadState {
+    /// Open the scratchpad for `detail`, restoring the saved content when it
+    /// belongs to the same problem and seeding from the examples otherwise.
+    pub fn new(detail: QuestionDetail, default_lang: &str, saved: Option<SavedScratchpad>) -> Self {
+        let saved = saved.filter(|s| s.title_slug == detail.title_slug);
+        let lang = saved
+            .as_ref()
+            .map(|s| s.lang.as_str())
+            .unwrap_or(default_lang);
+        let lang_idx = LANGUAGES.iter().position(|l| *l == lang).unwrap_or(0);
+
+        let (data_input, code) = match saved {
+            Some(s) => (s.data_input, s.code),
+            None => (detail.sample_input(), snippet_for(&detail, LANGUAGES[lang_idx])),
+        };
+
+        Self {
+            detail,
+            lang_idx,
+            input_cursor: data_input.len(),
+            code_cursor: code.len(),
+            data_input,
+            code,
+            active_field: ScratchpadField::Input,
+            dirty: false,
+        }
+    }
+
+    /// Replace the test input, e.g. with the case a submission failed on.
+    pub fn set_input(&mut self, input: &str) {
+        self.data_input = input.to_string();
+        self.input_cursor = self.data_input.len();
+        self.active_field = ScratchpadField::Input;
+        self.dirty = true;
+    }
+
+    /// Replace the code, e.g. with the solution file.
+    pub fn set_code(&mut self, code: String) {
+        self.code = code;
+        self.code_cursor = self.code.len();
+    }
+
+    pub fn save(&mut self) {
+        self.to_saved().save();
+        self.dirty = false;
+    }
+
+    /// Save only if something changed since the last save. Returns whether
+    /// a write happened.
+    pub fn autosave(&mut self) -> bool {
+        self.autosave_with(SavedScratchpad::save)
+    }
+
+    fn autosave_with(&mut self, write: impl FnOnce(&SavedScratchpad)) -> bool {
+        if !self.dirty {
+            return false;
+        }
+        write(&self.to_saved());
+        self.dirty = false;
+        true
+    }
+
+    pub fn lang(&self) -> &'static str {
+        LANGUAGES[self.lang_idx]
+    }
+
+    pub fn to_saved(&self) -> SavedScratchpad {
+        SavedScratchpad {
+            title_slug: self.detail.title_slug.clone(),
+            lang: self.lang().to_string(),
+            data_input: self.data_input.clone(),
+            code: self.code.clone(),
+        }
+    }
+
+    /// Assemble the arguments passed to `run_code`.
+    pub fn run_request(&self) -> RunRequest {
+        RunRequest {
+            slug: self.detail.title_slug.clone(),
+            question_id: self.detail.question_id.clone(),
+            lang: self.lang().to_string(),
+            typed_code: self.code.clone(),
+            data_input: self.data_input.trim_end_matches('\n').to_string(),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> ScratchpadAction {
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            return match key.code {
+                KeyCode::Char('r') => {
+                    if self.code.trim().is_empty() {
+                        ScratchpadAction::None
+                    } else {
+                        ScratchpadAction::Run
+                    }
+                }
+                KeyCode::Char('n') => {
+                    self.switch_field(true);
+                    ScratchpadAction::None
+                }
+                KeyCode::Char('p') => {
+                    self.switch_field(false);
+                    ScratchpadAction::None
+                }
+                KeyCode::Char('c') => ScratchpadAction::Quit,
+                _ => ScratchpadAction::None,
+            };
+        }
+
+        match key.code {
+            KeyCode::Esc => ScratchpadAction::Back,
+            // Tab indents in the code; in the other fields it moves on
+            KeyCode::Tab | KeyCode::BackTab if self.active_field != ScratchpadField::Code => {
+                self.switch_field(key.code == KeyCode::Tab);
+                ScratchpadAction::None
+            }
+            _ => {
+                let changed = match self.active_field {
+                    ScratchpadField::Language => self.handle_language_key(key),
+                    ScratchpadField::Input => {
+                        edit_text(&mut self.data_input, &mut self.input_cursor, key, false)
+                    }
+                    ScratchpadField::Code => {
+                        edit_text(&mut self.code, &mut self.code_cursor, key, true)
+                    }
+                };
+                self.dirty |= changed;
+                ScratchpadAction::None
+            }
+        }
+    }
+
+    fn switch_field(&mut self, forward: bool) {
+        self.active_field = match (self.active_field, forward) {
+            (ScratchpadField::Language, true) | (ScratchpadField::Code, false) => {
+                ScratchpadField::Input
+            }
+            (ScratchpadField::Input, true) | (ScratchpadField::Language, false) => {
+                ScratchpadField::Code
+            }
+            (ScratchpadField::Code, true) | (ScratchpadField::Input, false) => {
+                ScratchpadField::Language
+            }
+        };
+    }
+
+    fn handle_language_key(&mut self, key: KeyEvent) -> bool {
+        let delta = match key.code {
+            KeyCode::Left | KeyCode::Char('h') => LANGUAGES.len() - 1,
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => 1,
+            _ => return false,
+        };
+        // Swap the template along with the language unless the user edited it
+        let untouched = self.code.trim().is_empty()
+            || self.code == snippet_for(&self.detail, self.lang());
+        self.lang_idx = (self.lang_idx + delta) % LANGUAGES.len();
+        if untouched {
+            self.set_code(snippet_for(&self.detail, self.lang()));
+        }
+        true
+    }
+}
+
+fn snippet_for(detail: &QuestionDetail, lang: &str) -> String {
+    detail
+        .code_snippets
+        .as_ref()
+        .and_then(|snippets| snippets.iter().find(|s| s.lang_slug == lang))
+        .map(|s| s.code.clone())
+        .unwrap_or_default()
+}
+
+const INDENT: &str = "    ";
+
+/// Apply an editing key to `buf` at the byte offset `cursor`, returning
+/// whether the text changed. With `tabs`, Tab indents at the cursor and
+/// BackTab dedents the cursor line.
+fn edit_text(buf: &mut String, cursor: &mut usize, key: KeyEvent, tabs: bool) -> bool {
+    let line_start = buf[..*cursor].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = buf[*cursor..].find('\n').map_or(buf.len(), |i| *cursor + i);
+    let col = buf[line_start..*cursor].chars().count();
+    match key.code {
+        KeyCode::Char(c) => {
+            buf.insert(*cursor, c);
+            *cursor += c.len_utf8();
+        }
+        KeyCode::Enter => {
+            buf.insert(*cursor, '\n');
+            *cursor += 1;
+        }
+        KeyCode::Tab if tabs => {
+            buf.insert_str(*cursor, INDENT);
+            *cursor += INDENT.len();
+        }
+        KeyCode::BackTab if tabs => {
+            let line = &buf[line_start..line_end];
+            let n = (line.len() - line.trim_start_matches(' ').len()).min(INDENT.len());
+            if n == 0 {
+                return false;
+            }
+            buf.replace_range(line_start..line_start + n, "");
+            *cursor = (*cursor).saturating_sub(n).max(line_start);
+        }
+        KeyCode::Backspace => {
+            let Some(c) = buf[..*cursor].chars().next_back() else {
+                return false;
+            };
+            *cursor -= c.len_utf8();
+            buf.remove(*cursor);
+        }
+        KeyCode::Delete if *cursor < buf.len() => {
+            buf.remove(*cursor);
+        }
+        // Movement leaves the text alone
+        KeyCode::Left => {
+            if let Some(c) = buf[..*cursor].chars().next_back() {
+                *cursor -= c.len_utf8();
+            }
+            return false;
+        }
+        KeyCode::Right => {
+            if let Some(c) = buf[*cursor..].chars().next() {
+                *cursor += c.len_utf8();
+            }
+            return false;
+        }
+        KeyCode::Home => {
+            *cursor = line_start;
+            return false;
+        }
+        KeyCode::End => {
+            *cursor = line_end;
+            return false;
+        }
+        KeyCode::Up if line_start > 0 => {
+            let prev_start = buf[..line_start - 1].rfind('\n').map_or(0, |i| i + 1);
+            *cursor = prev_start + byte_at(&buf[prev_start..line_start - 1], col);
+            return false;
+        }
+        KeyCode::Down if line_end < buf.len() => {
+            let next_start = line_end + 1;
+            let next_end = buf[next_start..]
+                .find('\n')
+                .map_or(buf.len(), |i| next_start + i);
+            *cursor = next_start + byte_at(&buf[next_start..next_end], col);
+            return false;
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Byte offset of char column `col` in `line`, or its end when shorter.
+fn byte_at(line: &str, col: usize) -> usize {
+    line.char_indices().nth(col).map_or(line.len(), |(i, _)| i)
+}
+
+/// Row and char column of the byte offset `cursor` in `text`.
+fn cursor_position(text: &str, cursor: usize) -> (usize, usize) {
+    let before = &text[..cursor];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (before.matches('\n').count(), before[line_start..].chars().count())
+}
+
+/// Show the cursor as a reversed cell at char column `col` of `line`.
+fn place_cursor(line: &mut Line<'static>, col: usize) {
+    let cursor = Style::default().add_modifier(Modifier::REVERSED);
+    let mut start = 0;
+    for i in 0..line.spans.len() {
+        let len = line.spans[i].content.chars().count();
+        if col < start + len {
+            let span = line.spans.remove(i);
+            let text: Vec<char> = span.content.chars().collect();
+            let (before, rest) = text.split_at(col - start);
+            let parts = [
+                Span::styled(before.iter().collect::<String>(), span.style),
+                Span::styled(rest[0].to_string(), span.style.patch(cursor)),
+                Span::styled(rest[1..].iter().collect::<String>(), span.style),
+            ];
+            line.spans.splice(i..i, parts);
+            return;
+        }
+        start += len;
+    }
+    line.spans.push(Span::styled(" ", cursor));
+}
+
+pub enum ScratchpadAction {
+    None,
+    Back,
+    Quit,
+    Run,
+}
+
+pub fn render_scratchpad(frame: &mut Frame, area: Rect, state: &ScratchpadState) {
+    let input_lines = (state.data_input.lines().count() as u16 + 2).clamp(4, 10);
+    let layout = Layout::vertical([
+        Constraint::Length(3),           // title bar
+        Constraint::Length(1),           // language picker
+        Constraint::Length(input_lines), // test input
+        Constraint::Min(5),              // code
+        Constraint::Length(1),           // status bar
+    ])
+    .split(area);
+
+    // Title bar
+    let title_line = Line::from(vec![
+        Span::styled(
+            " Scratchpad ",
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!("{}. {}", state.detail.frontend_question_id, state.detail.title),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    let title_block = Paragraph::new(vec![title_line]).block(
+        Block::default()
+            .borders(Borders::BOTTOM)
+            .border_style(Style::default().fg(Color::DarkGray)),
+    );
+    frame.render_widget(title_block, layout[0]);
+
+    // Language picker
+    let lang_active = state.active_field == ScratchpadField::Language;
+    let mut lang_spans = vec![Span::styled(
+        " Language: ",
+        if lang_active {
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        },
+    )];
+    for (i, lang) in LANGUAGES.iter().enumerate() {
+        let style = if i == state.lang_idx {
+            Style::default()
+                .fg(Color::Black)
+                .bg(if lang_active { Color::Cyan } else { Color::Gray })
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        lang_spans.push(Span::styled(format!(" {lang} "), style));
+        lang_spans.push(Span::raw(" "));
+    }
+    frame.render_widget(Paragraph::new(Line::from(lang_spans)), layout[1]);
+
+    render_text_field(
+        frame,
+        layout[2],
+        " Test Input ",
+        &state.data_input,
+        None,
+        (state.active_field == ScratchpadField::Input).then_some(state.input_cursor),
+    );
+    render_text_field(
+        frame,
+        layout[3],
+        " Code ",
+        &state.code,
+        Some(state.lang()),
+        (state.active_field == ScratchpadField::Code).then_some(state.code_cursor),
+    );
+
+    let hints = if lang_active {
+        vec![
+            ("\u{2190}/\u{2192}", "Language"),
+            ("Tab/^N", "Next field"),
+            ("Ctrl+R", "Run"),
+            ("Esc", "Back"),
+        ]
+    } else {
+        vec![
+            ("type", "Edit"),
+            ("^N/^P", "Next/prev field"),
+            ("Ctrl+R", "Run"),
+            ("Esc", "Back"),
+        ]
+    };
+    render_status_bar(frame, layout[4], &hints);
+}
+
+/// A bordered text area; with `lang`, the text is highlighted as code.
+/// `cursor` is set when the field is being edited.
+fn render_text_field(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    text: &str,
+    lang: Option<&str>,
+    cursor: Option<usize>,
+) {
+    let active = cursor.is_some();
+    let mut lines: Vec<Line> = match lang {
+        Some(lang) => {
+            let mut lines = highlight(text, lang);
+            // Keep the empty last line the cursor sits on
+            if text.ends_with('\n') {
+                lines.push(Line::default());
+            }
+            lines
+        }
+        None => text.split('\n').map(|l| Line::from(l.to_string())).collect(),
+    };
+    let mut cursor_row = 0;
+    if let Some(cursor) = cursor {
+        let (row, col) = cursor_position(text, cursor);
+        cursor_row = row;
+        if let Some(line) = lines.get_mut(row) {
+            place_cursor(line, col);
+        }
+    }
+
+    // Keep the cursor in view
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let scroll = (cursor_row + 1).saturating_sub(inner_height) as u16;
+
+    let p = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(if active {
+                    Color::Cyan
+                } else {
+                    Color::DarkGray
+                })),
+        )
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+    frame.render_widget(p, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail() -> QuestionDetail {
+        serde_json::from_value(serde_json::json!({
+            "questionId": "1",
+            "frontendQuestionId": "1",
+            "title": "Two Sum",
+            "titleSlug": "two-sum",
+            "difficulty": "Easy",
+            "isPaidOnly": false,
+            "topicTags": [],
+            "hints": [],
+            "codeSnippets": [
+                {"lang": "Python3", "langSlug": "python3", "code": "class Solution:\n    pass\n"}
+            ],
+            "exampleTestcaseList": ["[2,7,11,15]\n9"],
+        }))
+        .unwrap()
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    #[test]
+    fn run_request_assembles_the_run_code_arguments() {
+        let mut pad = ScratchpadState::new(detail(), "python3", None);
+        pad.set_input("[3,3]\n6\n\n");
+        pad.set_code("class Solution: ...".to_string());
+
+        assert_eq!(
+            pad.run_request(),
+            RunRequest {
+                slug: "two-sum".to_string(),
+                question_id: "1".to_string(),
+                lang: "python3".to_string(),
+                typed_code: "class Solution: ...".to_string(),
+                // Trailing newlines would be read as extra empty cases
+                data_input: "[3,3]\n6".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn new_seeds_from_the_examples_or_restores_the_saved_pad() {
+        let pad = ScratchpadState::new(detail(), "python3", None);
+        assert_eq!(pad.data_input, "[2,7,11,15]\n9");
+        assert_eq!(pad.code, "class Solution:\n    pass\n");
+
+        let saved = SavedScratchpad {
+            title_slug: "two-sum".to_string(),
+            lang: "cpp".to_string(),
+            data_input: "[1]\n1".to_string(),
+            code: "int x;".to_string(),
+        };
+        let pad = ScratchpadState::new(detail(), "python3", Some(saved.clone()));
+        assert_eq!(pad.run_request().lang, "cpp");
+        assert_eq!(pad.data_input, "[1]\n1");
+
+        // A pad saved for another problem is ignored
+        let other = SavedScratchpad {
+            title_slug: "add-two-numbers".to_string(),
+            ..saved
+        };
+        let pad = ScratchpadState::new(detail(), "python3", Some(other));
+        assert_eq!(pad.data_input, "[2,7,11,15]\n9");
+    }
+
+    #[test]
+    fn tab_indents_in_the_code_and_ctrl_n_switches_fields() {
+        let mut pad = ScratchpadState::new(detail(), "python3", None);
+        pad.set_code("x".to_string());
+        pad.active_field = ScratchpadField::Code;
+
+        pad.handle_key(key(KeyCode::Home));
+        pad.handle_key(key(KeyCode::Tab));
+        assert_eq!(pad.code, "    x");
+        assert_eq!(pad.active_field, ScratchpadField::Code);
+        pad.handle_key(key(KeyCode::BackTab));
+        assert_eq!(pad.code, "x");
+
+        pad.handle_key(ctrl('n'));
+        assert_eq!(pad.active_field, ScratchpadField::Language);
+        pad.handle_key(ctrl('p'));
+        assert_eq!(pad.active_field, ScratchpadField::Code);
+
+        // Outside the code, Tab still moves on
+        pad.active_field = ScratchpadField::Input;
+        pad.handle_key(key(KeyCode::Tab));
+        assert_eq!(pad.active_field, ScratchpadField::Code);
+    }
+
+    #[test]
+    fn edit_text_edits_at_the_cursor() {
+        let mut buf = "ab\ncd".to_string();
+        let mut cursor = 1;
+        assert!(edit_text(&mut buf, &mut cursor, key(KeyCode::Char('x')), false));
+        assert_eq!((buf.as_str(), cursor), ("axb\ncd", 2));
+
+        // Down keeps the column, clamped to the shorter line
+        assert!(!edit_text(&mut buf, &mut cursor, key(KeyCode::Down), false));
+        assert_eq!(cursor, 6);
+        assert!(edit_text(&mut buf, &mut cursor, key(KeyCode::Backspace), false));
+        assert_eq!((buf.as_str(), cursor), ("axb\nc", 5));
+
+        assert!(!edit_text(&mut buf, &mut cursor, key(KeyCode::Up), false));
+        assert_eq!(cursor, 1);
+        assert!(edit_text(&mut buf, &mut cursor, key(KeyCode::Delete), false));
+        assert_eq!(buf, "ab\nc");
+
+        // Without tabs, Tab is no edit
+        assert!(!edit_text(&mut buf, &mut cursor, key(KeyCode::Tab), false));
+        assert_eq!(cursor_position(&buf, 4), (1, 1));
+    }
+
+    #[test]
+    fn autosave_writes_only_after_an_edit() {
+        let mut state = ScratchpadState::new(detail(), "python3", None);
+        let mut writes = Vec::new();
+        assert!(!state.autosave_with(|saved| writes.push(saved.clone())));
+        assert!(writes.is_empty());
+
+        state.handle_key(key(KeyCode::Char('x')));
+        assert!(state.dirty);
+        assert!(state.autosave_with(|saved| writes.push(saved.clone())));
+        assert_eq!(writes.len(), 1);
+        assert!(writes[0].data_input.ends_with('x'));
+
+        // Nothing changed since that write
+        assert!(!state.autosave_with(|saved| writes.push(saved.clone())));
+        // Moving between fields isn't an edit either
+        state.handle_key(key(KeyCode::Tab));
+        assert!(!state.autosave_with(|saved| writes.push(saved.clone())));
+        assert_eq!(writes.len(), 1);
+    }
+}
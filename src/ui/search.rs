@@ -0,0 +1,208 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use super::theme::theme;
+
+/// `/` search inside a scrollable text view: matches are highlighted and
+/// `n`/`N` step through them.
+#[derive(Default)]
+pub struct TextSearch {
+    pub query: String,
+    pub editing: bool,
+    // Index of the current match, and the line the search started from
+    current: usize,
+    origin: usize,
+}
+
+/// What a key did to the search.
+pub enum SearchInput {
+    /// The query changed; scroll to the current match
+    Changed,
+    /// Typing is done, the query stays
+    Done,
+    /// The search was dropped
+    Cleared,
+    None,
+}
+
+impl TextSearch {
+    pub fn is_active(&self) -> bool {
+        self.editing || !self.query.is_empty()
+    }
+
+    /// Start typing a new query, searching forward from line `from`.
+    pub fn start(&mut self, from: usize) {
+        self.query.clear();
+        self.editing = true;
+        self.current = 0;
+        self.origin = from;
+    }
+
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.editing = false;
+    }
+
+    pub fn handle_input(&mut self, key: KeyEvent, lines: &[Line]) -> SearchInput {
+        match key.code {
+            KeyCode::Esc => {
+                self.clear();
+                return SearchInput::Cleared;
+            }
+            KeyCode::Enter => {
+                self.editing = false;
+                return SearchInput::Done;
+            }
+            KeyCode::Backspace => {
+                if self.query.pop().is_none() {
+                    self.editing = false;
+                    return SearchInput::Cleared;
+                }
+            }
+            KeyCode::Char(c) => self.query.push(c),
+            _ => return SearchInput::None,
+        }
+        // Incremental: the first match at or after where the search began
+        let matches = self.matches(lines);
+        self.current = matches
+            .iter()
+            .position(|&(line, _)| line >= self.origin)
+            .unwrap_or(0);
+        SearchInput::Changed
+    }
+
+    /// Every match as (line, char offset), in reading order.
+    pub fn matches(&self, lines: &[Line]) -> Vec<(usize, usize)> {
+        let needle = fold(&self.query);
+        if needle.is_empty() {
+            return Vec::new();
+        }
+        let mut matches = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+            let hay = fold(&text);
+            let mut start = 0;
+            while start + needle.len() <= hay.len() {
+                if hay[start..start + needle.len()] == needle[..] {
+                    matches.push((i, start));
+                    start += needle.len();
+                } else {
+                    start += 1;
+                }
+            }
+        }
+        matches
+    }
+
+    /// Line of the current match.
+    pub fn current_line(&self, lines: &[Line]) -> Option<usize> {
+        self.matches(lines).get(self.current).map(|&(line, _)| line)
+    }
+
+    /// Step to the next or previous match, wrapping around, and return its
+    /// line.
+    pub fn step(&mut self, lines: &[Line], forward: bool) -> Option<usize> {
+        let matches = self.matches(lines);
+        if matches.is_empty() {
+            return None;
+        }
+        let len = matches.len();
+        self.current = if forward {
+            (self.current + 1) % len
+        } else {
+            (self.current + len - 1) % len
+        };
+        Some(matches[self.current].0)
+    }
+
+    /// `lines` with every match highlighted, the current one brighter.
+    pub fn highlight(&self, lines: &[Line<'static>]) -> Vec<Line<'static>> {
+        let matches = self.matches(lines);
+        if matches.is_empty() {
+            return lines.to_vec();
+        }
+        let len = fold(&self.query).len();
+        let mut out = lines.to_vec();
+        for (n, &(line, start)) in matches.iter().enumerate() {
+            let style = if n == self.current {
+                Style::default()
+                    .fg(theme().badge_fg)
+                    .bg(theme().accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme().badge_fg).bg(theme().warning)
+            };
+            out[line] = restyle(&out[line], start..start + len, style);
+        }
+        out
+    }
+
+    /// The prompt while typing, or the query and match count after.
+    pub fn render(&self, frame: &mut Frame, area: Rect, lines: &[Line]) {
+        let count = self.matches(lines).len();
+        let mut spans = vec![Span::styled(
+            format!(" /{}", self.query),
+            Style::default().fg(theme().accent),
+        )];
+        if self.editing {
+            spans.push(Span::styled("\u{258e}", Style::default().fg(theme().accent)));
+        }
+        let status = match count {
+            0 if !self.query.is_empty() => "  no matches".to_string(),
+            0 => String::new(),
+            n => format!("  {}/{n}", self.current.min(n - 1) + 1),
+        };
+        spans.push(Span::styled(status, Style::default().fg(theme().muted)));
+        if !self.editing {
+            spans.push(Span::styled(
+                "  n/N: next/prev  Esc: clear",
+                Style::default().fg(theme().muted),
+            ));
+        }
+        let bar = Paragraph::new(Line::from(spans)).style(Style::default().bg(theme().bar_bg));
+        frame.render_widget(bar, area);
+    }
+}
+
+/// Chars folded for a case-insensitive match, one per input char so
+/// offsets line up with the original text.
+fn fold(text: &str) -> Vec<char> {
+    text.chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect()
+}
+
+/// `line` with the chars in `range` patched with `style`.
+fn restyle(line: &Line<'static>, range: std::ops::Range<usize>, style: Style) -> Line<'static> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut pos = 0;
+    for span in &line.spans {
+        let mut run = String::new();
+        let mut run_hit = false;
+        for c in span.content.chars() {
+            let hit = range.contains(&pos);
+            if hit != run_hit && !run.is_empty() {
+                let text = std::mem::take(&mut run);
+                let s = if run_hit { span.style.patch(style) } else { span.style };
+                spans.push(Span::styled(text, s));
+            }
+            run_hit = hit;
+            run.push(c);
+            pos += 1;
+        }
+        if !run.is_empty() {
+            let s = if run_hit { span.style.patch(style) } else { span.style };
+            spans.push(Span::styled(run, s));
+        }
+    }
+    let mut out = Line::from(spans);
+    out.style = line.style;
+    out.alignment = line.alignment;
+    out
+}
@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::api::types::{ProblemSummary, TagCount, TagProblemCounts, UserStats};
+
+use super::home::group_digits;
+use super::status_bar::render_status_bar;
+use super::theme::theme;
+
+const BAR_WIDTH: usize = 24;
+const NAME_WIDTH: usize = 26;
+
+/// The signed-in user's profile: overall and contest stats, then solved
+/// problems per topic, grouped by skill level, weakest topics first.
+pub struct SkillsState {
+    pub stats: Option<UserStats>,
+    pub skills: Option<TagProblemCounts>,
+    /// Problems per topic slug, counted from the loaded problem list.
+    pub tag_totals: HashMap<String, usize>,
+    pub loading: bool,
+    pub error_message: Option<String>,
+    pub spinner_frame: usize,
+    pub scroll_offset: u16,
+    pub content_height: u16,
+}
+
+impl SkillsState {
+    pub fn new(problems: &[ProblemSummary], stats: Option<UserStats>) -> Self {
+        let mut tag_totals = HashMap::new();
+        for tag in problems.iter().flat_map(|p| p.topic_tags.iter()) {
+            *tag_totals.entry(tag.slug.clone()).or_insert(0) += 1;
+        }
+        Self {
+            stats,
+            skills: None,
+            tag_totals,
+            loading: true,
+            error_message: None,
+            spinner_frame: 0,
+            scroll_offset: 0,
+            content_height: 0,
+        }
+    }
+
+    pub fn set_skills(&mut self, result: Result<TagProblemCounts, String>) {
+        self.loading = false;
+        match result {
+            Ok(skills) => self.skills = Some(skills),
+            Err(e) => self.error_message = Some(e),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> SkillsAction {
+        match key.code {
+            KeyCode::Char('b') | KeyCode::Esc => SkillsAction::Back,
+            KeyCode::Char('q') => SkillsAction::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                SkillsAction::Quit
+            }
+            KeyCode::Char('r') if !self.loading => {
+                self.loading = true;
+                self.error_message = None;
+                SkillsAction::Refresh
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.scroll(1);
+                SkillsAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.scroll(-1);
+                SkillsAction::None
+            }
+            KeyCode::Char('d') => {
+                self.scroll(self.content_height as i32 / 2);
+                SkillsAction::None
+            }
+            KeyCode::Char('u') => {
+                self.scroll(-(self.content_height as i32 / 2));
+                SkillsAction::None
+            }
+            KeyCode::Char('g') => {
+                self.scroll_offset = 0;
+                SkillsAction::None
+            }
+            KeyCode::Char('G') => {
+                self.scroll_offset = u16::MAX;
+                SkillsAction::None
+            }
+            _ => SkillsAction::None,
+        }
+    }
+
+    fn scroll(&mut self, delta: i32) {
+        let new_offset = self.scroll_offset as i32 + delta;
+        self.scroll_offset = new_offset.clamp(0, u16::MAX as i32) as u16;
+    }
+
+    fn profile_lines(stats: &UserStats) -> Vec<Line<'static>> {
+        let label = |text: &str| {
+            Span::styled(format!("   {text:<10}"), Style::default().fg(theme().muted))
+        };
+        let value = |text: String| Span::styled(text, Style::default().fg(theme().text));
+        let solved = stats.easy_solved + stats.medium_solved + stats.hard_solved;
+        let total = stats.easy_total + stats.medium_total + stats.hard_total;
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!(" {}", stats.username),
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(vec![
+                label("Solved"),
+                value(format!("{solved} / {total}   ")),
+                Span::styled("Easy ", Style::default().fg(theme().easy)),
+                value(format!("{}/{}  ", stats.easy_solved, stats.easy_total)),
+                Span::styled("Med ", Style::default().fg(theme().medium)),
+                value(format!("{}/{}  ", stats.medium_solved, stats.medium_total)),
+                Span::styled("Hard ", Style::default().fg(theme().hard)),
+                value(format!("{}/{}", stats.hard_solved, stats.hard_total)),
+            ]),
+        ];
+        if let Some(streak) = stats.streak {
+            lines.push(Line::from(vec![
+                label("Streak"),
+                value(format!(
+                    "{}d current \u{b7} {}d best",
+                    streak.current, streak.longest
+                )),
+            ]));
+        }
+        match stats.contest {
+            Some(ref contest) => {
+                let mut rank = format!(
+                    " \u{b7} #{} of {}",
+                    group_digits(contest.global_ranking),
+                    group_digits(contest.total_participants)
+                );
+                if let Some(top) = contest.top_percentage {
+                    rank.push_str(&format!(" (top {top:.1}%)"));
+                }
+                lines.push(Line::from(vec![
+                    label("Rating"),
+                    Span::styled(
+                        format!("{:.0}", contest.rating),
+                        Style::default()
+                            .fg(theme().special)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    value(rank),
+                    Span::styled(
+                        format!(
+                            " \u{b7} {} contests attended",
+                            contest.attended_contests_count
+                        ),
+                        Style::default().fg(theme().muted),
+                    ),
+                ]));
+            }
+            None => lines.push(Line::from(vec![
+                label("Rating"),
+                Span::styled("no rated contests yet", Style::default().fg(theme().muted)),
+            ])),
+        }
+        lines
+    }
+
+    /// One heading per skill level, then a gauge per topic.
+    fn skill_lines(&self, skills: &TagProblemCounts) -> Vec<Line<'static>> {
+        let groups = [
+            ("Fundamental", &skills.fundamental),
+            ("Intermediate", &skills.intermediate),
+            ("Advanced", &skills.advanced),
+        ];
+        let mut lines = Vec::new();
+        for (level, tags) in groups {
+            if tags.is_empty() {
+                continue;
+            }
+            if !lines.is_empty() {
+                lines.push(Line::default());
+            }
+            lines.push(Line::from(Span::styled(
+                format!(" {level}"),
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.extend(self.group_lines(tags));
+        }
+        lines
+    }
+
+    fn group_lines(&self, tags: &[TagCount]) -> Vec<Line<'static>> {
+        // Without a total (problem list not loaded), gauges are relative to
+        // the group's strongest topic.
+        let max_solved = tags.iter().map(|t| t.problems_solved).max().unwrap_or(0);
+        let mut rows: Vec<(&TagCount, Option<usize>, f64)> = tags
+            .iter()
+            .map(|t| {
+                let solved = t.problems_solved.max(0) as f64;
+                let total = self.tag_totals.get(&t.tag_slug).copied();
+                let ratio = match total {
+                    Some(total) if total > 0 => solved / total as f64,
+                    _ if max_solved > 0 => solved / max_solved as f64,
+                    _ => 0.0,
+                };
+                (t, total, ratio.min(1.0))
+            })
+            .collect();
+        rows.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+        rows.into_iter()
+            .map(|(tag, total, ratio)| {
+                let filled = (ratio * BAR_WIDTH as f64).round() as usize;
+                let color = if ratio < 0.2 {
+                    theme().hard
+                } else if ratio < 0.5 {
+                    theme().medium
+                } else {
+                    theme().easy
+                };
+                let count = match total {
+                    Some(total) => format!(
+                        " {:>4} / {:<4} {:>3.0}%",
+                        tag.problems_solved,
+                        total,
+                        ratio * 100.0
+                    ),
+                    None => format!(" {:>4}", tag.problems_solved),
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("   {:<NAME_WIDTH$}", tag.tag_name),
+                        Style::default().fg(theme().text),
+                    ),
+                    Span::styled("\u{2588}".repeat(filled), Style::default().fg(color)),
+                    Span::styled(
+                        "\u{2591}".repeat(BAR_WIDTH - filled),
+                        Style::default().fg(theme().muted),
+                    ),
+                    Span::styled(count, Style::default().fg(theme().subtle)),
+                ])
+            })
+            .collect()
+    }
+}
+
+pub enum SkillsAction {
+    None,
+    Back,
+    Quit,
+    Refresh,
+}
+
+pub fn render_skills(frame: &mut Frame, area: Rect, state: &mut SkillsState) {
+    let layout = Layout::vertical([
+        Constraint::Length(2), // title bar
+        Constraint::Min(3),    // gauges
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    let title = Paragraph::new(Line::from(vec![
+        Span::styled(
+            " Profile ",
+            Style::default()
+                .fg(theme().badge_fg)
+                .bg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            "Solved problems by topic, weakest first",
+            Style::default().fg(theme().muted),
+        ),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::BOTTOM)
+            .border_style(Style::default().fg(theme().muted)),
+    );
+    frame.render_widget(title, layout[0]);
+
+    let body = layout[1];
+    state.content_height = body.height;
+    let mut lines = match state.stats {
+        Some(ref stats) => {
+            let mut lines = SkillsState::profile_lines(stats);
+            lines.push(Line::default());
+            lines
+        }
+        None => Vec::new(),
+    };
+    match (&state.skills, &state.error_message) {
+        (_, Some(err)) => lines.push(Line::from(Span::styled(
+            format!(" Error: {err}"),
+            Style::default().fg(theme().error),
+        ))),
+        (None, None) => {
+            let spinner = [
+                "\u{280b}", "\u{2819}", "\u{2839}", "\u{2838}", "\u{283c}", "\u{2834}", "\u{2826}",
+                "\u{2827}", "\u{2807}", "\u{280f}",
+            ];
+            let s = spinner[state.spinner_frame % spinner.len()];
+            lines.push(Line::from(Span::styled(
+                format!(" {s} Loading topic stats..."),
+                Style::default().fg(theme().warning),
+            )));
+        }
+        (Some(skills), None) => lines.extend(state.skill_lines(skills)),
+    }
+    let max_scroll = (lines.len() as u16).saturating_sub(state.content_height);
+    state.scroll_offset = state.scroll_offset.min(max_scroll);
+    frame.render_widget(Paragraph::new(lines).scroll((state.scroll_offset, 0)), body);
+
+    render_status_bar(
+        frame,
+        layout[2],
+        &[
+            ("j/k", "Scroll"),
+            ("r", "Refresh"),
+            ("b/Esc", "Back"),
+            ("q", "Quit"),
+            ("?", "Help"),
+        ],
+    );
+}
@@ -0,0 +1,386 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
+    Frame,
+};
+
+use crate::api::types::{CommunitySolution, QuestionDetail};
+
+use super::rich_text::{RenderOptions, markdown_to_lines};
+use super::status_bar::render_status_bar;
+use super::submissions::format_age;
+use super::theme::theme;
+
+/// Solution posts fetched per page.
+pub const PAGE_SIZE: usize = 15;
+
+/// Community solution posts for one problem, most upvoted first.
+pub struct SolutionsState {
+    pub detail: QuestionDetail,
+    pub solutions: Vec<CommunitySolution>,
+    pub total: i32,
+    pub table_state: TableState,
+    pub page: usize,
+    pub loading: bool,
+    pub error_message: Option<String>,
+    pub spinner_frame: usize,
+    // The opened post
+    pub viewing: Option<PostView>,
+}
+
+pub struct PostView {
+    pub solution: CommunitySolution,
+    /// None while loading.
+    pub lines: Option<Vec<Line<'static>>>,
+    pub error_message: Option<String>,
+    pub scroll_offset: u16,
+    pub content_height: u16,
+}
+
+impl PostView {
+    fn scroll(&mut self, delta: i32) {
+        let new_offset = self.scroll_offset as i32 + delta;
+        self.scroll_offset = new_offset.clamp(0, u16::MAX as i32) as u16;
+    }
+}
+
+impl SolutionsState {
+    pub fn new(detail: QuestionDetail) -> Self {
+        Self {
+            detail,
+            solutions: Vec::new(),
+            total: 0,
+            table_state: TableState::default(),
+            page: 0,
+            loading: true,
+            error_message: None,
+            spinner_frame: 0,
+            viewing: None,
+        }
+    }
+
+    pub fn set_page(&mut self, page: usize, solutions: Vec<CommunitySolution>, total: i32) {
+        self.page = page;
+        self.total = total;
+        self.loading = false;
+        self.error_message = None;
+        self.table_state
+            .select(if solutions.is_empty() { None } else { Some(0) });
+        self.solutions = solutions;
+    }
+
+    pub fn set_post(&mut self, id: i64, result: Result<String, String>, options: RenderOptions) {
+        let Some(ref mut view) = self.viewing else {
+            return;
+        };
+        if view.solution.id != id {
+            return;
+        }
+        match result {
+            Ok(content) => view.lines = Some(markdown_to_lines(&content, options)),
+            Err(e) => view.error_message = Some(e),
+        }
+    }
+
+    fn has_next(&self) -> bool {
+        ((self.page + 1) * PAGE_SIZE) < self.total.max(0) as usize
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> SolutionsAction {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return SolutionsAction::Quit;
+        }
+        if let Some(ref mut view) = self.viewing {
+            match key.code {
+                KeyCode::Char('b') | KeyCode::Esc => self.viewing = None,
+                KeyCode::Char('q') => return SolutionsAction::Quit,
+                KeyCode::Char('j') | KeyCode::Down => view.scroll(1),
+                KeyCode::Char('k') | KeyCode::Up => view.scroll(-1),
+                KeyCode::Char('d') => view.scroll(view.content_height as i32 / 2),
+                KeyCode::Char('u') => view.scroll(-(view.content_height as i32 / 2)),
+                KeyCode::Char('g') => view.scroll_offset = 0,
+                KeyCode::Char('G') => view.scroll_offset = u16::MAX,
+                _ => {}
+            }
+            return SolutionsAction::None;
+        }
+        match key.code {
+            KeyCode::Char('b') | KeyCode::Esc => SolutionsAction::Back,
+            KeyCode::Char('q') => SolutionsAction::Quit,
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.move_selection(1);
+                SolutionsAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.move_selection(-1);
+                SolutionsAction::None
+            }
+            KeyCode::Enter => {
+                let Some(solution) = self
+                    .table_state
+                    .selected()
+                    .and_then(|i| self.solutions.get(i))
+                else {
+                    return SolutionsAction::None;
+                };
+                let id = solution.id;
+                self.viewing = Some(PostView {
+                    solution: solution.clone(),
+                    lines: None,
+                    error_message: None,
+                    scroll_offset: 0,
+                    content_height: 0,
+                });
+                SolutionsAction::OpenPost(id)
+            }
+            KeyCode::Char('n') | KeyCode::Right if self.has_next() && !self.loading => {
+                self.loading = true;
+                SolutionsAction::FetchPage(self.page + 1)
+            }
+            KeyCode::Char('p') | KeyCode::Left if self.page > 0 && !self.loading => {
+                self.loading = true;
+                SolutionsAction::FetchPage(self.page - 1)
+            }
+            _ => SolutionsAction::None,
+        }
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        if self.solutions.is_empty() {
+            return;
+        }
+        let current = self.table_state.selected().unwrap_or(0) as i32;
+        let max = self.solutions.len() as i32 - 1;
+        let next = (current + delta).clamp(0, max) as usize;
+        self.table_state.select(Some(next));
+    }
+}
+
+pub enum SolutionsAction {
+    None,
+    Back,
+    Quit,
+    OpenPost(i64),
+    FetchPage(usize),
+}
+
+fn author(solution: &CommunitySolution) -> &str {
+    solution
+        .post
+        .author
+        .as_ref()
+        .map_or("[deleted]", |a| a.username.as_str())
+}
+
+pub fn render_solutions(frame: &mut Frame, area: Rect, state: &mut SolutionsState) {
+    let layout = Layout::vertical([
+        Constraint::Length(1), // title bar
+        Constraint::Min(3),    // content
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    // Title bar
+    let d = &state.detail;
+    let mut spans = vec![
+        Span::styled(
+            " Solutions ",
+            Style::default()
+                .fg(theme().badge_fg)
+                .bg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+    ];
+    match state.viewing {
+        Some(ref view) => spans.extend([
+            Span::styled(
+                format!("{} ", view.solution.title),
+                Style::default()
+                    .fg(theme().text)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "by {} \u{b7} \u{25b2} {} \u{b7} {} views",
+                    author(&view.solution),
+                    view.solution.post.vote_count,
+                    view.solution.view_count
+                ),
+                Style::default().fg(theme().muted),
+            ),
+        ]),
+        None => {
+            spans.push(Span::styled(
+                format!("{}. {} ", d.frontend_question_id, d.title),
+                Style::default()
+                    .fg(theme().text)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            spans.push(Span::styled(
+                format!("page {}", state.page + 1),
+                Style::default().fg(theme().muted),
+            ));
+            // Spoiler warning for problems not solved yet
+            if d.status.as_deref() != Some("ac") {
+                spans.push(Span::styled(
+                    "  not solved yet",
+                    Style::default().fg(theme().warning),
+                ));
+            }
+        }
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(spans)).style(Style::default().bg(theme().bar_bg)),
+        layout[0],
+    );
+
+    let spinner = [
+        "\u{280b}", "\u{2819}", "\u{2839}", "\u{2838}", "\u{283c}", "\u{2834}", "\u{2826}",
+        "\u{2827}", "\u{2807}", "\u{280f}",
+    ];
+    let s = spinner[state.spinner_frame % spinner.len()];
+    match state.viewing {
+        Some(ref mut view) => match (&view.lines, &view.error_message) {
+            (_, Some(err)) => {
+                let error = Paragraph::new(format!(" Error: {err}"))
+                    .style(Style::default().fg(theme().error));
+                frame.render_widget(error, layout[1]);
+            }
+            (None, None) => {
+                let loading = Paragraph::new(format!(" {s} Loading post..."))
+                    .style(Style::default().fg(theme().warning));
+                frame.render_widget(loading, layout[1]);
+            }
+            (Some(lines), None) => {
+                view.content_height = layout[1].height;
+                let max_scroll = (lines.len() as u16).saturating_sub(view.content_height);
+                view.scroll_offset = view.scroll_offset.min(max_scroll);
+
+                let padded: Vec<Line> = lines
+                    .iter()
+                    .map(|line| {
+                        let mut spans = vec![Span::raw("  ")];
+                        spans.extend(line.spans.iter().cloned());
+                        Line::from(spans)
+                    })
+                    .collect();
+                let post = Paragraph::new(padded)
+                    .wrap(Wrap { trim: false })
+                    .scroll((view.scroll_offset, 0));
+                frame.render_widget(post, layout[1]);
+            }
+        },
+        None if state.loading => {
+            let loading = Paragraph::new(format!(" {s} Loading solutions..."))
+                .style(Style::default().fg(theme().warning));
+            frame.render_widget(loading, layout[1]);
+        }
+        None => match state.error_message {
+            Some(ref err) => {
+                let error = Paragraph::new(format!(" Error: {err}"))
+                    .style(Style::default().fg(theme().error));
+                frame.render_widget(error, layout[1]);
+            }
+            None if state.solutions.is_empty() => {
+                let empty = Paragraph::new(" No community solutions yet.")
+                    .style(Style::default().fg(theme().muted));
+                frame.render_widget(empty, layout[1]);
+            }
+            None => render_solution_table(frame, layout[1], state),
+        },
+    }
+
+    // Status bar
+    let hints = if state.viewing.is_some() {
+        vec![
+            ("j/k", "Scroll"),
+            ("d/u", "Half page"),
+            ("b/Esc", "Back"),
+            ("?", "Help"),
+        ]
+    } else {
+        let mut hints = vec![("j/k", "Navigate"), ("Enter", "Read")];
+        if state.page > 0 {
+            hints.push(("p", "Prev page"));
+        }
+        if state.has_next() {
+            hints.push(("n", "Next page"));
+        }
+        hints.extend([("b/Esc", "Back"), ("q", "Quit"), ("?", "Help")]);
+        hints
+    };
+    render_status_bar(frame, layout[2], &hints);
+}
+
+fn render_solution_table(frame: &mut Frame, area: Rect, state: &mut SolutionsState) {
+    let header = Row::new([
+        Cell::from(" Votes"),
+        Cell::from("Title"),
+        Cell::from("Author"),
+        Cell::from("Tags"),
+        Cell::from("Posted"),
+    ])
+    .style(
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let rows: Vec<Row> = state
+        .solutions
+        .iter()
+        .map(|s| {
+            let tags = s
+                .solution_tags
+                .iter()
+                .map(|t| t.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            Row::new([
+                Cell::from(Span::styled(
+                    format!(" {}", s.post.vote_count),
+                    Style::default().fg(theme().success),
+                )),
+                Cell::from(s.title.clone()),
+                Cell::from(Span::styled(
+                    author(s).to_string(),
+                    Style::default().fg(theme().special),
+                )),
+                Cell::from(Span::styled(tags, Style::default().fg(theme().subtle))),
+                Cell::from(Span::styled(
+                    format_age(s.post.creation_date.max(0) as u64, now),
+                    Style::default().fg(theme().muted),
+                )),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(7),
+        Constraint::Min(30),
+        Constraint::Length(18),
+        Constraint::Length(24),
+        Constraint::Length(9),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::NONE))
+        .row_highlight_style(
+            Style::default()
+                .bg(theme().selection)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{25b8} ");
+
+    frame.render_stateful_widget(table, area, &mut state.table_state);
+}
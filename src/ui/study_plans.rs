@@ -0,0 +1,442 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::api::types::{StudyPlan, StudyPlanDetail};
+
+use super::status_bar::render_status_bar;
+use super::theme::theme;
+
+/// Popular plans offered alongside the enrolled ones, so there's something
+/// to open before enrolling (or when signed out).
+const FEATURED: &[(&str, &str)] = &[
+    ("top-interview-150", "Top Interview 150"),
+    ("leetcode-75", "LeetCode 75"),
+    ("top-100-liked", "Top 100 Liked"),
+    ("sql-50", "SQL 50"),
+    ("30-days-of-javascript", "30 Days of JavaScript"),
+];
+
+pub struct StudyPlansState {
+    /// Enrolled plans, then featured ones not already enrolled in.
+    pub plans: Vec<StudyPlan>,
+    pub enrolled: usize,
+    pub loading: bool,
+    pub error_message: Option<String>,
+    pub spinner_frame: usize,
+    pub table_state: TableState,
+    // The opened plan
+    pub viewing: Option<PlanView>,
+}
+
+pub struct PlanView {
+    pub slug: String,
+    pub name: String,
+    /// None while loading.
+    pub detail: Option<StudyPlanDetail>,
+    pub error_message: Option<String>,
+    pub table_state: TableState,
+}
+
+/// A row of the plan table: a group heading or one of its problems.
+#[derive(Clone, Copy)]
+enum PlanRow {
+    Group(usize),
+    Question(usize, usize),
+}
+
+impl PlanView {
+    fn rows(&self) -> Vec<PlanRow> {
+        let Some(ref detail) = self.detail else {
+            return Vec::new();
+        };
+        detail
+            .plan_sub_groups
+            .iter()
+            .enumerate()
+            .flat_map(|(g, group)| {
+                std::iter::once(PlanRow::Group(g))
+                    .chain((0..group.questions.len()).map(move |q| PlanRow::Question(g, q)))
+            })
+            .collect()
+    }
+
+    /// Move to the next problem row in `delta`'s direction, skipping headings.
+    fn move_selection(&mut self, delta: i32) {
+        let rows = self.rows();
+        let mut i = self.table_state.selected().unwrap_or(0) as i32;
+        loop {
+            i += delta;
+            match rows.get(i.max(0) as usize) {
+                _ if i < 0 => return,
+                None => return,
+                Some(PlanRow::Question(..)) => break,
+                Some(PlanRow::Group(_)) => {}
+            }
+        }
+        self.table_state.select(Some(i as usize));
+    }
+
+    fn selected_slug(&self) -> Option<String> {
+        let detail = self.detail.as_ref()?;
+        match self.rows().get(self.table_state.selected()?)? {
+            PlanRow::Question(g, q) => {
+                Some(detail.plan_sub_groups[*g].questions[*q].title_slug.clone())
+            }
+            PlanRow::Group(_) => None,
+        }
+    }
+}
+
+impl StudyPlansState {
+    pub fn new() -> Self {
+        let mut state = Self {
+            plans: Vec::new(),
+            enrolled: 0,
+            loading: true,
+            error_message: None,
+            spinner_frame: 0,
+            table_state: TableState::default(),
+            viewing: None,
+        };
+        state.set_plans(Vec::new());
+        state.loading = true;
+        state
+    }
+
+    pub fn set_plans(&mut self, enrolled: Vec<StudyPlan>) {
+        self.enrolled = enrolled.len();
+        self.plans = enrolled;
+        for &(slug, name) in FEATURED {
+            if !self.plans.iter().any(|p| p.slug == slug) {
+                self.plans.push(StudyPlan {
+                    slug: slug.to_string(),
+                    name: name.to_string(),
+                    question_num: 0,
+                    finished_question_num: 0,
+                });
+            }
+        }
+        self.loading = false;
+        if self
+            .table_state
+            .selected()
+            .is_none_or(|i| i >= self.plans.len())
+        {
+            self.table_state.select(Some(0));
+        }
+    }
+
+    pub fn set_plan(&mut self, slug: &str, result: Result<StudyPlanDetail, String>) {
+        let Some(ref mut view) = self.viewing else {
+            return;
+        };
+        if view.slug != slug {
+            return;
+        }
+        match result {
+            Ok(detail) => {
+                view.name = detail.name.clone();
+                view.detail = Some(detail);
+                // First problem, just below the first heading
+                view.table_state.select(Some(0));
+                view.move_selection(1);
+            }
+            Err(e) => view.error_message = Some(e),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> StudyPlansAction {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return StudyPlansAction::Quit;
+        }
+        if let Some(ref mut view) = self.viewing {
+            return match key.code {
+                KeyCode::Esc | KeyCode::Char('b') => {
+                    self.viewing = None;
+                    StudyPlansAction::None
+                }
+                KeyCode::Char('q') => StudyPlansAction::Quit,
+                KeyCode::Char('j') | KeyCode::Down => {
+                    view.move_selection(1);
+                    StudyPlansAction::None
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    view.move_selection(-1);
+                    StudyPlansAction::None
+                }
+                KeyCode::Enter => match view.selected_slug() {
+                    Some(slug) => StudyPlansAction::OpenDetail(slug),
+                    None => StudyPlansAction::None,
+                },
+                _ => StudyPlansAction::None,
+            };
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') => StudyPlansAction::Back,
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.move_selection(1);
+                StudyPlansAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.move_selection(-1);
+                StudyPlansAction::None
+            }
+            KeyCode::Enter => {
+                let Some(plan) = self.table_state.selected().and_then(|i| self.plans.get(i)) else {
+                    return StudyPlansAction::None;
+                };
+                let slug = plan.slug.clone();
+                self.viewing = Some(PlanView {
+                    slug: slug.clone(),
+                    name: plan.name.clone(),
+                    detail: None,
+                    error_message: None,
+                    table_state: TableState::default(),
+                });
+                StudyPlansAction::OpenPlan(slug)
+            }
+            _ => StudyPlansAction::None,
+        }
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        if self.plans.is_empty() {
+            return;
+        }
+        let current = self.table_state.selected().unwrap_or(0) as i32;
+        let max = self.plans.len() as i32 - 1;
+        let next = (current + delta).clamp(0, max) as usize;
+        self.table_state.select(Some(next));
+    }
+}
+
+pub enum StudyPlansAction {
+    None,
+    Back,
+    Quit,
+    OpenPlan(String),
+    OpenDetail(String),
+}
+
+/// "████░░░░ 12/150".
+fn progress_bar(solved: usize, total: usize, width: usize) -> Vec<Span<'static>> {
+    let filled = (solved * width).checked_div(total).unwrap_or(0);
+    vec![
+        Span::styled(
+            "\u{2588}".repeat(filled),
+            Style::default().fg(theme().success),
+        ),
+        Span::styled(
+            "\u{2591}".repeat(width - filled),
+            Style::default().fg(theme().muted),
+        ),
+        Span::styled(
+            format!(" {solved}/{total}"),
+            Style::default().fg(theme().subtle),
+        ),
+    ]
+}
+
+pub fn render_study_plans(frame: &mut Frame, area: Rect, state: &mut StudyPlansState) {
+    let layout = Layout::vertical([
+        Constraint::Length(1), // title bar
+        Constraint::Min(3),    // content
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    let mut spans = vec![
+        Span::styled(
+            " Study Plans ",
+            Style::default()
+                .fg(theme().badge_fg)
+                .bg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+    ];
+    match state.viewing {
+        Some(ref view) => {
+            spans.push(Span::styled(
+                format!("{} ", view.name),
+                Style::default()
+                    .fg(theme().text)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            if let Some(ref detail) = view.detail {
+                let (solved, total) = detail.progress();
+                spans.extend(progress_bar(solved, total, 20));
+            }
+        }
+        None => spans.push(Span::styled(
+            format!("{} in progress", state.enrolled),
+            Style::default().fg(theme().muted),
+        )),
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(spans)).style(Style::default().bg(theme().bar_bg)),
+        layout[0],
+    );
+
+    let spinner = [
+        "\u{280b}", "\u{2819}", "\u{2839}", "\u{2838}", "\u{283c}", "\u{2834}", "\u{2826}",
+        "\u{2827}", "\u{2807}", "\u{280f}",
+    ];
+    let s = spinner[state.spinner_frame % spinner.len()];
+    match state.viewing {
+        Some(ref mut view) => match (&view.detail, &view.error_message) {
+            (_, Some(err)) => {
+                let error = Paragraph::new(format!(" Error: {err}"))
+                    .style(Style::default().fg(theme().error));
+                frame.render_widget(error, layout[1]);
+            }
+            (None, None) => {
+                let loading = Paragraph::new(format!(" {s} Loading plan..."))
+                    .style(Style::default().fg(theme().warning));
+                frame.render_widget(loading, layout[1]);
+            }
+            (Some(_), None) => render_plan_table(frame, layout[1], view),
+        },
+        None if state.loading => {
+            let loading = Paragraph::new(format!(" {s} Loading study plans..."))
+                .style(Style::default().fg(theme().warning));
+            frame.render_widget(loading, layout[1]);
+        }
+        None => render_plan_list(frame, layout[1], state),
+    }
+
+    let hints = if state.viewing.is_some() {
+        vec![
+            ("j/k", "Navigate"),
+            ("Enter", "View"),
+            ("b/Esc", "Back"),
+            ("?", "Help"),
+        ]
+    } else {
+        vec![
+            ("j/k", "Navigate"),
+            ("Enter", "Open"),
+            ("Esc", "Back"),
+            ("?", "Help"),
+        ]
+    };
+    render_status_bar(frame, layout[2], &hints);
+}
+
+fn render_plan_list(frame: &mut Frame, area: Rect, state: &mut StudyPlansState) {
+    let layout = Layout::vertical([Constraint::Length(1), Constraint::Min(1)]).split(area);
+    if let Some(ref err) = state.error_message {
+        let error = Paragraph::new(format!(" Couldn't load your plans: {err}"))
+            .style(Style::default().fg(theme().error));
+        frame.render_widget(error, layout[0]);
+    }
+
+    let header = Row::new([Cell::from("Plan"), Cell::from("Progress")]).style(
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let rows: Vec<Row> = state
+        .plans
+        .iter()
+        .enumerate()
+        .map(|(i, plan)| {
+            let progress = if i < state.enrolled {
+                Line::from(progress_bar(
+                    plan.finished_question_num.max(0) as usize,
+                    plan.question_num.max(0) as usize,
+                    16,
+                ))
+            } else {
+                Line::from(Span::styled(
+                    "not started",
+                    Style::default().fg(theme().muted),
+                ))
+            };
+            Row::new([Cell::from(format!(" {}", plan.name)), Cell::from(progress)])
+        })
+        .collect();
+
+    let widths = [Constraint::Min(24), Constraint::Length(28)];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::NONE))
+        .row_highlight_style(
+            Style::default()
+                .bg(theme().selection)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{25b8} ");
+
+    frame.render_stateful_widget(table, layout[1], &mut state.table_state);
+}
+
+fn render_plan_table(frame: &mut Frame, area: Rect, view: &mut PlanView) {
+    let Some(ref detail) = view.detail else {
+        return;
+    };
+
+    let rows: Vec<Row> = view
+        .rows()
+        .into_iter()
+        .map(|row| match row {
+            PlanRow::Group(g) => {
+                let group = &detail.plan_sub_groups[g];
+                let (solved, total) = group.progress();
+                let mut spans = vec![Span::styled(
+                    format!("{}  ", group.name),
+                    Style::default()
+                        .fg(theme().accent)
+                        .add_modifier(Modifier::BOLD),
+                )];
+                spans.extend(progress_bar(solved, total, 10));
+                Row::new([Cell::from(""), Cell::from(Line::from(spans))])
+            }
+            PlanRow::Question(g, q) => {
+                let q = &detail.plan_sub_groups[g].questions[q];
+                let status = match q.status.as_deref() {
+                    Some("ac") => Span::styled(" \u{2714}", Style::default().fg(theme().success)),
+                    Some("notac") => {
+                        Span::styled(" \u{25cf}", Style::default().fg(theme().warning))
+                    }
+                    _ => Span::raw("  "),
+                };
+                let mut title = vec![
+                    Span::raw(format!("  {}. {}", q.frontend_question_id, q.title)),
+                    Span::styled(
+                        format!("  {}", q.difficulty),
+                        Style::default().fg(theme().difficulty(&q.difficulty)),
+                    ),
+                ];
+                if q.paid_only {
+                    title.push(Span::styled(
+                        "  \u{1f512}",
+                        Style::default().fg(theme().warning),
+                    ));
+                }
+                Row::new([Cell::from(status), Cell::from(Line::from(title))])
+            }
+        })
+        .collect();
+
+    let widths = [Constraint::Length(3), Constraint::Min(20)];
+
+    let table = Table::new(rows, widths)
+        .block(Block::default().borders(Borders::NONE))
+        .row_highlight_style(
+            Style::default()
+                .bg(theme().selection)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{25b8} ");
+
+    frame.render_stateful_widget(table, area, &mut view.table_state);
+}
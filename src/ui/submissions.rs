@@ -0,0 +1,267 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::api::types::{QuestionDetail, SubmissionSummary};
+
+use super::status_bar::render_status_bar;
+
+/// Submissions fetched per page.
+pub const PAGE_SIZE: usize = 20;
+
+/// Past submissions for one problem, a page at a time.
+pub struct SubmissionsState {
+    pub detail: QuestionDetail,
+    pub submissions: Vec<SubmissionSummary>,
+    pub table_state: TableState,
+    pub page: usize,
+    pub has_next: bool,
+    pub loading: bool,
+    pub error_message: Option<String>,
+    pub spinner_frame: usize,
+}
+
+impl SubmissionsState {
+    pub fn new(detail: QuestionDetail) -> Self {
+        Self {
+            detail,
+            submissions: Vec::new(),
+            table_state: TableState::default(),
+            page: 0,
+            has_next: false,
+            loading: true,
+            error_message: None,
+            spinner_frame: 0,
+        }
+    }
+
+    pub fn set_page(&mut self, page: usize, submissions: Vec<SubmissionSummary>, has_next: bool) {
+        self.page = page;
+        self.has_next = has_next;
+        self.loading = false;
+        self.error_message = None;
+        self.table_state
+            .select(if submissions.is_empty() { None } else { Some(0) });
+        self.submissions = submissions;
+    }
+
+    pub fn selected(&self) -> Option<&SubmissionSummary> {
+        self.table_state
+            .selected()
+            .and_then(|i| self.submissions.get(i))
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> SubmissionsAction {
+        match key.code {
+            KeyCode::Char('b') | KeyCode::Esc => SubmissionsAction::Back,
+            KeyCode::Char('q') => SubmissionsAction::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                SubmissionsAction::Quit
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.move_selection(1);
+                SubmissionsAction::None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.move_selection(-1);
+                SubmissionsAction::None
+            }
+            KeyCode::Enter => match self.selected() {
+                Some(s) => SubmissionsAction::View(s.id.clone()),
+                None => SubmissionsAction::None,
+            },
+            KeyCode::Char('O') => match self.selected() {
+                Some(s) => SubmissionsAction::Open(s.id.clone()),
+                None => SubmissionsAction::None,
+            },
+            KeyCode::Char('s') => match self.selected() {
+                Some(s) => SubmissionsAction::Resubmit(s.id.clone()),
+                None => SubmissionsAction::None,
+            },
+            KeyCode::Char('n') | KeyCode::Right if self.has_next && !self.loading => {
+                self.loading = true;
+                SubmissionsAction::FetchPage(self.page + 1)
+            }
+            KeyCode::Char('p') | KeyCode::Left if self.page > 0 && !self.loading => {
+                self.loading = true;
+                SubmissionsAction::FetchPage(self.page - 1)
+            }
+            _ => SubmissionsAction::None,
+        }
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        if self.submissions.is_empty() {
+            return;
+        }
+        let current = self.table_state.selected().unwrap_or(0) as i32;
+        let max = self.submissions.len() as i32 - 1;
+        let next = (current + delta).clamp(0, max) as usize;
+        self.table_state.select(Some(next));
+    }
+}
+
+pub enum SubmissionsAction {
+    None,
+    Back,
+    Quit,
+    /// Show the submitted code
+    View(String),
+    /// Open the submission on LeetCode
+    Open(String),
+    /// Submit the same code again
+    Resubmit(String),
+    FetchPage(usize),
+}
+
+/// Coarse age of a unix timestamp relative to `now`: "just now", "5m ago",
+/// "3h ago", "12d ago", "4mo ago", "2y ago".
+pub fn format_age(timestamp: u64, now: u64) -> String {
+    let secs = now.saturating_sub(timestamp);
+    match secs {
+        0..60 => "just now".to_string(),
+        60..3600 => format!("{}m ago", secs / 60),
+        3600..86_400 => format!("{}h ago", secs / 3600),
+        86_400..2_592_000 => format!("{}d ago", secs / 86_400),
+        2_592_000..31_536_000 => format!("{}mo ago", secs / 2_592_000),
+        _ => format!("{}y ago", secs / 31_536_000),
+    }
+}
+
+pub fn render_submissions(frame: &mut Frame, area: Rect, state: &mut SubmissionsState) {
+    let layout = Layout::vertical([
+        Constraint::Length(1), // title bar
+        Constraint::Min(3),   // content
+        Constraint::Length(1), // status bar
+    ])
+    .split(area);
+
+    // Title bar
+    let d = &state.detail;
+    let title = Line::from(vec![
+        Span::styled(
+            " Submissions ",
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+        Span::styled(
+            format!("{}. {} ", d.frontend_question_id, d.title),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("page {}", state.page + 1),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ]);
+    frame.render_widget(
+        Paragraph::new(title).style(Style::default().bg(Color::Black)),
+        layout[0],
+    );
+
+    // Content
+    if state.loading {
+        let spinner = ["\u{280b}", "\u{2819}", "\u{2839}", "\u{2838}", "\u{283c}", "\u{2834}", "\u{2826}", "\u{2827}", "\u{2807}", "\u{280f}"];
+        let s = spinner[state.spinner_frame % spinner.len()];
+        let loading = Paragraph::new(format!(" {s} Loading submissions..."))
+            .style(Style::default().fg(Color::Yellow));
+        frame.render_widget(loading, layout[1]);
+    } else if let Some(ref err) = state.error_message {
+        let error = Paragraph::new(format!(" Error: {err}"))
+            .style(Style::default().fg(Color::Red));
+        frame.render_widget(error, layout[1]);
+    } else if state.submissions.is_empty() {
+        let empty = Paragraph::new(" No submissions yet.")
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(empty, layout[1]);
+    } else {
+        render_submission_table(frame, layout[1], state);
+    }
+
+    // Status bar
+    let mut hints = vec![("j/k", "Navigate"), ("Enter", "View code"), ("O", "Browser"), ("s", "Resubmit")];
+    if state.page > 0 {
+        hints.push(("p", "Prev page"));
+    }
+    if state.has_next {
+        hints.push(("n", "Next page"));
+    }
+    hints.extend([("b/Esc", "Back"), ("q", "Quit"), ("?", "Help")]);
+    render_status_bar(frame, layout[2], &hints);
+}
+
+fn render_submission_table(frame: &mut Frame, area: Rect, state: &mut SubmissionsState) {
+    let header = Row::new([
+        Cell::from(" Status"),
+        Cell::from("Language"),
+        Cell::from("Runtime"),
+        Cell::from("Memory"),
+        Cell::from("Submitted"),
+    ])
+    .style(
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let rows: Vec<Row> = state
+        .submissions
+        .iter()
+        .map(|s| {
+            let color = if s.status_display == "Accepted" {
+                Color::Green
+            } else {
+                Color::Red
+            };
+            let age = s
+                .timestamp
+                .parse()
+                .map(|ts| format_age(ts, now))
+                .unwrap_or_default();
+            Row::new([
+                Cell::from(Span::styled(
+                    format!(" {}", s.status_display),
+                    Style::default().fg(color),
+                )),
+                Cell::from(s.lang_name.clone().unwrap_or_else(|| s.lang.clone())),
+                Cell::from(s.runtime.clone()),
+                Cell::from(s.memory.clone()),
+                Cell::from(Span::styled(age, Style::default().fg(Color::DarkGray))),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Min(22),
+        Constraint::Length(12),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(10),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::NONE))
+        .row_highlight_style(
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("\u{25b8} ");
+
+    frame.render_stateful_widget(table, area, &mut state.table_state);
+}
@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+/// Built-in color schemes, selected with `theme` in the config.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    Gruvbox,
+    Solarized,
+}
+
+/// Colors used across the screens. Fields are named by role rather than
+/// hue so light themes can remap them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub easy: Color,
+    pub medium: Color,
+    pub hard: Color,
+    /// Headers, borders of focused elements, links.
+    pub accent: Color,
+    /// List markers, filter summaries, secondary badges.
+    pub special: Color,
+    pub text: Color,
+    /// Less important text (test input echoes).
+    pub subtle: Color,
+    /// Hints, separators, inactive elements.
+    pub muted: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    /// Background of title bars.
+    pub bar_bg: Color,
+    /// Text on colored badges.
+    pub badge_fg: Color,
+    /// Background of the selected table row.
+    pub selection: Color,
+    /// Background of tag chips.
+    pub surface: Color,
+}
+
+impl Theme {
+    pub const DARK: Theme = Theme {
+        easy: Color::Green,
+        medium: Color::Yellow,
+        hard: Color::Red,
+        accent: Color::Cyan,
+        special: Color::Magenta,
+        text: Color::White,
+        subtle: Color::Gray,
+        muted: Color::DarkGray,
+        success: Color::Green,
+        warning: Color::Yellow,
+        error: Color::Red,
+        bar_bg: Color::Black,
+        badge_fg: Color::Black,
+        selection: Color::DarkGray,
+        surface: Color::DarkGray,
+    };
+
+    pub const LIGHT: Theme = Theme {
+        easy: Color::Rgb(0x1a, 0x7f, 0x37),
+        medium: Color::Rgb(0x9a, 0x67, 0x00),
+        hard: Color::Rgb(0xcf, 0x22, 0x2e),
+        accent: Color::Rgb(0x09, 0x69, 0xda),
+        special: Color::Rgb(0x82, 0x50, 0xdf),
+        text: Color::Rgb(0x1f, 0x23, 0x28),
+        subtle: Color::Rgb(0x42, 0x4a, 0x53),
+        muted: Color::Rgb(0x6e, 0x77, 0x81),
+        success: Color::Rgb(0x1a, 0x7f, 0x37),
+        warning: Color::Rgb(0x9a, 0x67, 0x00),
+        error: Color::Rgb(0xcf, 0x22, 0x2e),
+        bar_bg: Color::Rgb(0xea, 0xee, 0xf2),
+        badge_fg: Color::Rgb(0xff, 0xff, 0xff),
+        selection: Color::Rgb(0xd0, 0xd7, 0xde),
+        surface: Color::Rgb(0xd0, 0xd7, 0xde),
+    };
+
+    pub const GRUVBOX: Theme = Theme {
+        easy: Color::Rgb(0xb8, 0xbb, 0x26),
+        medium: Color::Rgb(0xfa, 0xbd, 0x2f),
+        hard: Color::Rgb(0xfb, 0x49, 0x34),
+        accent: Color::Rgb(0x83, 0xa5, 0x98),
+        special: Color::Rgb(0xd3, 0x86, 0x9b),
+        text: Color::Rgb(0xeb, 0xdb, 0xb2),
+        subtle: Color::Rgb(0xbd, 0xae, 0x93),
+        muted: Color::Rgb(0x92, 0x83, 0x74),
+        success: Color::Rgb(0xb8, 0xbb, 0x26),
+        warning: Color::Rgb(0xfa, 0xbd, 0x2f),
+        error: Color::Rgb(0xfb, 0x49, 0x34),
+        bar_bg: Color::Rgb(0x28, 0x28, 0x28),
+        badge_fg: Color::Rgb(0x28, 0x28, 0x28),
+        selection: Color::Rgb(0x50, 0x49, 0x45),
+        surface: Color::Rgb(0x50, 0x49, 0x45),
+    };
+
+    pub const SOLARIZED: Theme = Theme {
+        easy: Color::Rgb(0x85, 0x99, 0x00),
+        medium: Color::Rgb(0xb5, 0x89, 0x00),
+        hard: Color::Rgb(0xdc, 0x32, 0x2f),
+        accent: Color::Rgb(0x2a, 0xa1, 0x98),
+        special: Color::Rgb(0xd3, 0x36, 0x82),
+        text: Color::Rgb(0x93, 0xa1, 0xa1),
+        subtle: Color::Rgb(0x83, 0x94, 0x96),
+        muted: Color::Rgb(0x58, 0x6e, 0x75),
+        success: Color::Rgb(0x85, 0x99, 0x00),
+        warning: Color::Rgb(0xb5, 0x89, 0x00),
+        error: Color::Rgb(0xdc, 0x32, 0x2f),
+        bar_bg: Color::Rgb(0x00, 0x2b, 0x36),
+        badge_fg: Color::Rgb(0x00, 0x2b, 0x36),
+        selection: Color::Rgb(0x07, 0x36, 0x42),
+        surface: Color::Rgb(0x07, 0x36, 0x42),
+    };
+
+    pub fn named(name: ThemeName) -> Theme {
+        match name {
+            ThemeName::Dark => Self::DARK,
+            ThemeName::Light => Self::LIGHT,
+            ThemeName::Gruvbox => Self::GRUVBOX,
+            ThemeName::Solarized => Self::SOLARIZED,
+        }
+    }
+
+    /// Apply `role = "color"` overrides from the config. Colors are ratatui
+    /// names ("red", "lightblue"), "#rrggbb", or a 0-255 palette index.
+    /// Returns the roles or colors that weren't understood.
+    pub fn with_overrides(mut self, overrides: &BTreeMap<String, String>) -> (Theme, Vec<String>) {
+        let mut unknown = Vec::new();
+        for (role, value) in overrides {
+            let Ok(color) = value.parse::<Color>() else {
+                unknown.push(format!("{role} = {value:?}"));
+                continue;
+            };
+            let slot = match role.as_str() {
+                "easy" => &mut self.easy,
+                "medium" => &mut self.medium,
+                "hard" => &mut self.hard,
+                "accent" => &mut self.accent,
+                "special" => &mut self.special,
+                "text" => &mut self.text,
+                "subtle" => &mut self.subtle,
+                "muted" => &mut self.muted,
+                "success" => &mut self.success,
+                "warning" => &mut self.warning,
+                "error" => &mut self.error,
+                "bar_bg" => &mut self.bar_bg,
+                "badge_fg" => &mut self.badge_fg,
+                "selection" => &mut self.selection,
+                "surface" => &mut self.surface,
+                _ => {
+                    unknown.push(role.clone());
+                    continue;
+                }
+            };
+            *slot = color;
+        }
+        (self, unknown)
+    }
+
+    pub fn difficulty(&self, difficulty: &str) -> Color {
+        match difficulty {
+            "Easy" => self.easy,
+            "Medium" => self.medium,
+            "Hard" => self.hard,
+            _ => self.text,
+        }
+    }
+}
+
+static CURRENT: RwLock<Theme> = RwLock::new(Theme::DARK);
+
+/// The active theme. Render code reads this rather than threading the
+/// theme through every widget helper.
+pub fn theme() -> Theme {
+    *CURRENT.read().unwrap_or_else(|e| e.into_inner())
+}
+
+pub fn set_theme(theme: Theme) {
+    *CURRENT.write().unwrap_or_else(|e| e.into_inner()) = theme;
+}